@@ -0,0 +1,142 @@
+//! Generate the tests of the solutions from the test data.
+//!
+//! Every expected output `tests/test-data/output/yearYYYY/dayDD/partN.txt` gets a test
+//! named `yearYYYY_dayDD_partN`, which is included by `tests/puzzles.rs`.
+//! A day may have more inputs `tests/test-data/input/yearYYYY/dayDD/NAME.txt`,
+//! whose expected outputs `tests/test-data/output/yearYYYY/dayDD/NAME/partN.txt`
+//! get tests named `yearYYYY_dayDD_NAME_partN`.
+//! Every day with a main input `tests/test-data/input/yearYYYY/dayDD.txt` also gets a fuzz target
+//! named `yearYYYY_dayDD`, which is included by `tests/fuzz.rs` and only runs with the `fuzz` feature.
+//! The published crate has no test data, so no tests are generated for it.
+
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+fn main() -> io::Result<()> {
+    let data = Path::new("tests").join("test-data");
+    println!("cargo:rerun-if-changed={}", data.display());
+
+    let mut source = String::new();
+    for (year, day, name, part) in outputs(&data.join("output"))? {
+        let (test, input) = match name {
+            Some(name) => (
+                format!(
+                    "year{:04}_day{:02}_{}_part{}",
+                    year,
+                    day,
+                    name.replace('-', "_"),
+                    part
+                ),
+                format!("Some({:?})", name),
+            ),
+            None => (
+                format!("year{:04}_day{:02}_part{}", year, day, part),
+                String::from("None"),
+            ),
+        };
+        source += &format!(
+            "#[test]\nfn {}() {{\n    run_test({}, {}, {}, {});\n}}\n\n",
+            test, year, day, input, part
+        );
+    }
+
+    let mut fuzz_source = String::new();
+    for (year, year_dir) in entries(&data.join("input"), "year", "")? {
+        let mut days = entries(&year_dir, "day", ".txt")?;
+        days.sort_unstable();
+        for (day, _) in days {
+            fuzz_source += &format!(
+                "#[test]\nfn year{:04}_day{:02}() {{\n    fuzz({}, {});\n}}\n\n",
+                year, day, year, day
+            );
+        }
+    }
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo"));
+    fs::write(out_dir.join("puzzles.rs"), source)?;
+    fs::write(out_dir.join("fuzz.rs"), fuzz_source)
+}
+
+/// The year, day, name of the input, if it is not the main one, and part of an expected output.
+type Output = (usize, usize, Option<String>, usize);
+
+/// Find the expected outputs in the test data.
+/// # Returns
+/// * The [Output]s, sorted, or the [io::Error].
+/// # Errors
+/// * [io::Error] - The test data cannot be read, the name of an input is not a part of an identifier
+///   or two names of inputs of a day only differ in '_' and '-', so their tests have the same name.
+fn outputs(dir: &Path) -> io::Result<Vec<Output>> {
+    let mut outputs = Vec::new();
+    // the names of the inputs by the names of their tests
+    let mut tests = HashMap::new();
+    for (year, year_dir) in entries(dir, "year", "")? {
+        for (day, day_dir) in entries(&year_dir, "day", "")? {
+            for (part, _) in entries(&day_dir, "part", ".txt")? {
+                outputs.push((year, day, None, part));
+            }
+
+            for entry in fs::read_dir(&day_dir)? {
+                let entry = entry?;
+                if !entry.file_type()?.is_dir() {
+                    continue;
+                }
+                let name = entry.file_name().into_string().ok().filter(|name| {
+                    !name.is_empty()
+                        && name
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+                });
+                let Some(name) = name else {
+                    return Err(io::Error::other(format!(
+                        "{}: the name of an input may only contain letters, digits, '_' and '-'",
+                        entry.path().display()
+                    )));
+                };
+                if let Some(other) = tests.insert((year, day, name.replace('-', "_")), name.clone())
+                {
+                    return Err(io::Error::other(format!(
+                        "{}: the input {:?} has the same test name as the input {:?}",
+                        entry.path().display(),
+                        name,
+                        other
+                    )));
+                }
+                for (part, _) in entries(&entry.path(), "part", ".txt")? {
+                    outputs.push((year, day, Some(name.clone()), part));
+                }
+            }
+        }
+    }
+    outputs.sort_unstable();
+    Ok(outputs)
+}
+
+/// Get the entries of a directory whose names are a number between the prefix and the suffix.
+/// A missing directory has no entries.
+fn entries(dir: &Path, prefix: &str, suffix: &str) -> io::Result<Vec<(usize, PathBuf)>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    let mut numbered = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name();
+        let number = name.to_str().and_then(|name| {
+            name.strip_prefix(prefix)?
+                .strip_suffix(suffix)?
+                .parse()
+                .ok()
+        });
+        if let Some(number) = number {
+            numbered.push((number, entry.path()));
+        }
+    }
+    Ok(numbered)
+}
@@ -0,0 +1,178 @@
+//! The typed answer to a part of a day's challenge.
+
+use advent_of_code_ocr::parse_string_to_letters;
+use std::convert::Infallible;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// An enum representing the answer to a part of a day's challenge.
+///
+/// Answers compare equal to the strings a user would submit on the website,
+/// ignoring surrounding whitespace, e.g. `Answer::from(42) == " 42\n"`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Answer {
+    /// An integer answer which fits into an [i64].
+    Integer(i64),
+    /// An integer answer which does not fit into an [i64].
+    BigInteger(i128),
+    /// A textual answer, e.g. a password or the letters displayed on a screen.
+    Text(String),
+    /// A rendered grid of characters, one string per row.
+    /// It is used when the answer is drawn on a screen but the letters cannot be recognized.
+    Grid(Vec<String>),
+}
+impl Answer {
+    /// Get the integer value of the answer.
+    /// # Returns
+    /// * The value of an [Answer::Integer] or [Answer::BigInteger], otherwise [None].
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Self::Integer(value) => Some(i128::from(*value)),
+            Self::BigInteger(value) => Some(*value),
+            Self::Text(_) | Self::Grid(_) => None,
+        }
+    }
+
+    /// Get the text of the answer.
+    /// # Returns
+    /// * The text of an [Answer::Text], otherwise [None].
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            Self::Integer(_) | Self::BigInteger(_) | Self::Grid(_) => None,
+        }
+    }
+
+    /// Get the rows of the rendered grid.
+    /// # Returns
+    /// * The rows of an [Answer::Grid], otherwise [None].
+    pub fn as_grid(&self) -> Option<&[String]> {
+        match self {
+            Self::Grid(rows) => Some(rows),
+            Self::Integer(_) | Self::BigInteger(_) | Self::Text(_) => None,
+        }
+    }
+
+    /// Check whether the answer is numeric.
+    /// # Returns
+    /// * `true` for an [Answer::Integer] or [Answer::BigInteger], otherwise `false`.
+    pub fn is_integer(&self) -> bool {
+        self.as_integer().is_some()
+    }
+
+    /// Read the letters displayed on a screen of lit (`#`) and dark (`.`) pixels.
+    /// # Arguments
+    /// * `rows` - The rows of the screen.
+    /// # Returns
+    /// * The [Answer::Text] with the displayed letters or the [Answer::Grid]
+    ///   with the rows of the screen if not every letter can be recognized.
+    pub(crate) fn from_screen(rows: Vec<String>) -> Self {
+        let width = rows.first().map_or(0, |row| row.chars().count());
+        // every letter is 4 pixels wide and followed by an empty column
+        let letters = parse_string_to_letters(&rows.join("\n"));
+        if letters.chars().count() == width.div_ceil(5) {
+            Self::Text(letters)
+        } else {
+            Self::Grid(rows)
+        }
+    }
+}
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{}", value),
+            Self::BigInteger(value) => write!(f, "{}", value),
+            Self::Text(text) => write!(f, "{}", text),
+            Self::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Parse a submitted answer, ignoring surrounding whitespace.
+    /// Integers become [Answer::Integer] or [Answer::BigInteger], multiple lines
+    /// become an [Answer::Grid] and anything else becomes an [Answer::Text].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(value) = s.parse::<i128>() {
+            Ok(Self::from(value))
+        } else if s.contains('\n') {
+            Ok(Self::Grid(
+                s.lines().map(|row| row.trim().to_string()).collect(),
+            ))
+        } else {
+            Ok(Self::Text(s.to_string()))
+        }
+    }
+}
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Self::Integer(_) | Self::BigInteger(_) => {
+                other.trim().parse::<i128>().ok() == self.as_integer()
+            }
+            Self::Text(text) => text == other.trim(),
+            Self::Grid(rows) => rows
+                .iter()
+                .map(|row| row.trim())
+                .eq(other.trim().lines().map(str::trim)),
+        }
+    }
+}
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+impl PartialEq<String> for Answer {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+impl From<char> for Answer {
+    fn from(value: char) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+impl From<Answer> for String {
+    fn from(value: Answer) -> Self {
+        value.to_string()
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    match i64::try_from(value) {
+                        Ok(value) => Self::Integer(value),
+                        Err(_) => Self::BigInteger(value as i128),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        match i64::try_from(value) {
+            Ok(value) => Self::Integer(value),
+            Err(_) => Self::BigInteger(value),
+        }
+    }
+}
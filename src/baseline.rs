@@ -0,0 +1,357 @@
+//! Saving benchmarks of every challenge as baselines and comparing later benchmarks to them.
+
+use crate::report::{puzzles, read_inputs};
+use crate::{AdventOfCode, BenchmarkOptions, CatalogYear, ReportEntry, measure};
+use serde_json::{Map, Value, json};
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, ErrorKind};
+use std::path::Path;
+use std::time::Duration;
+use std::{fs, slice};
+
+/// A benchmark of every available part of the challenges with an input,
+/// which is saved to detect parts becoming slower.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Baseline {
+    /// The measured parts, sorted by year, day and part in ascending order.
+    pub entries: Vec<BaselineEntry>,
+    /// The parts which failed to be solved, which are not saved.
+    pub failures: Vec<ReportEntry>,
+}
+impl Baseline {
+    /// Benchmark every available part of the challenges with an input in the directory,
+    /// one after another.
+    /// The inputs are read from `yearYYYY/dayDD.txt` files and days without an input file are skipped.
+    /// # Arguments
+    /// * `advent_of_code` - The [AdventOfCode] solving the challenges.
+    /// * `inputs` - The directory with the inputs.
+    /// * `options` - The [BenchmarkOptions] of the runs of every part.
+    /// # Returns
+    /// * The new [Baseline] or the [io::Error].
+    /// # Errors
+    /// * [io::Error] - An input file exists but cannot be read.
+    pub fn measure<T: AdventOfCode + ?Sized>(
+        advent_of_code: &T,
+        inputs: &Path,
+        options: &BenchmarkOptions,
+    ) -> io::Result<Self> {
+        Self::measure_years(
+            advent_of_code,
+            &advent_of_code.catalog().years,
+            inputs,
+            options,
+        )
+    }
+
+    /// Benchmark every available part of the challenges of a year with an input in the directory,
+    /// one after another.
+    /// The inputs are read from `yearYYYY/dayDD.txt` files and days without an input file are skipped.
+    /// # Arguments
+    /// * `advent_of_code` - The [AdventOfCode] solving the challenges.
+    /// * `year` - The identifier of the year of the challenges to benchmark.
+    /// * `inputs` - The directory with the inputs.
+    /// * `options` - The [BenchmarkOptions] of the runs of every part.
+    /// # Returns
+    /// * The new [Baseline], which is empty for an unavailable year, or the [io::Error].
+    /// # Errors
+    /// * [io::Error] - An input file exists but cannot be read.
+    pub fn measure_year<T: AdventOfCode + ?Sized>(
+        advent_of_code: &T,
+        year: usize,
+        inputs: &Path,
+        options: &BenchmarkOptions,
+    ) -> io::Result<Self> {
+        let catalog = advent_of_code.catalog();
+        let years = catalog.year(year).map(slice::from_ref).unwrap_or_default();
+        Self::measure_years(advent_of_code, years, inputs, options)
+    }
+
+    /// Benchmark every part of the challenges of the years with an input in the directory.
+    fn measure_years<T: AdventOfCode + ?Sized>(
+        advent_of_code: &T,
+        years: &[CatalogYear],
+        inputs: &Path,
+        options: &BenchmarkOptions,
+    ) -> io::Result<Self> {
+        let days = read_inputs(years, inputs)?;
+
+        // the parts are measured one after another, so that they do not slow each other down
+        let mut baseline = Self::default();
+        for (year, day, part, input) in puzzles(years, &days) {
+            let (result, time) =
+                measure(|| advent_of_code.benchmark_statistics(year, day, part, input, options));
+            match result {
+                Ok(statistics) => baseline.entries.push(BaselineEntry {
+                    year,
+                    day,
+                    part,
+                    runs: statistics.total.samples().len(),
+                    min: statistics.total.min(),
+                    median: statistics.total.median(),
+                    max: statistics.total.max(),
+                }),
+                Err(err) => baseline.failures.push(ReportEntry {
+                    year,
+                    day,
+                    part,
+                    result: Err(err),
+                    time,
+                }),
+            }
+        }
+        Ok(baseline)
+    }
+
+    /// Get the entry of a part of a day's challenge.
+    /// # Arguments
+    /// * `year` - The identifier of the year of the challenge.
+    /// * `day` - The identifier of the day's challenge.
+    /// * `part` - The part of the challenge.
+    /// # Returns
+    /// * The entry of the part, if it was measured.
+    pub fn entry(&self, year: usize, day: usize, part: usize) -> Option<&BaselineEntry> {
+        self.entries
+            .iter()
+            .find(|entry| (entry.year, entry.day, entry.part) == (year, day, part))
+    }
+
+    /// Convert the baseline to JSON.
+    /// # Returns
+    /// * A JSON array with an object per entry, with the times in seconds.
+    pub fn to_json(&self) -> String {
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| {
+                json!({
+                    "year": entry.year,
+                    "day": entry.day,
+                    "part": entry.part,
+                    "runs": entry.runs,
+                    "min": entry.min.as_secs_f64(),
+                    "median": entry.median.as_secs_f64(),
+                    "max": entry.max.as_secs_f64(),
+                })
+            })
+            .collect::<Vec<_>>();
+        serde_json::to_string_pretty(&entries).unwrap_or_default()
+    }
+
+    /// Parse a baseline from the JSON created by [Baseline::to_json].
+    /// # Arguments
+    /// * `json` - The JSON array of the entries.
+    /// # Returns
+    /// * The parsed [Baseline] or the [io::Error].
+    /// # Errors
+    /// * [io::Error] - The JSON is malformed or an entry misses a field.
+    pub fn from_json(json: &str) -> io::Result<Self> {
+        let invalid = |message: String| io::Error::new(ErrorKind::InvalidData, message);
+        let values = serde_json::from_str::<Vec<Map<String, Value>>>(json)
+            .map_err(|err| invalid(err.to_string()))?;
+
+        let mut entries = Vec::with_capacity(values.len());
+        for (i, value) in values.iter().enumerate() {
+            let missing = |key: &str| invalid(format!("entry {} has no valid field {:?}", i, key));
+            let integer = |key: &str| {
+                value
+                    .get(key)
+                    .and_then(Value::as_u64)
+                    .map(|value| value as usize)
+                    .ok_or_else(|| missing(key))
+            };
+            let time = |key: &str| {
+                value
+                    .get(key)
+                    .and_then(Value::as_f64)
+                    .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                    .ok_or_else(|| missing(key))
+            };
+            entries.push(BaselineEntry {
+                year: integer("year")?,
+                day: integer("day")?,
+                part: integer("part")?,
+                runs: integer("runs")?,
+                min: time("min")?,
+                median: time("median")?,
+                max: time("max")?,
+            });
+        }
+        entries.sort_by_key(|entry| (entry.year, entry.day, entry.part));
+        Ok(Self {
+            entries,
+            failures: Vec::new(),
+        })
+    }
+
+    /// Save the baseline to a JSON file.
+    /// # Arguments
+    /// * `path` - The path of the file, which is replaced if it exists.
+    /// # Returns
+    /// * The [io::Error], if the file cannot be written.
+    /// # Errors
+    /// * [io::Error] - The file cannot be written.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_json() + "\n")
+    }
+
+    /// Load a baseline from a JSON file created by [Baseline::save].
+    /// # Arguments
+    /// * `path` - The path of the file.
+    /// # Returns
+    /// * The loaded [Baseline] or the [io::Error].
+    /// # Errors
+    /// * [io::Error] - The file cannot be read or it is malformed.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        Self::from_json(&fs::read_to_string(path)?)
+    }
+
+    /// Compare a later benchmark to the baseline.
+    /// A part regressed if its median time grew by more than the threshold and the minimum difference,
+    /// which keeps the noise of very short times from being flagged.
+    /// # Arguments
+    /// * `current` - The later benchmark.
+    /// * `threshold` - The relative change of the median which is flagged, e.g. `0.1` for 10 %.
+    ///   A negative or NaN threshold is treated as zero.
+    /// * `min_difference` - The smallest absolute change of the median which is flagged.
+    /// # Returns
+    /// * The [Comparison] of every part in either benchmark.
+    pub fn compare(
+        &self,
+        current: &Baseline,
+        threshold: f64,
+        min_difference: Duration,
+    ) -> Comparison {
+        let threshold = threshold.max(0.0);
+        let mut puzzles = self
+            .entries
+            .iter()
+            .chain(&current.entries)
+            .map(|entry| (entry.year, entry.day, entry.part))
+            .collect::<Vec<_>>();
+        puzzles.sort_unstable();
+        puzzles.dedup();
+
+        let entries = puzzles
+            .into_iter()
+            .map(|(year, day, part)| {
+                let baseline = self.entry(year, day, part).map(|entry| entry.median);
+                let current = current.entry(year, day, part).map(|entry| entry.median);
+                let change = match (baseline, current) {
+                    (Some(baseline), Some(current)) => {
+                        let (baseline_secs, current_secs) =
+                            (baseline.as_secs_f64(), current.as_secs_f64());
+                        if current_secs > baseline_secs * (1.0 + threshold)
+                            && current.saturating_sub(baseline) >= min_difference
+                        {
+                            Change::Regressed
+                        } else if baseline_secs > current_secs * (1.0 + threshold)
+                            && baseline.saturating_sub(current) >= min_difference
+                        {
+                            Change::Improved
+                        } else {
+                            Change::Unchanged
+                        }
+                    }
+                    (None, _) => Change::Added,
+                    (_, None) => Change::Removed,
+                };
+                ComparisonEntry {
+                    year,
+                    day,
+                    part,
+                    baseline,
+                    current,
+                    change,
+                }
+            })
+            .collect();
+        Comparison { entries }
+    }
+}
+
+/// The measured times of a part of a day's challenge in a [Baseline].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BaselineEntry {
+    /// The identifier of the year of the challenge.
+    pub year: usize,
+    /// The identifier of the day's challenge.
+    pub day: usize,
+    /// The part of the challenge.
+    pub part: usize,
+    /// The number of measured runs.
+    pub runs: usize,
+    /// The shortest time taken to parse the input and solve the part.
+    pub min: Duration,
+    /// The median time taken to parse the input and solve the part.
+    pub median: Duration,
+    /// The longest time taken to parse the input and solve the part.
+    pub max: Duration,
+}
+
+/// An enum representing how the time of a part changed since the [Baseline].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Change {
+    /// The part became slower by more than the threshold.
+    Regressed,
+    /// The part became faster by more than the threshold.
+    Improved,
+    /// The time of the part changed by less than the threshold.
+    Unchanged,
+    /// The part is not in the baseline.
+    Added,
+    /// The part is only in the baseline.
+    Removed,
+}
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Regressed => write!(f, "regressed"),
+            Self::Improved => write!(f, "improved"),
+            Self::Unchanged => write!(f, "unchanged"),
+            Self::Added => write!(f, "added"),
+            Self::Removed => write!(f, "removed"),
+        }
+    }
+}
+
+/// A comparison of a benchmark to a [Baseline].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Comparison {
+    /// The compared parts, sorted by year, day and part in ascending order.
+    pub entries: Vec<ComparisonEntry>,
+}
+impl Comparison {
+    /// Get the entries of the parts which became slower by more than the threshold.
+    /// # Returns
+    /// * An iterator over the entries with [Change::Regressed].
+    pub fn regressions(&self) -> impl Iterator<Item = &ComparisonEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.change == Change::Regressed)
+    }
+}
+
+/// The change of the median time of a part of a day's challenge.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ComparisonEntry {
+    /// The identifier of the year of the challenge.
+    pub year: usize,
+    /// The identifier of the day's challenge.
+    pub day: usize,
+    /// The part of the challenge.
+    pub part: usize,
+    /// The median time in the baseline, if the part is in it.
+    pub baseline: Option<Duration>,
+    /// The median time in the later benchmark, if the part is in it.
+    pub current: Option<Duration>,
+    /// How the time changed.
+    pub change: Change,
+}
+impl ComparisonEntry {
+    /// Get the ratio of the median times.
+    /// # Returns
+    /// * The later median divided by the median of the baseline, if the part is in both.
+    pub fn ratio(&self) -> Option<f64> {
+        Some(self.current?.as_secs_f64() / self.baseline?.as_secs_f64())
+    }
+}
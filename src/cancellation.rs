@@ -0,0 +1,96 @@
+//! Cooperative cancellation of long-running solutions.
+
+use crate::{Error, now};
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+thread_local! {
+    /// The token of the challenge being solved on this thread.
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+/// A structure representing a token which stops solving a challenge
+/// when it is cancelled or its deadline passes.
+///
+/// The token can be cloned and cancelled from another thread.
+/// Long-running solutions poll it and stop with [Error::Cancelled] or [Error::Timeout].
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Duration>,
+}
+impl CancellationToken {
+    /// Create a new [CancellationToken] without a deadline.
+    /// # Returns
+    /// * The new [CancellationToken].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new [CancellationToken] with a deadline.
+    /// # Arguments
+    /// * `timeout` - The time from now after which solving stops.
+    /// # Returns
+    /// * The new [CancellationToken].
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self {
+            cancelled: Arc::default(),
+            deadline: Some(now().saturating_add(timeout)),
+        }
+    }
+
+    /// Cancel solving the challenge with this token and all of its clones.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Check whether the token was cancelled.
+    /// # Returns
+    /// * `true` if [CancellationToken::cancel] was called on the token or its clones, otherwise `false`.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Check whether solving should stop.
+    /// # Returns
+    /// * Nothing if solving can continue or the [Error].
+    /// # Errors
+    /// * [Error::Cancelled] - The token was cancelled.
+    /// * [Error::Timeout] - The deadline of the token has passed.
+    pub fn check(&self) -> Result<(), Error> {
+        if self.is_cancelled() {
+            Err(Error::Cancelled)
+        } else if self.deadline.is_some_and(|deadline| now() >= deadline) {
+            Err(Error::Timeout)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Run the function with the token polled by [check] on this thread.
+pub(crate) fn run_with<T>(token: &CancellationToken, function: impl FnOnce() -> T) -> T {
+    /// Restores the previous token, even if the function panics.
+    struct Restore(Option<CancellationToken>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT.set(self.0.take());
+        }
+    }
+
+    let _restore = Restore(CURRENT.replace(Some(token.clone())));
+    function()
+}
+
+/// Check whether the challenge being solved on this thread should stop.
+/// Long-running loops call this regularly.
+/// # Returns
+/// * Nothing if solving can continue or the [Error].
+/// # Errors
+/// * [Error::Cancelled] - The token was cancelled.
+/// * [Error::Timeout] - The deadline of the token has passed.
+pub(crate) fn check() -> Result<(), Error> {
+    CURRENT.with_borrow(|token| token.as_ref().map_or(Ok(()), CancellationToken::check))
+}
@@ -0,0 +1,123 @@
+//! A plain-data listing of all available challenges.
+
+use crate::{AdventOfCode, Difficulty, Tag};
+use std::fmt::{self, Display, Formatter};
+
+/// A listing of all available years, days' challenges and their implemented parts.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Catalog {
+    /// The available years, sorted by their identifier in ascending order.
+    pub years: Vec<CatalogYear>,
+}
+impl Catalog {
+    /// Create a new [Catalog] describing the given [AdventOfCode].
+    /// # Arguments
+    /// * `advent_of_code` - The [AdventOfCode] to describe.
+    /// # Returns
+    /// * The new [Catalog].
+    pub fn new<T: AdventOfCode + ?Sized>(advent_of_code: &T) -> Self {
+        Self {
+            years: advent_of_code
+                .years()
+                .map(|year| CatalogYear {
+                    id: year.id(),
+                    days: year
+                        .days()
+                        .map(|day| CatalogDay {
+                            id: day.id(),
+                            title: day.title().to_string(),
+                            parts: day.parts().to_vec(),
+                            url: day.url().to_string(),
+                            difficulty: day.difficulty(),
+                            tags: day.tags().to_vec(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Get the year with the specified identifier.
+    /// # Arguments
+    /// * `year` - The identifier of the year to retrieve.
+    /// # Returns
+    /// * The specified year, if it is available.
+    pub fn year(&self, year: usize) -> Option<&CatalogYear> {
+        self.years
+            .iter()
+            .find(|catalog_year| catalog_year.id == year)
+    }
+
+    /// Get all available year, day and part combinations.
+    /// # Returns
+    /// * An iterator over `(year, day, part)` tuples, sorted in ascending order.
+    pub fn puzzles(&self) -> impl Iterator<Item = (usize, usize, usize)> + '_ {
+        self.years.iter().flat_map(|year| {
+            year.days
+                .iter()
+                .flat_map(move |day| day.parts.iter().map(move |&part| (year.id, day.id, part)))
+        })
+    }
+}
+impl Display for Catalog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, year) in self.years.iter().enumerate() {
+            if i != 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", year)?;
+        }
+        Ok(())
+    }
+}
+
+/// A listing of the available days' challenges in a year.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct CatalogYear {
+    /// The identifier of the year.
+    pub id: usize,
+    /// The available days' challenges, sorted by their identifier in ascending order.
+    pub days: Vec<CatalogDay>,
+}
+impl CatalogYear {
+    /// Get the day's challenge with the specified identifier.
+    /// # Arguments
+    /// * `day` - The identifier of the day's challenge to retrieve.
+    /// # Returns
+    /// * The specified day's challenge, if it is available.
+    pub fn day(&self, day: usize) -> Option<&CatalogDay> {
+        self.days.iter().find(|catalog_day| catalog_day.id == day)
+    }
+}
+impl Display for CatalogYear {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Advent of Code {}", self.id)?;
+        for day in &self.days {
+            writeln!(f, "  {}", day)?;
+        }
+        Ok(())
+    }
+}
+
+/// A description of a day's challenge.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct CatalogDay {
+    /// The identifier of the day within the year.
+    pub id: usize,
+    /// The title of the day's challenge.
+    pub title: String,
+    /// The implemented parts of the challenge, sorted in ascending order.
+    pub parts: Vec<usize>,
+    /// The canonical URL of the puzzle.
+    pub url: String,
+    /// An informal rating of how hard the challenge is.
+    pub difficulty: Difficulty,
+    /// The topics of the challenge, sorted in ascending order.
+    pub tags: Vec<Tag>,
+}
+impl Display for CatalogDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let stars = "*".repeat(self.parts.len());
+        write!(f, "Day {:>2} {:<2} {}", self.id, stars, self.title)
+    }
+}
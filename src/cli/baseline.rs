@@ -0,0 +1,185 @@
+//! The `baseline` subcommand saving benchmarks and comparing later benchmarks to them.
+
+use crate::cli::table::{Format, Table};
+use aocode::{AdventOfCode, AoC, Baseline, BenchmarkOptions, Change};
+use std::io;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Benchmark every implemented part with an input, or those of a year, and save the times.
+/// # Arguments
+/// * `advent_of_code` - The [AoC] solving the challenges.
+/// * `year` - The identifier of the only year to benchmark, if any.
+/// * `inputs` - The directory with the inputs in `yearYYYY/dayDD.txt` files.
+/// * `options` - The [BenchmarkOptions] of the runs of every part.
+/// * `path` - The path of the baseline file.
+/// # Returns
+/// * [ExitCode::FAILURE] if a part failed or the baseline cannot be saved, otherwise [ExitCode::SUCCESS].
+pub fn save(
+    advent_of_code: &AoC,
+    year: Option<usize>,
+    inputs: &Path,
+    options: &BenchmarkOptions,
+    path: &Path,
+) -> ExitCode {
+    let baseline = match measure(advent_of_code, year, inputs, options) {
+        Ok(baseline) => baseline,
+        Err(code) => return code,
+    };
+    if let Err(err) = baseline.save(path) {
+        eprintln!("Error saving the baseline: {}: {}", path.display(), err);
+        return ExitCode::FAILURE;
+    }
+
+    println!(
+        "Saved {} parts to {}",
+        baseline.entries.len(),
+        path.display()
+    );
+    if baseline.failures.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Benchmark every implemented part with an input, or those of a year, and compare the times
+/// to a saved baseline.
+/// # Arguments
+/// * `advent_of_code` - The [AoC] solving the challenges.
+/// * `year` - The identifier of the only year to benchmark, if any.
+/// * `inputs` - The directory with the inputs in `yearYYYY/dayDD.txt` files.
+/// * `options` - The [BenchmarkOptions] of the runs of every part.
+/// * `path` - The path of the baseline file.
+/// * `threshold` - The relative change of the median time which is flagged.
+/// * `min_difference` - The smallest absolute change of the median time which is flagged.
+/// * `format` - The output [Format].
+/// # Returns
+/// * [ExitCode::FAILURE] if a part regressed or failed, otherwise [ExitCode::SUCCESS].
+#[allow(clippy::too_many_arguments)]
+pub fn compare(
+    advent_of_code: &AoC,
+    year: Option<usize>,
+    inputs: &Path,
+    options: &BenchmarkOptions,
+    path: &Path,
+    threshold: f64,
+    min_difference: Duration,
+    format: Format,
+) -> ExitCode {
+    let mut baseline = match Baseline::load(path) {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("Error loading the baseline: {}: {}", path.display(), err);
+            return ExitCode::FAILURE;
+        }
+    };
+    // only the compared year counts, so that the other years are not reported as removed
+    if let Some(year) = year {
+        baseline.entries.retain(|entry| entry.year == year);
+    }
+    let current = match measure(advent_of_code, year, inputs, options) {
+        Ok(current) => current,
+        Err(code) => return code,
+    };
+    let comparison = baseline.compare(&current, threshold, min_difference);
+
+    let mut table = Table::new(&[
+        ("year", "Year"),
+        ("day", "Day"),
+        ("title", "Title"),
+        ("part", "Part"),
+        ("baseline_duration", "Baseline"),
+        ("duration", "Time"),
+        ("change", "Change"),
+        ("status", "Status"),
+    ]);
+    for entry in &comparison.entries {
+        let title = advent_of_code
+            .year(entry.year)
+            .and_then(|year| year.day(entry.day))
+            .map_or("", |day| day.title());
+        table.push(vec![
+            entry.year.into(),
+            entry.day.into(),
+            title.into(),
+            entry.part.into(),
+            entry.baseline.into(),
+            entry.current.into(),
+            entry
+                .ratio()
+                .map(|ratio| format!("{:+.1}%", (ratio - 1.0) * 100.0))
+                .into(),
+            entry.change.to_string().into(),
+        ]);
+    }
+
+    print!("{}", table.render(format));
+    let count = |change: Change| {
+        comparison
+            .entries
+            .iter()
+            .filter(|entry| entry.change == change)
+            .count()
+    };
+    if format == Format::Text {
+        println!();
+        println!(
+            "{} regressed, {} improved, {} unchanged, {} added, {} removed, {} failed",
+            count(Change::Regressed),
+            count(Change::Improved),
+            count(Change::Unchanged),
+            count(Change::Added),
+            count(Change::Removed),
+            current.failures.len()
+        );
+    }
+    if count(Change::Regressed) > 0 || !current.failures.is_empty() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Benchmark the parts and report the failed ones on stderr.
+fn measure(
+    advent_of_code: &AoC,
+    year: Option<usize>,
+    inputs: &Path,
+    options: &BenchmarkOptions,
+) -> Result<Baseline, ExitCode> {
+    if let Some(year) = year
+        && let Err(err) = advent_of_code.year(year)
+    {
+        eprintln!("Error benchmarking the challenges: {}", err);
+        return Err(ExitCode::FAILURE);
+    }
+    let baseline: io::Result<Baseline> = match year {
+        Some(year) => advent_of_code.benchmark_all_in_year(year, inputs, options),
+        None => advent_of_code.benchmark_all(inputs, options),
+    };
+    let baseline = baseline.map_err(|err| {
+        eprintln!("Error reading the inputs: {}", err);
+        ExitCode::FAILURE
+    })?;
+    for failure in &baseline.failures {
+        eprintln!(
+            "{} day {} part {}: Error running the challenge: {}",
+            failure.year,
+            failure.day,
+            failure.part,
+            failure
+                .result
+                .as_ref()
+                .err()
+                .map(ToString::to_string)
+                .unwrap_or_default()
+        );
+    }
+    if baseline.entries.is_empty() && baseline.failures.is_empty() {
+        eprintln!("No inputs found in {}", inputs.display());
+        return Err(ExitCode::FAILURE);
+    }
+    Ok(baseline)
+}
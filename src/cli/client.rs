@@ -0,0 +1,138 @@
+//! A client of the *Advent of Code* website, used to fetch inputs and submit answers.
+
+use std::io;
+use std::thread;
+use std::time::{Duration, Instant};
+use ureq::Agent;
+
+/// The base URL of the *Advent of Code* website.
+pub const BASE_URL: &str = "https://adventofcode.com";
+/// The default time between two requests.
+pub const DEFAULT_DELAY: Duration = Duration::from_secs(3);
+/// The number of times a rate-limited request is retried.
+const RETRIES: usize = 3;
+
+/// A structure representing a client of the *Advent of Code* website,
+/// authenticated with a session cookie and throttled to one request per delay.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    delay: Duration,
+    last_request: Option<Instant>,
+}
+impl Client {
+    /// Create a new [Client].
+    /// # Arguments
+    /// * `base_url` - The base URL of the website, e.g. [BASE_URL] or a local server.
+    /// * `session` - The value of the session cookie of a logged-in user.
+    /// * `delay` - The minimum time between two requests.
+    /// * `contact` - The contact of the user, e.g. an email address, sent in the User-Agent header.
+    /// # Returns
+    /// * The new [Client].
+    pub fn new(base_url: &str, session: &str, delay: Duration, contact: Option<&str>) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(user_agent(contact))
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            delay,
+            last_request: None,
+        }
+    }
+
+    /// Get a page of the website.
+    /// # Arguments
+    /// * `path` - The path of the page, e.g. `/2015/day/1/input`.
+    /// # Returns
+    /// * The body of the page or the [io::Error].
+    /// # Errors
+    /// * [io::Error] - The request failed or the response has an error status.
+    pub fn get(&mut self, path: &str) -> io::Result<String> {
+        let url = format!("{}{}", self.base_url, path);
+        self.request(|agent, cookie| agent.get(&url).header("Cookie", cookie).call())
+    }
+
+    /// Post a form to a page of the website.
+    /// # Arguments
+    /// * `path` - The path of the page, e.g. `/2015/day/1/answer`.
+    /// * `form` - The names and values of the fields of the form.
+    /// # Returns
+    /// * The body of the response or the [io::Error].
+    /// # Errors
+    /// * [io::Error] - The request failed or the response has an error status.
+    pub fn post(&mut self, path: &str, form: &[(&str, &str)]) -> io::Result<String> {
+        let url = format!("{}{}", self.base_url, path);
+        self.request(|agent, cookie| {
+            agent
+                .post(&url)
+                .header("Cookie", cookie)
+                .send_form(form.iter().copied())
+        })
+    }
+
+    /// Send a request after the delay, retrying it while it is rate-limited.
+    fn request(
+        &mut self,
+        send: impl Fn(&Agent, &str) -> Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+    ) -> io::Result<String> {
+        let cookie = format!("session={}", self.session);
+        let mut retries = 0;
+        loop {
+            if let Some(last_request) = self.last_request {
+                thread::sleep(self.delay.saturating_sub(last_request.elapsed()));
+            }
+            self.last_request = Some(Instant::now());
+            let mut response = send(&self.agent, &cookie).map_err(io::Error::other)?;
+            let status = response.status().as_u16();
+
+            // a rate-limited request is retried after the time the server asks for
+            if status == 429 && retries < RETRIES {
+                let retry_after = response
+                    .headers()
+                    .get("Retry-After")
+                    .and_then(|value| value.to_str().ok()?.trim().parse().ok())
+                    .map_or(self.delay, Duration::from_secs);
+                thread::sleep(retry_after.saturating_sub(self.delay));
+                retries += 1;
+                continue;
+            }
+
+            let body = response
+                .body_mut()
+                .read_to_string()
+                .map_err(io::Error::other)?;
+            return match status {
+                200..=299 => Ok(body),
+                400 if body.contains("log in") => Err(io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    "the session cookie is invalid or expired",
+                )),
+                404 => Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    "the puzzle is not available yet",
+                )),
+                _ => Err(io::Error::other(format!(
+                    "the server responded with status {}: {}",
+                    status,
+                    body.lines().next().unwrap_or_default()
+                ))),
+            };
+        }
+    }
+}
+
+/// Get the User-Agent header, which identifies the tool and, if given,
+/// the contact of its user as the website asks.
+fn user_agent(contact: Option<&str>) -> String {
+    let tool = format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+    match contact.map(str::trim).filter(|contact| !contact.is_empty()) {
+        Some(contact) => format!("{} (+{})", tool, contact),
+        None => tool,
+    }
+}
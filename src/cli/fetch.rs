@@ -0,0 +1,54 @@
+//! The `fetch` subcommand downloading inputs into the inputs directory.
+
+use crate::cli::client::Client;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::process::ExitCode;
+
+/// Download the inputs to days' challenges which are not in the inputs directory yet.
+/// The inputs are written to `yearYYYY/dayDD.txt` files and cached inputs are never downloaded again.
+/// # Arguments
+/// * `client` - The [Client] of the website.
+/// * `inputs` - The directory with the inputs.
+/// * `days` - The identifiers of the years and the days' challenges.
+/// # Returns
+/// * [ExitCode::FAILURE] if an input could not be downloaded, otherwise [ExitCode::SUCCESS].
+pub fn fetch(client: &mut Client, inputs: &Path, days: &[(usize, usize)]) -> ExitCode {
+    let mut failed = false;
+    for &(year, day) in days {
+        let dir = inputs.join(format!("year{:04}", year));
+        let path = dir.join(format!("day{:02}.txt", day));
+        if path.exists() {
+            println!("{} day {:>2}: cached", year, day);
+            continue;
+        }
+
+        let downloaded = client
+            .get(&format!("/{}/day/{}/input", year, day))
+            .and_then(|input| {
+                // the input is renamed once complete, so that an interrupted download is not cached
+                let partial = dir.join(format!("day{:02}.txt.part", day));
+                fs::create_dir_all(&dir)?;
+                fs::write(&partial, input)?;
+                fs::rename(&partial, &path)
+            });
+        match downloaded {
+            Ok(()) => println!("{} day {:>2}: downloaded", year, day),
+            Err(err) => {
+                eprintln!("{} day {:>2}: Error fetching the input: {}", year, day, err);
+                failed = true;
+                // the other inputs cannot be downloaded with the same session either
+                if err.kind() == ErrorKind::PermissionDenied {
+                    break;
+                }
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,61 @@
+//! Listing the available challenges.
+
+use crate::cli::table::{Cell, Format, Table};
+use aocode::{AdventOfCode, AoC, Day, Tag};
+
+/// Print the available challenges, or only those with a topic.
+/// # Arguments
+/// * `advent_of_code` - The [AoC] with the challenges.
+/// * `tag` - The only topic to list, if any.
+/// * `format` - The output [Format].
+pub fn list(advent_of_code: &AoC, tag: Option<Tag>, format: Format) {
+    let days: Vec<(usize, &dyn Day)> = match tag {
+        Some(tag) => advent_of_code.days_tagged(tag).collect(),
+        None => advent_of_code
+            .years()
+            .flat_map(|year| year.days().map(move |day| (year.id(), day)))
+            .collect(),
+    };
+
+    match (format, tag) {
+        (Format::Text, None) => println!("{}", advent_of_code),
+        (Format::Text, Some(_)) => {
+            for (year, day) in days {
+                let tags = day.tags().iter().map(Tag::name).collect::<Vec<_>>();
+                println!(
+                    "{} Day {:>2} {:<2} {} ({}; {}) {}",
+                    year,
+                    day.id(),
+                    "*".repeat(day.parts().len()),
+                    day.title(),
+                    day.difficulty(),
+                    tags.join(", "),
+                    day.url()
+                );
+            }
+        }
+        (format, _) => {
+            let mut table = Table::new(&[
+                ("year", "Year"),
+                ("day", "Day"),
+                ("title", "Title"),
+                ("parts", "Parts"),
+                ("url", "URL"),
+                ("difficulty", "Difficulty"),
+                ("tags", "Tags"),
+            ]);
+            for (year, day) in days {
+                table.push(vec![
+                    year.into(),
+                    day.id().into(),
+                    day.title().into(),
+                    Cell::List(day.parts().iter().map(usize::to_string).collect()),
+                    day.url().into(),
+                    day.difficulty().to_string().into(),
+                    Cell::List(day.tags().iter().map(Tag::to_string).collect()),
+                ]);
+            }
+            print!("{}", table.render(format));
+        }
+    }
+}
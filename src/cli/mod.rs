@@ -0,0 +1,14 @@
+//! Subcommands and output formatting of the `aocode` binary.
+
+pub mod baseline;
+pub mod client;
+pub mod fetch;
+pub mod list;
+pub mod run_all;
+pub mod scaffold;
+pub mod solve;
+pub mod stats;
+pub mod submit;
+pub mod table;
+pub mod verify;
+pub mod watch;
@@ -0,0 +1,163 @@
+//! The `run-all` subcommand solving every implemented challenge from an inputs directory.
+
+use crate::cli::solve::{RESULT_COLUMNS, result_cells};
+use crate::cli::table::{Cell, Format, Table};
+use aocode::{AdventOfCode, Answer, AoC, Report};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Solve every implemented challenge, or those of a year, and print the answers and totals.
+/// # Arguments
+/// * `advent_of_code` - The [AoC] solving the challenges.
+/// * `year` - The identifier of the only year to solve, if any.
+/// * `inputs` - The directory with the inputs in `yearYYYY/dayDD.txt` files.
+/// * `timeout` - The time after which the solutions polling for cancellation stop.
+/// * `format` - The output [Format].
+/// # Returns
+/// * [ExitCode::SUCCESS] if every part was solved, otherwise [ExitCode::FAILURE].
+pub fn run_all(
+    advent_of_code: &AoC,
+    year: Option<usize>,
+    inputs: &Path,
+    timeout: Duration,
+    format: Format,
+) -> ExitCode {
+    if let Some(year) = year
+        && let Err(err) = advent_of_code.year(year)
+    {
+        eprintln!("Error running the challenges: {}", err);
+        return ExitCode::FAILURE;
+    }
+    let report = match year {
+        Some(year) => advent_of_code.solve_all_in_year(year, inputs, timeout),
+        None => advent_of_code.solve_all(inputs, timeout),
+    };
+    let report = match report {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("Error reading the inputs: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    if report.entries.is_empty() {
+        eprintln!("No inputs found in {}", inputs.display());
+        return ExitCode::FAILURE;
+    }
+
+    match format {
+        Format::Text => {
+            print!("{}", answers(advent_of_code, &report));
+            println!();
+            print!("{}", totals(&report));
+            println!("Wall-clock time: {}", milliseconds(report.total_time));
+        }
+        Format::Markdown => {
+            print!("{}", results(advent_of_code, &report).render(format));
+            println!();
+            print!("{}", totals(&report).render(format));
+        }
+        Format::Json | Format::Csv => {
+            print!("{}", results(advent_of_code, &report).render(format));
+        }
+    }
+
+    if report.failures().next().is_some() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Create the table of the answers to every solved part.
+fn answers(advent_of_code: &AoC, report: &Report) -> Table {
+    let mut table = Table::new(&[
+        ("year", "Year"),
+        ("day", "Day"),
+        ("title", "Title"),
+        ("part", "Part"),
+        ("answer", "Answer"),
+        ("duration", "Time"),
+    ]);
+    for entry in &report.entries {
+        let title = advent_of_code
+            .year(entry.year)
+            .and_then(|year| year.day(entry.day))
+            .map_or("", |day| day.title());
+        let answer = match &entry.result {
+            Ok(Answer::Grid(rows)) => format!("({} rows)", rows.len()),
+            Ok(answer) => answer.to_string(),
+            Err(err) => format!("error: {}", err),
+        };
+        table.push(vec![
+            entry.year.into(),
+            entry.day.into(),
+            title.into(),
+            entry.part.into(),
+            answer.into(),
+            entry.time.into(),
+        ]);
+    }
+    table
+}
+
+/// Create the table of the results of every solved part with the [RESULT_COLUMNS].
+fn results(advent_of_code: &AoC, report: &Report) -> Table {
+    let mut table = Table::new(&RESULT_COLUMNS);
+    for entry in &report.entries {
+        table.push(result_cells(
+            advent_of_code,
+            entry.year,
+            entry.day,
+            entry.part,
+            entry.result.as_ref().map_err(|err| *err),
+            Some(entry.time),
+        ));
+    }
+    table
+}
+
+/// Create the table of the number of parts, failures and times of every year and of all years.
+fn totals(report: &Report) -> Table {
+    let mut table = Table::new(&[
+        ("year", "Year"),
+        ("parts", "Parts"),
+        ("failed", "Failed"),
+        ("duration", "Time"),
+    ]);
+    let mut years = report
+        .entries
+        .iter()
+        .map(|entry| entry.year)
+        .collect::<Vec<_>>();
+    years.dedup();
+
+    let total_row = |name: Cell, year: Option<usize>| {
+        let entries = report
+            .entries
+            .iter()
+            .filter(|entry| year.is_none_or(|year| entry.year == year))
+            .collect::<Vec<_>>();
+        let failed = entries.iter().filter(|entry| entry.result.is_err()).count();
+        vec![
+            name,
+            entries.len().into(),
+            failed.into(),
+            entries
+                .iter()
+                .map(|entry| entry.time)
+                .sum::<Duration>()
+                .into(),
+        ]
+    };
+    for &year in &years {
+        table.push(total_row(year.into(), Some(year)));
+    }
+    table.push(total_row("All".into(), None));
+    table
+}
+
+/// Format a duration in milliseconds.
+fn milliseconds(time: Duration) -> String {
+    format!("{:.3} ms", time.as_secs_f64() * 1000.0)
+}
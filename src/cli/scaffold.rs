@@ -0,0 +1,268 @@
+//! The `new` subcommand creating the skeleton of a new day's challenge.
+
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+
+/// The widest argument list which rustfmt keeps on the line of a macro call.
+const CALL_WIDTH: usize = 60;
+/// The widest line which rustfmt produces.
+const MAX_WIDTH: usize = 100;
+
+/// Create the skeleton of a new day's challenge in the source tree of the crate.
+/// This creates the solution with the `day!` macro and registers it in the lists of days
+/// of the year's module, leaving the rest of the module as it is.
+/// The parts of the solution return [Error::UnavailablePart](aocode::Error::UnavailablePart)
+/// until they are implemented, and its test data is left to `fetch` and `verify --bless`.
+/// A new year gets its module, which is registered in `problems` and `AoC::new`,
+/// and an empty file for the examples of its days.
+/// # Arguments
+/// * `root` - The root directory of the crate.
+/// * `year` - The identifier of the year of the challenge.
+/// * `day` - The identifier of the day's challenge.
+/// * `title` - The title of the day's challenge.
+/// # Returns
+/// * The paths of the created and changed files or the [io::Error].
+/// # Errors
+/// * [io::Error] - The day already exists or the source tree cannot be read or written.
+pub fn scaffold(root: &Path, year: usize, day: usize, title: &str) -> io::Result<Vec<PathBuf>> {
+    let problems = root.join("src").join("problems");
+    let year_dir = problems.join(format!("year{:04}", year));
+    let day_path = year_dir.join(format!("day{:02}.rs", day));
+    let year_path = year_dir.join("mod.rs");
+    if day_path.exists() {
+        return Err(io::Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{} already exists", day_path.display()),
+        ));
+    }
+
+    // prepare all changes first, so that nothing is written if a file cannot be changed
+    let mut files = vec![(day_path, day_source(year, day, title))];
+    match fs::read_to_string(&year_path) {
+        Ok(source) => {
+            // the year's module is changed in place to keep the rest of it as it is
+            let source = insert_sorted(&source, "mod day", &[format!("mod day{:02};", day)])?;
+            let source = insert_sorted(
+                &source,
+                "pub use day",
+                &[
+                    String::from("#[doc(inline)]"),
+                    format!("pub use day{:02}::Day{:02};", day, day),
+                ],
+            )?;
+            let source = insert_year_day(&source, day)?;
+            files.push((year_path, source));
+        }
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            let problems_path = problems.join("mod.rs");
+            let problems_source = read(&problems_path)?;
+            let problems_source = insert_sorted(
+                &problems_source,
+                "pub mod year",
+                &[format!("pub mod year{:04};", year)],
+            )?;
+            let problems_source = insert_sorted(
+                &problems_source,
+                "pub use year",
+                &[
+                    String::from("#[doc(inline)]"),
+                    format!("pub use year{:04}::Year{:04};", year, year),
+                ],
+            )?;
+            files.push((problems_path, problems_source));
+
+            let lib_path = root.join("src").join("lib.rs");
+            let lib_source = insert_sorted(
+                &read(&lib_path)?,
+                "Box::new(problems::Year",
+                &[format!("Box::new(problems::Year{:04}::new()),", year)],
+            )?;
+            files.push((lib_path, lib_source));
+            files.push((year_dir.join("examples.txt"), String::new()));
+            files.push((year_path, year_source(year, day)));
+        }
+        Err(err) => return Err(with_path(&year_path, err)),
+    }
+
+    let mut changed = Vec::new();
+    for (path, source) in files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|err| with_path(parent, err))?;
+        }
+        fs::write(&path, source).map_err(|err| with_path(&path, err))?;
+        changed.push(path);
+    }
+
+    Ok(changed)
+}
+
+/// Get the source of the solution to a new day's challenge.
+/// No part is implemented yet, except for the second part of the last day, which has no puzzle.
+fn day_source(year: usize, day: usize, title: &str) -> String {
+    let (parts, part2) = if day == 25 {
+        (
+            "parts = [2]",
+            format!("Ok(\"Advent of Code {} solved!\".into())", year),
+        )
+    } else {
+        ("parts = []", String::from("Err(Error::UnavailablePart)"))
+    };
+    let arguments = [
+        format!("Day{:02}", day),
+        year.to_string(),
+        day.to_string(),
+        format!("{:?}", title),
+        String::from(parts),
+        String::from("difficulty = Easy"),
+        String::from("tags = []"),
+    ];
+    format!(
+        "use crate::{{Answer, Error, Solution}};\n\
+         \n\
+         {}\n\
+         \n\
+         impl Solution for Day{:02} {{\n    \
+         fn part1(&self, _input: &str) -> Result<Answer, Error> {{\n        \
+         Err(Error::UnavailablePart)\n    \
+         }}\n\
+         \n    \
+         fn part2(&self, _input: &str) -> Result<Answer, Error> {{\n        \
+         {}\n    \
+         }}\n\
+         }}\n",
+        macro_call("day", &arguments, false),
+        day,
+        part2
+    )
+}
+
+/// Get the source of the module of a new year with its first day.
+fn year_source(year: usize, day: usize) -> String {
+    let arguments = [
+        format!("Year{:04}", year),
+        year.to_string(),
+        format!("Day{:02}", day),
+    ];
+    format!(
+        "//! Solutions to the *Advent of Code {}*\n\n\
+         mod day{:02};\n\n\
+         #[doc(inline)]\n\
+         pub use day{:02}::Day{:02};\n\n\
+         {}\n",
+        year,
+        day,
+        day,
+        day,
+        macro_call("year", &arguments, true)
+    )
+}
+
+/// Insert a day into the sorted days of the `year!` macro call of a year's module,
+/// which is formatted again while the rest of the module is kept as it is.
+fn insert_year_day(source: &str, day: usize) -> io::Result<String> {
+    let start = source
+        .find("\nyear!(")
+        .map(|i| i + 1)
+        .ok_or_else(|| io::Error::other("no line starts with \"year!(\""))?;
+    let end = source[start..]
+        .find(");")
+        .map(|i| start + i + 2)
+        .ok_or_else(|| io::Error::other("the year! macro call is not closed"))?;
+    let mut arguments = source[start + "year!(".len()..end - 2]
+        .split(',')
+        .map(|argument| argument.trim().to_string())
+        .filter(|argument| !argument.is_empty())
+        .collect::<Vec<_>>();
+    let entry = format!("Day{:02}", day);
+    if arguments.contains(&entry) {
+        return Err(io::Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{:?} already exists", entry),
+        ));
+    }
+
+    // the days follow the name and the identifier of the year
+    let position = arguments
+        .iter()
+        .skip(2)
+        .position(|argument| *argument > entry)
+        .map_or(arguments.len(), |i| i + 2);
+    arguments.insert(position, entry);
+    Ok(format!(
+        "{}{}{}",
+        &source[..start],
+        macro_call("year", &arguments, true),
+        &source[end..]
+    ))
+}
+
+/// Get a macro call statement formatted like rustfmt does,
+/// with the arguments on the line of the call if they are short enough.
+/// Otherwise, the arguments are on separate lines or packed into as few lines as possible.
+fn macro_call(name: &str, arguments: &[String], packed: bool) -> String {
+    let line = arguments.join(", ");
+    if line.len() <= CALL_WIDTH {
+        return format!("{}!({});", name, line);
+    }
+
+    let mut lines = Vec::<String>::new();
+    for (i, argument) in arguments.iter().enumerate() {
+        let separator = if i + 1 < arguments.len() { "," } else { "" };
+        match lines.last_mut() {
+            Some(line)
+                if packed && line.len() + 1 + argument.len() + separator.len() <= MAX_WIDTH =>
+            {
+                *line += &format!(" {}{}", argument, separator);
+            }
+            _ => lines.push(format!("    {}{}", argument, separator)),
+        }
+    }
+    format!("{}!(\n{}\n);", name, lines.join("\n"))
+}
+
+/// Insert an entry into a sorted list of lines starting with the prefix.
+/// The last line of the entry is compared to the lines of the list
+/// and the entry gets the indentation of the list.
+fn insert_sorted(source: &str, prefix: &str, entry: &[String]) -> io::Result<String> {
+    let mut lines = source.lines().map(String::from).collect::<Vec<_>>();
+    let key = entry.last().map_or("", String::as_str);
+    let matches = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.trim_start().starts_with(prefix))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    let (Some(&first), Some(&last)) = (matches.first(), matches.last()) else {
+        return Err(io::Error::other(format!(
+            "no line starts with {:?}",
+            prefix
+        )));
+    };
+    if matches.iter().any(|&i| lines[i].trim_start() == key) {
+        return Err(io::Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{:?} already exists", key),
+        ));
+    }
+
+    let indent = lines[first][..lines[first].len() - lines[first].trim_start().len()].to_string();
+    let position = matches
+        .iter()
+        .find(|&&i| lines[i].trim_start() > key)
+        .map_or(last + 1, |&i| i + 1 - entry.len());
+    for (offset, line) in entry.iter().enumerate() {
+        lines.insert(position + offset, format!("{}{}", indent, line));
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Read a file of the source tree.
+fn read(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path).map_err(|err| with_path(path, err))
+}
+
+/// Add the path to the message of an [io::Error].
+fn with_path(path: &Path, err: io::Error) -> io::Error {
+    io::Error::new(err.kind(), format!("{}: {}", path.display(), err))
+}
@@ -0,0 +1,290 @@
+//! Solving and benchmarking parts of a day's challenge.
+
+use crate::cli::table::{Cell, Column, Format, Table};
+use aocode::{
+    AdventOfCode, Answer, AoC, Benchmark, BenchmarkOptions, BenchmarkStatistics, Error, Parameters,
+    Progress,
+};
+use std::io::{IsTerminal, Write, stderr};
+use std::process::ExitCode;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// The columns describing the result of solving a part, shared by every machine-readable output.
+pub const RESULT_COLUMNS: [Column; 7] = [
+    ("year", "Year"),
+    ("day", "Day"),
+    ("title", "Title"),
+    ("part", "Part"),
+    ("answer", "Answer"),
+    ("error", "Error"),
+    ("duration", "Time"),
+];
+
+/// Create the cells of the [RESULT_COLUMNS] of a part.
+/// # Arguments
+/// * `advent_of_code` - The [AoC] with the challenge.
+/// * `year` - The identifier of the year of the challenge.
+/// * `day` - The identifier of the day's challenge.
+/// * `part` - The part of the challenge.
+/// * `result` - The solution to the part or the [Error].
+/// * `duration` - The time taken to solve the part, if it was measured.
+/// # Returns
+/// * The cells of the part.
+pub fn result_cells(
+    advent_of_code: &AoC,
+    year: usize,
+    day: usize,
+    part: usize,
+    result: Result<&Answer, Error>,
+    duration: Option<Duration>,
+) -> Vec<Cell> {
+    let title = advent_of_code
+        .year(year)
+        .and_then(|year| year.day(day))
+        .map(|day| day.title());
+    vec![
+        year.into(),
+        day.into(),
+        title.ok().into(),
+        part.into(),
+        result.ok().map(Answer::to_string).into(),
+        result.err().map(|err| err.kind()).into(),
+        duration.into(),
+    ]
+}
+
+/// Solve parts of a day's challenge and print the answers.
+/// # Arguments
+/// * `advent_of_code` - The [AoC] with the challenge.
+/// * `year` - The identifier of the year of the challenge.
+/// * `day` - The identifier of the day's challenge.
+/// * `parts` - The parts to solve.
+/// * `input` - The input to the challenge.
+/// * `parameters` - The values of the parameters of the challenge.
+/// * `progress` - Whether to show the progress on stderr.
+/// * `format` - The output [Format].
+/// # Returns
+/// * [ExitCode::SUCCESS] if every part was solved, otherwise [ExitCode::FAILURE].
+#[allow(clippy::too_many_arguments)]
+pub fn solve(
+    advent_of_code: &AoC,
+    year: usize,
+    day: usize,
+    parts: &[usize],
+    input: &str,
+    parameters: &Parameters,
+    progress: bool,
+    format: Format,
+) -> ExitCode {
+    let mut table = Table::new(&RESULT_COLUMNS);
+    let mut exit_code = ExitCode::SUCCESS;
+    let terminal = stderr().is_terminal();
+    for &part in parts {
+        let start = Instant::now();
+        let result = if progress {
+            let result = advent_of_code.solve_with_progress(
+                year,
+                day,
+                part,
+                input,
+                parameters,
+                progress_bar(terminal),
+            );
+            if terminal {
+                eprint!("\r\x1b[2K");
+            }
+            result
+        } else {
+            advent_of_code.solve_with(year, day, part, input, parameters)
+        };
+        let duration = start.elapsed();
+
+        if format == Format::Text {
+            // label the answers only if there are several of them
+            let label = if parts.len() > 1 {
+                format!("Part {}: ", part)
+            } else {
+                String::new()
+            };
+            match &result {
+                Ok(answer) => println!("{}{}", label, answer),
+                Err(err) => {
+                    if !label.is_empty() {
+                        println!("{}", label);
+                    }
+                    eprintln!("Error running the challenge: {}", err);
+                    return ExitCode::FAILURE;
+                }
+            }
+        } else {
+            if let Err(err) = &result {
+                eprintln!("Error running the challenge: {}", err);
+                exit_code = ExitCode::FAILURE;
+            }
+            let result = result.as_ref().map_err(|err| *err);
+            table.push(result_cells(
+                advent_of_code,
+                year,
+                day,
+                part,
+                result,
+                Some(duration),
+            ));
+        }
+    }
+
+    if format != Format::Text {
+        print!("{}", table.render(format));
+    }
+    exit_code
+}
+
+/// The measured times of a part.
+enum Measurement {
+    /// The times of a single run.
+    Single(Benchmark),
+    /// The statistics of repeated runs.
+    Repeated(BenchmarkStatistics),
+}
+
+/// Benchmark parts of a day's challenge once or repeatedly and print the answers with the times.
+/// # Arguments
+/// * `advent_of_code` - The [AoC] with the challenge.
+/// * `year` - The identifier of the year of the challenge.
+/// * `day` - The identifier of the day's challenge.
+/// * `parts` - The parts to benchmark.
+/// * `input` - The input to the challenge.
+/// * `options` - The [BenchmarkOptions] of repeated runs, if any.
+/// * `format` - The output [Format].
+/// # Returns
+/// * [ExitCode::SUCCESS] if every part was solved, otherwise [ExitCode::FAILURE].
+pub fn benchmark(
+    advent_of_code: &AoC,
+    year: usize,
+    day: usize,
+    parts: &[usize],
+    input: &str,
+    options: Option<BenchmarkOptions>,
+    format: Format,
+) -> ExitCode {
+    let mut columns = RESULT_COLUMNS.to_vec();
+    match options {
+        Some(_) => columns.extend([
+            ("runs", "Runs"),
+            ("min", "Min"),
+            ("median", "Median"),
+            ("std_dev", "Std dev"),
+            ("p95", "P95"),
+            ("max", "Max"),
+        ]),
+        None => columns.extend([("parse_duration", "Parse"), ("solve_duration", "Solve")]),
+    }
+    let mut table = Table::new(&columns);
+    let mut exit_code = ExitCode::SUCCESS;
+    for &part in parts {
+        let measurement = match options {
+            Some(options) => advent_of_code
+                .benchmark_statistics(year, day, part, input, &options)
+                .map(Measurement::Repeated),
+            None => advent_of_code
+                .benchmark(year, day, part, input)
+                .map(Measurement::Single),
+        };
+
+        if format == Format::Text {
+            // label the answers only if there are several of them
+            if parts.len() > 1 {
+                print!("Part {}: ", part);
+            }
+            match measurement {
+                Ok(Measurement::Single(benchmark)) => println!(
+                    "{}    --- {} s (parse {} s, solve {} s)",
+                    benchmark.answer,
+                    benchmark.total_time().as_secs_f64(),
+                    benchmark.parse_time.as_secs_f64(),
+                    benchmark.solve_time.as_secs_f64()
+                ),
+                Ok(Measurement::Repeated(statistics)) => {
+                    println!("{}", statistics.answer);
+                    println!("total {}", statistics.total);
+                    println!("parse {}", statistics.parse);
+                    println!("solve {}", statistics.solve);
+                }
+                Err(err) => {
+                    if parts.len() > 1 {
+                        println!();
+                    }
+                    eprintln!("Error running the challenge: {}", err);
+                    return ExitCode::FAILURE;
+                }
+            }
+            continue;
+        }
+
+        let row = match measurement {
+            Ok(Measurement::Single(benchmark)) => {
+                let answer = Ok(&benchmark.answer);
+                let total = Some(benchmark.total_time());
+                let mut row = result_cells(advent_of_code, year, day, part, answer, total);
+                row.extend([benchmark.parse_time.into(), benchmark.solve_time.into()]);
+                row
+            }
+            Ok(Measurement::Repeated(statistics)) => {
+                let total = &statistics.total;
+                let answer = Ok(&statistics.answer);
+                let mean = Some(total.mean());
+                let mut row = result_cells(advent_of_code, year, day, part, answer, mean);
+                row.extend([
+                    total.samples().len().into(),
+                    total.min().into(),
+                    total.median().into(),
+                    total.std_dev().into(),
+                    total.percentile(95.0).into(),
+                    total.max().into(),
+                ]);
+                row
+            }
+            Err(err) => {
+                eprintln!("Error running the challenge: {}", err);
+                exit_code = ExitCode::FAILURE;
+                let mut row = result_cells(advent_of_code, year, day, part, Err(err), None);
+                row.resize(columns.len(), Cell::Empty);
+                row
+            }
+        };
+        table.push(row);
+    }
+
+    if format != Format::Text {
+        print!("{}", table.render(format));
+    }
+    exit_code
+}
+
+/// Create a reporter drawing a progress bar on stderr, redrawn only when it changes.
+/// If stderr is not a terminal, e.g. a log file, the progress is written as plain lines instead,
+/// one when a phase starts and one for every tenth of it which is done.
+fn progress_bar(terminal: bool) -> Arc<dyn Progress> {
+    const WIDTH: usize = 40;
+    let last = Mutex::new((String::new(), usize::MAX));
+    Arc::new(move |phase: &str, fraction: f64| {
+        let percent = (fraction * 100.0) as usize;
+        let mut last = last.lock().unwrap_or_else(PoisonError::into_inner);
+        if terminal && (last.0 != phase || last.1 != percent) {
+            let filled = percent * WIDTH / 100;
+            eprint!(
+                "\r\x1b[2K{} [{}{}] {:>3}%",
+                phase,
+                "#".repeat(filled),
+                " ".repeat(WIDTH - filled),
+                percent
+            );
+            let _ = stderr().flush();
+            *last = (phase.to_string(), percent);
+        } else if !terminal && (last.0 != phase || last.1 / 10 != percent / 10) {
+            eprintln!("{} {:>3}%", phase, percent);
+            *last = (phase.to_string(), percent);
+        }
+    })
+}
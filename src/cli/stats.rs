@@ -0,0 +1,184 @@
+//! The `stats` subcommand showing a calendar of the implemented parts.
+
+use crate::cli::table::{Cell, Format, Table};
+use aocode::{AdventOfCode, AoC, CatalogYear, Error};
+use serde_json::{Deserializer, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// The number of days in the calendar of a year.
+const DAYS: usize = 25;
+/// The number of days in a row of the calendar.
+const DAYS_PER_ROW: usize = 5;
+
+/// Print a calendar of the implemented parts and the number of stars of every year, or of a year.
+/// # Arguments
+/// * `advent_of_code` - The [AoC] with the challenges.
+/// * `year` - The identifier of the only year to show, if any.
+/// * `timings` - The benchmark file with the times of the parts, if any.
+///   It contains one or more JSON arrays printed by `--benchmark --format json` or `run-all --format json`.
+/// * `format` - The output [Format].
+/// # Returns
+/// * [ExitCode::FAILURE] if the year is unavailable or the benchmark file is unreadable,
+///   otherwise [ExitCode::SUCCESS].
+pub fn stats(
+    advent_of_code: &AoC,
+    year: Option<usize>,
+    timings: Option<&Path>,
+    format: Format,
+) -> ExitCode {
+    let catalog = advent_of_code.catalog();
+    let years = match year {
+        Some(year) => match catalog.year(year) {
+            Some(catalog_year) => vec![catalog_year],
+            None => {
+                eprintln!("Error showing the statistics: {}", Error::UnavailableYear);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => catalog.years.iter().collect(),
+    };
+    let best_times = match timings.map(read_best_times).transpose() {
+        Ok(best_times) => best_times,
+        Err(err) => {
+            eprintln!("Error reading the benchmark file: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let best_time = |year: usize, day: usize| best_times.as_ref()?.get(&(year, day)).copied();
+
+    if format == Format::Text {
+        let mut stars = 0;
+        for (i, catalog_year) in years.iter().enumerate() {
+            if i != 0 {
+                println!();
+            }
+            print_calendar(catalog_year, best_times.is_some(), |day| {
+                best_time(catalog_year.id, day)
+            });
+            stars += year_stars(catalog_year);
+        }
+        if years.len() > 1 {
+            println!();
+            println!("Total: {}/{} stars", stars, 2 * DAYS * years.len());
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let mut table = Table::new(&[
+        ("year", "Year"),
+        ("day", "Day"),
+        ("title", "Title"),
+        ("parts", "Parts"),
+        ("stars", "Stars"),
+        ("best_duration", "Best time"),
+    ]);
+    for catalog_year in years {
+        for id in 1..=DAYS {
+            let day = catalog_year.day(id);
+            let parts = day.map(|day| day.parts.clone()).unwrap_or_default();
+            table.push(vec![
+                catalog_year.id.into(),
+                id.into(),
+                day.map(|day| day.title.as_str()).into(),
+                Cell::List(parts.iter().map(usize::to_string).collect()),
+                parts.len().into(),
+                best_time(catalog_year.id, id).into(),
+            ]);
+        }
+    }
+    print!("{}", table.render(format));
+    ExitCode::SUCCESS
+}
+
+/// Get the number of stars of the implemented parts of a year.
+fn year_stars(year: &CatalogYear) -> usize {
+    year.days.iter().map(|day| day.parts.len()).sum()
+}
+
+/// Print the calendar of a year with a star for every implemented part and a dot for every other part.
+fn print_calendar(year: &CatalogYear, timed: bool, best_time: impl Fn(usize) -> Option<Duration>) {
+    println!(
+        "Advent of Code {}: {}/{} stars",
+        year.id,
+        year_stars(year),
+        2 * DAYS
+    );
+    for row in (1..=DAYS).collect::<Vec<_>>().chunks(DAYS_PER_ROW) {
+        let cells = row
+            .iter()
+            .map(|&id| {
+                let parts = year
+                    .day(id)
+                    .map(|day| day.parts.as_slice())
+                    .unwrap_or_default();
+                let stars = [1, 2]
+                    .map(|part| if parts.contains(&part) { '*' } else { '.' })
+                    .iter()
+                    .collect::<String>();
+                if timed {
+                    let time = best_time(id)
+                        .map(|time| format!("{:.3} ms", time.as_secs_f64() * 1000.0))
+                        .unwrap_or_default();
+                    format!("{:>2} {} {:>12}", id, stars, time)
+                } else {
+                    format!("{:>2} {}", id, stars)
+                }
+            })
+            .collect::<Vec<_>>();
+        println!("{}", cells.join("   ").trim_end());
+    }
+}
+
+/// Read the best time of every day from a benchmark file,
+/// the sum of the shortest times of its parts.
+fn read_best_times(path: &Path) -> io::Result<HashMap<(usize, usize), Duration>> {
+    let contents = fs::read_to_string(path)
+        .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path.display(), err)))?;
+    let invalid = |message: &str| {
+        io::Error::new(
+            ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), message),
+        )
+    };
+
+    // the file may contain several arrays, e.g. the appended outputs of several benchmarks
+    let mut part_times = HashMap::<(usize, usize, usize), Duration>::new();
+    for rows in Deserializer::from_str(&contents).into_iter::<Vec<Value>>() {
+        let rows = rows.map_err(|err| invalid(&err.to_string()))?;
+        for row in rows {
+            let field = |key: &str| {
+                row.get(key)
+                    .and_then(Value::as_u64)
+                    .map(|value| value as usize)
+            };
+            let (Some(year), Some(day), Some(part)) = (field("year"), field("day"), field("part"))
+            else {
+                return Err(invalid("expected a year, a day and a part in every row"));
+            };
+            // repeated runs have a minimum, single runs only a duration
+            let Some(seconds) = ["min", "duration"]
+                .iter()
+                .find_map(|key| row.get(*key).and_then(Value::as_f64))
+            else {
+                continue;
+            };
+            let time =
+                Duration::try_from_secs_f64(seconds).map_err(|err| invalid(&err.to_string()))?;
+            part_times
+                .entry((year, day, part))
+                .and_modify(|best| *best = (*best).min(time))
+                .or_insert(time);
+        }
+    }
+
+    let mut day_times = HashMap::new();
+    for ((year, day, _), time) in part_times {
+        *day_times.entry((year, day)).or_default() += time;
+    }
+    Ok(day_times)
+}
@@ -0,0 +1,350 @@
+//! The `submit` subcommand posting answers and keeping a log of the verdicts.
+
+use crate::cli::client::Client;
+use std::fmt::{self, Display, Formatter};
+use std::fs::{self, OpenOptions};
+use std::io::{self, ErrorKind, Write};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// An enum representing the verdict of the website on a submitted answer.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+enum Verdict {
+    /// The answer is correct.
+    Correct,
+    /// The answer is wrong.
+    Wrong,
+    /// The answer is wrong and higher than the correct one.
+    TooHigh,
+    /// The answer is wrong and lower than the correct one.
+    TooLow,
+    /// An answer was submitted too recently, so the answer was not checked,
+    /// with the time to wait if the page states it.
+    RateLimited(Option<Duration>),
+    /// The part is already solved or not unlocked yet, so the answer was not checked.
+    WrongLevel,
+}
+impl Verdict {
+    /// Parse the verdict from the page returned after submitting an answer.
+    fn parse(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Some(Self::TooHigh)
+            } else if page.contains("your answer is too low") {
+                Some(Self::TooLow)
+            } else {
+                Some(Self::Wrong)
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Some(Self::RateLimited(parse_wait(page)))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Self::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    /// Get the name of the verdict in the submission log.
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::Wrong => "wrong",
+            Self::TooHigh => "too-high",
+            Self::TooLow => "too-low",
+            Self::RateLimited(_) => "rate-limited",
+            Self::WrongLevel => "wrong-level",
+        }
+    }
+}
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::TooHigh => write!(f, "wrong, too high"),
+            Self::TooLow => write!(f, "wrong, too low"),
+            Self::RateLimited(Some(wait)) => {
+                write!(
+                    f,
+                    "not checked, wait {} s before submitting again",
+                    wait.as_secs()
+                )
+            }
+            Self::RateLimited(None) => {
+                write!(f, "not checked, an answer was submitted too recently")
+            }
+            Self::WrongLevel => write!(f, "not checked, the part is solved or locked"),
+        }
+    }
+}
+
+/// Parse the time to wait before submitting another answer from the page returned after submitting one.
+/// The website asks to wait after an answer submitted too recently, e.g. "You have 1m 23s left to wait.",
+/// and after a wrong answer, e.g. "Please wait one minute before trying again."
+fn parse_wait(page: &str) -> Option<Duration> {
+    if let Some((_, wait)) = page.split_once("You have ") {
+        let (wait, _) = wait.split_once(" left to wait")?;
+        let mut seconds: u64 = 0;
+        for amount in wait.split_whitespace() {
+            let (unit, multiplier) = match amount.chars().last()? {
+                'h' => ('h', 3600),
+                'm' => ('m', 60),
+                's' => ('s', 1),
+                _ => return None,
+            };
+            let amount = amount.trim_end_matches(unit).parse::<u64>().ok()?;
+            seconds = seconds.checked_add(amount.checked_mul(multiplier)?)?;
+        }
+        Some(Duration::from_secs(seconds))
+    } else {
+        let (_, wait) = page.split_once("lease wait ")?;
+        let mut words = wait.split_whitespace();
+        let amount = match words.next()? {
+            "one" => 1,
+            amount => amount.parse::<u64>().ok()?,
+        };
+        let unit = words.next()?;
+        let multiplier = match unit.split(|c: char| !c.is_ascii_alphabetic()).next()? {
+            "second" | "seconds" => 1,
+            "minute" | "minutes" => 60,
+            "hour" | "hours" => 3600,
+            _ => return None,
+        };
+        Some(Duration::from_secs(amount.checked_mul(multiplier)?))
+    }
+}
+
+/// A submitted answer in the submission log.
+struct Submission {
+    year: usize,
+    day: usize,
+    part: usize,
+    verdict: String,
+    answer: String,
+    /// The time in seconds since the Unix epoch until which the website refuses answers, if any.
+    wait_until: Option<u64>,
+}
+
+/// Submit the answer to a part of a day's challenge unless the submission log rules it out.
+/// Known wrong answers and answers outside of the bounds given by earlier answers are not submitted,
+/// and no answer is submitted before the time to wait asked for by the website has passed.
+/// # Arguments
+/// * `client` - The [Client] of the website.
+/// * `log` - The submission log, with a line of tab-separated fields per submission.
+/// * `year` - The identifier of the year of the challenge.
+/// * `day` - The identifier of the day's challenge.
+/// * `part` - The part of the challenge.
+/// * `answer` - The answer to submit.
+/// # Returns
+/// * [ExitCode::SUCCESS] if the answer is correct, otherwise [ExitCode::FAILURE].
+pub fn submit(
+    client: &mut Client,
+    log: &Path,
+    year: usize,
+    day: usize,
+    part: usize,
+    answer: &str,
+) -> ExitCode {
+    let label = format!("{} day {} part {}", year, day, part);
+    if answer.contains(['\t', '\n']) {
+        eprintln!(
+            "Error submitting {}: cannot submit a multi-line answer",
+            label
+        );
+        return ExitCode::FAILURE;
+    }
+    let submissions = match read_log(log) {
+        Ok(submissions) => submissions,
+        Err(err) => {
+            eprintln!("Error reading the submission log: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    // the website limits the rate of the answers of the whole account, not of a single part
+    let wait_until = submissions
+        .iter()
+        .filter_map(|submission| submission.wait_until)
+        .max();
+    let submissions = submissions
+        .iter()
+        .filter(|submission| {
+            (submission.year, submission.day, submission.part) == (year, day, part)
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(correct) = submissions
+        .iter()
+        .find(|submission| submission.verdict == Verdict::Correct.name())
+    {
+        return if correct.answer == answer {
+            println!("{}: {} is correct (already submitted)", label, answer);
+            ExitCode::SUCCESS
+        } else {
+            eprintln!(
+                "{}: not submitting {}, the correct answer {} is already known",
+                label, answer, correct.answer
+            );
+            ExitCode::FAILURE
+        };
+    }
+    if let Some(reason) = rule_out(&submissions, answer) {
+        eprintln!("{}: not submitting {}, {}", label, answer, reason);
+        return ExitCode::FAILURE;
+    }
+    let time = now();
+    if let Some(wait_until) = wait_until
+        && wait_until > time
+    {
+        eprintln!(
+            "{}: not submitting {}, wait {} s before submitting again",
+            label,
+            answer,
+            wait_until - time
+        );
+        return ExitCode::FAILURE;
+    }
+
+    let page = match client.post(
+        &format!("/{}/day/{}/answer", year, day),
+        &[("level", &part.to_string()), ("answer", answer)],
+    ) {
+        Ok(page) => page,
+        Err(err) => {
+            eprintln!("Error submitting {}: {}", label, err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let Some(verdict) = Verdict::parse(&page) else {
+        eprintln!("Error submitting {}: the verdict is not recognized", label);
+        return ExitCode::FAILURE;
+    };
+    let submission = Submission {
+        year,
+        day,
+        part,
+        verdict: verdict.name().to_string(),
+        answer: answer.to_string(),
+        wait_until: parse_wait(&page).map(|wait| now().saturating_add(wait.as_secs())),
+    };
+    if let Err(err) = append_log(log, &submission) {
+        eprintln!("Error writing the submission log: {}", err);
+    }
+
+    println!("{}: {} is {}", label, answer, verdict);
+    if verdict == Verdict::Correct {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Get the reason why an answer is known to be wrong from earlier submissions of the part, if any.
+fn rule_out(submissions: &[&Submission], answer: &str) -> Option<String> {
+    let wrong = [Verdict::Wrong, Verdict::TooHigh, Verdict::TooLow].map(|verdict| verdict.name());
+    if submissions.iter().any(|submission| {
+        submission.answer == answer && wrong.contains(&submission.verdict.as_str())
+    }) {
+        return Some(String::from("it is already known to be wrong"));
+    }
+
+    // numeric answers have to be between the answers which were too low and too high
+    let value = answer.parse::<i128>().ok()?;
+    let bound = |verdict: Verdict| {
+        submissions
+            .iter()
+            .filter(move |submission| submission.verdict == verdict.name())
+            .filter_map(|submission| submission.answer.parse::<i128>().ok())
+    };
+    if let Some(high) = bound(Verdict::TooHigh).min()
+        && value >= high
+    {
+        return Some(format!("{} is already too high", high));
+    }
+    if let Some(low) = bound(Verdict::TooLow).max()
+        && value <= low
+    {
+        return Some(format!("{} is already too low", low));
+    }
+    None
+}
+
+/// Read the submissions in the log, which is empty if the file does not exist.
+fn read_log(log: &Path) -> io::Result<Vec<Submission>> {
+    let contents = match fs::read_to_string(log) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => {
+            return Err(io::Error::new(
+                err.kind(),
+                format!("{}: {}", log.display(), err),
+            ));
+        }
+    };
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            // the fields are the time, year, day, part, verdict, answer and the time to wait until, if any
+            let fields = line.split('\t').collect::<Vec<_>>();
+            let malformed = || {
+                io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!("{}:{}: malformed line", log.display(), i + 1),
+                )
+            };
+            let (submission, wait_until) = match &fields[..] {
+                [submission @ .., wait_until] if submission.len() == 6 => (
+                    submission,
+                    Some(wait_until.parse().map_err(|_| malformed())?),
+                ),
+                submission => (submission, None),
+            };
+            let &[_, year, day, part, verdict, answer] = submission else {
+                return Err(malformed());
+            };
+            Ok(Submission {
+                year: year.parse().map_err(|_| malformed())?,
+                day: day.parse().map_err(|_| malformed())?,
+                part: part.parse().map_err(|_| malformed())?,
+                verdict: verdict.to_string(),
+                answer: answer.to_string(),
+                wait_until,
+            })
+        })
+        .collect()
+}
+
+/// Append a submission with the current time to the log.
+fn append_log(log: &Path, submission: &Submission) -> io::Result<()> {
+    if let Some(parent) = log.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        fs::create_dir_all(parent)?;
+    }
+    let mut line = format!(
+        "{}\t{}\t{}\t{}\t{}\t{}",
+        now(),
+        submission.year,
+        submission.day,
+        submission.part,
+        submission.verdict,
+        submission.answer
+    );
+    if let Some(wait_until) = submission.wait_until {
+        line += &format!("\t{}", wait_until);
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(log)?;
+    writeln!(file, "{}", line)
+}
+
+/// Get the current time in seconds since the Unix epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
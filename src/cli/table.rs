@@ -0,0 +1,257 @@
+//! Tables of typed cells, printed as aligned text, JSON, CSV or Markdown.
+
+use serde_json::{Map, Value, json};
+use std::fmt::{self, Display, Formatter};
+use std::time::Duration;
+
+/// An enum representing the output format of the binary.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Format {
+    /// Human-readable text.
+    #[default]
+    Text,
+    /// An array of JSON objects, one per row.
+    Json,
+    /// Comma-separated values with a header row.
+    Csv,
+    /// A Markdown table.
+    Markdown,
+}
+impl Format {
+    /// The names of all formats.
+    pub const NAMES: [&'static str; 4] = ["text", "json", "csv", "markdown"];
+
+    /// Get the format with the specified name.
+    /// # Arguments
+    /// * `name` - The name of the format.
+    /// # Returns
+    /// * The format or a message listing the known names.
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            "markdown" => Ok(Self::Markdown),
+            _ => Err(format!("expected one of {}", Self::NAMES.join(", "))),
+        }
+    }
+}
+
+/// An enum representing the value of a cell in a [Table].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Cell {
+    /// A missing value, e.g. the answer of a failed part.
+    Empty,
+    /// An integer.
+    Integer(i64),
+    /// A text, which may span several lines.
+    Text(String),
+    /// A duration, printed in milliseconds as text and in seconds otherwise.
+    Duration(Duration),
+    /// A list of texts.
+    List(Vec<String>),
+}
+impl Cell {
+    /// Get the value of the cell for aligned text and Markdown.
+    fn text(&self) -> String {
+        match self {
+            Self::Empty => String::new(),
+            Self::Integer(value) => value.to_string(),
+            Self::Text(text) => text.clone(),
+            Self::Duration(duration) => format!("{:.3} ms", duration.as_secs_f64() * 1000.0),
+            Self::List(items) => items.join(", "),
+        }
+    }
+
+    /// Get the value of the cell for CSV.
+    fn csv(&self) -> String {
+        match self {
+            Self::Duration(duration) => duration.as_secs_f64().to_string(),
+            Self::List(items) => items.join(";"),
+            _ => self.text(),
+        }
+    }
+
+    /// Get the value of the cell for JSON.
+    fn json(&self) -> Value {
+        match self {
+            Self::Empty => Value::Null,
+            Self::Integer(value) => json!(value),
+            Self::Text(text) => json!(text),
+            Self::Duration(duration) => json!(duration.as_secs_f64()),
+            Self::List(items) => json!(items),
+        }
+    }
+}
+impl From<usize> for Cell {
+    fn from(value: usize) -> Self {
+        Self::Integer(value as i64)
+    }
+}
+impl From<&str> for Cell {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+impl From<String> for Cell {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+impl From<Duration> for Cell {
+    fn from(duration: Duration) -> Self {
+        Self::Duration(duration)
+    }
+}
+impl<T: Into<Cell>> From<Option<T>> for Cell {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Empty, Into::into)
+    }
+}
+
+/// A column of a [Table] with a key for JSON and CSV and a title for text and Markdown.
+pub type Column = (&'static str, &'static str);
+
+/// A table with columns and rows of typed cells.
+pub struct Table {
+    columns: Vec<Column>,
+    rows: Vec<Vec<Cell>>,
+}
+impl Table {
+    /// Create a new empty [Table].
+    /// # Arguments
+    /// * `columns` - The keys and titles of the columns.
+    /// # Returns
+    /// * The new [Table].
+    pub fn new(columns: &[Column]) -> Self {
+        Self {
+            columns: columns.to_vec(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Add a row to the table.
+    /// # Arguments
+    /// * `row` - The cells of the row, one for every column.
+    pub fn push(&mut self, row: Vec<Cell>) {
+        debug_assert_eq!(row.len(), self.columns.len());
+        self.rows.push(row);
+    }
+
+    /// Render the table in a format.
+    /// # Arguments
+    /// * `format` - The output [Format].
+    /// # Returns
+    /// * The rendered table, ending with a newline.
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Text => self.to_string(),
+            Format::Json => self.json(),
+            Format::Csv => self.csv(),
+            Format::Markdown => self.markdown(),
+        }
+    }
+
+    /// Render the table as an array of JSON objects.
+    fn json(&self) -> String {
+        let objects = self
+            .rows
+            .iter()
+            .map(|row| {
+                let object = self
+                    .columns
+                    .iter()
+                    .zip(row)
+                    .map(|(&(key, _), cell)| (key.to_string(), cell.json()))
+                    .collect::<Map<_, _>>();
+                Value::Object(object)
+            })
+            .collect();
+        let mut output = serde_json::to_string_pretty(&Value::Array(objects)).unwrap();
+        output.push('\n');
+        output
+    }
+
+    /// Render the table as comma-separated values with quoted cells where needed.
+    fn csv(&self) -> String {
+        let escape = |cell: String| {
+            if cell.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell
+            }
+        };
+        let mut output = String::new();
+        let header = self.columns.iter().map(|&(key, _)| key.to_string());
+        output.push_str(&header.map(escape).collect::<Vec<_>>().join(","));
+        output.push('\n');
+        for row in &self.rows {
+            let cells = row.iter().map(|cell| escape(cell.csv()));
+            output.push_str(&cells.collect::<Vec<_>>().join(","));
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Render the table as a Markdown table.
+    fn markdown(&self) -> String {
+        let escape = |cell: String| cell.replace('|', "\\|").replace('\n', "<br>");
+        let line = |cells: Vec<String>| format!("| {} |\n", cells.join(" | "));
+        let mut output = line(
+            self.columns
+                .iter()
+                .map(|&(_, title)| escape(title.to_string()))
+                .collect(),
+        );
+        output.push_str(&line(
+            self.columns.iter().map(|_| String::from("---")).collect(),
+        ));
+        for row in &self.rows {
+            output.push_str(&line(row.iter().map(|cell| escape(cell.text())).collect()));
+        }
+        output
+    }
+}
+impl Display for Table {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(Cell::text).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let header = self
+            .columns
+            .iter()
+            .map(|&(_, title)| title.to_string())
+            .collect::<Vec<_>>();
+        let widths = (0..header.len())
+            .map(|column| {
+                rows.iter()
+                    .chain([&header])
+                    .map(|row| row[column].chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+
+        let write_row = |f: &mut Formatter<'_>, row: &[String]| {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, &width)| format!("{:<width$}", cell))
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())
+        };
+        write_row(f, &header)?;
+        let rule = widths
+            .iter()
+            .map(|&width| "-".repeat(width))
+            .collect::<Vec<_>>();
+        writeln!(f, "{}", rule.join("  "))?;
+        for row in &rows {
+            write_row(f, row)?;
+        }
+        Ok(())
+    }
+}
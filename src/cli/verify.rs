@@ -0,0 +1,218 @@
+//! The `verify` subcommand checking answers against a tree of expected answers.
+
+use crate::cli::table::{Cell, Format, Table};
+use aocode::{AdventOfCode, Answer, AoC};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+use std::{fs, io};
+
+/// An enum representing the outcome of verifying a part.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+enum Status {
+    /// The answer matches the expected answer.
+    Pass,
+    /// The answer differs from the expected answer.
+    Fail,
+    /// The input or the expected answer is missing.
+    Missing,
+    /// Solving the part failed.
+    Error,
+    /// The answer was written as the new expected answer.
+    Blessed,
+}
+impl Status {
+    /// Get the name of the status.
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Fail => "fail",
+            Self::Missing => "missing",
+            Self::Error => "error",
+            Self::Blessed => "blessed",
+        }
+    }
+}
+
+/// Solve every implemented part, or those of a year, and compare the answers to the expected ones.
+/// The expected answers are read from `yearYYYY/dayDD/partN.txt` files in the outputs directory.
+/// When blessing, the answers which differ from the expected ones or have none are written
+/// as the new expected answers and the changes are printed to the standard error.
+/// # Arguments
+/// * `advent_of_code` - The [AoC] solving the challenges.
+/// * `year` - The identifier of the only year to verify, if any.
+/// * `inputs` - The directory with the inputs in `yearYYYY/dayDD.txt` files.
+/// * `outputs` - The directory with the expected answers.
+/// * `timeout` - The time after which the solutions polling for cancellation stop.
+/// * `format` - The output [Format].
+/// * `bless` - Whether to write the answers as the new expected answers.
+/// # Returns
+/// * [ExitCode::FAILURE] if an answer is wrong or a part failed, otherwise [ExitCode::SUCCESS].
+pub fn verify(
+    advent_of_code: &AoC,
+    year: Option<usize>,
+    inputs: &Path,
+    outputs: &Path,
+    timeout: Duration,
+    format: Format,
+    bless: bool,
+) -> ExitCode {
+    if let Some(year) = year
+        && let Err(err) = advent_of_code.year(year)
+    {
+        eprintln!("Error verifying the challenges: {}", err);
+        return ExitCode::FAILURE;
+    }
+    let report = match year {
+        Some(year) => advent_of_code.solve_all_in_year(year, inputs, timeout),
+        None => advent_of_code.solve_all(inputs, timeout),
+    };
+    let report = match report {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("Error reading the inputs: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut table = Table::new(&[
+        ("year", "Year"),
+        ("day", "Day"),
+        ("title", "Title"),
+        ("part", "Part"),
+        ("status", "Status"),
+        ("answer", "Answer"),
+        ("expected", "Expected"),
+        ("error", "Error"),
+    ]);
+    let mut counts = [0; 5];
+    let catalog = advent_of_code.catalog();
+    for catalog_year in catalog
+        .years
+        .iter()
+        .filter(|catalog_year| year.is_none_or(|year| catalog_year.id == year))
+    {
+        for day in &catalog_year.days {
+            for &part in &day.parts {
+                let expected = match read_expected(outputs, catalog_year.id, day.id, part) {
+                    Ok(expected) => expected,
+                    Err(err) => {
+                        eprintln!("Error reading the expected answers: {}", err);
+                        return ExitCode::FAILURE;
+                    }
+                };
+                let entry = report.entry(catalog_year.id, day.id, part);
+                if entry.is_none() && expected.is_none() {
+                    continue;
+                }
+
+                let result = entry.map(|entry| &entry.result);
+                let mut status = match (result, &expected) {
+                    (Some(Err(_)), _) => Status::Error,
+                    (None, _) | (_, None) => Status::Missing,
+                    (Some(Ok(answer)), Some(expected)) if answer == expected => Status::Pass,
+                    (Some(Ok(_)), Some(_)) => Status::Fail,
+                };
+                if bless
+                    && let Some(Ok(answer)) = result
+                    && status != Status::Pass
+                {
+                    let path = expected_path(outputs, catalog_year.id, day.id, part);
+                    match aocode::golden::bless(&path, answer) {
+                        Ok(Some(diff)) => {
+                            eprintln!("Blessed {}:\n{}", path.display(), diff);
+                            status = Status::Blessed;
+                        }
+                        Ok(None) => status = Status::Pass,
+                        Err(err) => {
+                            eprintln!(
+                                "Error writing the expected answers: {}: {}",
+                                path.display(),
+                                err
+                            );
+                            return ExitCode::FAILURE;
+                        }
+                    }
+                }
+                counts[status as usize] += 1;
+
+                let answer = match result {
+                    Some(Ok(Answer::Grid(rows))) if format == Format::Text => {
+                        Cell::Text(format!("({} rows)", rows.len()))
+                    }
+                    Some(Ok(answer)) => answer.to_string().into(),
+                    _ => Cell::Empty,
+                };
+                let error = match result {
+                    Some(Err(err)) if format == Format::Text => Cell::Text(err.to_string()),
+                    Some(Err(err)) => err.kind().into(),
+                    _ => Cell::Empty,
+                };
+                // passed answers are equal to the expected ones, so they are printed once
+                let expected = match expected {
+                    Some(_) if format == Format::Text && status == Status::Pass => Cell::Empty,
+                    Some(expected) if format == Format::Text && expected.contains('\n') => {
+                        Cell::Text(format!("({} rows)", expected.lines().count()))
+                    }
+                    expected => expected.into(),
+                };
+                table.push(vec![
+                    catalog_year.id.into(),
+                    day.id.into(),
+                    day.title.as_str().into(),
+                    part.into(),
+                    status.name().into(),
+                    answer,
+                    expected,
+                    error,
+                ]);
+            }
+        }
+    }
+
+    let [passed, failed, missing, errors, blessed] = counts;
+    print!("{}", table.render(format));
+    if format == Format::Text {
+        println!();
+        print!(
+            "{} passed, {} failed, {} missing, {} errors",
+            passed, failed, missing, errors
+        );
+        if bless {
+            print!(", {} blessed", blessed);
+        }
+        println!();
+    }
+
+    if failed > 0 || errors > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Read the expected answer to a part, if its file exists.
+fn read_expected(
+    outputs: &Path,
+    year: usize,
+    day: usize,
+    part: usize,
+) -> io::Result<Option<String>> {
+    let path = expected_path(outputs, year, day, part);
+    match fs::read_to_string(&path) {
+        Ok(expected) => Ok(Some(expected.replace("\r\n", "\n").trim().to_string())),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(io::Error::new(
+            err.kind(),
+            format!("{}: {}", path.display(), err),
+        )),
+    }
+}
+
+/// Get the path to the file with the expected answer to a part.
+fn expected_path(outputs: &Path, year: usize, day: usize, part: usize) -> PathBuf {
+    outputs
+        .join(format!("year{:04}", year))
+        .join(format!("day{:02}", day))
+        .join(format!("part{}.txt", part))
+}
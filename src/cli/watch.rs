@@ -0,0 +1,191 @@
+//! Watching an input file and solving the challenge again whenever it changes.
+
+use aocode::{AdventOfCode, AoC, Parameters};
+use std::fs;
+use std::io::{IsTerminal, Write, stdout};
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+/// The time between two checks of the input file.
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// The outcome of solving a part in a run.
+struct Outcome {
+    part: usize,
+    result: Result<String, String>,
+    total_time: Duration,
+    /// The parse and solve times, if the part was benchmarked.
+    times: Option<(Duration, Duration)>,
+}
+
+/// Solve parts of a day's challenge whenever the input file changes, until the process is stopped.
+/// The file is polled, and every run replaces the output of the previous run
+/// with the answers, the times and how they changed since the previous run.
+/// # Arguments
+/// * `advent_of_code` - The [AoC] with the challenge.
+/// * `year` - The identifier of the year of the challenge.
+/// * `day` - The identifier of the day's challenge.
+/// * `parts` - The parts to solve.
+/// * `path` - The path of the input file.
+/// * `parameters` - The values of the parameters of the challenge.
+/// * `benchmark` - Whether to measure the parse and solve times separately.
+pub fn watch(
+    advent_of_code: &AoC,
+    year: usize,
+    day: usize,
+    parts: &[usize],
+    path: &Path,
+    parameters: &Parameters,
+    benchmark: bool,
+) -> ! {
+    let mut stamp = None::<(SystemTime, u64)>;
+    let mut input = None::<String>;
+    let mut error_shown = false;
+    let mut previous = Vec::new();
+    let mut run = 0;
+    loop {
+        // the file is only read when its modification time or length changed
+        let contents = fs::metadata(path)
+            .and_then(|metadata| Ok((metadata.modified()?, metadata.len())))
+            .and_then(|new_stamp| {
+                if stamp == Some(new_stamp) {
+                    return Ok(None);
+                }
+                let contents = fs::read_to_string(path)?;
+                stamp = Some(new_stamp);
+                Ok(Some(contents.replace("\r\n", "\n")))
+            });
+
+        match contents {
+            Ok(Some(contents)) if input.as_ref() != Some(&contents) => {
+                run += 1;
+                let outcomes = parts
+                    .iter()
+                    .map(|&part| {
+                        solve(
+                            advent_of_code,
+                            year,
+                            day,
+                            part,
+                            &contents,
+                            parameters,
+                            benchmark,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                clear();
+                println!("{} day {} --- {} (run {})", year, day, path.display(), run);
+                for outcome in &outcomes {
+                    let before = previous
+                        .iter()
+                        .find(|before: &&Outcome| before.part == outcome.part);
+                    println!("{}", render(outcome, before));
+                }
+                print_footer();
+                input = Some(contents);
+                previous = outcomes;
+                error_shown = false;
+            }
+            Ok(_) => {}
+            Err(err) => {
+                // the error is shown once, e.g. while an editor replaces the file
+                if !error_shown {
+                    clear();
+                    println!("Error reading the input: {}: {}", path.display(), err);
+                    print_footer();
+                    error_shown = true;
+                }
+                stamp = None;
+                input = None;
+            }
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Print the hint below the output of a run.
+fn print_footer() {
+    println!();
+    println!("Watching for changes, press Ctrl+C to stop");
+    let _ = stdout().flush();
+}
+
+/// Solve a part and measure the time taken.
+fn solve(
+    advent_of_code: &AoC,
+    year: usize,
+    day: usize,
+    part: usize,
+    input: &str,
+    parameters: &Parameters,
+    benchmark: bool,
+) -> Outcome {
+    if benchmark {
+        let start = Instant::now();
+        let result = advent_of_code.benchmark(year, day, part, input);
+        let total_time = start.elapsed();
+        return match result {
+            Ok(benchmark) => Outcome {
+                part,
+                result: Ok(benchmark.answer.to_string()),
+                total_time: benchmark.total_time(),
+                times: Some((benchmark.parse_time, benchmark.solve_time)),
+            },
+            Err(err) => Outcome {
+                part,
+                result: Err(err.to_string()),
+                total_time,
+                times: None,
+            },
+        };
+    }
+
+    let start = Instant::now();
+    let result = advent_of_code.solve_with(year, day, part, input, parameters);
+    Outcome {
+        part,
+        result: result
+            .map(|answer| answer.to_string())
+            .map_err(|err| err.to_string()),
+        total_time: start.elapsed(),
+        times: None,
+    }
+}
+
+/// Render the outcome of a part with the changes since the outcome of the previous run.
+fn render(outcome: &Outcome, before: Option<&Outcome>) -> String {
+    let show = |result: &Result<String, String>| match result {
+        Ok(answer) => answer.clone(),
+        Err(err) => format!("error: {}", err),
+    };
+    let mut line = format!("Part {}: {}", outcome.part, show(&outcome.result));
+    if let Some(before) = before
+        && before.result != outcome.result
+    {
+        line += &format!(" (was {})", show(&before.result));
+    }
+
+    line += &format!("    --- {} s", outcome.total_time.as_secs_f64());
+    if let Some(before) = before {
+        let delta = outcome.total_time.as_secs_f64() - before.total_time.as_secs_f64();
+        line += &format!(" ({:+} s)", delta);
+    }
+    if let Some((parse_time, solve_time)) = outcome.times {
+        line += &format!(
+            " (parse {} s, solve {} s)",
+            parse_time.as_secs_f64(),
+            solve_time.as_secs_f64()
+        );
+    }
+    line
+}
+
+/// Clear the terminal, or separate the runs with a blank line if the output is not a terminal.
+fn clear() {
+    if stdout().is_terminal() {
+        print!("\x1b[2J\x1b[H");
+    } else {
+        println!();
+    }
+}
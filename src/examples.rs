@@ -0,0 +1,143 @@
+//! Worked examples from the puzzle texts, bundled with the solutions of every year.
+//!
+//! The examples of a year are stored in the `examples.txt` file next to its solutions.
+//! Every example starts with header lines beginning with `=== `, followed by its input:
+//!
+//! ```text
+//! === day 18
+//! === parameter size = 7
+//! === parameter bytes = 12
+//! === part 1 = 22
+//! === part 2 = 6,1
+//! 5,4
+//! 4,2
+//! ```
+//!
+//! Blank lines at the end of an input are ignored, so that the examples can be separated by them.
+
+use crate::input::{OrInvalid, parse_number};
+use crate::{Error, Parameters};
+
+/// The prefix of the header lines of an example.
+const HEADER: &str = "=== ";
+
+/// A worked example from the puzzle text of a day's challenge with its expected answers.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Example {
+    /// The identifier of the day's challenge.
+    pub day: usize,
+    /// The input to the example, ending with a newline like the puzzle inputs.
+    pub input: String,
+    /// The values of the parameters which differ between the puzzle and the example.
+    pub parameters: Parameters,
+    /// The parts with their expected answers, in the order of the header lines.
+    pub answers: Vec<(usize, String)>,
+}
+impl Example {
+    /// Get the expected answer to a part of the example.
+    /// # Arguments
+    /// * `part` - The part of the challenge.
+    /// # Returns
+    /// * The expected answer or [None] if the puzzle text has no answer to the part for the example.
+    pub fn answer(&self, part: usize) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|(answer_part, _)| *answer_part == part)
+            .map(|(_, answer)| answer.as_str())
+    }
+
+    /// Parse the examples of a year.
+    /// # Arguments
+    /// * `text` - The contents of the examples file of the year.
+    /// # Returns
+    /// * The examples in the order of the file or the [Error].
+    /// # Errors
+    /// * [Error::InvalidInput] - A header line is malformed or an example has no day or no answer.
+    pub(crate) fn parse_all(text: &str) -> Result<Vec<Self>, Error> {
+        let mut examples = Vec::new();
+        // the header of the current example and the lines of its input
+        let mut current = None::<(&str, Example)>;
+        let mut lines = Vec::new();
+
+        for line in text.lines() {
+            let Some(header) = line.strip_prefix(HEADER) else {
+                current.as_ref().or_invalid(
+                    text,
+                    line,
+                    "expected a header line before the input",
+                )?;
+                lines.push(line);
+                continue;
+            };
+            if !lines.is_empty()
+                && let Some(finished) = current.take()
+            {
+                examples.push(finish(text, finished, &mut lines)?);
+            }
+            let (_, example) = current.get_or_insert_with(|| {
+                let example = Example {
+                    day: 0,
+                    input: String::new(),
+                    parameters: Parameters::new(),
+                    answers: Vec::new(),
+                };
+                (line, example)
+            });
+
+            // the values are everything after the equals sign, as answers may contain spaces
+            let (key, value) = match header.split_once('=') {
+                Some((key, value)) => (key, Some(value.trim())),
+                None => (header, None),
+            };
+            match (key.split_whitespace().collect::<Vec<_>>().as_slice(), value) {
+                (["day", day], None) => example.day = parse_number(text, day)?,
+                (["part", part], Some(answer)) if !answer.is_empty() => {
+                    let part = parse_number(text, part)?;
+                    example.answers.push((part, answer.to_string()));
+                }
+                // the values are converted to the types of the parameters when solving
+                (["parameter", name], Some(value)) if !value.is_empty() => {
+                    example.parameters.set(name, value);
+                }
+                _ => {
+                    return Err(Error::invalid_input(
+                        text,
+                        header,
+                        "expected a day, a part with an answer or a parameter with a value",
+                    ));
+                }
+            }
+        }
+        if let Some(finished) = current {
+            examples.push(finish(text, finished, &mut lines)?);
+        }
+        Ok(examples)
+    }
+}
+
+/// Complete an example with the lines of its input and check that it has a day and an answer.
+fn finish(
+    text: &str,
+    (header, mut example): (&str, Example),
+    lines: &mut Vec<&str>,
+) -> Result<Example, Error> {
+    if example.day == 0 {
+        return Err(Error::invalid_input(
+            text,
+            header,
+            "expected the day of the example",
+        ));
+    }
+    if example.answers.is_empty() {
+        return Err(Error::invalid_input(text, header, "expected an answer"));
+    }
+
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    for line in lines.drain(..) {
+        example.input.push_str(line);
+        example.input.push('\n');
+    }
+    Ok(example)
+}
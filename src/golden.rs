@@ -0,0 +1,76 @@
+//! Golden files with the expected answers, blessed from new answers and compared line by line.
+
+use crate::Answer;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+
+/// Write an answer as the expected answer in a file, unless the file already holds an equal answer.
+/// The file and its directories are created if they do not exist.
+/// # Arguments
+/// * `path` - The path to the file with the expected answer.
+/// * `answer` - The new expected [Answer].
+/// # Returns
+/// * The [diff] of the old and the new expected answer, [None] if the file was left unchanged,
+///   or the [io::Error].
+/// # Errors
+/// * [io::Error] - The file cannot be read or written.
+pub fn bless(path: &Path, answer: &Answer) -> io::Result<Option<String>> {
+    let expected = match fs::read_to_string(path) {
+        Ok(expected) => expected.replace("\r\n", "\n"),
+        Err(err) if err.kind() == ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err),
+    };
+    if !expected.trim().is_empty() && answer == &expected {
+        return Ok(None);
+    }
+
+    let answer = answer.to_string();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, format!("{}\n", answer))?;
+    Ok(Some(diff(&expected, &answer)))
+}
+
+/// Compare two texts line by line.
+/// # Returns
+/// * Every line of the texts in order, prefixed with `-` if it was removed from the old text,
+///   `+` if it was added in the new text, or a space if it is in both.
+pub fn diff(old: &str, new: &str) -> String {
+    let old = old.lines().collect::<Vec<_>>();
+    let new = new.lines().collect::<Vec<_>>();
+
+    // the lengths of the longest common subsequences of the suffixes of the texts
+    let mut lengths = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i] == new[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            diff.push(' ');
+            diff.push_str(old[i]);
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lengths[i + 1][j] >= lengths[i][j + 1]) {
+            diff.push('-');
+            diff.push_str(old[i]);
+            i += 1;
+        } else {
+            diff.push('+');
+            diff.push_str(new[j]);
+            j += 1;
+        }
+        diff.push('\n');
+    }
+    diff
+}
@@ -112,12 +112,13 @@ impl Graph {
     }
 
     /// Finds the shortest Hamiltonian cycle in the graph.
-    /// Returns a tuple containing the minimum cost and the vertices in the cycle.
+    /// Returns a tuple containing the minimum cost and the vertices in the cycle,
+    /// or `None` if the graph has fewer than 2 vertices, no such cycle or a cost overflows.
     /// Since this is a cycle, vertices can be rotated to start from any vertex.
     /// The direction of the cycle is from lower indices to higher indices.
-    pub fn hamiltonian_cycle_min(&self) -> (isize, Vec<Vertex>) {
+    pub fn hamiltonian_cycle_min(&self) -> Option<(isize, Vec<Vertex>)> {
         if self.adj_list.len() < 2 {
-            panic!("The graph must contain at least 2 vertices.");
+            return None;
         }
 
         // define node structure used in the algorithm
@@ -142,20 +143,12 @@ impl Graph {
         let mut min_cost = isize::MAX;
 
         // find minimum edge weight from every vertex
+        // a vertex with no edges is on no cycle
         let min_edges = self
             .adj_list
             .iter()
-            .map(|(key, value)| {
-                (
-                    *key,
-                    value
-                        .iter()
-                        .map(|edge| edge.1)
-                        .min()
-                        .expect("Invalid graph! (vertex with no edges)"),
-                )
-            })
-            .collect::<HashMap<_, _>>();
+            .map(|(key, value)| Some((*key, value.iter().map(|edge| edge.1).min()?)))
+            .collect::<Option<HashMap<_, _>>>()?;
 
         // priority queue
         // nodes with smaller min_cost are popped first
@@ -165,7 +158,9 @@ impl Graph {
         // it doesn't matter which vertex is chosen as starting point
         // because the cycle can be rotated to start from any vertex
         let start_node = Node {
-            min_cost: min_edges.values().sum(),
+            min_cost: min_edges
+                .values()
+                .try_fold(0isize, |sum, &weight| sum.checked_add(weight))?,
             path: vec![*self.adj_list.keys().next().unwrap()],
         };
 
@@ -186,13 +181,13 @@ impl Graph {
 
                 let first_vertex = node.path.first().unwrap();
                 let last_vertex = node.path.last().unwrap();
-                node.min_cost -= min_edges[last_vertex];
+                node.min_cost = node.min_cost.checked_sub(min_edges[last_vertex])?;
                 match self.adj_list[last_vertex]
                     .iter()
                     .find(|(id, _)| id == first_vertex)
                 {
                     None => continue,
-                    Some(edge) => node.min_cost += edge.1,
+                    Some(edge) => node.min_cost = node.min_cost.checked_add(edge.1)?,
                 }
 
                 if node.min_cost < min_cost {
@@ -208,8 +203,9 @@ impl Graph {
                 for (other, weight) in &self.adj_list[last_vertex] {
                     if !node.path.contains(other) {
                         let mut new_node = node.clone();
-                        new_node.min_cost -= min_edges[last_vertex];
-                        new_node.min_cost += weight;
+                        new_node.min_cost =
+                            new_node.min_cost.checked_sub(min_edges[last_vertex])?;
+                        new_node.min_cost = new_node.min_cost.checked_add(*weight)?;
                         if new_node.min_cost < min_cost {
                             new_node.path.push(*other);
                             queue.push(new_node)
@@ -222,19 +218,20 @@ impl Graph {
         // if min_cost is still at its initial value, no cycle was found
         // else return the minimum cost and the cycle
         if min_cost == isize::MAX {
-            panic!("No cycle found!");
+            None
         } else {
-            (min_cost, min_cycle)
+            Some((min_cost, min_cycle))
         }
     }
 
     /// Finds the longest Hamiltonian cycle in the graph.
-    /// Returns a tuple containing the maximum cost and the vertices in the cycle.
+    /// Returns a tuple containing the maximum cost and the vertices in the cycle,
+    /// or `None` if the graph has fewer than 2 vertices, no such cycle or a cost overflows.
     /// Since this is a cycle, vertices can be rotated to start from any vertex.
     /// The direction of the cycle is from lower indices to higher indices.
-    pub fn hamiltonian_cycle_max(&self) -> (isize, Vec<Vertex>) {
+    pub fn hamiltonian_cycle_max(&self) -> Option<(isize, Vec<Vertex>)> {
         if self.adj_list.len() < 2 {
-            panic!("The graph must contain at least 2 vertices.");
+            return None;
         }
 
         // define node structure used in the algorithm
@@ -259,20 +256,12 @@ impl Graph {
         let mut max_cost = isize::MIN;
 
         // find maximum edge weight from every vertex
+        // a vertex with no edges is on no cycle
         let max_edges = self
             .adj_list
             .iter()
-            .map(|(key, value)| {
-                (
-                    *key,
-                    value
-                        .iter()
-                        .map(|edge| edge.1)
-                        .max()
-                        .expect("Invalid graph! (vertex with no edges)"),
-                )
-            })
-            .collect::<HashMap<_, _>>();
+            .map(|(key, value)| Some((*key, value.iter().map(|edge| edge.1).max()?)))
+            .collect::<Option<HashMap<_, _>>>()?;
 
         // priority queue
         // nodes with bigger max_cost are popped first
@@ -282,7 +271,9 @@ impl Graph {
         // it doesn't matter which vertex is chosen as the starting point
         // because the cycle can be rotated to start from any vertex
         let start_node = Node {
-            max_cost: max_edges.values().sum(),
+            max_cost: max_edges
+                .values()
+                .try_fold(0isize, |sum, &weight| sum.checked_add(weight))?,
             path: vec![*self.adj_list.keys().next().unwrap()],
         };
 
@@ -305,13 +296,13 @@ impl Graph {
 
                 let first_vertex = node.path.first().unwrap();
                 let last_vertex = node.path.last().unwrap();
-                node.max_cost -= max_edges[last_vertex];
+                node.max_cost = node.max_cost.checked_sub(max_edges[last_vertex])?;
                 match self.adj_list[last_vertex]
                     .iter()
                     .find(|(id, _)| id == first_vertex)
                 {
                     None => continue,
-                    Some(edge) => node.max_cost += edge.1,
+                    Some(edge) => node.max_cost = node.max_cost.checked_add(edge.1)?,
                 }
 
                 if node.max_cost > max_cost {
@@ -327,8 +318,9 @@ impl Graph {
                 for (other, weight) in &self.adj_list[last_vertex] {
                     if !node.path.contains(other) {
                         let mut new_node = node.clone();
-                        new_node.max_cost -= max_edges[last_vertex];
-                        new_node.max_cost += weight;
+                        new_node.max_cost =
+                            new_node.max_cost.checked_sub(max_edges[last_vertex])?;
+                        new_node.max_cost = new_node.max_cost.checked_add(*weight)?;
                         if new_node.max_cost > max_cost {
                             new_node.path.push(*other);
                             queue.push(new_node)
@@ -341,18 +333,19 @@ impl Graph {
         // if max_cost is still at its initial value, no cycle was found
         // else return the maximum cost and the cycle
         if max_cost == isize::MIN {
-            panic!("No cycle found!");
+            None
         } else {
-            (max_cost, max_cycle)
+            Some((max_cost, max_cycle))
         }
     }
 
     /// Finds the shortest Hamiltonian path in the graph.
-    /// Returns a tuple containing the minimum cost and the vertices in the path.
+    /// Returns a tuple containing the minimum cost and the vertices in the path,
+    /// or `None` if the graph has fewer than 2 vertices, no such path or a cost overflows.
     /// The direction of the path is from lower indices to higher indices.
-    pub fn hamiltonian_path_min(&mut self) -> (isize, Vec<Vertex>) {
+    pub fn hamiltonian_path_min(&mut self) -> Option<(isize, Vec<Vertex>)> {
         if self.adj_list.len() < 2 {
-            panic!("The graph must contain at least 2 vertices.");
+            return None;
         }
 
         // existing vertices
@@ -374,7 +367,10 @@ impl Graph {
             .extend(vertices.iter().map(|vertex| (*vertex, 0)));
 
         // find minimum hamiltonian cycle
-        let (min_cost, mut min_path) = self.hamiltonian_cycle_min();
+        let cycle = self.hamiltonian_cycle_min();
+        // remove added_vertex from the graph, even if there is no cycle
+        self.remove_vertex(added_vertex);
+        let (min_cost, mut min_path) = cycle?;
 
         // since added_vertex edges are 0, min_cost is correct
         // min_path is actually min_cycle that needs to be transformed into min_path
@@ -392,19 +388,17 @@ impl Graph {
         // remove added_vertex
         min_path.remove(0);
 
-        // remove added_vertex from the graph
-        self.remove_vertex(added_vertex);
-
         // return min_cost and min_path
-        (min_cost, min_path)
+        Some((min_cost, min_path))
     }
 
     /// Finds the longest Hamiltonian path in the graph.
-    /// Returns a tuple containing the maximum cost and the vertices in the path.
+    /// Returns a tuple containing the maximum cost and the vertices in the path,
+    /// or `None` if the graph has fewer than 2 vertices, no such path or a cost overflows.
     /// The direction of the path is from lower indices to higher indices.
-    pub fn hamiltonian_path_max(&mut self) -> (isize, Vec<Vertex>) {
+    pub fn hamiltonian_path_max(&mut self) -> Option<(isize, Vec<Vertex>)> {
         if self.adj_list.len() < 2 {
-            panic!("The graph must contain at least 2 vertices.");
+            return None;
         }
 
         // existing vertices
@@ -426,7 +420,10 @@ impl Graph {
             .extend(vertices.iter().map(|vertex| (*vertex, 0)));
 
         // find maximum hamiltonian cycle
-        let (max_cost, mut max_path) = self.hamiltonian_cycle_max();
+        let cycle = self.hamiltonian_cycle_max();
+        // remove added_vertex from the graph, even if there is no cycle
+        self.remove_vertex(added_vertex);
+        let (max_cost, mut max_path) = cycle?;
 
         // since added_vertex edges are 0, max_cost is correct
         // max_path is actually max_cycle that needs to be transformed into max_path
@@ -444,30 +441,26 @@ impl Graph {
         // remove added_vertex
         max_path.remove(0);
 
-        // remove added_vertex from the graph
-        self.remove_vertex(added_vertex);
-
-        // return min_cost and min_path
-        (max_cost, max_path)
+        // return max_cost and max_path
+        Some((max_cost, max_path))
     }
 
     /// Finds the shortest Hamiltonian path in the graph with fixed ends.
     /// Note that the ends are fixed, but not the direction of the path.
-    /// Returns a tuple containing the minimum cost and the vertices in the path.
+    /// Returns a tuple containing the minimum cost and the vertices in the path,
+    /// or `None` if the graph has fewer than 2 vertices, an end is not in the graph, there is no such path
+    /// or a cost overflows.
     /// The direction of the path is from lower indices to higher indices.
     pub fn hamiltonian_path_fixed_ends_min(
         &mut self,
         end1: Vertex,
         end2: Vertex,
-    ) -> (isize, Vec<Vertex>) {
-        if self.adj_list.len() < 2 {
-            panic!("The graph must contain at least 2 vertices.");
-        }
-        if !self.adj_list.contains_key(&end1) {
-            panic!("end1 not present in the graph.");
-        }
-        if !self.adj_list.contains_key(&end2) {
-            panic!("end2 not present in the graph.");
+    ) -> Option<(isize, Vec<Vertex>)> {
+        if self.adj_list.len() < 2
+            || !self.adj_list.contains_key(&end1)
+            || !self.adj_list.contains_key(&end2)
+        {
+            return None;
         }
 
         // add new vertex
@@ -492,7 +485,10 @@ impl Graph {
             .push((added_vertex, 0));
 
         // find minimum hamiltonian cycle
-        let (min_cost, mut min_path) = self.hamiltonian_cycle_min();
+        let cycle = self.hamiltonian_cycle_min();
+        // remove added_vertex from the graph, even if there is no cycle
+        self.remove_vertex(added_vertex);
+        let (min_cost, mut min_path) = cycle?;
 
         // since added_vertex edges are 0, min_cost is correct
         // min_path is actually min_cycle that needs to be transformed into min_path
@@ -511,30 +507,26 @@ impl Graph {
         // remove added_vertex
         min_path.remove(0);
 
-        // remove added_vertex from graph
-        self.remove_vertex(added_vertex);
-
         // return min_cost and min_path
-        (min_cost, min_path)
+        Some((min_cost, min_path))
     }
 
     /// Finds the longest Hamiltonian path in the graph with fixed ends.
     /// Note that the ends are fixed, but not the direction of the path.
-    /// Returns a tuple containing the maximum cost and the vertices in the path.
+    /// Returns a tuple containing the maximum cost and the vertices in the path,
+    /// or `None` if the graph has fewer than 2 vertices, an end is not in the graph, there is no such path
+    /// or a cost overflows.
     /// The direction of the path is from lower indices to higher indices.
     pub fn hamiltonian_path_fixed_ends_max(
         &mut self,
         end1: Vertex,
         end2: Vertex,
-    ) -> (isize, Vec<Vertex>) {
-        if self.adj_list.len() < 2 {
-            panic!("The graph must contain at least 2 vertices.");
-        }
-        if !self.adj_list.contains_key(&end1) {
-            panic!("end1 not present in the graph.");
-        }
-        if !self.adj_list.contains_key(&end2) {
-            panic!("end2 not present in the graph.");
+    ) -> Option<(isize, Vec<Vertex>)> {
+        if self.adj_list.len() < 2
+            || !self.adj_list.contains_key(&end1)
+            || !self.adj_list.contains_key(&end2)
+        {
+            return None;
         }
 
         // add new vertex
@@ -559,7 +551,10 @@ impl Graph {
             .push((added_vertex, 0));
 
         // find maximum hamiltonian cycle
-        let (max_cost, mut max_path) = self.hamiltonian_cycle_max();
+        let cycle = self.hamiltonian_cycle_max();
+        // remove added_vertex from the graph, even if there is no cycle
+        self.remove_vertex(added_vertex);
+        let (max_cost, mut max_path) = cycle?;
 
         // since added_vertex edges are 0, max_cost is correct
         // max_path is actually max_cycle that needs to be transformed into max_path
@@ -578,11 +573,8 @@ impl Graph {
         // remove added_vertex
         max_path.remove(0);
 
-        // remove added_vertex from the graph
-        self.remove_vertex(added_vertex);
-
         // return max_cost and max_path
-        (max_cost, max_path)
+        Some((max_cost, max_path))
     }
 }
 impl Default for Graph {
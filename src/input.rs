@@ -0,0 +1,75 @@
+//! Helpers for parsing the input to the challenges.
+
+use crate::Error;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// An extension trait for turning missing or malformed values into [Error::InvalidInput].
+pub(crate) trait OrInvalid<T> {
+    /// Convert the value to a [Result], reporting a missing or malformed value as [Error::InvalidInput].
+    /// # Arguments
+    /// * `input` - The whole input to the challenge.
+    /// * `fragment` - The part of the input the value was expected in.
+    /// * `message` - The description of the problem.
+    /// # Returns
+    /// * The contained value or the [Error::InvalidInput].
+    fn or_invalid(self, input: &str, fragment: &str, message: &'static str) -> Result<T, Error>;
+}
+impl<T> OrInvalid<T> for Option<T> {
+    fn or_invalid(self, input: &str, fragment: &str, message: &'static str) -> Result<T, Error> {
+        match self {
+            Some(value) => Ok(value),
+            None => Err(Error::invalid_input(input, fragment, message)),
+        }
+    }
+}
+impl<T, E> OrInvalid<T> for Result<T, E> {
+    fn or_invalid(self, input: &str, fragment: &str, message: &'static str) -> Result<T, Error> {
+        self.ok().or_invalid(input, fragment, message)
+    }
+}
+
+/// Parse a number from a fragment of the input.
+/// # Arguments
+/// * `input` - The whole input to the challenge.
+/// * `fragment` - The part of the input containing the number.
+/// # Returns
+/// * The parsed number or the [Error::InvalidInput].
+pub(crate) fn parse_number<T: FromStr>(input: &str, fragment: &str) -> Result<T, Error> {
+    fragment
+        .parse()
+        .or_invalid(input, fragment, "expected a number")
+}
+
+/// Get the empty fragment at the end of the input.
+/// Used to report that the input ended unexpectedly.
+/// # Arguments
+/// * `input` - The whole input to the challenge.
+/// # Returns
+/// * The empty fragment at the end of the input.
+pub(crate) fn end(input: &str) -> &str {
+    &input[input.len()..]
+}
+
+/// Parse a number from a fragment of the input, which must be in a range.
+/// # Arguments
+/// * `input` - The whole input to the challenge.
+/// * `fragment` - The part of the input containing the number.
+/// * `range` - The accepted numbers.
+/// # Returns
+/// * The parsed number or the [Error::InvalidInput].
+pub(crate) fn parse_limited<T: FromStr + PartialOrd>(
+    input: &str,
+    fragment: &str,
+    range: RangeInclusive<T>,
+) -> Result<T, Error> {
+    let number = parse_number(input, fragment)?;
+    if !range.contains(&number) {
+        return Err(Error::invalid_input(
+            input,
+            fragment,
+            "number is out of range",
+        ));
+    }
+    Ok(number)
+}
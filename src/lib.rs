@@ -1,13 +1,50 @@
 #![doc = include_str!("../README.md")]
 
+use std::any::Any;
 use std::cmp::Ordering;
 use std::error::Error as StdError;
 use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
 use std::time::Duration;
 
+mod answer;
+mod baseline;
+mod cancellation;
+mod catalog;
+mod examples;
+pub mod golden;
 pub mod graph;
+mod input;
 pub mod math;
+mod metadata;
+mod parameters;
 pub mod problems;
+mod progress;
+mod report;
+mod statistics;
+
+#[doc(inline)]
+pub use answer::Answer;
+#[doc(inline)]
+pub use baseline::{Baseline, BaselineEntry, Change, Comparison, ComparisonEntry};
+#[doc(inline)]
+pub use cancellation::CancellationToken;
+#[doc(inline)]
+pub use catalog::{Catalog, CatalogDay, CatalogYear};
+#[doc(inline)]
+pub use examples::Example;
+#[doc(inline)]
+pub use metadata::{Difficulty, Tag};
+#[doc(inline)]
+pub use parameters::{Parameter, ParameterValue, Parameters};
+#[doc(inline)]
+pub use progress::Progress;
+#[doc(inline)]
+pub use report::{Report, ReportEntry};
+#[doc(inline)]
+pub use statistics::{BenchmarkOptions, BenchmarkStatistics, Runs, Statistics};
 
 /// An enum representing the errors that can occur in this crate.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -20,6 +57,74 @@ pub enum Error {
     UnavailablePart,
     /// There is no solution for the challenge with the given input.
     NoSolution,
+    /// The input to the challenge is malformed.
+    InvalidInput {
+        /// The line of the input where the problem was found, starting at `1`.
+        /// It is `0` if the location is unknown.
+        line: usize,
+        /// The column of the input where the problem was found, starting at `1`.
+        /// It is `0` if the location is unknown.
+        column: usize,
+        /// The description of the problem.
+        message: &'static str,
+    },
+    /// A parameter of the challenge is unknown or its value is invalid.
+    InvalidParameter,
+    /// Solving the challenge was cancelled.
+    Cancelled,
+    /// Solving the challenge took longer than allowed.
+    Timeout,
+    /// Solving the challenge panicked.
+    Panicked,
+}
+impl Error {
+    /// Get a stable name of the kind of the error, e.g. for machine-readable output.
+    /// # Returns
+    /// * The name of the variant in kebab case, e.g. `"invalid-input"`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::UnavailableYear => "unavailable-year",
+            Self::UnavailableDay => "unavailable-day",
+            Self::UnavailablePart => "unavailable-part",
+            Self::NoSolution => "no-solution",
+            Self::InvalidInput { .. } => "invalid-input",
+            Self::InvalidParameter => "invalid-parameter",
+            Self::Cancelled => "cancelled",
+            Self::Timeout => "timeout",
+            Self::Panicked => "panicked",
+        }
+    }
+
+    /// Create an [Error::InvalidInput] pointing at a fragment of the input.
+    /// # Arguments
+    /// * `input` - The whole input to the challenge.
+    /// * `fragment` - The malformed part of the input. It should be a subslice of `input`.
+    /// * `message` - The description of the problem.
+    /// # Returns
+    /// * The [Error::InvalidInput] with the location of the fragment within the input.
+    ///   If the fragment is not a subslice of the input, the location is unknown.
+    pub fn invalid_input(input: &str, fragment: &str, message: &'static str) -> Self {
+        let input_start = input.as_ptr() as usize;
+        let fragment_start = fragment.as_ptr() as usize;
+
+        if input_start <= fragment_start
+            && fragment_start + fragment.len() <= input_start + input.len()
+        {
+            let preceding = &input[..(fragment_start - input_start)];
+            let line_start = preceding.rfind('\n').map_or(0, |i| i + 1);
+            Self::InvalidInput {
+                line: preceding.matches('\n').count() + 1,
+                column: preceding[line_start..].chars().count() + 1,
+                message,
+            }
+        } else {
+            Self::InvalidInput {
+                line: 0,
+                column: 0,
+                message,
+            }
+        }
+    }
 }
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -31,6 +136,25 @@ impl Display for Error {
                 f,
                 "There is no solution for the challenge with the given input."
             ),
+            Self::InvalidInput {
+                line: 0, message, ..
+            } => write!(f, "The input is invalid: {}.", message),
+            Self::InvalidInput {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "The input is invalid at line {}, column {}: {}.",
+                line, column, message
+            ),
+            Self::InvalidParameter => write!(
+                f,
+                "A parameter of the challenge is unknown or its value is invalid."
+            ),
+            Self::Cancelled => write!(f, "Solving the challenge was cancelled."),
+            Self::Timeout => write!(f, "Solving the challenge took longer than allowed."),
+            Self::Panicked => write!(f, "Solving the challenge panicked."),
         }
     }
 }
@@ -61,6 +185,41 @@ pub trait AdventOfCode: Send + Sync {
         Err(Error::UnavailableYear)
     }
 
+    /// Get a listing of all available years, days' challenges and their implemented parts.
+    /// # Returns
+    /// * The [Catalog] describing all available challenges.
+    fn catalog(&self) -> Catalog {
+        Catalog::new(self)
+    }
+
+    /// Get all available days' challenges with the specified topic.
+    /// # Arguments
+    /// * `tag` - The topic of the days' challenges.
+    /// # Returns
+    /// * An iterator over the identifiers of the years and the days' challenges with the [Tag],
+    ///   sorted by year and day in ascending order.
+    fn days_tagged<'a>(&'a self, tag: Tag) -> Box<dyn Iterator<Item = (usize, &'a dyn Day)> + 'a> {
+        Box::new(self.years().flat_map(move |year| {
+            year.days()
+                .filter(move |day| day.tags().contains(&tag))
+                .map(move |day| (year.id(), day))
+        }))
+    }
+
+    /// Get the worked examples from the puzzle text of the specified day's challenge from the specified year.
+    /// # Arguments
+    /// * `year` - The identifier of the year of the challenge.
+    /// * `day` - The identifier of the day's challenge.
+    /// # Returns
+    /// * The [Example]s of the day's challenge or the [Error].
+    /// # Errors
+    /// * [Error::UnavailableYear] - The requested year is unavailable.
+    /// * [Error::UnavailableDay] - The requested day's challenge is unavailable.
+    /// * [Error::InvalidInput] - The bundled examples of the year are malformed.
+    fn examples(&self, year: usize, day: usize) -> Result<Vec<Example>, Error> {
+        self.year(year)?.examples(day)
+    }
+
     /// Solve a part of the specified day's challenge from the specified year.
     /// # Arguments
     /// * `year` - The identifier of the year of the challenge to solve.
@@ -74,10 +233,163 @@ pub trait AdventOfCode: Send + Sync {
     /// * [Error::UnavailableDay] - The requested day's challenge is unavailable.
     /// * [Error::UnavailablePart] - The requested part is unavailable.
     /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
-    fn solve(&self, year: usize, day: usize, part: usize, input: &str) -> Result<String, Error> {
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    fn solve(&self, year: usize, day: usize, part: usize, input: &str) -> Result<Answer, Error> {
         self.year(year)?.solve(day, part, input)
     }
 
+    /// Solve a part of the specified day's challenge from the specified year
+    /// with some of its parameters overridden.
+    /// # Arguments
+    /// * `year` - The identifier of the year of the challenge to solve.
+    /// * `day` - The identifier of the day's challenge to solve.
+    /// * `part` - The part of the challenge to solve.
+    /// * `input` - The input to the challenge.
+    /// * `parameters` - The values of the parameters, the others keep their default values.
+    /// # Returns
+    /// * The solution to the part of the challenge or the [Error].
+    /// # Errors
+    /// * [Error::UnavailableYear] - The requested year is unavailable.
+    /// * [Error::UnavailableDay] - The requested day's challenge is unavailable.
+    /// * [Error::UnavailablePart] - The requested part is unavailable.
+    /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    /// * [Error::InvalidParameter] - A parameter is unknown or its value is invalid.
+    fn solve_with(
+        &self,
+        year: usize,
+        day: usize,
+        part: usize,
+        input: &str,
+        parameters: &Parameters,
+    ) -> Result<Answer, Error> {
+        self.year(year)?.solve_with(day, part, input, parameters)
+    }
+
+    /// Solve a part of the specified day's challenge from the specified year
+    /// until the token is cancelled or its deadline passes.
+    /// # Arguments
+    /// * `year` - The identifier of the year of the challenge to solve.
+    /// * `day` - The identifier of the day's challenge to solve.
+    /// * `part` - The part of the challenge to solve.
+    /// * `input` - The input to the challenge.
+    /// * `token` - The [CancellationToken] polled while solving.
+    /// # Returns
+    /// * The solution to the part of the challenge or the [Error].
+    /// # Errors
+    /// * [Error::UnavailableYear] - The requested year is unavailable.
+    /// * [Error::UnavailableDay] - The requested day's challenge is unavailable.
+    /// * [Error::UnavailablePart] - The requested part is unavailable.
+    /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    /// * [Error::Cancelled] - The token was cancelled.
+    /// * [Error::Timeout] - The deadline of the token has passed.
+    fn solve_cancellable(
+        &self,
+        year: usize,
+        day: usize,
+        part: usize,
+        input: &str,
+        token: &CancellationToken,
+    ) -> Result<Answer, Error> {
+        token.check()?;
+        cancellation::run_with(token, || self.solve(year, day, part, input))
+    }
+
+    /// Solve a part of the specified day's challenge from the specified year
+    /// with some of its parameters overridden until the token is cancelled or its deadline passes.
+    /// # Arguments
+    /// * `year` - The identifier of the year of the challenge to solve.
+    /// * `day` - The identifier of the day's challenge to solve.
+    /// * `part` - The part of the challenge to solve.
+    /// * `input` - The input to the challenge.
+    /// * `parameters` - The values of the parameters, the others keep their default values.
+    /// * `token` - The [CancellationToken] polled while solving.
+    /// # Returns
+    /// * The solution to the part of the challenge or the [Error].
+    /// # Errors
+    /// * [Error::UnavailableYear] - The requested year is unavailable.
+    /// * [Error::UnavailableDay] - The requested day's challenge is unavailable.
+    /// * [Error::UnavailablePart] - The requested part is unavailable.
+    /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    /// * [Error::InvalidParameter] - A parameter is unknown or its value is invalid.
+    /// * [Error::Cancelled] - The token was cancelled.
+    /// * [Error::Timeout] - The deadline of the token has passed.
+    fn solve_cancellable_with(
+        &self,
+        year: usize,
+        day: usize,
+        part: usize,
+        input: &str,
+        parameters: &Parameters,
+        token: &CancellationToken,
+    ) -> Result<Answer, Error> {
+        token.check()?;
+        cancellation::run_with(token, || {
+            self.solve_with(year, day, part, input, parameters)
+        })
+    }
+
+    /// Solve a part of the specified day's challenge from the specified year within a time limit.
+    /// # Arguments
+    /// * `year` - The identifier of the year of the challenge to solve.
+    /// * `day` - The identifier of the day's challenge to solve.
+    /// * `part` - The part of the challenge to solve.
+    /// * `input` - The input to the challenge.
+    /// * `timeout` - The time after which solving stops.
+    /// # Returns
+    /// * The solution to the part of the challenge or the [Error].
+    /// # Errors
+    /// * [Error::UnavailableYear] - The requested year is unavailable.
+    /// * [Error::UnavailableDay] - The requested day's challenge is unavailable.
+    /// * [Error::UnavailablePart] - The requested part is unavailable.
+    /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    /// * [Error::Timeout] - Solving took longer than the time limit.
+    fn solve_with_deadline(
+        &self,
+        year: usize,
+        day: usize,
+        part: usize,
+        input: &str,
+        timeout: Duration,
+    ) -> Result<Answer, Error> {
+        let token = CancellationToken::with_timeout(timeout);
+        self.solve_cancellable(year, day, part, input, &token)
+    }
+
+    /// Solve a part of the specified day's challenge from the specified year and report its progress.
+    /// # Arguments
+    /// * `year` - The identifier of the year of the challenge to solve.
+    /// * `day` - The identifier of the day's challenge to solve.
+    /// * `part` - The part of the challenge to solve.
+    /// * `input` - The input to the challenge.
+    /// * `parameters` - The values of the parameters of the challenge.
+    /// * `progress` - The [Progress] receiving the phases and their progress while solving.
+    /// # Returns
+    /// * The solution to the part of the challenge or the [Error].
+    /// # Errors
+    /// * [Error::UnavailableYear] - The requested year is unavailable.
+    /// * [Error::UnavailableDay] - The requested day's challenge is unavailable.
+    /// * [Error::UnavailablePart] - The requested part is unavailable.
+    /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    /// * [Error::InvalidParameter] - A parameter is unknown or its value is invalid.
+    fn solve_with_progress(
+        &self,
+        year: usize,
+        day: usize,
+        part: usize,
+        input: &str,
+        parameters: &Parameters,
+        progress: Arc<dyn Progress>,
+    ) -> Result<Answer, Error> {
+        progress::run_with(progress, || {
+            self.solve_with(year, day, part, input, parameters)
+        })
+    }
+
     /// Solve a part of the specified day's challenge from the specified year and measure the elapsed time.
     /// # Arguments
     /// * `year` - The identifier of the year of the day's challenge to solve.
@@ -85,21 +397,123 @@ pub trait AdventOfCode: Send + Sync {
     /// * `part` - The part of the challenge to solve.
     /// * `input` - The input to the challenge.
     /// # Returns
-    /// * The solution to the part of the challenge and the elapsed time or the [Error].
+    /// * The [Benchmark] with the solution to the part of the challenge or the [Error].
     /// # Errors
     /// * [Error::UnavailableYear] - The requested year is unavailable.
     /// * [Error::UnavailableDay] - The requested day's challenge is unavailable.
     /// * [Error::UnavailablePart] - The requested part is unavailable.
     /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
     fn benchmark(
         &self,
         year: usize,
         day: usize,
         part: usize,
         input: &str,
-    ) -> Result<(String, Duration), Error> {
+    ) -> Result<Benchmark, Error> {
         self.year(year)?.benchmark(day, part, input)
     }
+
+    /// Solve a part of the specified day's challenge from the specified year repeatedly
+    /// and summarize the elapsed times.
+    /// # Arguments
+    /// * `year` - The identifier of the year of the day's challenge to solve.
+    /// * `day` - The identifier of the day's challenge to solve.
+    /// * `part` - The part of the challenge to solve.
+    /// * `input` - The input to the challenge.
+    /// * `options` - The [BenchmarkOptions] with the warm-up and measured runs.
+    /// # Returns
+    /// * The [BenchmarkStatistics] with the solution to the part of the challenge or the [Error].
+    /// # Errors
+    /// * [Error::UnavailableYear] - The requested year is unavailable.
+    /// * [Error::UnavailableDay] - The requested day's challenge is unavailable.
+    /// * [Error::UnavailablePart] - The requested part is unavailable.
+    /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    fn benchmark_statistics(
+        &self,
+        year: usize,
+        day: usize,
+        part: usize,
+        input: &str,
+        options: &BenchmarkOptions,
+    ) -> Result<BenchmarkStatistics, Error> {
+        self.year(year)?
+            .benchmark_statistics(day, part, input, options)
+    }
+
+    /// Solve every available part of the challenges with an input in the directory on a thread pool.
+    /// The inputs are read from `yearYYYY/dayDD.txt` files and days without an input file are skipped.
+    /// Solutions which poll for cancellation stop after the time limit with [Error::Timeout],
+    /// the others run to completion. A part which panics is reported with [Error::Panicked].
+    /// # Arguments
+    /// * `inputs` - The directory with the inputs.
+    /// * `timeout` - The time after which the solutions polling for cancellation stop.
+    /// # Returns
+    /// * The [Report] of the answers, errors and times or the [io::Error].
+    /// # Errors
+    /// * [io::Error] - An input file exists but cannot be read.
+    fn solve_all(&self, inputs: &Path, timeout: Duration) -> io::Result<Report> {
+        Report::solve(self, inputs, timeout)
+    }
+
+    /// Solve every available part of the challenges of a year with an input in the directory
+    /// on a thread pool.
+    /// The inputs are read from `yearYYYY/dayDD.txt` files and days without an input file are skipped.
+    /// Solutions which poll for cancellation stop after the time limit with [Error::Timeout],
+    /// the others run to completion. A part which panics is reported with [Error::Panicked].
+    /// # Arguments
+    /// * `year` - The identifier of the year of the challenges to solve.
+    /// * `inputs` - The directory with the inputs.
+    /// * `timeout` - The time after which the solutions polling for cancellation stop.
+    /// # Returns
+    /// * The [Report] of the answers, errors and times, which is empty for an unavailable year,
+    ///   or the [io::Error].
+    /// # Errors
+    /// * [io::Error] - An input file exists but cannot be read.
+    fn solve_all_in_year(
+        &self,
+        year: usize,
+        inputs: &Path,
+        timeout: Duration,
+    ) -> io::Result<Report> {
+        Report::solve_year(self, year, inputs, timeout)
+    }
+
+    /// Benchmark every available part of the challenges with an input in the directory,
+    /// one after another, e.g. to save a [Baseline].
+    /// The inputs are read from `yearYYYY/dayDD.txt` files and days without an input file are skipped.
+    /// # Arguments
+    /// * `inputs` - The directory with the inputs.
+    /// * `options` - The [BenchmarkOptions] of the runs of every part.
+    /// # Returns
+    /// * The [Baseline] with the times and the failed parts or the [io::Error].
+    /// # Errors
+    /// * [io::Error] - An input file exists but cannot be read.
+    fn benchmark_all(&self, inputs: &Path, options: &BenchmarkOptions) -> io::Result<Baseline> {
+        Baseline::measure(self, inputs, options)
+    }
+
+    /// Benchmark every available part of the challenges of a year with an input in the directory,
+    /// one after another, e.g. to save a [Baseline].
+    /// The inputs are read from `yearYYYY/dayDD.txt` files and days without an input file are skipped.
+    /// # Arguments
+    /// * `year` - The identifier of the year of the challenges to benchmark.
+    /// * `inputs` - The directory with the inputs.
+    /// * `options` - The [BenchmarkOptions] of the runs of every part.
+    /// # Returns
+    /// * The [Baseline] with the times and the failed parts, which is empty for an unavailable year,
+    ///   or the [io::Error].
+    /// # Errors
+    /// * [io::Error] - An input file exists but cannot be read.
+    fn benchmark_all_in_year(
+        &self,
+        year: usize,
+        inputs: &Path,
+        options: &BenchmarkOptions,
+    ) -> io::Result<Baseline> {
+        Baseline::measure_year(self, year, inputs, options)
+    }
 }
 
 /// A trait representing the [*Advent of Code*](https://adventofcode.com/) year.
@@ -133,6 +547,19 @@ pub trait Year: Send + Sync {
         Err(Error::UnavailableDay)
     }
 
+    /// Get the worked examples from the puzzle text of the specified day's challenge.
+    /// # Arguments
+    /// * `day` - The identifier of the day's challenge.
+    /// # Returns
+    /// * The [Example]s of the day's challenge, which are empty unless the year bundles them, or the [Error].
+    /// # Errors
+    /// * [Error::UnavailableDay] - The requested day's challenge is unavailable.
+    /// * [Error::InvalidInput] - The bundled examples of the year are malformed.
+    fn examples(&self, day: usize) -> Result<Vec<Example>, Error> {
+        self.day(day)?;
+        Ok(Vec::new())
+    }
+
     /// Solve a part of the specified day's challenge.
     /// # Arguments
     /// * `day` - The identifier of the day's challenge to solve.
@@ -144,24 +571,73 @@ pub trait Year: Send + Sync {
     /// * [Error::UnavailableDay] - The requested day's challenge is unavailable.
     /// * [Error::UnavailablePart] - The requested part is unavailable.
     /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
-    fn solve(&self, day: usize, part: usize, input: &str) -> Result<String, Error> {
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    fn solve(&self, day: usize, part: usize, input: &str) -> Result<Answer, Error> {
         self.day(day)?.solve(part, input)
     }
 
+    /// Solve a part of the specified day's challenge with some of its parameters overridden.
+    /// # Arguments
+    /// * `day` - The identifier of the day's challenge to solve.
+    /// * `part` - The part of the challenge to solve.
+    /// * `input` - The input to the challenge.
+    /// * `parameters` - The values of the parameters, the others keep their default values.
+    /// # Returns
+    /// * The solution to the part of the challenge or the [Error].
+    /// # Errors
+    /// * [Error::UnavailableDay] - The requested day's challenge is unavailable.
+    /// * [Error::UnavailablePart] - The requested part is unavailable.
+    /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    /// * [Error::InvalidParameter] - A parameter is unknown or its value is invalid.
+    fn solve_with(
+        &self,
+        day: usize,
+        part: usize,
+        input: &str,
+        parameters: &Parameters,
+    ) -> Result<Answer, Error> {
+        self.day(day)?.solve_with(part, input, parameters)
+    }
+
     /// Solve a part of the specified day's challenge and measure the elapsed time.
     /// # Arguments
     /// * `day` - The identifier of the day's challenge to solve.
     /// * `part` - The part of the challenge to solve.
     /// * `input` - The input to the challenge.
     /// # Returns
-    /// * The solution to the part of the challenge and the elapsed time or the [Error].
+    /// * The [Benchmark] with the solution to the part of the challenge or the [Error].
     /// # Errors
     /// * [Error::UnavailableDay] - The requested day's challenge is unavailable.
     /// * [Error::UnavailablePart] - The requested part is unavailable.
     /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
-    fn benchmark(&self, day: usize, part: usize, input: &str) -> Result<(String, Duration), Error> {
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    fn benchmark(&self, day: usize, part: usize, input: &str) -> Result<Benchmark, Error> {
         self.day(day)?.benchmark(part, input)
     }
+
+    /// Solve a part of the specified day's challenge repeatedly and summarize the elapsed times.
+    /// # Arguments
+    /// * `day` - The identifier of the day's challenge to solve.
+    /// * `part` - The part of the challenge to solve.
+    /// * `input` - The input to the challenge.
+    /// * `options` - The [BenchmarkOptions] with the warm-up and measured runs.
+    /// # Returns
+    /// * The [BenchmarkStatistics] with the solution to the part of the challenge or the [Error].
+    /// # Errors
+    /// * [Error::UnavailableDay] - The requested day's challenge is unavailable.
+    /// * [Error::UnavailablePart] - The requested part is unavailable.
+    /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    fn benchmark_statistics(
+        &self,
+        day: usize,
+        part: usize,
+        input: &str,
+        options: &BenchmarkOptions,
+    ) -> Result<BenchmarkStatistics, Error> {
+        self.day(day)?.benchmark_statistics(part, input, options)
+    }
 }
 
 /// A trait representing the [*Advent of Code*](https://adventofcode.com/) day's challenge.
@@ -175,6 +651,26 @@ pub trait Day: Send + Sync + Solution {
     /// # Returns
     /// * The title of the day's challenge.
     fn title(&self) -> &str;
+
+    /// The implemented parts of the day's challenge.
+    /// # Returns
+    /// * The parts which can be solved, sorted in ascending order.
+    fn parts(&self) -> &[usize];
+
+    /// The canonical URL of the puzzle of the day's challenge.
+    /// # Returns
+    /// * The URL, e.g. `https://adventofcode.com/2015/day/1`.
+    fn url(&self) -> &str;
+
+    /// An informal rating of how hard the day's challenge is.
+    /// # Returns
+    /// * The [Difficulty] of the day's challenge.
+    fn difficulty(&self) -> Difficulty;
+
+    /// The topics of the day's challenge.
+    /// # Returns
+    /// * The [Tag]s of the day's challenge, sorted in ascending order.
+    fn tags(&self) -> &[Tag];
 }
 
 /// A trait representing the [*Advent of Code*](https://adventofcode.com/) day's challenge solution.
@@ -188,57 +684,140 @@ pub trait Solution: Send + Sync {
     /// # Errors
     /// * [Error::UnavailablePart] - The requested part is unavailable.
     /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
-    fn solve(&self, part: usize, input: &str) -> Result<String, Error> {
-        match part {
-            1 => self.part1(input),
-            2 => self.part2(input),
-            _ => Err(Error::UnavailablePart),
-        }
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    fn solve(&self, part: usize, input: &str) -> Result<Answer, Error> {
+        self.solve_with(part, input, &Parameters::new())
     }
 
-    /// Solve a part of the day's challenge and measure the elapsed time.
+    /// Solve a part of the day's challenge with some of its parameters overridden.
     /// # Arguments
     /// * `part` - The part of the challenge to solve.
     /// * `input` - The input to the challenge.
+    /// * `parameters` - The values of the parameters, the others keep their default values.
     /// # Returns
-    /// * The solution to the part of the challenge and the elapsed time or the [Error].
+    /// * The solution to the part of the challenge or the [Error].
     /// # Errors
     /// * [Error::UnavailablePart] - The requested part is unavailable.
     /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
-    fn benchmark(&self, part: usize, input: &str) -> Result<(String, Duration), Error> {
-        let result;
-        let elapsed;
-
-        #[cfg(not(all(
-            target_arch = "wasm32",
-            target_vendor = "unknown",
-            target_os = "unknown"
-        )))]
-        {
-            let instant = std::time::Instant::now();
-            result = self.solve(part, input);
-            elapsed = instant.elapsed();
-        }
-        #[cfg(all(
-            target_arch = "wasm32",
-            target_vendor = "unknown",
-            target_os = "unknown"
-        ))]
-        {
-            use wasm_bindgen::prelude::*;
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    /// * [Error::InvalidParameter] - A parameter is unknown or its value is invalid.
+    fn solve_with(
+        &self,
+        part: usize,
+        input: &str,
+        parameters: &Parameters,
+    ) -> Result<Answer, Error> {
+        let parameters = parameters.resolve(&self.parameters())?;
+        self.solve_parsed_with(part, &self.parse(input)?, &parameters)
+    }
 
-            let global_obj = js_sys::global();
-            let performance = js_sys::Reflect::get(&global_obj, &JsValue::from_str("performance"))
-                .unwrap()
-                .dyn_into::<web_sys::Performance>()
-                .unwrap();
+    /// The parameters of the day's challenge, e.g. grid sizes or iteration counts,
+    /// which differ between the puzzle and its examples.
+    /// # Returns
+    /// * The parameters with the values used by the puzzle as their defaults.
+    fn parameters(&self) -> Vec<Parameter> {
+        Vec::new()
+    }
 
-            let instant = performance.now();
-            result = self.solve(part, input);
-            elapsed = Duration::from_secs_f64((performance.now() - instant) / 1000.0);
-        }
+    /// Solve a part of the day's challenge and measure the elapsed time
+    /// of parsing the input and of solving the part separately.
+    /// # Arguments
+    /// * `part` - The part of the challenge to solve.
+    /// * `input` - The input to the challenge.
+    /// # Returns
+    /// * The [Benchmark] with the solution to the part of the challenge or the [Error].
+    /// # Errors
+    /// * [Error::UnavailablePart] - The requested part is unavailable.
+    /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    fn benchmark(&self, part: usize, input: &str) -> Result<Benchmark, Error> {
+        let (parsed, parse_time) = measure(|| self.parse(input));
+        let parsed = parsed?;
+        let (answer, solve_time) = measure(|| self.solve_parsed(part, &parsed));
+
+        Ok(Benchmark {
+            answer: answer?,
+            parse_time,
+            solve_time,
+        })
+    }
 
-        result.map(|val| (val, elapsed))
+    /// Solve a part of the day's challenge repeatedly after warm-up runs and summarize
+    /// the elapsed times of parsing the input, solving the part and both together.
+    /// # Arguments
+    /// * `part` - The part of the challenge to solve.
+    /// * `input` - The input to the challenge.
+    /// * `options` - The [BenchmarkOptions] with the warm-up and measured runs.
+    /// # Returns
+    /// * The [BenchmarkStatistics] with the solution to the part of the challenge or the [Error].
+    /// # Errors
+    /// * [Error::UnavailablePart] - The requested part is unavailable.
+    /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    fn benchmark_statistics(
+        &self,
+        part: usize,
+        input: &str,
+        options: &BenchmarkOptions,
+    ) -> Result<BenchmarkStatistics, Error> {
+        BenchmarkStatistics::measure(self, part, input, options)
+    }
+
+    /// Parse the input to the day's challenge once, so that every part can be solved from it.
+    /// Solutions without a separate parse step keep the raw input.
+    /// # Arguments
+    /// * `input` - The input to the challenge.
+    /// # Returns
+    /// * The [ParsedInput] or the [Error].
+    /// # Errors
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    fn parse(&self, input: &str) -> Result<ParsedInput, Error> {
+        Ok(ParsedInput::new(input.to_string()))
+    }
+
+    /// Solve a part of the day's challenge from the input parsed by [Solution::parse]
+    /// with the default parameters.
+    /// # Arguments
+    /// * `part` - The part of the challenge to solve.
+    /// * `parsed` - The input parsed by [Solution::parse] of the same day's challenge.
+    /// # Returns
+    /// * The solution to the part of the challenge or the [Error].
+    /// # Errors
+    /// * [Error::UnavailablePart] - The requested part is unavailable.
+    /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
+    /// * [Error::InvalidInput] - The input to the challenge is malformed
+    ///   or it was parsed by another day's challenge.
+    fn solve_parsed(&self, part: usize, parsed: &ParsedInput) -> Result<Answer, Error> {
+        let parameters = Parameters::new().resolve(&self.parameters())?;
+        self.solve_parsed_with(part, parsed, &parameters)
+    }
+
+    /// Solve a part of the day's challenge from the input parsed by [Solution::parse]
+    /// with the given parameters.
+    /// # Arguments
+    /// * `part` - The part of the challenge to solve.
+    /// * `parsed` - The input parsed by [Solution::parse] of the same day's challenge.
+    /// * `parameters` - The values of all parameters, as returned by [Parameters::resolve].
+    /// # Returns
+    /// * The solution to the part of the challenge or the [Error].
+    /// # Errors
+    /// * [Error::UnavailablePart] - The requested part is unavailable.
+    /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
+    /// * [Error::InvalidInput] - The input to the challenge is malformed
+    ///   or it was parsed by another day's challenge.
+    /// * [Error::InvalidParameter] - The value of a parameter is missing or invalid.
+    fn solve_parsed_with(
+        &self,
+        part: usize,
+        parsed: &ParsedInput,
+        parameters: &Parameters,
+    ) -> Result<Answer, Error> {
+        let input = parsed.downcast_ref::<String>()?;
+        match part {
+            1 => self.part1_with(input, parameters),
+            2 => self.part2_with(input, parameters),
+            _ => Err(Error::UnavailablePart),
+        }
     }
 
     /// Solve the first part of the day's challenge.
@@ -249,7 +828,8 @@ pub trait Solution: Send + Sync {
     /// # Errors
     /// * [Error::UnavailablePart] - The solution for the first part is unavailable.
     /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let _ = input; // suppress unused variable warning
         Err(Error::UnavailablePart)
     }
@@ -262,10 +842,204 @@ pub trait Solution: Send + Sync {
     /// # Errors
     /// * [Error::UnavailablePart] - The solution to the second part is unavailable.
     /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let _ = input; // suppress unused variable warning
         Err(Error::UnavailablePart)
     }
+
+    /// Solve the first part of the day's challenge with the given parameters.
+    /// Solutions declaring [Solution::parameters] implement this instead of [Solution::part1].
+    /// # Arguments
+    /// * `input` - The input to the challenge.
+    /// * `parameters` - The values of all parameters, as returned by [Parameters::resolve].
+    /// # Returns
+    /// * The solution to the first part of the challenge or the [Error].
+    /// # Errors
+    /// * [Error::UnavailablePart] - The solution for the first part is unavailable.
+    /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    /// * [Error::InvalidParameter] - The value of a parameter is missing or invalid.
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let _ = parameters; // suppress unused variable warning
+        self.part1(input)
+    }
+
+    /// Solve the second part of the day's challenge with the given parameters.
+    /// Solutions declaring [Solution::parameters] implement this instead of [Solution::part2].
+    /// # Arguments
+    /// * `input` - The input to the challenge.
+    /// * `parameters` - The values of all parameters, as returned by [Parameters::resolve].
+    /// # Returns
+    /// * The solution to the second part of the challenge or the [Error].
+    /// # Errors
+    /// * [Error::UnavailablePart] - The solution to the second part is unavailable.
+    /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    /// * [Error::InvalidParameter] - The value of a parameter is missing or invalid.
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let _ = parameters; // suppress unused variable warning
+        self.part2(input)
+    }
+}
+
+/// A trait representing the [*Advent of Code*](https://adventofcode.com/) day's challenge solution
+/// which parses the input once and solves both parts from the parsed value.
+///
+/// Every [ParsedSolution] is also a [Solution].
+pub trait ParsedSolution: Send + Sync {
+    /// The parsed input shared by both parts.
+    type Input: Send + Sync + 'static;
+
+    /// Parse the input to the day's challenge.
+    /// # Arguments
+    /// * `input` - The input to the challenge.
+    /// # Returns
+    /// * The parsed input or the [Error].
+    /// # Errors
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    fn parse(&self, input: &str) -> Result<Self::Input, Error>;
+
+    /// Solve the first part of the day's challenge.
+    /// # Arguments
+    /// * `input` - The parsed input to the challenge.
+    /// # Returns
+    /// * The solution to the first part of the challenge or the [Error].
+    /// # Errors
+    /// * [Error::UnavailablePart] - The solution for the first part is unavailable.
+    /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
+    fn part1(&self, input: &Self::Input) -> Result<Answer, Error> {
+        let _ = input; // suppress unused variable warning
+        Err(Error::UnavailablePart)
+    }
+
+    /// Solve the second part of the day's challenge.
+    /// # Arguments
+    /// * `input` - The parsed input to the challenge.
+    /// # Returns
+    /// * The solution to the second part of the challenge or the [Error].
+    /// # Errors
+    /// * [Error::UnavailablePart] - The solution to the second part is unavailable.
+    /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
+    fn part2(&self, input: &Self::Input) -> Result<Answer, Error> {
+        let _ = input; // suppress unused variable warning
+        Err(Error::UnavailablePart)
+    }
+}
+impl<T: ParsedSolution> Solution for T {
+    fn parse(&self, input: &str) -> Result<ParsedInput, Error> {
+        ParsedSolution::parse(self, input).map(ParsedInput::new)
+    }
+
+    fn solve_parsed_with(
+        &self,
+        part: usize,
+        parsed: &ParsedInput,
+        parameters: &Parameters,
+    ) -> Result<Answer, Error> {
+        let _ = parameters; // suppress unused variable warning
+        let input = parsed.downcast_ref::<T::Input>()?;
+        match part {
+            1 => ParsedSolution::part1(self, input),
+            2 => ParsedSolution::part2(self, input),
+            _ => Err(Error::UnavailablePart),
+        }
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        ParsedSolution::part1(self, &ParsedSolution::parse(self, input)?)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        ParsedSolution::part2(self, &ParsedSolution::parse(self, input)?)
+    }
+}
+
+/// A structure representing the input to a day's challenge parsed by [Solution::parse].
+pub struct ParsedInput(Box<dyn Any + Send + Sync>);
+impl ParsedInput {
+    /// Create a new [ParsedInput] holding the given value.
+    /// # Arguments
+    /// * `value` - The parsed input.
+    /// # Returns
+    /// * The new [ParsedInput].
+    pub fn new<T: Any + Send + Sync>(value: T) -> Self {
+        Self(Box::new(value))
+    }
+
+    /// Get a reference to the parsed input.
+    /// # Returns
+    /// * A reference to the parsed input or the [Error].
+    /// # Errors
+    /// * [Error::InvalidInput] - The input was parsed into another type, e.g. by another day's challenge.
+    pub fn downcast_ref<T: Any>(&self) -> Result<&T, Error> {
+        self.0.downcast_ref().ok_or(Error::InvalidInput {
+            line: 0,
+            column: 0,
+            message: "expected an input parsed by the same challenge",
+        })
+    }
+}
+impl fmt::Debug for ParsedInput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParsedInput").finish_non_exhaustive()
+    }
+}
+
+/// A structure representing the solution to a part of a day's challenge with the elapsed time.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Benchmark {
+    /// The solution to the part of the challenge.
+    pub answer: Answer,
+    /// The time taken to parse the input.
+    pub parse_time: Duration,
+    /// The time taken to solve the part from the parsed input.
+    pub solve_time: Duration,
+}
+impl Benchmark {
+    /// The total time taken to parse the input and solve the part.
+    /// # Returns
+    /// * The sum of the parse and solve time.
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.solve_time
+    }
+}
+
+/// Run the function and measure the elapsed time.
+fn measure<T>(function: impl FnOnce() -> T) -> (T, Duration) {
+    let start = now();
+    let result = function();
+    (result, now().saturating_sub(start))
+}
+
+/// Get the time elapsed since a fixed point in time, which is monotonic and works on wasm.
+pub(crate) fn now() -> Duration {
+    #[cfg(not(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    )))]
+    {
+        static START: std::sync::LazyLock<std::time::Instant> =
+            std::sync::LazyLock::new(std::time::Instant::now);
+        START.elapsed()
+    }
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_vendor = "unknown",
+        target_os = "unknown"
+    ))]
+    {
+        use wasm_bindgen::prelude::*;
+
+        let global_obj = js_sys::global();
+        let performance = js_sys::Reflect::get(&global_obj, &JsValue::from_str("performance"))
+            .unwrap()
+            .dyn_into::<web_sys::Performance>()
+            .unwrap();
+
+        Duration::from_secs_f64(performance.now() / 1000.0)
+    }
 }
 
 /// A structure representing the [*Advent of Code*](https://adventofcode.com/).
@@ -301,6 +1075,11 @@ impl Default for AoC {
         Self::new()
     }
 }
+impl Display for AoC {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.catalog())
+    }
+}
 impl AdventOfCode for AoC {
     fn years<'a>(&'a self) -> Box<dyn Iterator<Item = &'a dyn Year> + 'a> {
         Box::new(self.years.iter().map(|year| year.as_ref()))
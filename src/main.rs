@@ -1,10 +1,207 @@
-use aocode::{AdventOfCode, AoC};
-use clap::{Arg, ArgAction, command, value_parser};
+mod cli;
+
+use aocode::{AdventOfCode, AoC, BenchmarkOptions, Parameters, Tag};
+use clap::builder::FalseyValueParser;
+use clap::{Arg, ArgAction, ArgMatches, Command, command, value_parser};
+use cli::client::Client;
+use cli::table::Format;
 use std::io::{Read, stdin};
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
+use std::time::Duration;
+use std::{fs, io};
 
 fn main() -> ExitCode {
     let argv = command!()
+        .args_conflicts_with_subcommands(true)
+        .subcommand_negates_reqs(true)
+        .subcommand(
+            Command::new("run-all")
+                .about("Solve every implemented challenge with an input in the inputs directory")
+                .arg(
+                    Arg::new("year")
+                        .value_name("YEAR")
+                        .help("Solve only the challenges of the given year")
+                        .value_parser(value_parser!(u32).range(2015..)),
+                )
+                .arg(timeout_arg()),
+        )
+        .subcommand(
+            Command::new("verify")
+                .about("Check the answers to every challenge with an input against the expected answers")
+                .arg(
+                    Arg::new("year")
+                        .value_name("YEAR")
+                        .help("Verify only the challenges of the given year")
+                        .value_parser(value_parser!(u32).range(2015..)),
+                )
+                .arg(timeout_arg())
+                .arg(
+                    Arg::new("outputs")
+                        .long("outputs")
+                        .value_name("DIR")
+                        .env("AOCODE_OUTPUTS")
+                        .help("Read the expected answers from yearYYYY/dayDD/partN.txt in the given directory")
+                        .required(true)
+                        .value_parser(value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("bless")
+                        .long("bless")
+                        .env("AOCODE_BLESS")
+                        .value_parser(FalseyValueParser::new())
+                        .help("Write the answers which differ from the expected answers as the new expected answers and show the changes")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("stats")
+                .about("Show a calendar of the implemented parts and the stars of every year")
+                .arg(
+                    Arg::new("year")
+                        .value_name("YEAR")
+                        .help("Show only the given year")
+                        .value_parser(value_parser!(u32).range(2015..)),
+                )
+                .arg(
+                    Arg::new("timings")
+                        .long("timings")
+                        .value_name("PATH")
+                        .help("Show the best time of every day from the JSON output of benchmarks")
+                        .value_parser(value_parser!(PathBuf)),
+                ),
+        )
+        .subcommand(
+            Command::new("baseline")
+                .about("Save the benchmark of every challenge with an input, or compare a later benchmark to it")
+                .subcommand_required(true)
+                .subcommand(
+                    Command::new("save")
+                        .about("Benchmark the challenges and save the times to a baseline file")
+                        .args(baseline_args()),
+                )
+                .subcommand(
+                    Command::new("compare")
+                        .about("Benchmark the challenges and flag the parts which became slower than the baseline")
+                        .args(baseline_args())
+                        .arg(
+                            Arg::new("threshold")
+                                .long("threshold")
+                                .value_name("PERCENT")
+                                .help("Flag the parts whose median time changed by more than the given percentage")
+                                .default_value("10")
+                                .value_parser(parse_non_negative),
+                        )
+                        .arg(
+                            Arg::new("min-difference")
+                                .long("min-difference")
+                                .value_name("MILLISECONDS")
+                                .help("Flag only the parts whose median time changed by at least the given time")
+                                .default_value("0.1")
+                                .value_parser(parse_non_negative),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("new")
+                .about("Create the solution and test data of a new challenge")
+                .arg(
+                    Arg::new("year")
+                        .value_name("YEAR")
+                        .help("The year of the new challenge")
+                        .required(true)
+                        .value_parser(value_parser!(u32).range(2015..)),
+                )
+                .arg(
+                    Arg::new("day")
+                        .value_name("DAY")
+                        .help("The day of the new challenge")
+                        .required(true)
+                        .value_parser(value_parser!(u32).range(1..=25)),
+                )
+                .arg(
+                    Arg::new("title")
+                        .value_name("TITLE")
+                        .help("The title of the new challenge")
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("root")
+                        .long("root")
+                        .value_name("DIR")
+                        .help("The root directory of the crate [default: the current directory]")
+                        .value_parser(value_parser!(PathBuf)),
+                ),
+        )
+        .subcommand(
+            Command::new("fetch")
+                .about("Download the inputs of challenges into the inputs directory")
+                .arg(
+                    Arg::new("year")
+                        .value_name("YEAR")
+                        .help("The year of the challenge")
+                        .required_unless_present("all")
+                        .value_parser(value_parser!(u32).range(2015..)),
+                )
+                .arg(
+                    Arg::new("day")
+                        .value_name("DAY")
+                        .help("The day of the challenge")
+                        .required_unless_present("all")
+                        .conflicts_with("all")
+                        .value_parser(value_parser!(u32).range(1..=25)),
+                )
+                .arg(
+                    Arg::new("all")
+                        .long("all")
+                        .action(ArgAction::SetTrue)
+                        .help("Download the inputs of all implemented challenges, or those of YEAR"),
+                )
+                .args(client_args()),
+        )
+        .subcommand(
+            Command::new("submit")
+                .about("Solve a part of a challenge and submit the answer")
+                .arg(
+                    Arg::new("year")
+                        .value_name("YEAR")
+                        .help("The year of the challenge")
+                        .required(true)
+                        .value_parser(value_parser!(u32).range(2015..)),
+                )
+                .arg(
+                    Arg::new("day")
+                        .value_name("DAY")
+                        .help("The day of the challenge")
+                        .required(true)
+                        .value_parser(value_parser!(u32).range(1..=25)),
+                )
+                .arg(
+                    Arg::new("part")
+                        .value_name("PART")
+                        .help("The part of the challenge")
+                        .required(true)
+                        .value_parser(value_parser!(u32).range(1..=2)),
+                )
+                .arg(
+                    Arg::new("input")
+                        .short('i')
+                        .long("input")
+                        .value_name("PATH")
+                        .help("Read the input from the given file instead of the inputs directory or stdin")
+                        .value_parser(value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("log")
+                        .long("log")
+                        .value_name("PATH")
+                        .env("AOCODE_SUBMISSIONS")
+                        .default_value("submissions.log")
+                        .help("The log of submitted answers and their verdicts")
+                        .value_parser(value_parser!(PathBuf)),
+                )
+                .args(client_args()),
+        )
         .arg(
             Arg::new("year")
                 .value_name("YEAR")
@@ -22,10 +219,28 @@ fn main() -> ExitCode {
         .arg(
             Arg::new("part")
                 .value_name("PART")
-                .help("The part of the Advent of Code challenge")
-                .required_unless_present("list")
+                .help("The parts of the Advent of Code challenge [default: all implemented parts]")
+                .num_args(1..=2)
                 .value_parser(value_parser!(u32).range(1..=2)),
         )
+        .arg(
+            Arg::new("input")
+                .short('i')
+                .long("input")
+                .value_name("PATH")
+                .help("Read the input from the given file instead of the inputs directory or stdin")
+                .conflicts_with("list")
+                .value_parser(value_parser!(PathBuf)),
+        )
+        .arg(
+            Arg::new("inputs")
+                .long("inputs")
+                .value_name("DIR")
+                .env("AOCODE_INPUTS")
+                .global(true)
+                .help("Read the input from yearYYYY/dayDD.txt in the given directory")
+                .value_parser(value_parser!(PathBuf)),
+        )
         .arg(
             Arg::new("list")
                 .short('l')
@@ -35,6 +250,15 @@ fn main() -> ExitCode {
                 .conflicts_with("benchmark")
                 .required(false),
         )
+        .arg(
+            Arg::new("tag")
+                .short('t')
+                .long("tag")
+                .value_name("TAG")
+                .help("List only the challenges with the given topic, e.g. grid or number-theory")
+                .requires("list")
+                .value_parser(parse_tag),
+        )
         .arg(
             Arg::new("benchmark")
                 .short('b')
@@ -44,51 +268,447 @@ fn main() -> ExitCode {
                 .conflicts_with("list")
                 .required(false),
         )
+        .arg(
+            Arg::new("param")
+                .short('p')
+                .long("param")
+                .value_name("NAME=VALUE")
+                .action(ArgAction::Append)
+                .help("Override a parameter of the challenge, e.g. a grid size")
+                .conflicts_with_all(["list", "benchmark"])
+                .value_parser(parse_parameter),
+        )
+        .arg(
+            Arg::new("iterations")
+                .long("iterations")
+                .value_name("N")
+                .help("Measure the given number of runs and summarize their times")
+                .requires("benchmark")
+                .conflicts_with("time-budget")
+                .value_parser(value_parser!(u32).range(1..)),
+        )
+        .arg(
+            Arg::new("time-budget")
+                .long("time-budget")
+                .value_name("SECONDS")
+                .help("Measure runs for the given time and summarize their times")
+                .requires("benchmark")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("warmup")
+                .long("warmup")
+                .value_name("N")
+                .help("The number of unmeasured runs before measuring [default: 3]")
+                .requires("benchmark")
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            Arg::new("format")
+                .short('f')
+                .long("format")
+                .value_name("FORMAT")
+                .help("The output format: text, json, csv or markdown [default: text]")
+                .global(true)
+                .value_parser(Format::from_name),
+        )
+        .arg(
+            Arg::new("progress")
+                .long("progress")
+                .action(ArgAction::SetTrue)
+                .help("Show the progress of solving the challenge on stderr")
+                .conflicts_with_all(["list", "benchmark"])
+                .required(false),
+        )
+        .arg(
+            Arg::new("watch")
+                .short('w')
+                .long("watch")
+                .action(ArgAction::SetTrue)
+                .help("Solve the challenge again whenever the input file changes")
+                .conflicts_with_all(["list", "progress", "iterations", "time-budget", "format"])
+                .required(false),
+        )
         .get_matches();
 
-    let year_num = argv.get_one::<u32>("year");
-    let day_num = argv.get_one::<u32>("day");
-    let part_num = argv.get_one::<u32>("part");
-    let list_flag: bool = argv.get_flag("list");
-    let benchmark_flag: bool = argv.get_flag("benchmark");
-
     let advent_of_code = AoC::new();
+    let format = argv
+        .get_one::<Format>("format")
+        .copied()
+        .unwrap_or_default();
 
-    if list_flag {
-        println!("{}", advent_of_code);
-    } else {
-        // unwrap the year, day, and part numbers
-        // clap will make sure that they are present here
-        let year_num = *year_num.unwrap() as usize;
-        let day_num = *day_num.unwrap() as usize;
-        let part_num = *part_num.unwrap() as usize;
-
-        let mut input = String::new();
-        if let Err(err) = stdin().read_to_string(&mut input) {
-            eprintln!("Error reading the input: {}", err);
+    if let Some(("stats", stats)) = argv.subcommand() {
+        let year = stats.get_one::<u32>("year").map(|&year| year as usize);
+        let timings = stats.get_one::<PathBuf>("timings").map(PathBuf::as_path);
+        return cli::stats::stats(&advent_of_code, year, timings, format);
+    }
+    if let Some(("new", new)) = argv.subcommand() {
+        let root = new
+            .get_one::<PathBuf>("root")
+            .map_or(Path::new("."), PathBuf::as_path);
+        let year = *new.get_one::<u32>("year").unwrap() as usize;
+        let day = *new.get_one::<u32>("day").unwrap() as usize;
+        let title = new.get_one::<String>("title").unwrap();
+        return match cli::scaffold::scaffold(root, year, day, title) {
+            Ok(paths) => {
+                for path in paths {
+                    println!("{}", path.display());
+                }
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("Error creating the challenge: {}", err);
+                ExitCode::FAILURE
+            }
+        };
+    }
+    if let Some(("submit", submit)) = argv.subcommand() {
+        let year = *submit.get_one::<u32>("year").unwrap() as usize;
+        let day = *submit.get_one::<u32>("day").unwrap() as usize;
+        let part = *submit.get_one::<u32>("part").unwrap() as usize;
+        let input = match read_input(submit, year, day) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Error reading the input: {}", err);
+                return ExitCode::FAILURE;
+            }
+        };
+        let answer = match advent_of_code.solve(year, day, part, &input) {
+            Ok(answer) => answer.to_string(),
+            Err(err) => {
+                eprintln!("Error running the challenge: {}", err);
+                return ExitCode::FAILURE;
+            }
+        };
+        let log = submit.get_one::<PathBuf>("log").unwrap();
+        return match client(submit) {
+            Ok(mut client) => cli::submit::submit(&mut client, log, year, day, part, &answer),
+            Err(err) => {
+                eprintln!("Error submitting the answer: {}", err);
+                ExitCode::FAILURE
+            }
+        };
+    }
+    if let Some(("baseline", baseline)) = argv.subcommand() {
+        let (name, baseline) = baseline.subcommand().unwrap();
+        let Some(inputs) = baseline.get_one::<PathBuf>("inputs") else {
+            eprintln!("Error reading the inputs: pass --inputs or set AOCODE_INPUTS");
             return ExitCode::FAILURE;
+        };
+        let year = baseline.get_one::<u32>("year").map(|&year| year as usize);
+        let path = baseline.get_one::<PathBuf>("file").unwrap();
+        let options =
+            BenchmarkOptions::iterations(*baseline.get_one::<u32>("iterations").unwrap() as usize)
+                .with_warmup(*baseline.get_one::<u32>("warmup").unwrap() as usize);
+        if name == "save" {
+            return cli::baseline::save(&advent_of_code, year, inputs, &options, path);
         }
-
-        if !benchmark_flag {
-            match advent_of_code.run(year_num, day_num, part_num, &input) {
-                Ok(result) => println!("{}", result),
-                Err(err) => {
-                    eprintln!("Error running the challenge: {}", err);
-                    return ExitCode::FAILURE;
-                }
+        let threshold = baseline.get_one::<f64>("threshold").unwrap() / 100.0;
+        let min_difference = match Duration::try_from_secs_f64(
+            baseline.get_one::<f64>("min-difference").unwrap() / 1000.0,
+        ) {
+            Ok(min_difference) => min_difference,
+            Err(err) => {
+                eprintln!("Error reading the minimum difference: {}", err);
+                return ExitCode::FAILURE;
             }
-        } else {
-            match advent_of_code.benchmark(year_num, day_num, part_num, &input) {
-                Ok((result, duration)) => {
-                    println!("{}    --- {} s", result, duration.as_secs_f64())
-                }
-                Err(err) => {
-                    eprintln!("Error running the challenge: {}", err);
-                    return ExitCode::FAILURE;
+        };
+        return cli::baseline::compare(
+            &advent_of_code,
+            year,
+            inputs,
+            &options,
+            path,
+            threshold,
+            min_difference,
+            format,
+        );
+    }
+    if let Some((name, subcommand)) = argv.subcommand() {
+        let Some(inputs) = subcommand.get_one::<PathBuf>("inputs") else {
+            eprintln!("Error reading the inputs: pass --inputs or set AOCODE_INPUTS");
+            return ExitCode::FAILURE;
+        };
+        let year = subcommand.get_one::<u32>("year").map(|&year| year as usize);
+        return match name {
+            "fetch" => {
+                let days = match subcommand.get_one::<u32>("day") {
+                    Some(&day) => vec![(year.unwrap(), day as usize)],
+                    None => advent_of_code
+                        .catalog()
+                        .years
+                        .iter()
+                        .filter(|catalog_year| year.is_none_or(|year| catalog_year.id == year))
+                        .flat_map(|catalog_year| {
+                            catalog_year
+                                .days
+                                .iter()
+                                .map(|day| (catalog_year.id, day.id))
+                        })
+                        .collect(),
+                };
+                match client(subcommand) {
+                    Ok(mut client) => cli::fetch::fetch(&mut client, inputs, &days),
+                    Err(err) => {
+                        eprintln!("Error fetching the inputs: {}", err);
+                        ExitCode::FAILURE
+                    }
                 }
             }
+            "verify" => {
+                let outputs = subcommand.get_one::<PathBuf>("outputs").unwrap();
+                let bless = subcommand.get_flag("bless");
+                cli::verify::verify(
+                    &advent_of_code,
+                    year,
+                    inputs,
+                    outputs,
+                    timeout(subcommand),
+                    format,
+                    bless,
+                )
+            }
+            _ => cli::run_all::run_all(&advent_of_code, year, inputs, timeout(subcommand), format),
+        };
+    }
+
+    if argv.get_flag("list") {
+        cli::list::list(&advent_of_code, argv.get_one::<Tag>("tag").copied(), format);
+        return ExitCode::SUCCESS;
+    }
+
+    // unwrap the year and day numbers
+    // clap will make sure that they are present here
+    let year_num = *argv.get_one::<u32>("year").unwrap() as usize;
+    let day_num = *argv.get_one::<u32>("day").unwrap() as usize;
+    let parts = match argv.get_many::<u32>("part") {
+        Some(parts) => parts.map(|&part| part as usize).collect(),
+        None => match advent_of_code
+            .year(year_num)
+            .and_then(|year| year.day(day_num))
+        {
+            Ok(day) => day.parts().to_vec(),
+            Err(err) => {
+                eprintln!("Error running the challenge: {}", err);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+
+    let mut options = match (
+        argv.get_one::<u32>("iterations"),
+        argv.get_one::<f64>("time-budget"),
+    ) {
+        (Some(&iterations), _) => Some(BenchmarkOptions::iterations(iterations as usize)),
+        (_, Some(&budget)) => match Duration::try_from_secs_f64(budget) {
+            Ok(budget) => Some(BenchmarkOptions::time_budget(budget)),
+            Err(err) => {
+                eprintln!("Error reading the time budget: {}", err);
+                return ExitCode::FAILURE;
+            }
+        },
+        _ => None,
+    };
+    if let Some(&warmup) = argv.get_one::<u32>("warmup") {
+        options = Some(options.unwrap_or_default().with_warmup(warmup as usize));
+    }
+    let mut parameters = Parameters::new();
+    for (name, value) in argv
+        .get_many::<(String, String)>("param")
+        .unwrap_or_default()
+    {
+        parameters.set(name, value.as_str());
+    }
+
+    if argv.get_flag("watch") {
+        let path = match (
+            argv.get_one::<PathBuf>("input"),
+            argv.get_one::<PathBuf>("inputs"),
+        ) {
+            (Some(path), _) => path.clone(),
+            (None, Some(inputs)) => input_path(inputs, year_num, day_num),
+            (None, None) => {
+                eprintln!("Error reading the input: pass --input or --inputs to watch a file");
+                return ExitCode::FAILURE;
+            }
+        };
+        cli::watch::watch(
+            &advent_of_code,
+            year_num,
+            day_num,
+            &parts,
+            &path,
+            &parameters,
+            argv.get_flag("benchmark"),
+        );
+    }
+
+    let input = match read_input(&argv, year_num, day_num) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Error reading the input: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if argv.get_flag("benchmark") {
+        cli::solve::benchmark(
+            &advent_of_code,
+            year_num,
+            day_num,
+            &parts,
+            &input,
+            options,
+            format,
+        )
+    } else {
+        cli::solve::solve(
+            &advent_of_code,
+            year_num,
+            day_num,
+            &parts,
+            &input,
+            &parameters,
+            argv.get_flag("progress"),
+            format,
+        )
+    }
+}
+
+/// Read the input from the `--input` file, the inputs directory or stdin, in this order.
+fn read_input(argv: &ArgMatches, year: usize, day: usize) -> io::Result<String> {
+    let path = match (
+        argv.get_one::<PathBuf>("input"),
+        argv.get_one::<PathBuf>("inputs"),
+    ) {
+        (Some(path), _) => path.clone(),
+        (None, Some(inputs)) => input_path(inputs, year, day),
+        (None, None) => {
+            let mut input = String::new();
+            stdin().read_to_string(&mut input)?;
+            return Ok(input);
         }
+    };
+    fs::read_to_string(&path)
+        .map(|input| input.replace("\r\n", "\n"))
+        .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path.display(), err)))
+}
+
+/// Get the path of the input to a day's challenge in the inputs directory.
+fn input_path(inputs: &Path, year: usize, day: usize) -> PathBuf {
+    inputs
+        .join(format!("year{:04}", year))
+        .join(format!("day{:02}.txt", day))
+}
+
+/// Get the arguments configuring the [Client] of the website.
+fn client_args() -> [Arg; 4] {
+    [
+        Arg::new("session")
+            .long("session")
+            .value_name("TOKEN")
+            .env("AOCODE_SESSION")
+            .hide_env_values(true)
+            .help("The session cookie of the logged-in user"),
+        Arg::new("base-url")
+            .long("base-url")
+            .value_name("URL")
+            .env("AOCODE_BASE_URL")
+            .default_value(cli::client::BASE_URL)
+            .help("The base URL of the Advent of Code website"),
+        Arg::new("delay")
+            .long("delay")
+            .value_name("SECONDS")
+            .help("The minimum time between two requests [default: 3]")
+            .value_parser(value_parser!(f64)),
+        Arg::new("contact")
+            .long("contact")
+            .value_name("CONTACT")
+            .env("AOCODE_CONTACT")
+            .help("The contact sent to the Advent of Code website with every request, e.g. an email address"),
+    ]
+}
+
+/// Get the time limit argument of the subcommands solving every challenge.
+fn timeout_arg() -> Arg {
+    Arg::new("timeout")
+        .long("timeout")
+        .value_name("SECONDS")
+        .help("Stop the solutions which can be stopped after the given time and report them as timed out")
+        .value_parser(parse_non_negative)
+}
+
+/// Get the arguments shared by the `baseline` subcommands.
+fn baseline_args() -> [Arg; 4] {
+    [
+        Arg::new("file")
+            .value_name("FILE")
+            .help("The JSON file of the baseline")
+            .required(true)
+            .value_parser(value_parser!(PathBuf)),
+        Arg::new("year")
+            .value_name("YEAR")
+            .help("Benchmark only the challenges of the given year")
+            .value_parser(value_parser!(u32).range(2015..)),
+        Arg::new("iterations")
+            .long("iterations")
+            .value_name("N")
+            .help("The number of measured runs of every part")
+            .default_value("5")
+            .value_parser(value_parser!(u32).range(1..)),
+        Arg::new("warmup")
+            .long("warmup")
+            .value_name("N")
+            .help("The number of unmeasured runs of every part before measuring")
+            .default_value("1")
+            .value_parser(value_parser!(u32)),
+    ]
+}
+
+/// Create the [Client] of the website from the arguments of a subcommand.
+fn client(argv: &ArgMatches) -> Result<Client, String> {
+    let session = argv
+        .get_one::<String>("session")
+        .ok_or("pass --session or set AOCODE_SESSION")?;
+    let delay = match argv.get_one::<f64>("delay") {
+        Some(&delay) => Duration::try_from_secs_f64(delay).map_err(|err| err.to_string())?,
+        None => cli::client::DEFAULT_DELAY,
+    };
+    let base_url = argv.get_one::<String>("base-url").unwrap();
+    let contact = argv.get_one::<String>("contact").map(String::as_str);
+    Ok(Client::new(base_url, session, delay, contact))
+}
+
+/// Get the time limit of solving a part from the arguments of a subcommand, which is unlimited by default.
+fn timeout(argv: &ArgMatches) -> Duration {
+    argv.get_one::<f64>("timeout")
+        .map_or(Duration::MAX, |&timeout| {
+            Duration::try_from_secs_f64(timeout).unwrap_or(Duration::MAX)
+        })
+}
+
+/// Parse a parameter given as `NAME=VALUE`.
+fn parse_parameter(parameter: &str) -> Result<(String, String), String> {
+    parameter
+        .split_once('=')
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .ok_or_else(|| String::from("expected NAME=VALUE"))
+}
+
+/// Parse a number which can't be negative.
+fn parse_non_negative(value: &str) -> Result<f64, String> {
+    let number = value.parse::<f64>().map_err(|err| err.to_string())?;
+    if number.is_finite() && number >= 0.0 {
+        Ok(number)
+    } else {
+        Err(String::from("expected a non-negative number"))
     }
+}
 
-    ExitCode::SUCCESS
+/// Parse the name of a [Tag].
+fn parse_tag(name: &str) -> Result<Tag, String> {
+    Tag::from_name(name).ok_or_else(|| {
+        let names = Tag::ALL.map(|tag| tag.name());
+        format!("expected one of {}", names.join(", "))
+    })
 }
@@ -13,34 +13,63 @@ pub const HEX_DIGITS: [char; 16] = [
 /// x ≡ a2 (mod m2)
 /// ...
 /// x ≡ an (mod mn)
-/// Where m1, m2, ..., mn are pairwise coprime, the solution x is unique modulo M, where M = m1 * m2 * ... * mn.
+/// The solution x is unique modulo M, where M is the least common multiple of m1, m2, ..., mn.
+/// The moduli do not have to be pairwise coprime.
 /// # Arguments
-/// * `congruences` - The congruences (tuples). Each tuple contains the (remainder, modulus). The slice should be sorted by modulus in descending order for the best performance.
+/// * `congruences` - The congruences (tuples). Each tuple contains the (remainder, modulus).
 /// # Returns
-/// * `u64` - The solution to the system of congruences.
-/// # Panics
-/// If there are no congruences. There must be at least 1 congruence.
-/// Note that this function will run infinitely if the moduli are not pairwise coprime.
-pub fn chinese_remainder_theorem<T, U>(congruences: U) -> u64
+/// * `Option<u64>` - The smallest solution to the system of congruences,
+///   or `None` if there are no congruences, a modulus is 0, the congruences contradict each other
+///   or M does not fit into `u64`.
+pub fn chinese_remainder_theorem<T, U>(congruences: U) -> Option<u64>
 where
     T: Borrow<(u64, u64)>,
     U: IntoIterator<Item = T>,
 {
     let mut congruences = congruences.into_iter();
-    let &(mut solution, mut modulus) = congruences
-        .next()
-        .expect("There must be at least 1 congruence.")
-        .borrow();
+    let &(remainder, modulus) = congruences.next()?.borrow();
+    if modulus == 0 {
+        return None;
+    }
+    let (mut solution, mut modulus) = (i128::from(remainder % modulus), i128::from(modulus));
 
     for congruence in congruences {
-        let (remainder, modulo) = congruence.borrow();
-        while solution % modulo != *remainder {
-            solution += modulus;
+        let &(remainder, modulo) = congruence.borrow();
+        if modulo == 0 {
+            return None;
+        }
+        let (remainder, modulo) = (i128::from(remainder), i128::from(modulo));
+
+        // solution + modulus * k ≡ remainder (mod modulo)
+        let (gcd, inverse, _) = extended_gcd(modulus, modulo);
+        let difference = remainder - solution;
+        if difference % gcd != 0 {
+            return None;
         }
-        modulus *= modulo;
+        let step = modulo / gcd;
+        let lcm = modulus
+            .checked_mul(step)
+            .filter(|&lcm| lcm <= i128::from(u64::MAX))?;
+        // both factors are below 2^64, so their product fits into u128
+        let k = (difference / gcd).rem_euclid(step) as u128 * inverse.rem_euclid(step) as u128
+            % step as u128;
+        solution += modulus * k as i128;
+        modulus = lcm;
     }
 
-    solution
+    u64::try_from(solution).ok()
+}
+
+/// Find the greatest common divisor of two numbers and the coefficients of Bézout's identity.
+/// # Returns
+/// * `(i128, i128, i128)` - The greatest common divisor g and the coefficients x and y, where a * x + b * y = g.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (gcd, x, y) = extended_gcd(b, a % b);
+        (gcd, y, x - a / b * y)
+    }
 }
 
 /// Finds the Manhattan distance between two locations.
@@ -0,0 +1,120 @@
+//! Topic tags and difficulty ratings of days' challenges.
+
+use std::fmt::{self, Display, Formatter};
+
+/// An enum representing a topic of a day's challenge.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Tag {
+    /// Operations on the bits of integers.
+    Bitwise,
+    /// A cellular automaton, e.g. a game of life.
+    CellularAutomaton,
+    /// Combinations, permutations or subsets.
+    Combinatorics,
+    /// A state which repeats after a number of steps.
+    Cycles,
+    /// Dynamic programming or memoization.
+    DynamicProgramming,
+    /// Coordinates, distances, areas or shapes.
+    Geometry,
+    /// A graph or a tree.
+    Graph,
+    /// A two-dimensional grid.
+    Grid,
+    /// A hash function, e.g. MD5 or a knot hash.
+    Hashing,
+    /// Divisors, modular arithmetic or number representations.
+    NumberTheory,
+    /// Input with a non-trivial structure.
+    Parsing,
+    /// Intervals of integers.
+    Ranges,
+    /// A search through states, e.g. a breadth-first search.
+    Search,
+    /// A step-by-step simulation.
+    Simulation,
+    /// Operations on strings.
+    Strings,
+    /// A virtual machine running a program from the input.
+    Vm,
+}
+impl Tag {
+    /// All tags, sorted in ascending order.
+    pub const ALL: [Tag; 16] = [
+        Self::Bitwise,
+        Self::CellularAutomaton,
+        Self::Combinatorics,
+        Self::Cycles,
+        Self::DynamicProgramming,
+        Self::Geometry,
+        Self::Graph,
+        Self::Grid,
+        Self::Hashing,
+        Self::NumberTheory,
+        Self::Parsing,
+        Self::Ranges,
+        Self::Search,
+        Self::Simulation,
+        Self::Strings,
+        Self::Vm,
+    ];
+
+    /// Get the name of the tag.
+    /// # Returns
+    /// * The name in kebab case, e.g. `"number-theory"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Bitwise => "bitwise",
+            Self::CellularAutomaton => "cellular-automaton",
+            Self::Combinatorics => "combinatorics",
+            Self::Cycles => "cycles",
+            Self::DynamicProgramming => "dynamic-programming",
+            Self::Geometry => "geometry",
+            Self::Graph => "graph",
+            Self::Grid => "grid",
+            Self::Hashing => "hashing",
+            Self::NumberTheory => "number-theory",
+            Self::Parsing => "parsing",
+            Self::Ranges => "ranges",
+            Self::Search => "search",
+            Self::Simulation => "simulation",
+            Self::Strings => "strings",
+            Self::Vm => "vm",
+        }
+    }
+
+    /// Get the tag with the specified name.
+    /// # Arguments
+    /// * `name` - The name of the tag in kebab case.
+    /// # Returns
+    /// * The tag, if the name is known.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|tag| tag.name() == name)
+    }
+}
+impl Display for Tag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// An enum representing an informal rating of how hard a day's challenge is.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Difficulty {
+    /// A direct implementation of the puzzle text.
+    #[default]
+    Easy,
+    /// A puzzle which needs a suitable algorithm or some care with performance.
+    Medium,
+    /// A puzzle which needs an insight into the input or a careful search.
+    Hard,
+}
+impl Display for Difficulty {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Easy => write!(f, "easy"),
+            Self::Medium => write!(f, "medium"),
+            Self::Hard => write!(f, "hard"),
+        }
+    }
+}
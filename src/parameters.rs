@@ -0,0 +1,204 @@
+//! Named parameters overriding the constants of a day's challenge.
+
+use crate::Error;
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// An enum representing the value of a parameter.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ParameterValue {
+    /// An integer value, e.g. a grid size or an iteration count.
+    Integer(i64),
+    /// A textual value, e.g. a password.
+    Text(String),
+}
+impl Display for ParameterValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{}", value),
+            Self::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+impl FromStr for ParameterValue {
+    type Err = Infallible;
+
+    /// Parse an integer value or fall back to a textual value.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.parse().map_or_else(|_| Self::from(s), Self::Integer))
+    }
+}
+impl From<i64> for ParameterValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+impl From<String> for ParameterValue {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+impl From<&str> for ParameterValue {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+/// A structure describing a named parameter of a day's challenge.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Parameter {
+    /// The name of the parameter.
+    pub name: &'static str,
+    /// The description of the parameter.
+    pub description: &'static str,
+    /// The value used by the puzzle, which also determines the type of the parameter.
+    pub default: ParameterValue,
+}
+impl Parameter {
+    /// Create a new integer [Parameter].
+    /// # Arguments
+    /// * `name` - The name of the parameter.
+    /// * `description` - The description of the parameter.
+    /// * `default` - The value used by the puzzle.
+    /// # Returns
+    /// * The new [Parameter].
+    pub fn integer(name: &'static str, description: &'static str, default: i64) -> Self {
+        Self {
+            name,
+            description,
+            default: ParameterValue::Integer(default),
+        }
+    }
+
+    /// Create a new textual [Parameter].
+    /// # Arguments
+    /// * `name` - The name of the parameter.
+    /// * `description` - The description of the parameter.
+    /// * `default` - The value used by the puzzle.
+    /// # Returns
+    /// * The new [Parameter].
+    pub fn text(name: &'static str, description: &'static str, default: &str) -> Self {
+        Self {
+            name,
+            description,
+            default: ParameterValue::from(default),
+        }
+    }
+}
+
+/// A structure representing the values of the parameters of a day's challenge.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Parameters {
+    values: BTreeMap<String, ParameterValue>,
+}
+impl Parameters {
+    /// Create a new empty [Parameters] instance, so that every parameter has its default value.
+    /// # Returns
+    /// * The new [Parameters] instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the value of a parameter.
+    /// # Arguments
+    /// * `name` - The name of the parameter.
+    /// * `value` - The value of the parameter.
+    /// # Returns
+    /// * The [Parameters] with the value set.
+    pub fn with(mut self, name: &str, value: impl Into<ParameterValue>) -> Self {
+        self.set(name, value);
+        self
+    }
+
+    /// Set the value of a parameter.
+    /// # Arguments
+    /// * `name` - The name of the parameter.
+    /// * `value` - The value of the parameter.
+    pub fn set(&mut self, name: &str, value: impl Into<ParameterValue>) {
+        self.values.insert(name.to_string(), value.into());
+    }
+
+    /// Get the value of a parameter.
+    /// # Arguments
+    /// * `name` - The name of the parameter.
+    /// # Returns
+    /// * The value of the parameter or [None] if it is not set.
+    pub fn get(&self, name: &str) -> Option<&ParameterValue> {
+        self.values.get(name)
+    }
+
+    /// Get all set parameters.
+    /// # Returns
+    /// * An iterator over the names and values of the parameters, sorted by their name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ParameterValue)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// Check the values against the parameters of a day's challenge and add the missing defaults.
+    /// Integers are accepted for textual parameters and textual integers for integer parameters.
+    /// # Arguments
+    /// * `parameters` - The parameters of the day's challenge.
+    /// # Returns
+    /// * The [Parameters] with a value for every parameter of the day's challenge or the [Error].
+    /// # Errors
+    /// * [Error::InvalidParameter] - A parameter is unknown or its value has the wrong type.
+    pub fn resolve(&self, parameters: &[Parameter]) -> Result<Self, Error> {
+        if self
+            .values
+            .keys()
+            .any(|name| parameters.iter().all(|parameter| parameter.name != name))
+        {
+            return Err(Error::InvalidParameter);
+        }
+
+        let mut resolved = Self::new();
+        for parameter in parameters {
+            let value = match (&parameter.default, self.get(parameter.name)) {
+                (default, None) => default.clone(),
+                (ParameterValue::Integer(_), Some(ParameterValue::Text(text))) => {
+                    ParameterValue::Integer(text.parse().map_err(|_| Error::InvalidParameter)?)
+                }
+                (ParameterValue::Text(_), Some(ParameterValue::Integer(value))) => {
+                    ParameterValue::Text(value.to_string())
+                }
+                (_, Some(value)) => value.clone(),
+            };
+            resolved.set(parameter.name, value);
+        }
+        Ok(resolved)
+    }
+
+    /// Get the value of an integer parameter.
+    /// # Arguments
+    /// * `name` - The name of the parameter.
+    /// # Returns
+    /// * The value of the parameter or the [Error].
+    /// # Errors
+    /// * [Error::InvalidParameter] - The parameter is not set, not an integer or out of range.
+    pub fn integer<T: TryFrom<i64>>(&self, name: &str) -> Result<T, Error> {
+        match self.get(name) {
+            Some(ParameterValue::Integer(value)) => {
+                T::try_from(*value).map_err(|_| Error::InvalidParameter)
+            }
+            _ => Err(Error::InvalidParameter),
+        }
+    }
+
+    /// Get the value of a textual parameter.
+    /// # Arguments
+    /// * `name` - The name of the parameter.
+    /// # Returns
+    /// * The value of the parameter or the [Error].
+    /// # Errors
+    /// * [Error::InvalidParameter] - The parameter is not set or not textual.
+    pub fn text(&self, name: &str) -> Result<&str, Error> {
+        match self.get(name) {
+            Some(ParameterValue::Text(text)) => Ok(text),
+            _ => Err(Error::InvalidParameter),
+        }
+    }
+}
@@ -32,24 +32,50 @@ macro_rules! year {
             fn days<'a>(&'a self) -> Box<dyn Iterator<Item = &'a dyn crate::Day> + 'a> {
                 Box::new(self.days.iter().map(|day| day.as_ref()))
             }
+            fn examples(&self, day: usize) -> Result<Vec<crate::Example>, crate::Error> {
+                self.day(day)?;
+                let examples = crate::Example::parse_all(include_str!("examples.txt"))?;
+                Ok(examples.into_iter().filter(|example| example.day == day).collect())
+            }
         }
     };
 }
 
 macro_rules! day {
-    ($struct_name:ident, $year_id:literal, $day_id:literal, $title:literal) => {
+    ($struct_name:ident, $year_id:literal, $day_id:literal, $title:literal, difficulty = $difficulty:ident, tags = [$($tag:ident),*]) => {
+        day!($struct_name, $year_id, $day_id, $title, parts = [1, 2], difficulty = $difficulty, tags = [$($tag),*]);
+    };
+    ($struct_name:ident, $year_id:literal, $day_id:literal, $title:literal, parts = [$($part:literal),*], difficulty = $difficulty:ident, tags = [$($tag:ident),*]) => {
         #[doc = concat!("[*", $title, "*](https://adventofcode.com/", $year_id, "/day/", $day_id, ")")]
         #[derive(Copy, Clone)]
         pub struct $struct_name {
             id: usize,
             title: &'static str,
+            parts: &'static [usize],
+            url: &'static str,
+            difficulty: crate::Difficulty,
+            tags: &'static [crate::Tag],
         }
+        // the parts are checked when compiling, as the catalog lists them as given
+        const _: () = {
+            let parts: &[usize] = &[$($part),*];
+            let mut i = 0;
+            while i < parts.len() {
+                assert!(parts[i] == 1 || parts[i] == 2, "expected the parts 1 and 2");
+                assert!(i == 0 || parts[i - 1] < parts[i], "expected sorted parts");
+                i += 1;
+            }
+        };
         impl $struct_name {
             #[doc = concat!("Create a new [", stringify!($struct_name), "] instance.")]
             pub fn new() -> Self {
                 Self {
                     id: $day_id,
                     title: $title,
+                    parts: &[$($part),*],
+                    url: concat!("https://adventofcode.com/", $year_id, "/day/", $day_id),
+                    difficulty: crate::Difficulty::$difficulty,
+                    tags: &[$(crate::Tag::$tag),*],
                 }
             }
         }
@@ -65,6 +91,18 @@ macro_rules! day {
             fn title(&self) -> &str {
                 self.title
             }
+            fn parts(&self) -> &[usize] {
+                self.parts
+            }
+            fn url(&self) -> &str {
+                self.url
+            }
+            fn difficulty(&self) -> crate::Difficulty {
+                self.difficulty
+            }
+            fn tags(&self) -> &[crate::Tag] {
+                self.tags
+            }
         }
     };
 }
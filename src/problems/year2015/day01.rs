@@ -1,9 +1,16 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
-day!(Day01, 2015, 1, "Not Quite Lisp");
+day!(
+    Day01,
+    2015,
+    1,
+    "Not Quite Lisp",
+    difficulty = Easy,
+    tags = [Parsing]
+);
 
 impl Solution for Day01 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let mut floor = 0;
         for c in input.trim().chars() {
             match c {
@@ -12,9 +19,9 @@ impl Solution for Day01 {
                 _ => (),
             }
         }
-        Ok(floor.to_string())
+        Ok(floor.into())
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut floor = 0;
         for (i, c) in input.trim().chars().enumerate() {
             match c {
@@ -23,7 +30,7 @@ impl Solution for Day01 {
                 _ => (),
             }
             if floor == -1 {
-                return Ok((i + 1).to_string());
+                return Ok((i + 1).into());
             }
         }
         Err(Error::NoSolution)
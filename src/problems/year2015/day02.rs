@@ -1,56 +1,78 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 
-day!(Day02, 2015, 2, "I Was Told There Would Be No Math");
+day!(
+    Day02,
+    2015,
+    2,
+    "I Was Told There Would Be No Math",
+    difficulty = Easy,
+    tags = [Geometry]
+);
 
 impl Solution for Day02 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut total_paper = 0;
-        let input = parse_input(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut total_paper: usize = 0;
+        let input = parse_input(input)?;
 
         for present in input {
-            total_paper += wrap_present(present);
+            total_paper = wrap_present(present)
+                .and_then(|paper| total_paper.checked_add(paper))
+                .ok_or(Error::NoSolution)?;
         }
 
-        Ok(total_paper.to_string())
+        Ok(total_paper.into())
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut total_ribbon = 0;
-        let input = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut total_ribbon: usize = 0;
+        let input = parse_input(input)?;
 
         for present in input {
-            total_ribbon += ribbon_present(present);
+            total_ribbon = ribbon_present(present)
+                .and_then(|ribbon| total_ribbon.checked_add(ribbon))
+                .ok_or(Error::NoSolution)?;
         }
 
-        Ok(total_ribbon.to_string())
+        Ok(total_ribbon.into())
     }
 }
 
-fn ribbon_present(present: [usize; 3]) -> usize {
-    let mut perimeters: [usize; 3] = [
-        (present[0] << 1) + (present[1] << 1),
-        (present[1] << 1) + (present[2] << 1),
-        (present[2] << 1) + (present[0] << 1),
-    ];
-    perimeters.sort();
-    present[0] * present[1] * present[2] + perimeters[0]
+/// Get the feet of ribbon a present needs, or [None] if it overflows.
+fn ribbon_present(present: [usize; 3]) -> Option<usize> {
+    let mut sorted = present;
+    sorted.sort();
+    let perimeter = sorted[0].checked_add(sorted[1])?.checked_mul(2)?;
+    let bow = present[0]
+        .checked_mul(present[1])?
+        .checked_mul(present[2])?;
+    bow.checked_add(perimeter)
 }
-fn wrap_present(present: [usize; 3]) -> usize {
+/// Get the square feet of wrapping paper a present needs, or [None] if it overflows.
+fn wrap_present(present: [usize; 3]) -> Option<usize> {
     let mut sides = [
-        present[0] * present[1],
-        present[1] * present[2],
-        present[2] * present[0],
+        present[0].checked_mul(present[1])?,
+        present[1].checked_mul(present[2])?,
+        present[2].checked_mul(present[0])?,
     ];
     sides.sort();
-    3 * sides[0] + 2 * sides[1] + 2 * sides[2]
+    let surface = sides[0].checked_add(sides[1])?.checked_add(sides[2])?;
+    surface.checked_mul(2)?.checked_add(sides[0])
 }
-fn parse_input(input: &str) -> Vec<[usize; 3]> {
+fn parse_input(input: &str) -> Result<Vec<[usize; 3]>, Error> {
     let mut result = Vec::new();
     let mut temp: [usize; 3] = [0; 3];
     for line in input.trim().lines() {
-        for (i, value) in line.split('x').enumerate() {
-            temp[i] = value.parse().unwrap();
+        let mut values = line.split('x');
+        for dimension in temp.iter_mut() {
+            let value = values
+                .next()
+                .or_invalid(input, line, "expected 3 dimensions")?;
+            *dimension = parse_number(input, value)?;
+        }
+        if values.next().is_some() {
+            return Err(Error::invalid_input(input, line, "expected 3 dimensions"));
         }
         result.push(temp);
     }
-    result
+    Ok(result)
 }
@@ -1,29 +1,43 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 use std::collections::HashSet;
 
-day!(Day03, 2015, 3, "Perfectly Spherical Houses in a Vacuum");
+day!(
+    Day03,
+    2015,
+    3,
+    "Perfectly Spherical Houses in a Vacuum",
+    difficulty = Easy,
+    tags = [Grid, Simulation]
+);
 
 impl Solution for Day03 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let mut x: isize = 0;
         let mut y: isize = 0;
         let mut visited: HashSet<(isize, isize)> = HashSet::new();
         visited.insert((x, y));
 
-        for char in input.trim().chars() {
+        let input = input.trim();
+        for (i, char) in input.char_indices() {
             match char {
                 '^' => y += 1,
                 'v' => y -= 1,
                 '>' => x += 1,
                 '<' => x -= 1,
-                _ => panic!("Invalid character"),
+                _ => {
+                    return Err(Error::invalid_input(
+                        input,
+                        &input[i..],
+                        "expected a direction",
+                    ));
+                }
             }
             visited.insert((x, y));
         }
 
-        Ok(visited.len().to_string())
+        Ok(visited.len().into())
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut x_santa = 0;
         let mut x_robo = 0;
         let mut y_santa = 0;
@@ -32,14 +46,21 @@ impl Solution for Day03 {
         let mut visited: HashSet<(isize, isize)> = HashSet::new();
         visited.insert((x_santa, y_santa));
 
-        for char in input.trim().chars() {
+        let input = input.trim();
+        for (i, char) in input.char_indices() {
             if turn {
                 match char {
                     '^' => y_santa += 1,
                     'v' => y_santa -= 1,
                     '>' => x_santa += 1,
                     '<' => x_santa -= 1,
-                    _ => panic!("Invalid character"),
+                    _ => {
+                        return Err(Error::invalid_input(
+                            input,
+                            &input[i..],
+                            "expected a direction",
+                        ));
+                    }
                 }
                 visited.insert((x_santa, y_santa));
             } else {
@@ -48,13 +69,19 @@ impl Solution for Day03 {
                     'v' => y_robo -= 1,
                     '>' => x_robo += 1,
                     '<' => x_robo -= 1,
-                    _ => panic!("Invalid character"),
+                    _ => {
+                        return Err(Error::invalid_input(
+                            input,
+                            &input[i..],
+                            "expected a direction",
+                        ));
+                    }
                 }
                 visited.insert((x_robo, y_robo));
             }
             turn = !turn;
         }
 
-        Ok(visited.len().to_string())
+        Ok(visited.len().into())
     }
 }
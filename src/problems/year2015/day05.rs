@@ -1,9 +1,16 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
-day!(Day05, 2015, 5, "Doesn't He Have Intern-Elves For This?");
+day!(
+    Day05,
+    2015,
+    5,
+    "Doesn't He Have Intern-Elves For This?",
+    difficulty = Easy,
+    tags = [Strings]
+);
 
 impl Solution for Day05 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         Ok(count_nice_strings(input, |string: &str| {
             let vowel_count = string.chars().filter(|c| "aeiou".contains(*c)).count();
 
@@ -24,9 +31,9 @@ impl Solution for Day05 {
 
             vowel_count >= 3 && double_letter && !disallowed_substrings
         })
-        .to_string())
+        .into())
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         Ok(count_nice_strings(input, |string: &str| {
             let string: Vec<char> = string.chars().collect();
 
@@ -53,7 +60,7 @@ impl Solution for Day05 {
 
             double_pair && letter_between
         })
-        .to_string())
+        .into())
     }
 }
 
@@ -1,92 +1,77 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_limited};
+use crate::{Answer, Error, Solution};
 
-day!(Day06, 2015, 6, "Probably a Fire Hazard");
+day!(
+    Day06,
+    2015,
+    6,
+    "Probably a Fire Hazard",
+    difficulty = Easy,
+    tags = [Grid, Simulation]
+);
+
+/// The width and the height of the grid of lights.
+const SIZE: usize = 1000;
 
 impl Solution for Day06 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let instructions = parse_input(input);
-        let mut grid = vec![vec![false; 1000]; 1000];
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let instructions = parse_input(input)?;
+        let mut grid = vec![vec![false; SIZE]; SIZE];
 
         for ins in instructions {
             execute_instruction(&mut grid, ins);
         }
 
-        Ok(lights_on(&grid).to_string())
+        Ok(lights_on(&grid).into())
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let instructions = parse_input(input);
-        let mut grid = vec![vec![0; 1000]; 1000];
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let instructions = parse_input(input)?;
+        let mut grid = vec![vec![0; SIZE]; SIZE];
 
         for ins in instructions {
             execute_instruction2(&mut grid, ins);
         }
 
-        Ok(brightness(&grid).to_string())
+        Ok(brightness(&grid).into())
     }
 }
 
 type Instruction = (u8, (usize, usize), (usize, usize));
 
-fn parse_input(input: &str) -> Vec<Instruction> {
+fn parse_input(input: &str) -> Result<Vec<Instruction>, Error> {
     // (instruction, lower, upper)
     // on = 1, off = 0, toggle = 2
 
     let mut result = Vec::new();
-    let mut line_vec: Vec<&str>;
 
     for line in input.trim().lines() {
-        line_vec = line.split(' ').collect();
-
-        match line_vec[1] {
-            "on" => {
-                let mut split = line_vec[2].split(',');
-                let mut split2 = line_vec[4].split(',');
-                result.push((
-                    1,
-                    (
-                        split.next().unwrap().parse().unwrap(),
-                        split.next().unwrap().parse().unwrap(),
-                    ),
-                    (
-                        split2.next().unwrap().parse().unwrap(),
-                        split2.next().unwrap().parse().unwrap(),
-                    ),
-                ));
-            }
-            "off" => {
-                let mut split = line_vec[2].split(',');
-                let mut split2 = line_vec[4].split(',');
-                result.push((
-                    0,
-                    (
-                        split.next().unwrap().parse().unwrap(),
-                        split.next().unwrap().parse().unwrap(),
-                    ),
-                    (
-                        split2.next().unwrap().parse().unwrap(),
-                        split2.next().unwrap().parse().unwrap(),
-                    ),
-                ));
-            }
-            _ => {
-                let mut split = line_vec[1].split(',');
-                let mut split2 = line_vec[3].split(',');
-                result.push((
-                    2,
-                    (
-                        split.next().unwrap().parse().unwrap(),
-                        split.next().unwrap().parse().unwrap(),
-                    ),
-                    (
-                        split2.next().unwrap().parse().unwrap(),
-                        split2.next().unwrap().parse().unwrap(),
-                    ),
-                ));
-            }
-        }
+        let line_vec: Vec<&str> = line.split(' ').collect();
+
+        let (instruction, lower, upper) = match line_vec.as_slice() {
+            ["turn", "on", lower, "through", upper] => (1, lower, upper),
+            ["turn", "off", lower, "through", upper] => (0, lower, upper),
+            ["toggle", lower, "through", upper] => (2, lower, upper),
+            _ => return Err(Error::invalid_input(input, line, "expected an instruction")),
+        };
+        result.push((
+            instruction,
+            parse_coordinates(input, lower)?,
+            parse_coordinates(input, upper)?,
+        ));
     }
 
-    result
+    Ok(result)
+}
+
+fn parse_coordinates(input: &str, coordinates: &str) -> Result<(usize, usize), Error> {
+    let (x, y) =
+        coordinates
+            .split_once(',')
+            .or_invalid(input, coordinates, "expected coordinates")?;
+    Ok((
+        parse_limited(input, x, 0..=SIZE - 1)?,
+        parse_limited(input, y, 0..=SIZE - 1)?,
+    ))
 }
 
 fn execute_instruction(grid: &mut [Vec<bool>], ins: Instruction) {
@@ -1,32 +1,51 @@
-use crate::{Error, Solution};
+use crate::input::parse_number;
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 use std::collections::HashMap;
 
-day!(Day07, 2015, 7, "Some Assembly Required");
+day!(
+    Day07,
+    2015,
+    7,
+    "Some Assembly Required",
+    difficulty = Medium,
+    tags = [Bitwise, Graph, Parsing]
+);
 
 impl Solution for Day07 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut circuit = Circuit::new(input);
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::text(
+            "wire",
+            "The wire whose signal is the answer",
+            "a",
+        )]
+    }
+
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let wire = parameters.text("wire")?;
+        let mut circuit = Circuit::new(input)?;
         circuit.simulate();
 
-        match circuit.get_wire_value("a") {
-            Some(a) => Ok(a.to_string()),
+        match circuit.get_wire_value(wire) {
+            Some(a) => Ok(a.into()),
             None => Err(Error::NoSolution),
         }
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut circuit = Circuit::new(input);
+
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let wire = parameters.text("wire")?;
+        let mut circuit = Circuit::new(input)?;
         let mut circuit2 = circuit.clone();
 
         circuit.simulate();
-        let a = match circuit.get_wire_value("a") {
+        let a = match circuit.get_wire_value(wire) {
             Some(a) => a,
-            None => panic!("Wire 'a' not found!"),
+            None => return Err(Error::NoSolution),
         };
 
         circuit2.wires.insert("b", a);
         circuit2.simulate();
-        match circuit2.get_wire_value("a") {
-            Some(a) => Ok(a.to_string()),
+        match circuit2.get_wire_value(wire) {
+            Some(a) => Ok(a.into()),
             None => Err(Error::NoSolution),
         }
     }
@@ -38,42 +57,39 @@ enum Instruction<'a> {
     Not(&'a str, &'a str),
     And(&'a str, &'a str, &'a str),
     Or(&'a str, &'a str, &'a str),
-    LShift(&'a str, u16, &'a str),
-    RShift(&'a str, u16, &'a str),
+    LShift(&'a str, u32, &'a str),
+    RShift(&'a str, u32, &'a str),
 }
 
 impl<'a> Instruction<'a> {
-    fn new(line: &'a str) -> Self {
+    fn new(input: &str, line: &'a str) -> Result<Self, Error> {
         let line_elements: Vec<&str> = line.split(' ').collect();
 
-        if line.contains("NOT") {
-            Self::Not(line_elements[1], line_elements[3])
-        } else if line.contains("AND") {
-            Self::And(line_elements[0], line_elements[2], line_elements[4])
-        } else if line.contains("OR") {
-            Self::Or(line_elements[0], line_elements[2], line_elements[4])
-        } else if line.contains("LSHIFT") {
-            Self::LShift(
-                line_elements[0],
-                line_elements[2]
-                    .parse::<u16>()
-                    .expect("Invalid left-shift amount!"),
-                line_elements[4],
-            )
-        } else if line.contains("RSHIFT") {
-            Self::RShift(
-                line_elements[0],
-                line_elements[2]
-                    .parse::<u16>()
-                    .expect("Invalid right-shift amount!"),
-                line_elements[4],
-            )
-        } else {
-            Self::Assign(line_elements[0], line_elements[2])
+        match line_elements.as_slice() {
+            [a, "->", b] => Ok(Self::Assign(a, b)),
+            ["NOT", a, "->", b] => Ok(Self::Not(a, b)),
+            [a, "AND", b, "->", c] => Ok(Self::And(a, b, c)),
+            [a, "OR", b, "->", c] => Ok(Self::Or(a, b, c)),
+            [a, "LSHIFT", b, "->", c] => Ok(Self::LShift(a, parse_shift(input, b)?, c)),
+            [a, "RSHIFT", b, "->", c] => Ok(Self::RShift(a, parse_shift(input, b)?, c)),
+            _ => Err(Error::invalid_input(input, line, "expected an instruction")),
         }
     }
 }
 
+/// Parse the number of bits a signal is shifted by, which must be less than the 16 bits of a signal.
+fn parse_shift(input: &str, fragment: &str) -> Result<u32, Error> {
+    let shift = parse_number(input, fragment)?;
+    if shift >= u16::BITS {
+        return Err(Error::invalid_input(
+            input,
+            fragment,
+            "expected a shift of less than 16 bits",
+        ));
+    }
+    Ok(shift)
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 struct Circuit<'a> {
     wires: HashMap<&'a str, u16>,
@@ -81,21 +97,22 @@ struct Circuit<'a> {
 }
 
 impl<'a> Circuit<'a> {
-    fn new(input: &'a str) -> Self {
+    fn new(input: &'a str) -> Result<Self, Error> {
         let mut instructions = Vec::new();
         for line in input.trim().lines() {
-            instructions.push(Instruction::new(line.trim()));
+            instructions.push(Instruction::new(input, line.trim())?);
         }
 
-        Self {
+        Ok(Self {
             wires: HashMap::new(),
             instructions,
-        }
+        })
     }
 
     fn simulate(&mut self) {
-        while !self.instructions.is_empty() {
-            let mut deleted: usize = 0;
+        let mut deleted: usize = 1;
+        while !self.instructions.is_empty() && deleted != 0 {
+            deleted = 0;
             for i in 0..self.instructions.len() {
                 match self.instructions[i - deleted] {
                     Instruction::Assign(a, b) => {
@@ -1,23 +1,30 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
-day!(Day08, 2015, 8, "Matchsticks");
+day!(
+    Day08,
+    2015,
+    8,
+    "Matchsticks",
+    difficulty = Easy,
+    tags = [Parsing, Strings]
+);
 
 impl Solution for Day08 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let mut result = 0;
         for line in input.trim().lines() {
-            let (literal_len, memory_len) = string_len(line.trim());
+            let (literal_len, memory_len) = string_len(input, line.trim())?;
             result += literal_len - memory_len;
         }
-        Ok(result.to_string())
+        Ok(result.into())
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut result: usize = 0;
         for line in input.trim().lines() {
             let (literal_len, memory_len) = encode_len(line.trim());
             result += literal_len - memory_len;
         }
-        Ok(result.to_string())
+        Ok(result.into())
     }
 }
 
@@ -31,7 +38,15 @@ fn encode_len(string: &str) -> (usize, usize) {
     (literal_len, memory_len)
 }
 
-fn string_len(string: &str) -> (usize, usize) {
+fn string_len(input: &str, string: &str) -> Result<(usize, usize), Error> {
+    if string.len() < 2 || !string.starts_with('"') || !string.ends_with('"') {
+        return Err(Error::invalid_input(
+            input,
+            string,
+            "expected a quoted string",
+        ));
+    }
+
     let literal_len = string.chars().count();
     let mut memory_len = literal_len - 2;
 
@@ -42,7 +57,7 @@ fn string_len(string: &str) -> (usize, usize) {
             continue;
         }
         if c == '\\' {
-            if string.chars().nth(i + 1).unwrap() == 'x' {
+            if string.chars().nth(i + 1) == Some('x') {
                 skip = 3;
                 memory_len -= 3;
             } else {
@@ -52,5 +67,5 @@ fn string_len(string: &str) -> (usize, usize) {
         }
     }
 
-    (literal_len, memory_len)
+    Ok((literal_len, memory_len))
 }
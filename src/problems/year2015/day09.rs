@@ -1,28 +1,47 @@
 use crate::graph::{Graph, Vertex};
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 use std::collections::HashMap;
 
-day!(Day09, 2015, 9, "All in a Single Night");
+day!(
+    Day09,
+    2015,
+    9,
+    "All in a Single Night",
+    difficulty = Medium,
+    tags = [Combinatorics, Graph]
+);
 
 impl Solution for Day09 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(parse_input(input).hamiltonian_path_min().0.to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        parse_input(input)?
+            .hamiltonian_path_min()
+            .map(|(distance, _)| distance.into())
+            .ok_or(Error::NoSolution)
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        Ok(parse_input(input).hamiltonian_path_max().0.to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        parse_input(input)?
+            .hamiltonian_path_max()
+            .map(|(distance, _)| distance.into())
+            .ok_or(Error::NoSolution)
     }
 }
 
-fn parse_input(input: &str) -> Graph {
+fn parse_input(input: &str) -> Result<Graph, Error> {
     let mut cities = HashMap::new();
     let mut city_index = 0;
     let mut edges = Vec::new();
 
     for line in input.trim().lines() {
-        let line: Vec<&str> = line.split_whitespace().collect();
-        let city1 = line[0];
-        let city2 = line[2];
-        let distance = line[4].parse::<usize>().unwrap();
+        let (city1, city2, distance) = match line.split_whitespace().collect::<Vec<_>>()[..] {
+            [city1, "to", city2, "=", distance] => (city1, city2, distance),
+            _ => return Err(Error::invalid_input(input, line, "expected a distance")),
+        };
+        let distance = isize::try_from(parse_number::<usize>(input, distance)?).or_invalid(
+            input,
+            distance,
+            "distance is too large",
+        )?;
         edges.push((city1, city2, distance));
         if !cities.contains_key(city1) {
             cities.insert(city1, city_index);
@@ -43,9 +62,9 @@ fn parse_input(input: &str) -> Graph {
         graph.set_edge_undirected(
             Vertex::new(cities[edge.0]),
             Vertex::new(cities[edge.1]),
-            edge.2 as isize,
+            edge.2,
         );
     }
 
-    graph
+    Ok(graph)
 }
@@ -1,22 +1,50 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 
-day!(Day10, 2015, 10, "Elves Look, Elves Say");
+day!(
+    Day10,
+    2015,
+    10,
+    "Elves Look, Elves Say",
+    difficulty = Easy,
+    tags = [Simulation, Strings]
+);
 
 impl Solution for Day10 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut input = input.trim().to_string();
-        for _ in 0..40 {
-            execute_round(&mut input);
-        }
-        Ok(input.chars().count().to_string())
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::integer("rounds1", "The number of rounds in the first part", 40),
+            Parameter::integer("rounds2", "The number of rounds in the second part", 50),
+        ]
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut input = input.trim().to_string();
-        for _ in 0..50 {
-            execute_round(&mut input);
-        }
-        Ok(input.chars().count().to_string())
+
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        look_and_say(input, parameters.integer("rounds1")?)
+    }
+
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        look_and_say(input, parameters.integer("rounds2")?)
+    }
+}
+
+/// Play the given number of rounds and get the length of the result.
+fn look_and_say(input: &str, rounds: usize) -> Result<Answer, Error> {
+    let mut input = parse_input(input)?;
+    for _ in 0..rounds {
+        execute_round(&mut input);
+    }
+    Ok(input.chars().count().into())
+}
+
+fn parse_input(input: &str) -> Result<String, Error> {
+    let sequence = input.trim();
+    if sequence.is_empty() || !sequence.chars().all(|c| c.is_ascii_digit()) {
+        return Err(Error::invalid_input(
+            input,
+            sequence,
+            "expected a sequence of digits",
+        ));
     }
+    Ok(sequence.to_string())
 }
 
 fn execute_round(input: &mut String) {
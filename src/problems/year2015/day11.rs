@@ -1,20 +1,40 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
-day!(Day11, 2015, 11, "Corporate Policy");
+day!(
+    Day11,
+    2015,
+    11,
+    "Corporate Policy",
+    difficulty = Easy,
+    tags = [Strings]
+);
 
 impl Solution for Day11 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(next_password(input.trim()))
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        Ok(next_password(parse_input(input)?).into())
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        Ok(next_password(&next_password(input.trim())))
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        Ok(next_password(&next_password(parse_input(input)?)).into())
     }
 }
 
+fn parse_input(input: &str) -> Result<&str, Error> {
+    let password = input.trim();
+    if password.len() != 8 || !password.chars().all(|c| c.is_ascii_lowercase()) {
+        return Err(Error::invalid_input(
+            input,
+            password,
+            "expected 8 lowercase letters",
+        ));
+    }
+    Ok(password)
+}
+
 fn next_password(old_pass: &str) -> String {
     let mut word: Vec<u32> = old_pass.chars().map(char_to_u32).collect();
-    fix_word(&mut word);
-    increment_word(&mut word);
+    if !fix_word(&mut word) {
+        increment_word(&mut word);
+    }
 
     while !two_pairs(&mut word) || !increasing_seq(&mut word) {
         increment_word(&mut word);
@@ -25,16 +45,22 @@ fn next_password(old_pass: &str) -> String {
 
 const FORBIDDEN_CHARS: [char; 3] = ['i', 'o', 'l'];
 
-fn fix_word(word: &mut [u32]) {
-    let limit = char_to_u32('z') - char_to_u32('a') + 1;
-
-    for i in 0..word.len() {
-        while FORBIDDEN_CHARS.contains(&u32_to_char(word[i])) {
+/// Skip every password containing the first forbidden letter of the word,
+/// which makes the word the smallest such password.
+/// # Returns
+/// * Whether the word changed.
+fn fix_word(word: &mut [u32]) -> bool {
+    match word
+        .iter()
+        .position(|&letter| FORBIDDEN_CHARS.contains(&u32_to_char(letter)))
+    {
+        Some(i) => {
+            // the letters after the forbidden ones are allowed
             word[i] += 1;
-            if word[word.len() - 1 - i] >= limit {
-                word[word.len() - 1 - i] = 0;
-            }
+            word[i + 1..].fill(0);
+            true
         }
+        None => false,
     }
 }
 
@@ -1,42 +1,72 @@
-use crate::{Error, Solution};
-use serde_json::{Value, from_str};
+use crate::{Answer, Error, Solution};
+use serde_json::{Number, Value, from_str};
 
-day!(Day12, 2015, 12, "JSAbacusFramework.io");
+day!(
+    Day12,
+    2015,
+    12,
+    "JSAbacusFramework.io",
+    difficulty = Easy,
+    tags = [Parsing]
+);
 
 impl Solution for Day12 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let json_val = from_str(input).unwrap();
-        Ok(sum_ints(&json_val).to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let json_val = parse_input(input)?;
+        Ok(sum_ints(&json_val)?.into())
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let json_val = from_str(input).unwrap();
-        Ok(sum_ints_wo_red(&json_val).to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let json_val = parse_input(input)?;
+        Ok(sum_ints_wo_red(&json_val)?.into())
     }
 }
 
-fn sum_ints(json_val: &Value) -> i64 {
+fn parse_input(input: &str) -> Result<Value, Error> {
+    from_str(input).map_err(|err| Error::InvalidInput {
+        line: err.line(),
+        column: err.column(),
+        message: "expected a JSON document",
+    })
+}
+
+fn parse_int(n: &Number) -> Result<i64, Error> {
+    n.as_i64().ok_or(Error::InvalidInput {
+        line: 0,
+        column: 0,
+        message: "expected an integer",
+    })
+}
+
+/// Sum the numbers, failing with [Error::NoSolution] if the sum overflows.
+fn checked_sum(mut values: impl Iterator<Item = Result<i64, Error>>) -> Result<i64, Error> {
+    values.try_fold(0, |sum: i64, value| {
+        sum.checked_add(value?).ok_or(Error::NoSolution)
+    })
+}
+
+fn sum_ints(json_val: &Value) -> Result<i64, Error> {
     match json_val {
-        Value::Null => 0,
-        Value::Bool(_) => 0,
-        Value::Number(n) => n.as_i64().unwrap(),
-        Value::String(_) => 0,
-        Value::Array(arr) => arr.iter().map(sum_ints).sum(),
-        Value::Object(obj) => obj.iter().map(|x| sum_ints(x.1)).sum(),
+        Value::Null => Ok(0),
+        Value::Bool(_) => Ok(0),
+        Value::Number(n) => parse_int(n),
+        Value::String(_) => Ok(0),
+        Value::Array(arr) => checked_sum(arr.iter().map(sum_ints)),
+        Value::Object(obj) => checked_sum(obj.iter().map(|x| sum_ints(x.1))),
     }
 }
 
-fn sum_ints_wo_red(json_val: &Value) -> i64 {
+fn sum_ints_wo_red(json_val: &Value) -> Result<i64, Error> {
     match json_val {
-        Value::Null => 0,
-        Value::Bool(_) => 0,
-        Value::Number(n) => n.as_i64().unwrap(),
-        Value::String(_) => 0,
-        Value::Array(arr) => arr.iter().map(sum_ints_wo_red).sum(),
+        Value::Null => Ok(0),
+        Value::Bool(_) => Ok(0),
+        Value::Number(n) => parse_int(n),
+        Value::String(_) => Ok(0),
+        Value::Array(arr) => checked_sum(arr.iter().map(sum_ints_wo_red)),
         Value::Object(obj) => {
             if obj.iter().any(|x| x.1 == "red") {
-                0
+                Ok(0)
             } else {
-                obj.iter().map(|x| sum_ints_wo_red(x.1)).sum()
+                checked_sum(obj.iter().map(|x| sum_ints_wo_red(x.1)))
             }
         }
     }
@@ -1,27 +1,79 @@
 use crate::graph::{Graph, Vertex};
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 use std::collections::HashMap;
 
-day!(Day13, 2015, 13, "Knights of the Dinner Table");
+day!(
+    Day13,
+    2015,
+    13,
+    "Knights of the Dinner Table",
+    difficulty = Medium,
+    tags = [Combinatorics, Graph]
+);
 
 impl Solution for Day13 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(parse_input(input).hamiltonian_cycle_max().0.to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        parse_input(input)?
+            .hamiltonian_cycle_max()
+            .map(|(happiness, _)| happiness.into())
+            .ok_or(Error::NoSolution)
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        Ok(parse_input(input).hamiltonian_path_max().0.to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        parse_input(input)?
+            .hamiltonian_path_max()
+            .map(|(happiness, _)| happiness.into())
+            .ok_or(Error::NoSolution)
     }
 }
 
-fn parse_input(input: &str) -> Graph {
+fn parse_input(input: &str) -> Result<Graph, Error> {
     let mut names = Vec::new();
 
     let mut weights_vec = Vec::new();
     for line in input.trim().lines() {
         let words = line.split_whitespace().collect::<Vec<_>>();
-        let name1 = words[0];
-        let name2 = words[10].trim_end_matches('.');
-        let value = words[3].parse::<isize>().unwrap() * if words[2] == "gain" { 1 } else { -1 };
+        let (name1, sign, value, name2) = match words[..] {
+            [
+                name1,
+                "would",
+                "gain",
+                value,
+                "happiness",
+                "units",
+                "by",
+                "sitting",
+                "next",
+                "to",
+                name2,
+            ] => (name1, 1, value, name2),
+            [
+                name1,
+                "would",
+                "lose",
+                value,
+                "happiness",
+                "units",
+                "by",
+                "sitting",
+                "next",
+                "to",
+                name2,
+            ] => (name1, -1, value, name2),
+            _ => {
+                return Err(Error::invalid_input(
+                    input,
+                    line,
+                    "expected a happiness change",
+                ));
+            }
+        };
+        let name2 = name2.trim_end_matches('.');
+        let value = isize::try_from(parse_number::<usize>(input, value)?).or_invalid(
+            input,
+            value,
+            "happiness is too large",
+        )? * sign;
 
         if !names.contains(&name1) {
             names.push(name1);
@@ -35,13 +87,15 @@ fn parse_input(input: &str) -> Graph {
 
     let mut weights = HashMap::new();
     for (name1, name2, value) in weights_vec {
-        if weights.contains_key(&(name1, name2)) {
-            *weights.get_mut(&(name1, name2)).unwrap() += value;
+        let weight = if weights.contains_key(&(name1, name2)) {
+            weights.get_mut(&(name1, name2)).unwrap()
         } else if weights.contains_key(&(name2, name1)) {
-            *weights.get_mut(&(name2, name1)).unwrap() += value;
+            weights.get_mut(&(name2, name1)).unwrap()
         } else {
             weights.insert((name1, name2), value);
-        }
+            continue;
+        };
+        *weight = weight.checked_add(value).ok_or(Error::NoSolution)?;
     }
 
     let mut graph = Graph::with_capacity(names.len());
@@ -54,5 +108,5 @@ fn parse_input(input: &str) -> Graph {
         graph.set_edge_undirected(Vertex::new(index1), Vertex::new(index2), val);
     }
 
-    graph
+    Ok(graph)
 }
@@ -1,24 +1,47 @@
-use crate::{Error, Solution};
+use crate::input::parse_number;
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 
-day!(Day14, 2015, 14, "Reindeer Olympics");
+day!(
+    Day14,
+    2015,
+    14,
+    "Reindeer Olympics",
+    difficulty = Easy,
+    tags = [Simulation]
+);
 
 impl Solution for Day14 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(parse_input(input)
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::integer(
+            "seconds",
+            "The duration of the race in seconds",
+            2503,
+        )]
+    }
+
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let seconds = parameters.integer("seconds")?;
+        parse_input(input)?
             .iter()
-            .map(|r| r.distance(2503))
-            .max()
-            .unwrap()
-            .to_string())
+            .map(|r| r.distance(seconds))
+            .collect::<Option<Vec<_>>>()
+            .and_then(|distances| distances.into_iter().max())
+            .map(Answer::from)
+            .ok_or(Error::NoSolution)
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let reindeers = parse_input(input);
+
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let seconds = parameters.integer("seconds")?;
+        let reindeers = parse_input(input)?;
+        if reindeers.is_empty() {
+            return Err(Error::NoSolution);
+        }
         let mut scores: Vec<usize> = vec![0; reindeers.len()];
         let mut temp_distances: Vec<usize> = vec![0; reindeers.len()];
 
-        for second in 1..=2503 {
+        for second in 1..=seconds {
             for (i, reindeer) in reindeers.iter().enumerate() {
-                temp_distances[i] = reindeer.distance(second);
+                temp_distances[i] = reindeer.distance(second).ok_or(Error::NoSolution)?;
             }
 
             let max_distance = temp_distances.iter().max().unwrap();
@@ -29,23 +52,50 @@ impl Solution for Day14 {
             }
         }
 
-        Ok(scores.iter().max().unwrap().to_string())
+        Ok(scores.iter().max().copied().unwrap().into())
     }
 }
 
-fn parse_input(input: &str) -> Vec<Reindeer> {
+fn parse_input(input: &str) -> Result<Vec<Reindeer>, Error> {
     let mut reindeers = Vec::new();
 
     for line in input.trim().lines() {
         let words: Vec<&str> = line.split_whitespace().collect();
-        let speed = words[3].parse::<usize>().unwrap();
-        let fly_time = words[6].parse::<usize>().unwrap();
-        let rest_time = words[13].parse::<usize>().unwrap();
+        let (speed, fly_time, rest_time) = match words[..] {
+            [
+                _,
+                "can",
+                "fly",
+                speed,
+                "km/s",
+                "for",
+                fly_time,
+                "seconds,",
+                "but",
+                "then",
+                "must",
+                "rest",
+                "for",
+                rest_time,
+                "seconds.",
+            ] => (speed, fly_time, rest_time),
+            _ => return Err(Error::invalid_input(input, line, "expected a reindeer")),
+        };
+        let speed = parse_number(input, speed)?;
+        let fly_time = parse_number::<usize>(input, fly_time)?;
+        let rest_time = parse_number::<usize>(input, rest_time)?;
+        if fly_time == 0 && rest_time == 0 {
+            return Err(Error::invalid_input(
+                input,
+                line,
+                "expected a non-zero duration",
+            ));
+        }
 
         reindeers.push(Reindeer::new(speed, fly_time, rest_time));
     }
 
-    reindeers
+    Ok(reindeers)
 }
 
 struct Reindeer {
@@ -63,8 +113,9 @@ impl Reindeer {
         }
     }
 
-    fn distance(&self, time: usize) -> usize {
-        let cycle_time = self.fly_time + self.rest_time;
+    /// Get the distance flown in the given time, or [None] if it overflows.
+    fn distance(&self, time: usize) -> Option<usize> {
+        let cycle_time = self.fly_time.checked_add(self.rest_time)?;
         let full_cycles = time / cycle_time;
         let leftover_time = time % cycle_time;
         let leftover_fly_time = if leftover_time > self.fly_time {
@@ -73,6 +124,7 @@ impl Reindeer {
             leftover_time
         };
 
-        (full_cycles * self.fly_time + leftover_fly_time) * self.speed
+        // the time spent flying is at most the given time
+        (full_cycles * self.fly_time + leftover_fly_time).checked_mul(self.speed)
     }
 }
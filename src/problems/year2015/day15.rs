@@ -1,115 +1,128 @@
-use crate::{Error, Solution};
-
-day!(Day15, 2015, 15, "Science for Hungry People");
+use crate::cancellation;
+use crate::input::{end, parse_number};
+use crate::{Answer, Error, Solution};
+
+day!(
+    Day15,
+    2015,
+    15,
+    "Science for Hungry People",
+    difficulty = Medium,
+    tags = [Combinatorics]
+);
+
+/// The number of teaspoons of ingredients in a cookie.
+const TEASPOONS: isize = 100;
+/// The number of calories of a cookie in the second part.
+const CALORIES: isize = 500;
 
 impl Solution for Day15 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let ingredients = parse_input(input);
-
-        let mut max = isize::MIN;
-        for i in 0..101 {
-            for j in 0..(101 - i) {
-                for k in 0..(101 - i - j) {
-                    let l = 100 - i - j - k;
-                    let capacity = i * ingredients[0][0]
-                        + j * ingredients[1][0]
-                        + k * ingredients[2][0]
-                        + l * ingredients[3][0];
-                    let durability = i * ingredients[0][1]
-                        + j * ingredients[1][1]
-                        + k * ingredients[2][1]
-                        + l * ingredients[3][1];
-                    let flavor = i * ingredients[0][2]
-                        + j * ingredients[1][2]
-                        + k * ingredients[2][2]
-                        + l * ingredients[3][2];
-                    let texture = i * ingredients[0][3]
-                        + j * ingredients[1][3]
-                        + k * ingredients[2][3]
-                        + l * ingredients[3][3];
-
-                    let value = if capacity < 0 || durability < 0 || flavor < 0 || texture < 0 {
-                        0
-                    } else {
-                        capacity * durability * flavor * texture
-                    };
-
-                    if value > max {
-                        max = value;
-                    }
-                }
-            }
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let ingredients = parse_input(input)?;
+        Ok(best_score(&ingredients, [0; 5], TEASPOONS, None)?.into())
+    }
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let ingredients = parse_input(input)?;
+        match best_score(&ingredients, [0; 5], TEASPOONS, Some(CALORIES))? {
+            isize::MIN => Err(Error::NoSolution),
+            score => Ok(score.into()),
         }
-
-        Ok(max.to_string())
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let ingredients = parse_input(input);
-
-        let mut max = isize::MIN;
-        for i in 0..101 {
-            for j in 0..(101 - i) {
-                for k in 0..(101 - i - j) {
-                    let l = 100 - i - j - k;
-
-                    let calories = i * ingredients[0][4]
-                        + j * ingredients[1][4]
-                        + k * ingredients[2][4]
-                        + l * ingredients[3][4];
-                    if calories != 500 {
-                        continue;
-                    }
-
-                    let capacity = i * ingredients[0][0]
-                        + j * ingredients[1][0]
-                        + k * ingredients[2][0]
-                        + l * ingredients[3][0];
-                    let durability = i * ingredients[0][1]
-                        + j * ingredients[1][1]
-                        + k * ingredients[2][1]
-                        + l * ingredients[3][1];
-                    let flavor = i * ingredients[0][2]
-                        + j * ingredients[1][2]
-                        + k * ingredients[2][2]
-                        + l * ingredients[3][2];
-                    let texture = i * ingredients[0][3]
-                        + j * ingredients[1][3]
-                        + k * ingredients[2][3]
-                        + l * ingredients[3][3];
-
-                    let value = if capacity < 0 || durability < 0 || flavor < 0 || texture < 0 {
-                        0
-                    } else {
-                        capacity * durability * flavor * texture
-                    };
+}
 
-                    if value > max {
-                        max = value;
-                    }
-                }
+/// Get the best score of the cookies with the properties of the ingredients added so far
+/// and the remaining teaspoons of the remaining ingredients.
+/// # Returns
+/// * The best score, [isize::MIN] if no cookie has the given number of calories, or the [Error].
+/// # Errors
+/// * [Error::NoSolution] - A property or a score overflows.
+/// * [Error::Cancelled] or [Error::Timeout] - The search does not finish in time.
+fn best_score(
+    ingredients: &[[isize; 5]],
+    properties: [isize; 5],
+    teaspoons: isize,
+    calories: Option<isize>,
+) -> Result<isize, Error> {
+    match ingredients {
+        [] => Ok(isize::MIN),
+        [last] => {
+            let properties = add_teaspoons(properties, teaspoons, last)?;
+            if calories.is_some_and(|calories| properties[4] != calories) {
+                return Ok(isize::MIN);
+            }
+            properties[..4]
+                .iter()
+                .try_fold(1isize, |score, &property| {
+                    score.checked_mul(property.max(0))
+                })
+                .ok_or(Error::NoSolution)
+        }
+        [first, rest @ ..] => {
+            cancellation::check()?;
+            let mut best = isize::MIN;
+            for amount in 0..=teaspoons {
+                let properties = add_teaspoons(properties, amount, first)?;
+                best = best.max(best_score(rest, properties, teaspoons - amount, calories)?);
             }
+            Ok(best)
         }
+    }
+}
 
-        Ok(max.to_string())
+/// Add the properties of the teaspoons of an ingredient to the properties of a cookie.
+/// # Returns
+/// * The properties of the cookie or [Error::NoSolution] if a property overflows.
+fn add_teaspoons(
+    properties: [isize; 5],
+    teaspoons: isize,
+    ingredient: &[isize; 5],
+) -> Result<[isize; 5], Error> {
+    let mut added = [0; 5];
+    for (i, property) in added.iter_mut().enumerate() {
+        *property = ingredient[i]
+            .checked_mul(teaspoons)
+            .and_then(|value| value.checked_add(properties[i]))
+            .ok_or(Error::NoSolution)?;
     }
+    Ok(added)
 }
 
-fn parse_input(input: &str) -> Vec<[isize; 5]> {
+fn parse_input(input: &str) -> Result<Vec<[isize; 5]>, Error> {
     let mut ingredients = Vec::new();
 
     for line in input.trim().lines() {
         let words: Vec<&str> = line.split_whitespace().collect();
+        let properties = match words[..] {
+            [
+                _,
+                "capacity",
+                capacity,
+                "durability",
+                durability,
+                "flavor",
+                flavor,
+                "texture",
+                texture,
+                "calories",
+                calories,
+            ] => [capacity, durability, flavor, texture, calories],
+            _ => return Err(Error::invalid_input(input, line, "expected an ingredient")),
+        };
         let mut line_array = [0; 5];
-        line_array[0] = words[2].trim_end_matches(',').parse::<isize>().unwrap();
-        line_array[1] = words[4].trim_end_matches(',').parse::<isize>().unwrap();
-        line_array[2] = words[6].trim_end_matches(',').parse::<isize>().unwrap();
-        line_array[3] = words[8].trim_end_matches(',').parse::<isize>().unwrap();
-        line_array[4] = words[10].trim_end_matches(',').parse::<isize>().unwrap();
+        for (value, property) in line_array.iter_mut().zip(properties) {
+            *value = parse_number(input, property.trim_end_matches(','))?;
+        }
 
         ingredients.push(line_array);
     }
 
-    assert_eq!(ingredients.len(), 4);
+    if ingredients.is_empty() {
+        return Err(Error::invalid_input(
+            input,
+            end(input),
+            "expected ingredients",
+        ));
+    }
 
-    ingredients
+    Ok(ingredients)
 }
@@ -1,11 +1,19 @@
-use crate::{Error, Solution};
+use crate::input::{end, parse_number};
+use crate::{Answer, Error, Solution};
 use std::collections::HashMap;
 
-day!(Day16, 2015, 16, "Aunt Sue");
+day!(
+    Day16,
+    2015,
+    16,
+    "Aunt Sue",
+    difficulty = Easy,
+    tags = [Parsing]
+);
 
 impl Solution for Day16 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let sues = parse_input(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let sues = parse_input(input)?;
         let wanted_sue = wanted_sue();
 
         for (i, sue) in sues.iter().enumerate() {
@@ -17,14 +25,14 @@ impl Solution for Day16 {
             }
 
             if good_match {
-                return Ok((i + 1).to_string());
+                return Ok((i + 1).into());
             }
         }
 
         Err(Error::NoSolution)
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let sues = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let sues = parse_input(input)?;
         let wanted_sue = wanted_sue();
 
         for (i, sue) in sues.iter().enumerate() {
@@ -52,7 +60,7 @@ impl Solution for Day16 {
             }
 
             if good_match {
-                return Ok((i + 1).to_string());
+                return Ok((i + 1).into());
             }
         }
 
@@ -77,39 +85,36 @@ fn wanted_sue() -> HashMap<&'static str, usize> {
     wanted_sue
 }
 
-fn parse_input(input: &str) -> Vec<HashMap<&str, usize>> {
+fn parse_input(input: &str) -> Result<Vec<HashMap<&str, usize>>, Error> {
     let mut sues = Vec::with_capacity(500);
 
     for line in input.trim().lines() {
         let mut new_sue = HashMap::new();
         let parsed_line: Vec<&str> = line.split_whitespace().collect();
 
-        new_sue.insert(
-            parsed_line[2].trim_end_matches(':'),
-            parsed_line[3]
-                .trim_end_matches(',')
-                .parse::<usize>()
-                .unwrap(),
-        );
-        new_sue.insert(
-            parsed_line[4].trim_end_matches(':'),
-            parsed_line[5]
-                .trim_end_matches(',')
-                .parse::<usize>()
-                .unwrap(),
-        );
-        new_sue.insert(
-            parsed_line[6].trim_end_matches(':'),
-            parsed_line[7]
-                .trim_end_matches(',')
-                .parse::<usize>()
-                .unwrap(),
-        );
+        let ["Sue", _, compounds @ ..] = &parsed_line[..] else {
+            return Err(Error::invalid_input(input, line, "expected an aunt"));
+        };
+        if compounds.len() != 6 {
+            return Err(Error::invalid_input(input, line, "expected 3 compounds"));
+        }
+        for compound in compounds.chunks_exact(2) {
+            new_sue.insert(
+                compound[0].trim_end_matches(':'),
+                parse_number(input, compound[1].trim_end_matches(','))?,
+            );
+        }
 
         sues.push(new_sue);
     }
 
-    assert_eq!(sues.len(), 500, "There should be 500 sues");
+    if sues.len() != 500 {
+        return Err(Error::invalid_input(
+            input,
+            end(input),
+            "expected 500 aunts",
+        ));
+    }
 
-    sues
+    Ok(sues)
 }
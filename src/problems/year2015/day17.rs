@@ -1,32 +1,64 @@
-use crate::{Error, Solution};
+use crate::input::parse_number;
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 use std::cmp::{Ordering, Reverse, min};
 use std::collections::HashMap;
 
-day!(Day17, 2015, 17, "No Such Thing as Too Much");
+day!(
+    Day17,
+    2015,
+    17,
+    "No Such Thing as Too Much",
+    difficulty = Easy,
+    tags = [Combinatorics, DynamicProgramming]
+);
 
 impl Solution for Day17 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut bottles: Vec<usize> = input
-            .trim()
-            .lines()
-            .map(|num| num.parse::<usize>().unwrap())
-            .collect();
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::integer(
+            "liters",
+            "The amount of eggnog to store",
+            150,
+        )]
+    }
+
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let liters = parameters.integer("liters")?;
+        let mut bottles = parse_input(input)?;
         bottles.sort_by_key(|value| Reverse(*value));
         let mut memoization = HashMap::new();
-        Ok(count_combinations(0, 150, &bottles, &mut memoization).to_string())
+        Ok(count_combinations(0, liters, &bottles, &mut memoization).into())
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut bottles: Vec<usize> = input
-            .trim()
-            .lines()
-            .map(|num| num.parse::<usize>().unwrap())
-            .collect();
+
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let liters = parameters.integer("liters")?;
+        let mut bottles = parse_input(input)?;
         bottles.sort_by_key(|value| Reverse(*value));
         let mut memoization = HashMap::new();
-        Ok(count_least_combinations(0, 150, &bottles, &mut memoization)
-            .0
-            .to_string())
+        Ok(
+            count_least_combinations(0, liters, &bottles, &mut memoization)
+                .0
+                .into(),
+        )
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<usize>, Error> {
+    let bottles = input
+        .trim()
+        .lines()
+        .map(|num| match parse_number(input, num)? {
+            0 => Err(Error::invalid_input(
+                input,
+                num,
+                "expected a non-zero capacity",
+            )),
+            capacity => Ok(capacity),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if bottles.is_empty() {
+        return Err(Error::NoSolution);
     }
+    Ok(bottles)
 }
 
 fn count_combinations(
@@ -1,12 +1,29 @@
-use crate::{Error, Solution};
-
-day!(Day18, 2015, 18, "Like a GIF For Your Yard");
+use crate::input::end;
+use crate::{Answer, Error, Parameter, Parameters, Solution};
+
+day!(
+    Day18,
+    2015,
+    18,
+    "Like a GIF For Your Yard",
+    difficulty = Easy,
+    tags = [CellularAutomaton, Grid]
+);
 
 impl Solution for Day18 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut grid = parse_input(input);
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::integer(
+            "steps",
+            "The number of animation steps",
+            100,
+        )]
+    }
+
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let steps = parameters.integer("steps")?;
+        let mut grid = parse_input(input)?;
 
-        for _ in 0..100 {
+        for _ in 0..steps {
             simulate_step(&mut grid);
         }
 
@@ -20,16 +37,19 @@ impl Solution for Day18 {
             }
         }
 
-        Ok(lights_on.to_string())
+        Ok(lights_on.into())
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut grid = parse_input(input);
+
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let steps = parameters.integer("steps")?;
+        let mut grid = parse_input(input)?;
+        let last = grid.len() - 1;
         grid[0][0][0] = true;
-        grid[0][99][0] = true;
-        grid[99][0][0] = true;
-        grid[99][99][0] = true;
+        grid[0][last][0] = true;
+        grid[last][0][0] = true;
+        grid[last][last][0] = true;
 
-        for _ in 0..100 {
+        for _ in 0..steps {
             simulate_step_2(&mut grid);
         }
 
@@ -43,29 +63,43 @@ impl Solution for Day18 {
             }
         }
 
-        Ok(lights_on.to_string())
+        Ok(lights_on.into())
     }
 }
 
-fn parse_input(input: &str) -> Vec<Vec<[bool; 2]>> {
-    let mut grid = Vec::with_capacity(100);
+/// Parse the square grid of lights, which has 100 rows in the puzzle.
+fn parse_input(input: &str) -> Result<Vec<Vec<[bool; 2]>>, Error> {
+    let size = input.trim().lines().count();
+    let mut grid = Vec::with_capacity(size);
 
     for line in input.trim().lines() {
-        let mut row = Vec::with_capacity(100);
-        for c in line.chars() {
+        let mut row = Vec::with_capacity(size);
+        for (i, c) in line.char_indices() {
             match c {
                 '#' => row.push([true, false]),
                 '.' => row.push([false, false]),
-                _ => panic!("Invalid character in input!"),
+                _ => return Err(Error::invalid_input(input, &line[i..], "expected a light")),
             }
         }
+        if row.len() != size {
+            return Err(Error::invalid_input(
+                input,
+                line,
+                "expected as many lights in every row as there are rows",
+            ));
+        }
         grid.push(row);
     }
 
-    assert_eq!(grid.len(), 100, "The grid should be 100x100");
-    assert_eq!(grid[0].len(), 100, "The grid should be 100x100");
+    if grid.is_empty() {
+        return Err(Error::invalid_input(
+            input,
+            end(input),
+            "expected rows of lights",
+        ));
+    }
 
-    grid
+    Ok(grid)
 }
 
 fn simulate_step(grid: &mut Vec<Vec<[bool; 2]>>) {
@@ -94,10 +128,11 @@ fn simulate_step_2(grid: &mut Vec<Vec<[bool; 2]>>) {
         }
     }
 
+    let last = grid.len() - 1;
     grid[0][0][1] = true;
-    grid[0][99][1] = true;
-    grid[99][0][1] = true;
-    grid[99][99][1] = true;
+    grid[0][last][1] = true;
+    grid[last][0][1] = true;
+    grid[last][last][1] = true;
 
     copy_new_to_old(grid);
 }
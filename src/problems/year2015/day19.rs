@@ -1,12 +1,20 @@
-use crate::{Error, Solution};
+use crate::input::OrInvalid;
+use crate::{Answer, Error, Solution};
 use regex::Regex;
 use std::collections::HashSet;
 
-day!(Day19, 2015, 19, "Medicine for Rudolph");
+day!(
+    Day19,
+    2015,
+    19,
+    "Medicine for Rudolph",
+    difficulty = Hard,
+    tags = [Search, Strings]
+);
 
 impl Solution for Day19 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let (substitutions, molecule_str) = parse_input(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let (substitutions, molecule_str) = parse_input(input)?;
         let molecule_str = String::from(molecule_str);
 
         let mut new_molecules = HashSet::new();
@@ -20,11 +28,11 @@ impl Solution for Day19 {
             }
         }
 
-        Ok(new_molecules.len().to_string())
+        Ok(new_molecules.len().into())
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         // wanted input molecule
-        let mut molecule_str = String::from(parse_input(input).1);
+        let mut molecule_str = String::from(parse_input(input)?.1);
 
         // solution using insights provided by u/askalski, confirmed by u/topaz2078 (creator of the AoC)
         // possible substitutions are
@@ -76,37 +84,45 @@ impl Solution for Day19 {
         // So the final equation for the number of steps is:
         // the length of the molecule - the number of ( or ) - 2 * the number of , - 1
 
-        Ok((molecule_str.chars().count()
-            - molecule_str.matches(['(', ')']).count()
-            - 2 * molecule_str.matches(',').count()
-            - 1)
-        .to_string())
+        molecule_str
+            .chars()
+            .count()
+            .checked_sub(molecule_str.matches(['(', ')']).count())
+            .and_then(|steps| steps.checked_sub(2 * molecule_str.matches(',').count()))
+            .and_then(|steps| steps.checked_sub(1))
+            .map(Answer::from)
+            .ok_or(Error::NoSolution)
     }
 }
 
-fn parse_input(input: &str) -> (Vec<(&str, &str)>, &str) {
+type Substitution<'a> = (&'a str, &'a str);
+
+fn parse_input(input: &str) -> Result<(Vec<Substitution<'_>>, &str), Error> {
     let mut substitutions = Vec::new();
     let mut full_string = "";
 
     for line in input.trim().lines() {
         if !line.is_empty() {
             if line.contains("=>") {
-                let mut split = line.split(" => ");
-                substitutions.push((split.next().unwrap(), split.next().unwrap()));
+                substitutions.push(line.split_once(" => ").or_invalid(
+                    input,
+                    line,
+                    "expected a replacement",
+                )?);
             } else {
                 full_string = line;
             }
         }
     }
 
-    (substitutions, full_string)
+    Ok((substitutions, full_string))
 }
 
 fn sub_string_positions(string: &str, sub_string: &str) -> Vec<usize> {
     let mut positions = Vec::new();
     let mut start = 0;
 
-    while let Some(pos) = string.get(start..).unwrap().find(sub_string) {
+    while let Some(pos) = string.get(start..).and_then(|rest| rest.find(sub_string)) {
         start += pos;
         positions.push(start);
         start += 1;
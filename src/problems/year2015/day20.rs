@@ -1,30 +1,43 @@
-use crate::{Error, Solution};
+use crate::input::parse_limited;
+use crate::{Answer, Error, Solution};
 
-day!(Day20, 2015, 20, "Infinite Elves and Infinite Houses");
+day!(
+    Day20,
+    2015,
+    20,
+    "Infinite Elves and Infinite Houses",
+    difficulty = Medium,
+    tags = [NumberTheory]
+);
+
+/// The largest accepted number of presents.
+/// The sieves have a house for every ten presents, so this keeps them under a gigabyte.
+const MAX_PRESENTS: usize = 100_000_000;
 
 impl Solution for Day20 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let input = input.trim().parse::<usize>().unwrap();
-        let mut sieve = vec![0; input / 10];
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let input = parse_limited(input, input.trim(), 0..=MAX_PRESENTS)?;
+        // house n gets at least 10 * n presents from its own elf
+        let mut sieve = vec![0; input.div_ceil(10)];
 
         for i in 0..sieve.len() {
-            let add_amount = i * 10;
+            let add_amount = (i + 1) * 10;
             for j in (i..sieve.len()).step_by(i + 1) {
                 sieve[j] += add_amount;
             }
             if sieve[i] >= input {
-                return Ok((i + 1).to_string());
+                return Ok((i + 1).into());
             }
         }
 
         Err(Error::NoSolution)
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let input = input.trim().parse::<usize>().unwrap();
-        let mut sieve = vec![0; (input as f64 / 11.0).ceil() as usize];
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let input = parse_limited(input, input.trim(), 0..=MAX_PRESENTS)?;
+        let mut sieve = vec![0; input.div_ceil(11)];
 
         for i in 0..sieve.len() {
-            let add_amount = i * 11;
+            let add_amount = (i + 1) * 11;
             for (loop_counter, j) in (i..sieve.len()).step_by(i + 1).enumerate() {
                 if loop_counter >= 50 {
                     break;
@@ -32,7 +45,7 @@ impl Solution for Day20 {
                 sieve[j] += add_amount;
             }
             if sieve[i] >= input {
-                return Ok((i + 1).to_string());
+                return Ok((i + 1).into());
             }
         }
 
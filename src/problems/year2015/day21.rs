@@ -1,11 +1,19 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, end, parse_number};
+use crate::{Answer, Error, Solution};
 use itertools::Itertools;
 
-day!(Day21, 2015, 21, "RPG Simulator 20XX");
+day!(
+    Day21,
+    2015,
+    21,
+    "RPG Simulator 20XX",
+    difficulty = Easy,
+    tags = [Combinatorics, Simulation]
+);
 
 impl Solution for Day21 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let boss_stats = parse_input(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let boss_stats = parse_input(input)?;
         let mut my_stats = [100, 0, 0];
         let mut least_gold = usize::MAX;
 
@@ -38,10 +46,10 @@ impl Solution for Day21 {
             }
         }
 
-        Ok(least_gold.to_string())
+        Ok(least_gold.into())
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let boss_stats = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let boss_stats = parse_input(input)?;
         let mut my_stats = [100, 0, 0];
         let mut most_gold = 0;
 
@@ -74,50 +82,34 @@ impl Solution for Day21 {
             }
         }
 
-        Ok(most_gold.to_string())
+        Ok(most_gold.into())
     }
 }
 
-fn parse_input(input: &str) -> [usize; 3] {
+fn parse_input(input: &str) -> Result<[usize; 3], Error> {
     let mut boss = [0; 3];
-
-    for (i, line) in input.trim().lines().enumerate() {
-        boss[i] = line
-            .split_whitespace()
-            .next_back()
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
+    let mut lines = input.trim().lines();
+
+    for (stat, label) in boss.iter_mut().zip(["Hit Points", "Damage", "Armor"]) {
+        let line = lines
+            .next()
+            .or_invalid(input, end(input), "expected the boss stats")?;
+        let value = line
+            .strip_prefix(label)
+            .and_then(|value| value.strip_prefix(": "))
+            .or_invalid(input, line, "expected a boss stat")?;
+        *stat = parse_number(input, value)?;
     }
 
-    boss
+    Ok(boss)
 }
 
-fn victory(mut me: [usize; 3], mut boss: [usize; 3]) -> bool {
-    let mut my_turn = true;
-
-    loop {
-        if my_turn {
-            let mut taken_dmg = me[1].saturating_sub(boss[2]);
-            if taken_dmg == 0 {
-                taken_dmg = 1;
-            };
-            boss[0] = boss[0].saturating_sub(taken_dmg);
-            if boss[0] == 0 {
-                return true;
-            }
-        } else {
-            let mut taken_dmg = boss[1].saturating_sub(me[2]);
-            if taken_dmg == 0 {
-                taken_dmg = 1;
-            };
-            me[0] = me[0].saturating_sub(taken_dmg);
-            if me[0] == 0 {
-                return false;
-            }
-        }
-        my_turn = !my_turn;
-    }
+fn victory(me: [usize; 3], boss: [usize; 3]) -> bool {
+    // every attack deals at least 1 damage, and the player attacks first,
+    // so the player wins if they need at most as many attacks as the boss
+    let my_attacks = boss[0].div_ceil(me[1].saturating_sub(boss[2]).max(1));
+    let boss_attacks = me[0].div_ceil(boss[1].saturating_sub(me[2]).max(1));
+    my_attacks <= boss_attacks
 }
 
 const WEAPONS: [(&str, usize, usize, usize); 5] = [
@@ -1,461 +1,195 @@
-use crate::{Error, Solution};
-
-day!(Day22, 2015, 22, "Wizard Simulator 20XX");
+use crate::cancellation;
+use crate::input::{OrInvalid, end, parse_number};
+use crate::{Answer, Error, Parameter, Parameters, Solution};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+
+day!(
+    Day22,
+    2015,
+    22,
+    "Wizard Simulator 20XX",
+    difficulty = Hard,
+    tags = [Search, Simulation]
+);
 
 impl Solution for Day22 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let boss_stats = parse_input(input); // health, damage
-        let my_stats = [50, 500]; // health, mana
-
-        let (victory, mana) = least_mana_to_win(boss_stats, my_stats, true, [None; 3]);
-
-        if !victory {
-            Err(Error::NoSolution)
-        } else {
-            Ok(mana.to_string())
-        }
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::integer(
+                "health",
+                "The health of the player at the start of the fight",
+                50,
+            ),
+            Parameter::integer(
+                "mana",
+                "The mana of the player at the start of the fight",
+                500,
+            ),
+        ]
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let boss_stats = parse_input(input); // health, damage
-        let my_stats = [50, 500]; // health, mana
-
-        let (victory, mana) = least_mana_to_win_hard(boss_stats, my_stats, true, [None; 3]);
 
-        if !victory {
-            Err(Error::NoSolution)
-        } else {
-            Ok(mana.to_string())
-        }
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let [boss_health, boss_damage] = parse_input(input)?;
+        let start = Fight::new(boss_health, parameters)?;
+        least_mana_to_win(start, boss_damage, false).map(Answer::from)
+    }
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let [boss_health, boss_damage] = parse_input(input)?;
+        let start = Fight::new(boss_health, parameters)?;
+        least_mana_to_win(start, boss_damage, true).map(Answer::from)
     }
 }
 
-fn parse_input(input: &str) -> [usize; 2] {
+fn parse_input(input: &str) -> Result<[usize; 2], Error> {
     let mut boss = [0; 2]; // health, damage
-
-    for (i, line) in input.trim().lines().enumerate() {
-        boss[i] = line
-            .split_whitespace()
-            .next_back()
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
+    let mut lines = input.trim().lines();
+
+    for (stat, label) in boss.iter_mut().zip(["Hit Points", "Damage"]) {
+        let line = lines
+            .next()
+            .or_invalid(input, end(input), "expected the boss stats")?;
+        let value = line
+            .strip_prefix(label)
+            .and_then(|value| value.strip_prefix(": "))
+            .or_invalid(input, line, "expected a boss stat")?;
+        *stat = parse_number(input, value)?;
     }
 
-    boss
+    Ok(boss)
 }
 
-const SPELLS: [(&str, usize); 5] = [
-    ("Magic Missile", 53), // instant 4 damage
-    ("Drain", 73),         // instant 2 damage, 2 health
-    ("Shield", 113),       // 6 turns, armor +7
-    ("Poison", 173),       // 6 turns, 3 damage
-    ("Recharge", 229),     // 5 turns, 101 mana
-];
-
-fn least_mana_to_win(
-    mut boss_stats: [usize; 2],
-    mut my_stats: [usize; 2],
-    turn: bool,
-    mut active_spells: [Option<((&str, usize), usize)>; 3],
-) -> (bool, usize) {
-    // (true if I win, mana spent)
-
-    // check if shield spell is active
-    let my_armor: usize = if let Some((spell, turns)) = active_spells[0] {
-        assert_eq!(spell.0, "Shield");
-        if turns == 0 {
-            active_spells[0] = None;
-        } else {
-            active_spells[0] = Some((spell, turns - 1));
-        }
-        7
-    } else {
-        0
-    };
-
-    // check if poison spell is active
-    if let Some((spell, turns)) = active_spells[1] {
-        assert_eq!(spell.0, "Poison");
-        boss_stats[0] = boss_stats[0].saturating_sub(3);
-        if boss_stats[0] == 0 {
-            return (true, 0);
-        }
-        if turns == 0 {
-            active_spells[1] = None;
-        } else {
-            active_spells[1] = Some((spell, turns - 1));
-        }
-    }
-
-    // check if the recharge spell is active
-    if let Some((spell, turns)) = active_spells[2] {
-        assert_eq!(spell.0, "Recharge");
-        my_stats[1] += 101;
-        if turns == 0 {
-            active_spells[2] = None;
-        } else {
-            active_spells[2] = Some((spell, turns - 1));
-        }
-    }
-
-    if turn {
-        // my turn
-        // check if I can cast any spell
-        if my_stats[1] < SPELLS[0].1 {
-            return (false, 0);
-        }
-
-        let mut least_mana = usize::MAX;
-        let mut victory = false;
-
-        // cast Magic Missile, there is already enough mana (checked)
-        let mana = SPELLS[0].1;
-        let new_my_stats = [my_stats[0], my_stats[1] - mana];
-        let new_boss_stats = [boss_stats[0].saturating_sub(4), boss_stats[1]];
-        if new_boss_stats[0] == 0 {
-            return (true, mana); // return immediately because 53 is the least mana possible to spend
-        } else {
-            let (new_victory, mut new_mana) =
-                least_mana_to_win(new_boss_stats, new_my_stats, !turn, active_spells);
-            new_mana += mana;
-            if victory {
-                // it was won already
-                if new_victory && new_mana < least_mana {
-                    // if I also won check if it was with less mana
-                    least_mana = new_mana;
-                }
-            } else if new_victory {
-                // it was not won yet, but I won now
-                victory = true;
-                least_mana = new_mana;
-            } else if new_mana < least_mana {
-                // it was not won yet, and I did not win now, but I spent less mana
-                least_mana = new_mana;
-            }
-        }
-
-        // cast Drain
-        let mana = SPELLS[1].1;
-        if my_stats[1] >= mana {
-            let new_my_stats = [my_stats[0] + 2, my_stats[1] - mana];
-            let new_boss_stats = [boss_stats[0].saturating_sub(2), boss_stats[1]];
-            if new_boss_stats[0] == 0 {
-                victory = true;
-                if mana < least_mana {
-                    least_mana = mana;
-                }
-            } else {
-                let (new_victory, mut new_mana) =
-                    least_mana_to_win(new_boss_stats, new_my_stats, !turn, active_spells);
-                new_mana += mana;
-                if victory {
-                    // it was won already
-                    if new_victory && new_mana < least_mana {
-                        // if I also won check if it was with less mana
-                        least_mana = new_mana;
-                    }
-                } else if new_victory {
-                    // it was not won yet, but I won now
-                    victory = true;
-                    least_mana = new_mana;
-                } else if new_mana < least_mana {
-                    // it was not won yet, and I did not win now, but I spent less mana
-                    least_mana = new_mana;
-                }
-            }
-        }
-
-        // cast Shield
-        let mana = SPELLS[2].1;
-        if active_spells[0].is_none() && my_stats[1] >= mana {
-            let new_my_stats = [my_stats[0], my_stats[1] - mana];
-            let mut new_active_spells = active_spells;
-            new_active_spells[0] = Some((SPELLS[2], 6 - 1));
-            let (new_victory, mut new_mana) =
-                least_mana_to_win(boss_stats, new_my_stats, !turn, new_active_spells);
-            new_mana += mana;
-            if victory {
-                // it was won already
-                if new_victory && new_mana < least_mana {
-                    // if I also won check if it was with less mana
-                    least_mana = new_mana;
-                }
-            } else if new_victory {
-                // it was not won yet, but I won now
-                victory = true;
-                least_mana = new_mana;
-            } else if new_mana < least_mana {
-                // it was not won yet, and I did not win now, but I spent less mana
-                least_mana = new_mana;
-            }
-        }
-
-        // cast Poison
-        let mana = SPELLS[3].1;
-        if active_spells[1].is_none() && my_stats[1] >= mana {
-            let new_my_stats = [my_stats[0], my_stats[1] - mana];
-            let mut new_active_spells = active_spells;
-            new_active_spells[1] = Some((SPELLS[3], 6 - 1));
-            let (new_victory, mut new_mana) =
-                least_mana_to_win(boss_stats, new_my_stats, !turn, new_active_spells);
-            new_mana += mana;
-            if victory {
-                // it was won already
-                if new_victory && new_mana < least_mana {
-                    // if I also won check if it was with less mana
-                    least_mana = new_mana;
-                }
-            } else if new_victory {
-                // it was not won yet, but I won now
-                victory = true;
-                least_mana = new_mana;
-            } else if new_mana < least_mana {
-                // it was not won yet, and I did not win now, but I spent less mana
-                least_mana = new_mana;
-            }
-        }
-
-        // cast Recharge
-        let mana = SPELLS[4].1;
-        if active_spells[2].is_none() && my_stats[1] >= mana {
-            let new_my_stats = [my_stats[0], my_stats[1] - mana];
-            let mut new_active_spells = active_spells;
-            new_active_spells[2] = Some((SPELLS[4], 5 - 1));
-            let (new_victory, mut new_mana) =
-                least_mana_to_win(boss_stats, new_my_stats, !turn, new_active_spells);
-            new_mana += mana;
-            if victory {
-                // it was won already
-                if new_victory && new_mana < least_mana {
-                    // if I also won check if it was with less mana
-                    least_mana = new_mana;
-                }
-            } else if new_victory {
-                // it was not won yet, but I won now
-                victory = true;
-                least_mana = new_mana;
-            } else if new_mana < least_mana {
-                // it was not won yet, and I did not win now, but I spent less mana
-                least_mana = new_mana;
-            }
-        }
-
-        (victory, least_mana)
-    } else {
-        // boss turn
-        let dmg = boss_stats[1].saturating_sub(my_armor);
-        my_stats[0] = my_stats[0].saturating_sub(if dmg == 0 { 1 } else { dmg });
-        if my_stats[0] == 0 {
-            (false, 0)
-        } else {
-            least_mana_to_win(boss_stats, my_stats, !turn, active_spells)
-        }
-    }
+/// The costs of the spells.
+const MAGIC_MISSILE: usize = 53; // instant 4 damage
+const DRAIN: usize = 73; // instant 2 damage, 2 health
+const SHIELD: usize = 113; // 6 turns, armor +7
+const POISON: usize = 173; // 6 turns, 3 damage
+const RECHARGE: usize = 229; // 5 turns, 101 mana
+
+/// The state of the fight at the start of the player's turn.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+struct Fight {
+    boss_health: usize,
+    health: usize,
+    mana: usize,
+    /// The remaining turns of the effects of Shield, Poison and Recharge.
+    effects: [usize; 3],
 }
-
-fn least_mana_to_win_hard(
-    mut boss_stats: [usize; 2],
-    mut my_stats: [usize; 2],
-    turn: bool,
-    mut active_spells: [Option<((&str, usize), usize)>; 3],
-) -> (bool, usize) {
-    // (true if I win, mana spent)
-    if turn {
-        my_stats[0] = my_stats[0].saturating_sub(1);
-        if my_stats[0] == 0 {
-            return (false, 0);
-        }
+impl Fight {
+    /// Create the fight at the start of the first turn with the health and mana of the player
+    /// in the parameters.
+    /// # Errors
+    /// * [Error::InvalidParameter] - The health is not positive or the mana is negative.
+    fn new(boss_health: usize, parameters: &Parameters) -> Result<Self, Error> {
+        let health = parameters.integer("health")?;
+        if health == 0 {
+            return Err(Error::InvalidParameter);
+        }
+        Ok(Self {
+            boss_health,
+            health,
+            mana: parameters.integer("mana")?,
+            effects: [0; 3],
+        })
     }
 
-    // check if shield spell is active
-    let my_armor: usize = if let Some((spell, turns)) = active_spells[0] {
-        assert_eq!(spell.0, "Shield");
-        if turns == 0 {
-            active_spells[0] = None;
-        } else {
-            active_spells[0] = Some((spell, turns - 1));
+    /// Apply the active effects at the start of a turn.
+    /// # Returns
+    /// * The armor of the player during the turn.
+    fn apply_effects(&mut self) -> usize {
+        let [shield, poison, recharge] = &mut self.effects;
+        let armor = if *shield > 0 { 7 } else { 0 };
+        if *poison > 0 {
+            self.boss_health = self.boss_health.saturating_sub(3);
         }
-        7
-    } else {
-        0
-    };
-
-    // check if poison spell is active
-    if let Some((spell, turns)) = active_spells[1] {
-        assert_eq!(spell.0, "Poison");
-        boss_stats[0] = boss_stats[0].saturating_sub(3);
-        if boss_stats[0] == 0 {
-            return (true, 0);
+        if *recharge > 0 {
+            self.mana += 101;
         }
-        if turns == 0 {
-            active_spells[1] = None;
-        } else {
-            active_spells[1] = Some((spell, turns - 1));
+        for timer in &mut self.effects {
+            *timer = timer.saturating_sub(1);
         }
+        armor
     }
+}
 
-    // check if recharge spell is active
-    if let Some((spell, turns)) = active_spells[2] {
-        assert_eq!(spell.0, "Recharge");
-        my_stats[1] += 101;
-        if turns == 0 {
-            active_spells[2] = None;
-        } else {
-            active_spells[2] = Some((spell, turns - 1));
+/// Find the least mana the player can spend and still win the fight,
+/// searching the fights in the order of the mana spent.
+/// On hard difficulty, the player loses 1 health at the start of every turn of the player.
+/// # Returns
+/// * The least mana spent or the [Error].
+/// # Errors
+/// * [Error::NoSolution] - The player cannot win.
+/// * [Error::Cancelled] or [Error::Timeout] - The search does not finish in time.
+fn least_mana_to_win(start: Fight, boss_damage: usize, hard: bool) -> Result<usize, Error> {
+    let mut queue = BinaryHeap::from([Reverse((0, start))]);
+    let mut visited = HashSet::new();
+
+    for step in 0usize.. {
+        let Some(Reverse((spent, mut fight))) = queue.pop() else {
+            break;
+        };
+        if step % 1024 == 0 {
+            cancellation::check()?;
+        }
+        if fight.boss_health == 0 {
+            return Ok(spent);
+        }
+        if !visited.insert(fight) {
+            continue;
+        }
+
+        // the player's turn
+        if hard {
+            fight.health -= 1;
+            if fight.health == 0 {
+                continue;
+            }
         }
-    }
-
-    if turn {
-        // my turn
-        // check if I can cast any spell
-        if my_stats[1] < SPELLS[0].1 {
-            return (false, 0);
+        fight.apply_effects();
+        if fight.boss_health == 0 {
+            queue.push(Reverse((spent, fight)));
+            continue;
         }
 
-        let mut least_mana = usize::MAX;
-        let mut victory = false;
-
-        // cast Magic Missile, there is already enough mana (checked)
-        let mana = SPELLS[0].1;
-        let new_my_stats = [my_stats[0], my_stats[1] - mana];
-        let new_boss_stats = [boss_stats[0].saturating_sub(4), boss_stats[1]];
-        if new_boss_stats[0] == 0 {
-            return (true, mana); // return immediately because 53 is the least mana possible to spend
-        } else {
-            let (new_victory, mut new_mana) =
-                least_mana_to_win_hard(new_boss_stats, new_my_stats, !turn, active_spells);
-            new_mana += mana;
-            if victory {
-                // it was won already
-                if new_victory && new_mana < least_mana {
-                    // if I also won check if it was with less mana
-                    least_mana = new_mana;
-                }
-            } else if new_victory {
-                // it was not won yet, but I won now
-                victory = true;
-                least_mana = new_mana;
-            } else if new_mana < least_mana {
-                // it was not won yet, and I did not win now, but I spent less mana
-                least_mana = new_mana;
+        for cost in [MAGIC_MISSILE, DRAIN, SHIELD, POISON, RECHARGE] {
+            if fight.mana < cost {
+                continue;
             }
-        }
-
-        // cast Drain
-        let mana = SPELLS[1].1;
-        if my_stats[1] >= mana {
-            let new_my_stats = [my_stats[0] + 2, my_stats[1] - mana];
-            let new_boss_stats = [boss_stats[0].saturating_sub(2), boss_stats[1]];
-            if new_boss_stats[0] == 0 {
-                victory = true;
-                if mana < least_mana {
-                    least_mana = mana;
+            let mut next = fight;
+            next.mana -= cost;
+            match cost {
+                MAGIC_MISSILE => next.boss_health = next.boss_health.saturating_sub(4),
+                DRAIN => {
+                    next.boss_health = next.boss_health.saturating_sub(2);
+                    next.health += 2;
                 }
-            } else {
-                let (new_victory, mut new_mana) =
-                    least_mana_to_win_hard(new_boss_stats, new_my_stats, !turn, active_spells);
-                new_mana += mana;
-                if victory {
-                    // it was won already
-                    if new_victory && new_mana < least_mana {
-                        // if I also won check if it was with less mana
-                        least_mana = new_mana;
+                _ => {
+                    let (effect, turns) = match cost {
+                        SHIELD => (0, 6),
+                        POISON => (1, 6),
+                        _ => (2, 5),
+                    };
+                    if next.effects[effect] > 0 {
+                        continue;
                     }
-                } else if new_victory {
-                    // it was not won yet, but I won now
-                    victory = true;
-                    least_mana = new_mana;
-                } else if new_mana < least_mana {
-                    // it was not won yet, and I did not win now, but I spent less mana
-                    least_mana = new_mana;
+                    next.effects[effect] = turns;
                 }
             }
-        }
-
-        // cast Shield
-        let mana = SPELLS[2].1;
-        if active_spells[0].is_none() && my_stats[1] >= mana {
-            let new_my_stats = [my_stats[0], my_stats[1] - mana];
-            let mut new_active_spells = active_spells;
-            new_active_spells[0] = Some((SPELLS[2], 6 - 1));
-            let (new_victory, mut new_mana) =
-                least_mana_to_win_hard(boss_stats, new_my_stats, !turn, new_active_spells);
-            new_mana += mana;
-            if victory {
-                // it was won already
-                if new_victory && new_mana < least_mana {
-                    // if I also won check if it was with less mana
-                    least_mana = new_mana;
-                }
-            } else if new_victory {
-                // it was not won yet, but I won now
-                victory = true;
-                least_mana = new_mana;
-            } else if new_mana < least_mana {
-                // it was not won yet, and I did not win now, but I spent less mana
-                least_mana = new_mana;
-            }
-        }
 
-        // cast Poison
-        let mana = SPELLS[3].1;
-        if active_spells[1].is_none() && my_stats[1] >= mana {
-            let new_my_stats = [my_stats[0], my_stats[1] - mana];
-            let mut new_active_spells = active_spells;
-            new_active_spells[1] = Some((SPELLS[3], 6 - 1));
-            let (new_victory, mut new_mana) =
-                least_mana_to_win_hard(boss_stats, new_my_stats, !turn, new_active_spells);
-            new_mana += mana;
-            if victory {
-                // it was won already
-                if new_victory && new_mana < least_mana {
-                    // if I also won check if it was with less mana
-                    least_mana = new_mana;
-                }
-            } else if new_victory {
-                // it was not won yet, but I won now
-                victory = true;
-                least_mana = new_mana;
-            } else if new_mana < least_mana {
-                // it was not won yet, and I did not win now, but I spent less mana
-                least_mana = new_mana;
-            }
-        }
-
-        // cast Recharge
-        let mana = SPELLS[4].1;
-        if active_spells[2].is_none() && my_stats[1] >= mana {
-            let new_my_stats = [my_stats[0], my_stats[1] - mana];
-            let mut new_active_spells = active_spells;
-            new_active_spells[2] = Some((SPELLS[4], 5 - 1));
-            let (new_victory, mut new_mana) =
-                least_mana_to_win_hard(boss_stats, new_my_stats, !turn, new_active_spells);
-            new_mana += mana;
-            if victory {
-                // it was won already
-                if new_victory && new_mana < least_mana {
-                    // if I also won check if it was with less mana
-                    least_mana = new_mana;
+            // the boss's turn
+            if next.boss_health > 0 {
+                let armor = next.apply_effects();
+                if next.boss_health > 0 {
+                    let damage = boss_damage.saturating_sub(armor).max(1);
+                    next.health = next.health.saturating_sub(damage);
+                    if next.health == 0 {
+                        continue;
+                    }
                 }
-            } else if new_victory {
-                // it was not won yet, but I won now
-                victory = true;
-                least_mana = new_mana;
-            } else if new_mana < least_mana {
-                // it was not won yet, and I did not win now, but I spent less mana
-                least_mana = new_mana;
             }
-        }
-
-        (victory, least_mana)
-    } else {
-        // boss turn
-        let dmg = boss_stats[1].saturating_sub(my_armor);
-        my_stats[0] = my_stats[0].saturating_sub(if dmg == 0 { 1 } else { dmg });
-        if my_stats[0] == 0 {
-            (false, 0)
-        } else {
-            least_mana_to_win_hard(boss_stats, my_stats, !turn, active_spells)
+            queue.push(Reverse((spent + cost, next)));
         }
     }
+
+    Err(Error::NoSolution)
 }
@@ -1,24 +1,54 @@
-use crate::{Error, Solution};
+use crate::cancellation;
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 use std::collections::HashMap;
 
-day!(Day23, 2015, 23, "Opening the Turing Lock");
+day!(
+    Day23,
+    2015,
+    23,
+    "Opening the Turing Lock",
+    difficulty = Easy,
+    tags = [Vm]
+);
 
 impl Solution for Day23 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let instructions = parse_input(input);
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::text(
+            "register",
+            "The register whose value is the answer",
+            "b",
+        )]
+    }
+
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let register = answer_register(parameters)?;
+        let instructions = parse_input(input)?;
         let mut registers = [0, 0];
 
-        simulate(&mut registers, &instructions);
+        simulate(&mut registers, &instructions)?;
 
-        Ok(registers[1].to_string())
+        Ok(registers[register].into())
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let instructions = parse_input(input);
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let register = answer_register(parameters)?;
+        let instructions = parse_input(input)?;
         let mut registers = [1, 0];
 
-        simulate(&mut registers, &instructions);
+        simulate(&mut registers, &instructions)?;
 
-        Ok(registers[1].to_string())
+        Ok(registers[register].into())
+    }
+}
+
+/// Get the index of the register whose value is the answer.
+/// # Errors
+/// * [Error::InvalidParameter] - The register is neither `a` nor `b`.
+fn answer_register(parameters: &Parameters) -> Result<usize, Error> {
+    match parameters.text("register")? {
+        "a" => Ok(0),
+        "b" => Ok(1),
+        _ => Err(Error::InvalidParameter),
     }
 }
 
@@ -31,7 +61,7 @@ enum Instruction {
     Jio(usize, isize),
 }
 
-fn parse_input(input: &str) -> Vec<Instruction> {
+fn parse_input(input: &str) -> Result<Vec<Instruction>, Error> {
     let mut instructions = Vec::new();
 
     let mut reg_map = HashMap::new();
@@ -39,48 +69,65 @@ fn parse_input(input: &str) -> Vec<Instruction> {
     reg_map.insert("b", 1);
 
     for line in input.trim().lines() {
-        let line = line.split_whitespace().collect::<Vec<&str>>();
-        match line[0] {
-            "hlf" => instructions.push(Instruction::Hlf(*reg_map.get(line[1]).unwrap())),
-            "tpl" => instructions.push(Instruction::Tpl(*reg_map.get(line[1]).unwrap())),
-            "inc" => instructions.push(Instruction::Inc(*reg_map.get(line[1]).unwrap())),
-            "jmp" => instructions.push(Instruction::Jmp(line[1].parse::<isize>().unwrap())),
-            "jie" => instructions.push(Instruction::Jie(
-                *reg_map.get(line[1].trim_end_matches(',')).unwrap(),
-                line[2].parse::<isize>().unwrap(),
-            )),
-            "jio" => instructions.push(Instruction::Jio(
-                *reg_map.get(line[1].trim_end_matches(',')).unwrap(),
-                line[2].parse::<isize>().unwrap(),
-            )),
-            _ => panic!("Invalid instruction"),
+        let register = |reg: &str| {
+            reg_map.get(reg.trim_end_matches(',')).copied().or_invalid(
+                input,
+                reg,
+                "expected a register",
+            )
+        };
+        match line.split_whitespace().collect::<Vec<&str>>()[..] {
+            ["hlf", r] => instructions.push(Instruction::Hlf(register(r)?)),
+            ["tpl", r] => instructions.push(Instruction::Tpl(register(r)?)),
+            ["inc", r] => instructions.push(Instruction::Inc(register(r)?)),
+            ["jmp", o] => instructions.push(Instruction::Jmp(parse_number(input, o)?)),
+            ["jie", r, o] => {
+                instructions.push(Instruction::Jie(register(r)?, parse_number(input, o)?))
+            }
+            ["jio", r, o] => {
+                instructions.push(Instruction::Jio(register(r)?, parse_number(input, o)?))
+            }
+            _ => return Err(Error::invalid_input(input, line, "expected an instruction")),
         }
     }
 
-    instructions
+    Ok(instructions)
 }
 
-fn simulate(registers: &mut [usize; 2], instructions: &[Instruction]) {
+/// Run the program until it jumps outside of it.
+/// # Errors
+/// * [Error::NoSolution] - A register overflows.
+/// * [Error::Cancelled] or [Error::Timeout] - The program does not halt in time.
+fn simulate(registers: &mut [usize; 2], instructions: &[Instruction]) -> Result<(), Error> {
     let mut pc: isize = 0;
 
-    while pc >= 0 && pc < instructions.len() as isize {
-        match instructions[pc as usize] {
-            Instruction::Hlf(r) => registers[r] /= 2,
-            Instruction::Tpl(r) => registers[r] *= 3,
-            Instruction::Inc(r) => registers[r] += 1,
-            Instruction::Jmp(o) => pc += o - 1,
-            Instruction::Jie(r, o) => {
-                if registers[r].is_multiple_of(2) {
-                    pc += o - 1
-                }
+    for step in 0usize.. {
+        if pc < 0 || pc >= instructions.len() as isize {
+            break;
+        }
+        if step % 1024 == 0 {
+            cancellation::check()?;
+        }
+        let offset = match instructions[pc as usize] {
+            Instruction::Hlf(r) => {
+                registers[r] /= 2;
+                1
             }
-            Instruction::Jio(r, o) => {
-                if registers[r] == 1 {
-                    pc += o - 1
-                }
+            Instruction::Tpl(r) => {
+                registers[r] = registers[r].checked_mul(3).ok_or(Error::NoSolution)?;
+                1
             }
-        }
-
-        pc += 1;
+            Instruction::Inc(r) => {
+                registers[r] = registers[r].checked_add(1).ok_or(Error::NoSolution)?;
+                1
+            }
+            Instruction::Jmp(o) => o,
+            Instruction::Jie(r, o) if registers[r].is_multiple_of(2) => o,
+            Instruction::Jio(r, o) if registers[r] == 1 => o,
+            Instruction::Jie(..) | Instruction::Jio(..) => 1,
+        };
+        pc = pc.saturating_add(offset);
     }
+
+    Ok(())
 }
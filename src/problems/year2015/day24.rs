@@ -1,63 +1,67 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 use itertools::Itertools;
 
-day!(Day24, 2015, 24, "It Hangs in the Balance");
+day!(
+    Day24,
+    2015,
+    24,
+    "It Hangs in the Balance",
+    difficulty = Medium,
+    tags = [Combinatorics, Search]
+);
 
 impl Solution for Day24 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let weights = input
-            .trim()
-            .lines()
-            .map(|line| line.parse::<usize>().unwrap())
-            .collect::<Vec<_>>();
-        let total_weight: usize = weights.iter().sum();
-        let group_weight = total_weight / 3;
-        let mut min_qe = usize::MAX;
-
-        for i in 0..(weights.len() / 3) {
-            let mut found = false;
-            for combination in weights.iter().combinations(i) {
-                if combination.iter().copied().sum::<usize>() == group_weight {
-                    found = true;
-                    let qe = combination.into_iter().product::<usize>();
-                    if qe < min_qe {
-                        min_qe = qe;
-                    }
-                }
-            }
-            if found {
-                break;
-            }
-        }
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        min_quantum_entanglement(input, 3)
+    }
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        min_quantum_entanglement(input, 4)
+    }
+}
 
-        Ok(min_qe.to_string())
+/// Get the smallest quantum entanglement of the smallest first group of packages
+/// when the packages are split into groups of equal weight.
+/// # Errors
+/// * [Error::InvalidInput] - A weight is malformed, or the total weight or a quantum entanglement overflows.
+/// * [Error::NoSolution] - The packages cannot be split into groups of equal weight.
+fn min_quantum_entanglement(input: &str, groups: u64) -> Result<Answer, Error> {
+    let packages = input
+        .trim()
+        .lines()
+        .map(|line| Ok((line, parse_number::<u64>(input, line)?)))
+        .collect::<Result<Vec<_>, Error>>()?;
+    // the sums of the combinations are at most the total weight, so they cannot overflow either
+    let mut total_weight: u64 = 0;
+    for &(line, weight) in &packages {
+        total_weight = total_weight.checked_add(weight).or_invalid(
+            input,
+            line,
+            "the total weight is too large",
+        )?;
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let weights = input
-            .trim()
-            .lines()
-            .map(|line| line.parse::<usize>().unwrap())
-            .collect::<Vec<_>>();
-        let total_weight: usize = weights.iter().sum();
-        let group_weight = total_weight / 4;
-        let mut min_qe = usize::MAX;
+    let group_weight = total_weight / groups;
+    let mut min_qe = None;
 
-        for i in 0..(weights.len() / 4) {
-            let mut found = false;
-            for combination in weights.iter().combinations(i) {
-                if combination.iter().copied().sum::<usize>() == group_weight {
-                    found = true;
-                    let qe = combination.into_iter().product::<usize>();
-                    if qe < min_qe {
-                        min_qe = qe;
-                    }
+    // the first group is the smallest one, so it has at most as many packages as the average group
+    for i in 1..=(packages.len() / groups as usize) {
+        for combination in packages.iter().combinations(i) {
+            if combination.iter().map(|(_, weight)| weight).sum::<u64>() == group_weight {
+                let mut qe: i128 = 1;
+                for &&(line, weight) in &combination {
+                    qe = qe.checked_mul(i128::from(weight)).or_invalid(
+                        input,
+                        line,
+                        "the quantum entanglement is too large",
+                    )?;
                 }
-            }
-            if found {
-                break;
+                min_qe = Some(min_qe.map_or(qe, |min_qe: i128| min_qe.min(qe)));
             }
         }
-
-        Ok(min_qe.to_string())
+        if min_qe.is_some() {
+            break;
+        }
     }
+
+    min_qe.map(Answer::from).ok_or(Error::NoSolution)
 }
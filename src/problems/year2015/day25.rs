@@ -1,66 +1,91 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, end, parse_number};
+use crate::{Answer, Error, Solution};
 
-day!(Day25, 2015, 25, "Let It Snow");
+day!(
+    Day25,
+    2015,
+    25,
+    "Let It Snow",
+    difficulty = Easy,
+    tags = [NumberTheory]
+);
 
 impl Solution for Day25 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let (row, col) = parse_input(input);
-        let position_in_seq = seq_n(row, col);
-        Ok(calculate_code(position_in_seq).to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let (row, col) = parse_input(input)?;
+        let position_in_seq = seq_n(row, col).ok_or(Error::NoSolution)?;
+        Ok(calculate_code(position_in_seq).into())
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let _ = input; // suppress unused variable warning
-        Ok(String::from("Advent of Code 2015 solved!"))
+        Ok("Advent of Code 2015 solved!".into())
     }
 }
 
-fn parse_input(input: &str) -> (usize, usize) {
-    let mut line_iter = input.trim().lines().next().unwrap().split_whitespace();
+fn parse_input(input: &str) -> Result<(usize, usize), Error> {
+    let mut line_iter = input.split_whitespace();
 
-    let mut element = line_iter.next().unwrap();
-    while element != "row" {
-        element = line_iter.next().unwrap();
-    }
-    let row = line_iter
-        .next()
-        .unwrap()
-        .trim_end_matches([',', '.'])
-        .parse::<usize>()
-        .unwrap();
-
-    while element != "column" {
-        element = line_iter.next().unwrap();
-    }
-    let col = line_iter
-        .next()
-        .unwrap()
-        .trim_end_matches([',', '.'])
-        .parse::<usize>()
-        .unwrap();
+    let mut coordinate = |name: &str| -> Result<usize, Error> {
+        line_iter
+            .by_ref()
+            .find(|&element| element == name)
+            .or_invalid(input, end(input), "expected the row and column")?;
+        let value = line_iter
+            .next()
+            .or_invalid(input, end(input), "expected the row and column")?
+            .trim_end_matches([',', '.']);
+        match parse_number(input, value)? {
+            0 => Err(Error::invalid_input(
+                input,
+                value,
+                "expected a positive number",
+            )),
+            value => Ok(value),
+        }
+    };
+    let row = coordinate("row")?;
+    let col = coordinate("column")?;
 
     // (row, column)
-    (row, col)
+    Ok((row, col))
 }
 
-fn seq_n(row: usize, col: usize) -> usize {
-    //! Returns which position in the sequence the code is at regarding the row and column
+fn seq_n(row: usize, col: usize) -> Option<usize> {
+    //! Returns which position in the sequence the code is at regarding the row and column,
+    //! or None if the position overflows
 
     // we find which diagonal the code is on by similarity of triangles
-    let diagonal = row + col - 1;
+    let diagonal = row.checked_add(col)? - 1;
 
     // we find sum all diagonals before this one and then just count how many numbers are in this diagonal before wanted one
     // the number of elements of each diagonal is the diagonal number, so the sum of all diagonals before this one is the sum of all numbers from 1 to diagonal (exclusive)
     // we get the formula for finding code position
     // (diagonal - 1)(diagonal) / 2 + col
 
-    ((diagonal - 1) * diagonal) / 2 + col
+    // one of two consecutive numbers is even, so it is halved before multiplying
+    let (first, second) = if diagonal % 2 == 0 {
+        (diagonal / 2, diagonal - 1)
+    } else {
+        ((diagonal - 1) / 2, diagonal)
+    };
+    first.checked_mul(second)?.checked_add(col)
 }
 
 fn calculate_code(position: usize) -> usize {
+    //! Returns the code at the position in the sequence
+    //! every code is the previous one multiplied by 252533, so the multiplier is raised
+    //! to the power of the number of previous codes by repeated squaring
+
     let mut current_code = 20151125;
+    let mut multiplier = 252533;
+    let mut exponent = position - 1;
 
-    for _ in 0..(position - 1) {
-        current_code = (current_code * 252533) % 33554393;
+    while exponent > 0 {
+        if exponent % 2 == 1 {
+            current_code = (current_code * multiplier) % 33554393;
+        }
+        multiplier = (multiplier * multiplier) % 33554393;
+        exponent /= 2;
     }
 
     current_code
@@ -1,36 +1,42 @@
-use crate::{Error, Solution};
+use crate::cancellation;
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 
-day!(Day01, 2016, 1, "No Time for a Taxicab");
+day!(
+    Day01,
+    2016,
+    1,
+    "No Time for a Taxicab",
+    difficulty = Easy,
+    tags = [Geometry, Grid]
+);
 
 impl Solution for Day01 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let end_position = calculate_position(parse_input(input));
-        Ok((end_position.0.abs() + end_position.1.abs()).to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let end_position = calculate_position(parse_input(input)?).ok_or(Error::NoSolution)?;
+        distance(end_position).map(Answer::from)
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let instructions = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let instructions = parse_input(input)?;
         let mut visited_positions = Vec::new();
 
         let mut x: isize = 0;
         let mut y: isize = 0;
-        let mut direction: u8 = 0;
-        // 0 = North
-        // 1 = East
-        // 2 = South
-        // 3 = West
+        let mut direction = Direction::North;
 
-        for instruction in instructions {
-            direction = change_direction(direction, instruction.0);
-            for _ in 0..instruction.1 {
+        for (turn, blocks) in instructions {
+            direction = direction.turn(turn);
+            for _ in 0..blocks {
+                // the visited positions are searched linearly, so checking every block is cheap
+                cancellation::check()?;
                 match direction {
-                    0 => y += 1,
-                    1 => x += 1,
-                    2 => y -= 1,
-                    3 => x -= 1,
-                    _ => panic!("Invalid direction value"),
+                    Direction::North => y += 1,
+                    Direction::East => x += 1,
+                    Direction::South => y -= 1,
+                    Direction::West => x -= 1,
                 }
                 if visited_positions.contains(&(x, y)) {
-                    return Ok((x.abs() + y.abs()).to_string());
+                    return distance((x, y)).map(Answer::from);
                 } else {
                     visited_positions.push((x, y));
                 }
@@ -41,75 +47,79 @@ impl Solution for Day01 {
     }
 }
 
-fn parse_input(input: &str) -> Vec<(u8, usize)> {
-    let mut instructions: Vec<(u8, usize)> = Vec::new();
-
-    let mut instruction: (u8, usize) = (0, 0);
-    let mut number: String = String::new();
-    for c in input.trim().chars() {
-        match c {
-            'R' => instruction.0 = 1,
-            'L' => instruction.0 = 0,
-            ',' => {
-                instruction.1 = number.parse::<usize>().unwrap();
-                number.clear();
-                instructions.push(instruction);
-                instruction = (0, 0);
-            }
-            _ => {
-                if c.is_ascii_digit() {
-                    number.push(c);
-                }
-            }
-        }
-    }
-    if !number.is_empty() {
-        instruction.1 = number.parse::<usize>().unwrap();
-        instructions.push(instruction)
-    }
-
-    instructions
+fn parse_input(input: &str) -> Result<Vec<(Turn, isize)>, Error> {
+    input
+        .trim()
+        .split(',')
+        .map(|instruction| {
+            let instruction = instruction.trim();
+            let turn = match instruction.chars().next() {
+                Some('L') => Turn::Left,
+                Some('R') => Turn::Right,
+                _ => return Err(Error::invalid_input(input, instruction, "expected a turn")),
+            };
+            let blocks = &instruction[1..];
+            let blocks = isize::try_from(parse_number::<usize>(input, blocks)?).or_invalid(
+                input,
+                blocks,
+                "too many blocks",
+            )?;
+            Ok((turn, blocks))
+        })
+        .collect()
 }
 
-fn calculate_position(instructions: Vec<(u8, usize)>) -> (isize, isize) {
+/// Get the position after following the instructions, or [None] if it overflows.
+fn calculate_position(instructions: Vec<(Turn, isize)>) -> Option<(isize, isize)> {
     let mut x: isize = 0;
     let mut y: isize = 0;
-    let mut direction: u8 = 0;
-    // 0 = North
-    // 1 = East
-    // 2 = South
-    // 3 = West
+    let mut direction = Direction::North;
 
-    for instruction in instructions {
-        direction = change_direction(direction, instruction.0);
+    for (turn, blocks) in instructions {
+        direction = direction.turn(turn);
         match direction {
-            0 => y += isize::try_from(instruction.1).unwrap(),
-            1 => x += isize::try_from(instruction.1).unwrap(),
-            2 => y -= isize::try_from(instruction.1).unwrap(),
-            3 => x -= isize::try_from(instruction.1).unwrap(),
-            _ => panic!("Invalid direction value"),
+            Direction::North => y = y.checked_add(blocks)?,
+            Direction::East => x = x.checked_add(blocks)?,
+            Direction::South => y = y.checked_sub(blocks)?,
+            Direction::West => x = x.checked_sub(blocks)?,
         }
     }
 
-    (x, y)
+    Some((x, y))
 }
 
-fn change_direction(direction: u8, turn: u8) -> u8 {
-    match turn {
-        0 => {
-            if direction == 0 {
-                3
-            } else {
-                direction - 1
-            }
-        }
-        1 => {
-            if direction == 3 {
-                0
-            } else {
-                direction + 1
-            }
+/// Get the number of blocks from the start to the position.
+/// # Returns
+/// * The distance or [Error::NoSolution] if it overflows.
+fn distance(position: (isize, isize)) -> Result<usize, Error> {
+    position
+        .0
+        .unsigned_abs()
+        .checked_add(position.1.unsigned_abs())
+        .ok_or(Error::NoSolution)
+}
+
+#[derive(Clone, Copy)]
+enum Turn {
+    Left,
+    Right,
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+impl Direction {
+    /// Get the direction faced after turning.
+    fn turn(self, turn: Turn) -> Self {
+        match (self, turn) {
+            (Direction::North, Turn::Left) | (Direction::South, Turn::Right) => Direction::West,
+            (Direction::East, Turn::Left) | (Direction::West, Turn::Right) => Direction::North,
+            (Direction::South, Turn::Left) | (Direction::North, Turn::Right) => Direction::East,
+            (Direction::West, Turn::Left) | (Direction::East, Turn::Right) => Direction::South,
         }
-        _ => panic!("Invalid turn value"),
     }
 }
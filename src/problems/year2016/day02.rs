@@ -1,6 +1,13 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
-day!(Day02, 2016, 2, "Bathroom Security");
+day!(
+    Day02,
+    2016,
+    2,
+    "Bathroom Security",
+    difficulty = Easy,
+    tags = [Grid]
+);
 
 const KEYPAD: [[char; 3]; 3] = [['1', '2', '3'], ['4', '5', '6'], ['7', '8', '9']];
 
@@ -13,12 +20,12 @@ const KEYPAD2: [[char; 5]; 5] = [
 ];
 
 impl Solution for Day02 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let mut code = String::new();
         let mut position: [usize; 2] = [1, 1];
 
         for line in input.trim().lines() {
-            for c in line.chars() {
+            for (i, c) in line.char_indices() {
                 match c {
                     'L' => position[1] = position[1].saturating_sub(1),
                     'R' => {
@@ -32,21 +39,27 @@ impl Solution for Day02 {
                             position[0] += 1
                         }
                     }
-                    _ => panic!("Invalid input!"),
+                    _ => {
+                        return Err(Error::invalid_input(
+                            input,
+                            &line[i..],
+                            "expected a direction",
+                        ));
+                    }
                 }
             }
             code.push(KEYPAD[position[0]][position[1]])
         }
 
-        Ok(code)
+        Ok(code.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut code = String::new();
         let mut position: [usize; 2] = [2, 0];
 
         for line in input.trim().lines() {
-            for c in line.chars() {
+            for (i, c) in line.char_indices() {
                 match c {
                     'L' => {
                         let new_pos = position[1].saturating_sub(1);
@@ -80,12 +93,18 @@ impl Solution for Day02 {
                             position[0] = new_pos;
                         }
                     }
-                    _ => panic!("Invalid input!"),
+                    _ => {
+                        return Err(Error::invalid_input(
+                            input,
+                            &line[i..],
+                            "expected a direction",
+                        ));
+                    }
                 }
             }
             code.push(KEYPAD2[position[0]][position[1]])
         }
 
-        Ok(code)
+        Ok(code.into())
     }
 }
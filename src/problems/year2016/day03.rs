@@ -1,16 +1,24 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 
-day!(Day03, 2016, 3, "Squares With Three Sides");
+day!(
+    Day03,
+    2016,
+    3,
+    "Squares With Three Sides",
+    difficulty = Easy,
+    tags = [Geometry, Parsing]
+);
 
 impl Solution for Day03 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let triangles = parse_input(input);
-        Ok(count_triangles(triangles).to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let triangles = parse_input(input)?;
+        Ok(count_triangles(triangles).into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let triangles = parse_input_2(input);
-        Ok(count_triangles(triangles).to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let triangles = parse_input_2(input)?;
+        Ok(count_triangles(triangles).into())
     }
 }
 
@@ -26,31 +34,32 @@ fn count_triangles(triangles: Vec<[usize; 3]>) -> usize {
     possible_triangles
 }
 
-fn parse_input(input: &str) -> Vec<[usize; 3]> {
+fn parse_line(input: &str, line: &str) -> Result<[usize; 3], Error> {
+    let sides = line
+        .split_whitespace()
+        .map(|s| parse_number(input, s))
+        .collect::<Result<Vec<usize>, _>>()?;
+    sides.try_into().or_invalid(input, line, "expected 3 sides")
+}
+
+fn parse_input(input: &str) -> Result<Vec<[usize; 3]>, Error> {
     input
         .trim()
         .lines()
         .map(|line| {
-            let mut sides = line
-                .split_whitespace()
-                .map(|s| s.parse::<usize>().unwrap())
-                .collect::<Vec<usize>>();
+            let mut sides = parse_line(input, line)?;
             sides.sort();
-            [sides[0], sides[1], sides[2]]
+            Ok(sides)
         })
         .collect()
 }
 
-fn parse_input_2(input: &str) -> Vec<[usize; 3]> {
+fn parse_input_2(input: &str) -> Result<Vec<[usize; 3]>, Error> {
     let mut triangles = Vec::new();
     let mut triangle_block = [Vec::new(), Vec::new(), Vec::new()];
 
     for line in input.trim().lines() {
-        for (i, side) in line
-            .split_whitespace()
-            .map(|s| s.parse::<usize>().unwrap())
-            .enumerate()
-        {
+        for (i, side) in parse_line(input, line)?.into_iter().enumerate() {
             triangle_block[i].push(side);
         }
 
@@ -63,9 +72,10 @@ fn parse_input_2(input: &str) -> Vec<[usize; 3]> {
         }
     }
 
-    triangles
+    Ok(triangles)
 }
 
 fn possible_triangle(triangle: [usize; 3]) -> bool {
-    triangle[0] + triangle[1] > triangle[2]
+    // the sides are sorted, so the difference can't underflow
+    triangle[0] > triangle[2] - triangle[1]
 }
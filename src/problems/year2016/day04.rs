@@ -1,19 +1,31 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 use regex::Regex;
 use std::cmp::Reverse;
 use std::collections::HashMap;
 
-day!(Day04, 2016, 4, "Security Through Obscurity");
+day!(
+    Day04,
+    2016,
+    4,
+    "Security Through Obscurity",
+    difficulty = Easy,
+    tags = [Strings]
+);
 
 impl Solution for Day04 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let rooms = filter_rooms(parse_input(input));
-        Ok(rooms.iter().map(|(_, id, _)| id).sum::<usize>().to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let rooms = filter_rooms(parse_input(input)?);
+        rooms
+            .iter()
+            .try_fold(0usize, |sum, (_, id, _)| sum.checked_add(*id))
+            .map(Answer::from)
+            .ok_or(Error::NoSolution)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let rooms = filter_rooms(parse_input(input));
-        Ok(rooms
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let rooms = filter_rooms(parse_input(input)?);
+        rooms
             .iter()
             .find(|(name, id, _)| {
                 let real_name = rotate_name(name, *id);
@@ -21,9 +33,8 @@ impl Solution for Day04 {
                     && real_name.contains("pole")
                     && real_name.contains("object")
             })
-            .unwrap()
-            .1
-            .to_string())
+            .map(|(_, id, _)| Answer::from(*id))
+            .ok_or(Error::NoSolution)
     }
 }
 
@@ -41,13 +52,11 @@ fn filter_rooms(rooms: Vec<(&str, usize, [char; 5])>) -> Vec<(&str, usize, [char
         }
         let mut char_counts: Vec<(char, usize)> = char_counts.into_iter().collect();
         char_counts.sort_by_key(|(c, count)| (Reverse(*count), *c));
-        let new_checksum: [char; 5] = char_counts
+        let new_checksum = char_counts
             .iter()
             .map(|(c, _)| *c)
             .take(5)
-            .collect::<Vec<char>>()
-            .try_into()
-            .unwrap();
+            .collect::<Vec<char>>();
         if new_checksum == checksum {
             filtered_rooms.push((name, id, checksum));
         }
@@ -64,7 +73,7 @@ fn rotate_name(name: &str, id: usize) -> String {
             continue;
         }
         let mut new_c = c;
-        for _ in 0..id {
+        for _ in 0..id % 26 {
             new_c = match new_c {
                 'z' => 'a',
                 _ => char::from_u32(u32::from(new_c) + 1).unwrap(),
@@ -75,31 +84,28 @@ fn rotate_name(name: &str, id: usize) -> String {
     new_name.trim().to_string()
 }
 
-fn parse_input(input: &str) -> Vec<(&str, usize, [char; 5])> {
-    let re_id = Regex::new(r"\d+").unwrap();
-    let re_checksum = Regex::new(r"\[\w{5}]").unwrap();
+/// A room as its encrypted name, sector id and checksum.
+type Room<'a> = (&'a str, usize, [char; 5]);
+
+fn parse_input(input: &str) -> Result<Vec<Room<'_>>, Error> {
+    let re_room = Regex::new(r"^([a-z-]+)(\d+)\[([a-z]{5})]$").unwrap();
 
     let mut rooms = Vec::new();
 
     for line in input.trim().lines() {
-        let id_match = re_id.find(line).unwrap();
+        let captures = re_room
+            .captures(line)
+            .or_invalid(input, line, "expected a room")?;
 
-        let name = line.get(..id_match.start()).unwrap();
-        let id = id_match.as_str().parse::<usize>().unwrap();
-        let checksum: [char; 5] = re_checksum
-            .find(line)
-            .unwrap()
-            .as_str()
-            .chars()
-            .enumerate()
-            .filter(|(i, _)| *i > 0 && *i < 6)
-            .map(|(_, c)| c)
-            .collect::<Vec<char>>()
-            .try_into()
-            .unwrap();
+        let name = captures.get(1).unwrap().as_str();
+        let id = parse_number(input, captures.get(2).unwrap().as_str())?;
+        let mut checksum = ['\0'; 5];
+        for (c, checksum_c) in captures.get(3).unwrap().as_str().chars().zip(&mut checksum) {
+            *checksum_c = c;
+        }
 
         rooms.push((name, id, checksum));
     }
 
-    rooms
+    Ok(rooms)
 }
@@ -1,19 +1,31 @@
+use crate::cancellation;
 use crate::math::HEX_DIGITS;
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 use md5::{Digest, Md5};
 use std::fmt::Write;
 
-day!(Day05, 2016, 5, "How About a Nice Game of Chess?");
+day!(
+    Day05,
+    2016,
+    5,
+    "How About a Nice Game of Chess?",
+    difficulty = Easy,
+    tags = [Hashing]
+);
 
 impl Solution for Day05 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let mut password = String::with_capacity(8);
 
         let mut input_str = String::from(input.trim());
         let input_len = input_str.len();
         let mut hasher = Md5::new();
 
-        for i in 0.. {
+        let mut i: u64 = 0;
+        loop {
+            if i.is_multiple_of(1024) {
+                cancellation::check()?;
+            }
             input_str.truncate(input_len);
             write!(&mut input_str, "{}", i).unwrap();
 
@@ -23,17 +35,16 @@ impl Solution for Day05 {
             if hash[0] == 0 && hash[1] == 0 && (hash[2] >> 4 == 0) {
                 write!(&mut password, "{:x}", hash[2] & 0x0f).unwrap();
                 if password.len() == 8 {
-                    break;
+                    return Ok(password.into());
                 }
             }
+            i += 1;
         }
-
-        Ok(password)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut password = ['_'; 8];
-        let mut i = 0;
+        let mut i: u64 = 0;
 
         let mut input_str = String::from(input.trim());
         let input_len = input_str.len();
@@ -41,6 +52,9 @@ impl Solution for Day05 {
 
         while password.contains(&'_') {
             loop {
+                if i.is_multiple_of(1024) {
+                    cancellation::check()?;
+                }
                 input_str.truncate(input_len);
                 write!(&mut input_str, "{}", i).unwrap();
 
@@ -60,6 +74,6 @@ impl Solution for Day05 {
             }
         }
 
-        Ok(password.iter().collect())
+        Ok(password.iter().collect::<String>().into())
     }
 }
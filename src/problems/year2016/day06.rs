@@ -1,20 +1,35 @@
-use crate::{Error, Solution};
+use crate::input::OrInvalid;
+use crate::{Answer, Error, Solution};
 use std::collections::HashMap;
 
-day!(Day06, 2016, 6, "Signals and Noise");
+day!(
+    Day06,
+    2016,
+    6,
+    "Signals and Noise",
+    difficulty = Easy,
+    tags = [Strings]
+);
 
 impl Solution for Day06 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(solve(input, false))
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        solve(input, false)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        Ok(solve(input, true))
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        solve(input, true)
     }
 }
 
-fn solve(input: &str, minimum: bool) -> String {
-    let message_len = input.trim().lines().next().unwrap().trim().chars().count();
+fn solve(input: &str, minimum: bool) -> Result<Answer, Error> {
+    let message_len = input
+        .trim()
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .chars()
+        .count();
     let mut message: Vec<HashMap<char, usize>> = Vec::with_capacity(message_len);
     for _ in 0..message_len {
         message.push(HashMap::new());
@@ -22,7 +37,11 @@ fn solve(input: &str, minimum: bool) -> String {
 
     for line in input.trim().lines() {
         for (i, c) in line.chars().enumerate() {
-            let count = message[i].entry(c).or_insert(0);
+            let count = message
+                .get_mut(i)
+                .or_invalid(input, line, "expected messages of equal length")?
+                .entry(c)
+                .or_insert(0);
             *count += 1;
         }
     }
@@ -37,5 +56,9 @@ fn solve(input: &str, minimum: bool) -> String {
         });
     }
 
-    result
+    if result.is_empty() {
+        return Err(Error::NoSolution);
+    }
+
+    Ok(result.into())
 }
@@ -1,17 +1,24 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 use regex::Regex;
 
-day!(Day07, 2016, 7, "Internet Protocol Version 7");
+day!(
+    Day07,
+    2016,
+    7,
+    "Internet Protocol Version 7",
+    difficulty = Easy,
+    tags = [Strings]
+);
 
 impl Solution for Day07 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let split_re = Regex::new(r"\[\w*]").unwrap();
         Ok(input
             .trim()
             .lines()
             .filter(|line| {
                 let is_tls = |elem: &str| {
-                    for i in 0..(elem.chars().count() - 3) {
+                    for i in 0..elem.chars().count().saturating_sub(3) {
                         if elem.chars().nth(i).unwrap() == elem.chars().nth(i + 3).unwrap()
                             && elem.chars().nth(i + 1).unwrap() == elem.chars().nth(i + 2).unwrap()
                             && elem.chars().nth(i).unwrap() != elem.chars().nth(i + 1).unwrap()
@@ -29,9 +36,9 @@ impl Solution for Day07 {
                 tls_outside && !tls_inside
             })
             .count()
-            .to_string())
+            .into())
     }
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let split_re = Regex::new(r"\[\w*]").unwrap();
         Ok(input
             .trim()
@@ -52,10 +59,9 @@ impl Solution for Day07 {
                         let second = supernet_element.chars().nth(triple_start + 1).unwrap();
                         let third = supernet_element.chars().nth(triple_start + 2).unwrap();
                         if first == third && first != second {
-                            let inverted_re =
-                                Regex::new(&format!(r"{}{}{}", second, first, second)).unwrap();
+                            let inverted = format!("{}{}{}", second, first, second);
                             for hypernet_element in &hypernet {
-                                if inverted_re.is_match(hypernet_element) {
+                                if hypernet_element.contains(&inverted) {
                                     return true;
                                 }
                             }
@@ -65,6 +71,6 @@ impl Solution for Day07 {
                 false
             })
             .count()
-            .to_string())
+            .into())
     }
 }
@@ -1,62 +1,89 @@
-use crate::{Error, Solution};
-use advent_of_code_ocr::parse_string_to_letters;
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 use itertools::Itertools;
 
-day!(Day08, 2016, 8, "Two-Factor Authentication");
+day!(
+    Day08,
+    2016,
+    8,
+    "Two-Factor Authentication",
+    difficulty = Medium,
+    tags = [Grid, Simulation]
+);
 
 impl Solution for Day08 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let screen = simulate_screen(input);
-        Ok(screen.pixels_on().to_string())
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::integer("width", "The width of the screen", 50),
+            Parameter::integer("height", "The height of the screen", 6),
+        ]
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let screen = simulate_screen(input);
-        let mut output = String::new();
-        for row in screen.pixels {
-            for pixel in row {
-                if pixel {
-                    output.push('#');
-                } else {
-                    output.push('.');
-                }
-            }
-            output.push('\n');
-        }
-        Ok(parse_string_to_letters(&output))
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let screen = simulate_screen(input, parameters)?;
+        Ok(screen.pixels_on().into())
+    }
+
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let screen = simulate_screen(input, parameters)?;
+        let rows = screen
+            .pixels
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&pixel| if pixel { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        Ok(Answer::from_screen(rows))
     }
 }
 
-fn simulate_screen(input: &str) -> Screen {
-    let mut screen = Screen::new();
+fn simulate_screen(input: &str, parameters: &Parameters) -> Result<Screen, Error> {
+    let (width, height) = match (parameters.integer("width")?, parameters.integer("height")?) {
+        (0, _) | (_, 0) => return Err(Error::InvalidParameter),
+        size => size,
+    };
+    let mut screen = Screen::new(width, height);
 
     for line in input.trim().lines() {
         let line_contents = line.split_whitespace().collect::<Vec<&str>>();
-        match line_contents[0] {
-            "rect" => {
-                let (x, y) = line_contents[1]
+        match line_contents[..] {
+            ["rect", size] => {
+                let (x, y) = size
                     .split('x')
-                    .map(|num| num.parse::<usize>().unwrap())
+                    .map(|num| parse_number::<usize>(input, num))
                     .collect_tuple()
-                    .unwrap();
+                    .or_invalid(input, size, "expected a rectangle size")?;
+                let (x, y) = (x?, y?);
+                if x > width || y > height {
+                    return Err(Error::invalid_input(input, size, "rectangle is too large"));
+                }
                 screen.rectangle(x, y);
             }
-            "rotate" => {
-                let n_th = line_contents[2].split('=').collect::<Vec<&str>>()[1]
-                    .parse::<usize>()
-                    .unwrap();
-                let by = line_contents[4].parse::<usize>().unwrap();
-                match line_contents[1] {
-                    "row" => screen.rotate_row(n_th, by),
-                    "column" => screen.rotate_col(n_th, by),
-                    _ => panic!("Invalid instruction"),
+            ["rotate", kind @ ("row" | "column"), n_th, "by", by] => {
+                let (_, n_th_value) =
+                    n_th.split_once('=')
+                        .or_invalid(input, n_th, "expected a row or a column")?;
+                let n_th_value = parse_number::<usize>(input, n_th_value)?;
+                let by = parse_number::<usize>(input, by)?;
+                if kind == "row" && n_th_value < height {
+                    screen.rotate_row(n_th_value, by);
+                } else if kind == "column" && n_th_value < width {
+                    screen.rotate_col(n_th_value, by);
+                } else {
+                    return Err(Error::invalid_input(
+                        input,
+                        n_th,
+                        "row or column is out of bounds",
+                    ));
                 }
             }
-            _ => panic!("Invalid instruction"),
+            _ => return Err(Error::invalid_input(input, line, "expected an instruction")),
         }
     }
 
-    screen
+    Ok(screen)
 }
 
 struct Screen {
@@ -64,9 +91,9 @@ struct Screen {
 }
 
 impl Screen {
-    fn new() -> Self {
+    fn new(width: usize, height: usize) -> Self {
         Self {
-            pixels: vec![vec![false; 50]; 6],
+            pixels: vec![vec![false; width]; height],
         }
     }
 
@@ -83,7 +110,8 @@ impl Screen {
     }
 
     fn rotate_row(&mut self, row: usize, amount: usize) {
-        self.pixels[row].rotate_right(amount);
+        let len = self.pixels[row].len();
+        self.pixels[row].rotate_right(amount % len);
     }
 
     fn rotate_col(&mut self, col: usize, amount: usize) {
@@ -1,99 +1,106 @@
-use crate::{Error, Solution};
-use regex::Regex;
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
+use regex::{Captures, Regex};
 use std::sync::LazyLock;
 
-day!(Day09, 2016, 9, "Explosives in Cyberspace");
+day!(
+    Day09,
+    2016,
+    9,
+    "Explosives in Cyberspace",
+    difficulty = Medium,
+    tags = [Parsing, Strings]
+);
 
 impl Solution for Day09 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut file = String::from(input.trim());
-        let re = Regex::new(r"\((\d+)x(\d+)\)").unwrap();
-        let mut position = 0;
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut file = input.trim();
+        let mut file_len: u64 = 0;
 
         while !file.is_empty() {
-            let re_match = match re.find_at(&file, position) {
+            let re_match = match MARKER_RE.captures(file) {
                 Some(caps) => caps,
-                None => break,
+                None => {
+                    file_len += file.chars().count() as u64;
+                    break;
+                }
             };
-            let start = re_match.start();
-            let range = re_match.range();
-
-            let len = re_match
-                .as_str()
-                .trim_start_matches('(')
-                .trim_end_matches(')')
-                .split('x')
-                .next()
-                .unwrap()
-                .parse::<usize>()
-                .unwrap();
-            let times = re_match
-                .as_str()
-                .trim_start_matches('(')
-                .trim_end_matches(')')
-                .split('x')
-                .next_back()
-                .unwrap()
-                .parse::<usize>()
-                .unwrap();
-
-            file.replace_range(range, "");
-            let compressed_str = file.get(start..(start + len)).unwrap();
-            file.insert_str(start, &compressed_str.repeat(times - 1));
-
-            position = start + len * times;
+            let start = re_match.get(0).unwrap().start();
+            let end = re_match.get(0).unwrap().end();
+
+            let (len, times) = parse_marker(input, &re_match)?;
+
+            let compressed_str = file.get(end..end.saturating_add(len)).or_invalid(
+                input,
+                &file[start..],
+                "marker exceeds the data",
+            )?;
+            // the repeated data is not decompressed again, so it is only counted
+            file_len = (compressed_str.chars().count() as u64)
+                .checked_mul(times as u64)
+                .and_then(|repeated_len| {
+                    repeated_len.checked_add(file[..start].chars().count() as u64)
+                })
+                .and_then(|added_len| added_len.checked_add(file_len))
+                .ok_or(Error::NoSolution)?;
+
+            file = &file[(end + len)..];
         }
 
-        Ok(file.chars().count().to_string())
+        Ok(file_len.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        Ok(decompress(input.trim()).to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        Ok(decompress(input, input.trim())?.into())
     }
 }
 
-fn decompress(mut current_string: &str) -> u64 {
+static MARKER_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\((\d+)x(\d+)\)").unwrap());
+
+/// Parse the length and the repeat count of a marker.
+fn parse_marker(input: &str, captures: &Captures) -> Result<(usize, usize), Error> {
+    let len = parse_number(input, captures.get(1).unwrap().as_str())?;
+    let times_str = captures.get(2).unwrap().as_str();
+    match parse_number(input, times_str)? {
+        0 => Err(Error::invalid_input(
+            input,
+            times_str,
+            "expected a positive repeat count",
+        )),
+        times => Ok((len, times)),
+    }
+}
+
+fn decompress(input: &str, mut current_string: &str) -> Result<u64, Error> {
     // assuming that no marker constructs a new marker while decompressing
     // seems to work for the input, I guess the input is intentionally constructed like this
 
     let mut file_len: u64 = 0;
-    static RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\((\d+)x(\d+)\)").unwrap());
 
     while !current_string.is_empty() {
-        let re_match = match RE.find(current_string) {
+        let re_match = match MARKER_RE.captures(current_string) {
             Some(caps) => caps,
             None => {
                 file_len += current_string.chars().count() as u64;
                 break;
             }
         };
-        let start = re_match.start();
-        let end = re_match.end();
-
-        let len = re_match
-            .as_str()
-            .trim_start_matches('(')
-            .trim_end_matches(')')
-            .split('x')
-            .next()
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
-        let times = re_match
-            .as_str()
-            .trim_start_matches('(')
-            .trim_end_matches(')')
-            .split('x')
-            .next_back()
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
-
-        file_len += start as u64;
-        file_len += decompress(&current_string[end..(end + len)]) * times as u64;
+        let start = re_match.get(0).unwrap().start();
+        let end = re_match.get(0).unwrap().end();
+
+        let (len, times) = parse_marker(input, &re_match)?;
+
+        let compressed_str = current_string
+            .get(end..end.saturating_add(len))
+            .or_invalid(input, &current_string[start..], "marker exceeds the data")?;
+        file_len = decompress(input, compressed_str)?
+            .checked_mul(times as u64)
+            .and_then(|repeated_len| repeated_len.checked_add(start as u64))
+            .and_then(|added_len| added_len.checked_add(file_len))
+            .ok_or(Error::NoSolution)?;
 
         current_string = &current_string[(end + len)..];
     }
 
-    file_len
+    Ok(file_len)
 }
@@ -1,21 +1,41 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, end, parse_number};
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 use std::collections::VecDeque;
 
-day!(Day10, 2016, 10, "Balance Bots");
+day!(
+    Day10,
+    2016,
+    10,
+    "Balance Bots",
+    difficulty = Medium,
+    tags = [Graph, Simulation]
+);
 
 impl Solution for Day10 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut processor = Processor::new(input);
-        match processor.simulate(1) {
-            Some(bot_id) => Ok(bot_id.to_string()),
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::integer("low", "The lower microchip compared in the first part", 17),
+            Parameter::integer(
+                "high",
+                "The higher microchip compared in the first part",
+                61,
+            ),
+        ]
+    }
+
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let chips = (parameters.integer("low")?, parameters.integer("high")?);
+        let mut processor = Processor::new(input)?;
+        match processor.simulate(Some(chips)) {
+            Some(bot_id) => Ok(bot_id.into()),
             None => Err(Error::NoSolution),
         }
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut processor = Processor::new(input);
-        match processor.simulate(2) {
-            Some(product) => Ok(product.to_string()),
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut processor = Processor::new(input)?;
+        match processor.simulate(None) {
+            Some(product) => Ok(product.into()),
             None => Err(Error::NoSolution),
         }
     }
@@ -26,7 +46,7 @@ struct Processor {
     outputs: Vec<Out>,
 }
 impl Processor {
-    fn new(input: &str) -> Self {
+    fn new(input: &str) -> Result<Self, Error> {
         let mut bots = Vec::new();
         let mut outputs = Vec::new();
 
@@ -35,67 +55,109 @@ impl Processor {
 
         for line in input.trim().lines() {
             let line_contents = line.split_whitespace().collect::<Vec<_>>();
-            match line_contents[0] {
-                "value" => values.push((
-                    line_contents[1].parse().unwrap(),
-                    line_contents[5].parse().unwrap(),
-                )),
-                "bot" => {
-                    let bot_id = line_contents[1].parse().unwrap();
-                    let low_id = line_contents[6].parse().unwrap();
-                    let high_id = line_contents[11].parse().unwrap();
-                    let low_pass_type = match line_contents[5] {
-                        "bot" => PassType::Bot(low_id),
-                        "output" => {
-                            let output = Out::new(low_id, None);
-                            if !outputs.contains(&output) {
-                                outputs.push(output);
-                            }
-                            PassType::Out(low_id)
-                        }
-                        _ => panic!("Invalid input"),
-                    };
-                    let high_pass_type = match line_contents[10] {
-                        "bot" => PassType::Bot(high_id),
-                        "output" => {
-                            let output = Out::new(high_id, None);
-                            if !outputs.contains(&output) {
-                                outputs.push(output);
+            match line_contents[..] {
+                ["value", value, "goes", "to", "bot", bot_id] => {
+                    values.push((parse_number(input, value)?, parse_number(input, bot_id)?))
+                }
+                [
+                    "bot",
+                    bot_id,
+                    "gives",
+                    "low",
+                    "to",
+                    low_type,
+                    low_id,
+                    "and",
+                    "high",
+                    "to",
+                    high_type,
+                    high_id,
+                ] => {
+                    let bot_id = parse_number(input, bot_id)?;
+                    let mut pass_type = |pass_type: &str, id: &str| {
+                        let id = parse_number(input, id)?;
+                        match pass_type {
+                            "bot" => Ok(PassType::Bot(id)),
+                            "output" => {
+                                let output = Out::new(id, None);
+                                if !outputs.contains(&output) {
+                                    outputs.push(output);
+                                }
+                                Ok(PassType::Out(id))
                             }
-                            PassType::Out(high_id)
+                            _ => Err(Error::invalid_input(
+                                input,
+                                pass_type,
+                                "expected a bot or an output",
+                            )),
                         }
-                        _ => panic!("Invalid input"),
                     };
+                    let low_pass_type = pass_type(low_type, low_id)?;
+                    let high_pass_type = pass_type(high_type, high_id)?;
 
                     bots.push(Bot::new(bot_id, low_pass_type, high_pass_type));
                 }
-                _ => panic!("Invalid input"),
+                _ => return Err(Error::invalid_input(input, line, "expected an instruction")),
             }
         }
 
         for value in values {
-            let bot = bots.iter_mut().find(|bot| bot.id == value.1).unwrap();
-            bot.add_value(value.0);
+            let bot = bots.iter_mut().find(|bot| bot.id == value.1).or_invalid(
+                input,
+                end(input),
+                "expected instructions for every bot",
+            )?;
+            if !bot.add_value(value.0) {
+                return Err(Error::NoSolution);
+            }
         }
 
         bots.sort_by_key(|bot| bot.id);
         outputs.sort_by_key(|out| out.id);
         let bots_len = bots.len();
-        let outputs_len = outputs.len();
 
         bots.dedup_by_key(|bot| bot.id);
-        outputs.dedup_by_key(|out| out.id);
-
-        assert_eq!(bots_len, bots.len());
-        assert_eq!(outputs_len, outputs.len());
 
-        assert_eq!(bots.len(), bots[bots.len() - 1].id as usize + 1);
-        assert_eq!(outputs.len(), outputs[outputs.len() - 1].id as usize + 1);
+        if bots_len != bots.len() {
+            return Err(Error::invalid_input(
+                input,
+                end(input),
+                "expected one instruction for every bot",
+            ));
+        }
+        if bots
+            .last()
+            .is_some_and(|bot| bots.len() != bot.id as usize + 1)
+            || outputs
+                .last()
+                .is_some_and(|out| outputs.len() != out.id as usize + 1)
+        {
+            return Err(Error::invalid_input(
+                input,
+                end(input),
+                "expected consecutive identifiers",
+            ));
+        }
+        for bot in &bots {
+            for pass_type in [bot.low, bot.high] {
+                if let PassType::Bot(id) = pass_type
+                    && id as usize >= bots.len()
+                {
+                    return Err(Error::invalid_input(
+                        input,
+                        end(input),
+                        "expected instructions for every bot",
+                    ));
+                }
+            }
+        }
 
-        Self { bots, outputs }
+        Ok(Self { bots, outputs })
     }
 
-    fn simulate(&mut self, part: u8) -> Option<u64> {
+    /// Run the bots until the bot comparing the given chips is found,
+    /// or until all chips are in the outputs if no chips are given.
+    fn simulate(&mut self, chips: Option<(u16, u16)>) -> Option<u64> {
         let mut current_bots: VecDeque<u16> = VecDeque::new();
         for bot in &self.bots {
             if bot.values() == 2 {
@@ -103,58 +165,49 @@ impl Processor {
             }
         }
 
-        while !current_bots.is_empty() {
-            let step_count = current_bots.len();
-            for _ in 0..step_count {
-                let working_bot = self.bots[current_bots.pop_front().unwrap() as usize];
-                let lower_value = working_bot
-                    .values
-                    .iter()
-                    .filter(|value| value.is_some())
-                    .min()
-                    .unwrap()
-                    .unwrap();
-                let higher_value = working_bot
-                    .values
-                    .iter()
-                    .filter(|value| value.is_some())
-                    .max()
-                    .unwrap()
-                    .unwrap();
-
-                if part == 1 && lower_value == 17 && higher_value == 61 {
-                    return Some(working_bot.id as u64);
-                }
+        while let Some(bot_id) = current_bots.pop_front() {
+            let working_bot = self.bots[bot_id as usize];
+            // only the bots holding two chips are queued
+            let Some((lower_value, higher_value)) = working_bot.chips() else {
+                continue;
+            };
 
-                match working_bot.low {
-                    PassType::Bot(id) => {
-                        self.bots[id as usize].add_value(lower_value);
-                        if self.bots[id as usize].values() == 2 {
-                            current_bots.push_back(id);
-                        }
+            if chips == Some((lower_value, higher_value)) {
+                return Some(working_bot.id as u64);
+            }
+
+            match working_bot.low {
+                PassType::Bot(id) => {
+                    if !self.bots[id as usize].add_value(lower_value) {
+                        return None;
+                    }
+                    if self.bots[id as usize].values() == 2 {
+                        current_bots.push_back(id);
                     }
-                    PassType::Out(id) => self.outputs[id as usize].value = Some(lower_value),
                 }
+                PassType::Out(id) => self.outputs[id as usize].value = Some(lower_value),
+            }
 
-                match working_bot.high {
-                    PassType::Bot(id) => {
-                        self.bots[id as usize].add_value(higher_value);
-                        if self.bots[id as usize].values() == 2 {
-                            current_bots.push_back(id);
-                        }
+            match working_bot.high {
+                PassType::Bot(id) => {
+                    if !self.bots[id as usize].add_value(higher_value) {
+                        return None;
+                    }
+                    if self.bots[id as usize].values() == 2 {
+                        current_bots.push_back(id);
                     }
-                    PassType::Out(id) => self.outputs[id as usize].value = Some(higher_value),
                 }
+                PassType::Out(id) => self.outputs[id as usize].value = Some(higher_value),
             }
         }
 
-        if part == 1 {
+        if chips.is_some() {
             None
         } else {
             let mut product = 1;
 
             for i in 0..3 {
-                match self.outputs[i].value {
+                match self.outputs.get(i)?.value {
                     Some(value) => product *= value as u64,
                     None => return None,
                 }
@@ -182,14 +235,25 @@ impl Bot {
         }
     }
 
-    fn add_value(&mut self, value: u16) {
+    /// Give a value to the bot.
+    /// Returns `false` if the bot already has two values.
+    fn add_value(&mut self, value: u16) -> bool {
         if self.values[0].is_none() {
             self.values[0] = Some(value);
         } else if self.values[1].is_none() {
             self.values[1] = Some(value);
         } else {
-            panic!("Bot {} already has two values", self.id);
+            return false;
         }
+        true
+    }
+
+    /// Get the lower and the higher chip, or `None` if the bot doesn't hold two chips.
+    fn chips(&self) -> Option<(u16, u16)> {
+        let [Some(first), Some(second)] = self.values else {
+            return None;
+        };
+        Some((first.min(second), first.max(second)))
     }
 
     fn values(&self) -> u8 {
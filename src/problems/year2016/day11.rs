@@ -1,14 +1,27 @@
-use crate::{Error, Solution};
+use crate::input::OrInvalid;
+use crate::{Answer, Error, ParsedSolution, progress};
 use regex::Regex;
 use smallvec::SmallVec;
 use std::collections::{HashMap, HashSet, VecDeque};
 
-day!(Day11, 2016, 11, "Radioisotope Thermoelectric Generators");
+day!(
+    Day11,
+    2016,
+    11,
+    "Radioisotope Thermoelectric Generators",
+    difficulty = Hard,
+    tags = [Search]
+);
 
-impl Solution for Day11 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        // get initial building state
-        let state = State::from_input(input);
+impl ParsedSolution for Day11 {
+    type Input = State;
+
+    fn parse(&self, input: &str) -> Result<State, Error> {
+        State::from_input(input)
+    }
+
+    fn part1(&self, state: &State) -> Result<Answer, Error> {
+        let state = state.clone();
 
         // setup wanted building state
         let mut wanted_state = State::new();
@@ -19,14 +32,14 @@ impl Solution for Day11 {
 
         // search for an optimal solution
         match bfs_search(state, wanted_state) {
-            Some(steps) => Ok(steps.to_string()),
+            Some(steps) => Ok(steps.into()),
             None => Err(Error::NoSolution),
         }
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        // get initial building state
-        let mut state = State::from_input(input);
+    fn part2(&self, state: &State) -> Result<Answer, Error> {
+        // add the two extra pairs to the initial building state
+        let mut state = state.clone();
         state.elements.push([0, 0]);
         state.elements.push([0, 0]);
         state.elements.sort();
@@ -40,7 +53,7 @@ impl Solution for Day11 {
 
         // search for an optimal solution
         match bfs_search(state, wanted_state) {
-            Some(steps) => Ok(steps.to_string()),
+            Some(steps) => Ok(steps.into()),
             None => Err(Error::NoSolution),
         }
     }
@@ -66,8 +79,19 @@ fn bfs_search(initial_state: State, wanted_state: State) -> Option<i32> {
     seen_states.insert(initial_state.clone());
     queue.push_back((initial_state, 0));
 
+    // the progress is the share of the floors climbed by the items of the most advanced state
+    let floors = 6 * wanted_state.elements.len();
+    let mut climbed = 0;
+    let mut depth = 0;
+
     let mut up_down_moves = Vec::with_capacity(2);
     while let Some((state, steps)) = queue.pop_front() {
+        climbed = climbed.max(state.climbed_floors());
+        if steps > depth {
+            depth = steps;
+            progress::report("searching", climbed as f64 / floors as f64);
+        }
+
         up_down_moves.clear();
         if state.elevator > 0 {
             up_down_moves.push(-1);
@@ -125,8 +149,9 @@ fn bfs_search(initial_state: State, wanted_state: State) -> Option<i32> {
     None
 }
 
+/// The floors of the building with the elevator and the microchip-generator pairs.
 #[derive(Clone, Eq, PartialEq, Hash)]
-struct State {
+pub struct State {
     elevator: u8,
     elements: SmallVec<[[u8; 2]; 10]>, // vector of elements, with [microchip, generator] (0..3 -> floor)
 }
@@ -138,8 +163,16 @@ impl State {
         }
     }
 
+    /// Count the floors climbed by all microchips and generators.
+    fn climbed_floors(&self) -> usize {
+        self.elements
+            .iter()
+            .map(|&[microchip, generator]| usize::from(microchip) + usize::from(generator))
+            .sum()
+    }
+
     /// Generate the initial building state from input
-    fn from_input(input: &str) -> Self {
+    fn from_input(input: &str) -> Result<Self, Error> {
         let mut element_id = HashMap::new();
         let mut floors = vec![vec![]; 4];
 
@@ -148,14 +181,14 @@ impl State {
             if floor_line.contains("nothing relevant") {
                 continue;
             }
-            re.split(
-                floor_line
-                    .trim_end_matches('.')
-                    .split_once(" contains ")
-                    .unwrap()
-                    .1,
-            )
-            .for_each(|element| {
+            let floor = floors
+                .get_mut(i)
+                .or_invalid(input, floor_line, "expected 4 floors")?;
+            let (_, contents) = floor_line
+                .trim_end_matches('.')
+                .split_once(" contains ")
+                .or_invalid(input, floor_line, "expected the floor contents")?;
+            re.split(contents).for_each(|element| {
                 let mut element = element.trim().trim_start_matches("a ");
                 let is_generator = if element.ends_with("generator") {
                     element = element.trim_end_matches(" generator");
@@ -166,7 +199,7 @@ impl State {
                 };
                 let len = element_id.len() as u8;
                 let id = *element_id.entry(element).or_insert(len);
-                floors[i].push((id, is_generator));
+                floor.push((id, is_generator));
             });
         }
 
@@ -184,7 +217,7 @@ impl State {
         state.elements.extend(elements);
         state.elements.sort();
 
-        state
+        Ok(state)
     }
 
     /// Check if the state is valid
@@ -1,76 +1,93 @@
-use crate::{Error, Solution};
-
-day!(Day12, 2016, 12, "Leonardo's Monorail");
+use crate::cancellation;
+use crate::input::OrInvalid;
+use crate::{Answer, Error, ParsedSolution};
+
+day!(
+    Day12,
+    2016,
+    12,
+    "Leonardo's Monorail",
+    difficulty = Easy,
+    tags = [Vm]
+);
+
+impl ParsedSolution for Day12 {
+    type Input = Cpu;
+
+    fn parse(&self, input: &str) -> Result<Cpu, Error> {
+        Cpu::new(input)
+    }
 
-impl Solution for Day12 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut cpu = Cpu::new(input);
-        cpu.execute();
+    fn part1(&self, cpu: &Cpu) -> Result<Answer, Error> {
+        let mut cpu = cpu.clone();
+        cpu.execute()?;
 
-        Ok(cpu.registers[0].to_string())
+        Ok(cpu.registers[0].into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut cpu = Cpu::new(input);
+    fn part2(&self, cpu: &Cpu) -> Result<Answer, Error> {
+        let mut cpu = cpu.clone();
         cpu.registers[2] = 1; // c = 1
-        cpu.execute();
+        cpu.execute()?;
 
-        Ok(cpu.registers[0].to_string())
+        Ok(cpu.registers[0].into())
     }
 }
 
-struct Cpu {
+/// The computer running the assembunny code.
+#[derive(Clone)]
+pub struct Cpu {
     registers: [i64; 4], // a, b, c, d
     instructions: Vec<Instruction>,
 }
 impl Cpu {
-    fn new(input: &str) -> Self {
+    fn new(input: &str) -> Result<Self, Error> {
         let instructions = input
             .trim()
             .lines()
-            .map(|line| {
-                let mut parts = line.split(' ');
-                let instruction = parts.next().unwrap();
-                let op1 = parts.next().unwrap();
-                let op2 = parts.next().unwrap_or("");
+            .map(|line| Instruction::new(input, line))
+            .collect::<Result<_, _>>()?;
 
-                Instruction::new(instruction, op1, op2)
-            })
-            .collect();
-
-        Self {
+        Ok(Self {
             registers: [0; 4],
             instructions,
-        }
+        })
     }
 
-    fn execute(&mut self) {
+    /// Run the program until it halts.
+    /// # Returns
+    /// * Nothing or the [Error].
+    /// # Errors
+    /// * [Error::NoSolution] - A register overflows.
+    /// * [Error::Cancelled] or [Error::Timeout] - The program does not halt in time.
+    fn execute(&mut self) -> Result<(), Error> {
         let mut ins_index = 0;
 
-        while ins_index < self.instructions.len() {
+        for step in 0usize.. {
+            if ins_index >= self.instructions.len() {
+                break;
+            }
+            if step % 1024 == 0 {
+                cancellation::check()?;
+            }
+
             match &self.instructions[ins_index] {
-                Instruction::Cpy(op1, op2) => {
-                    let op1_val = self.get_operand_value(op1);
-                    match op2 {
-                        Operand::Register(reg) => self.registers[*reg] = op1_val,
-                        _ => panic!("Invalid operand for cpy instruction"),
-                    }
+                Instruction::Cpy(op, reg) => {
+                    self.registers[*reg] = self.get_operand_value(op);
 
                     ins_index += 1;
                 }
-                Instruction::Inc(op) => {
-                    match op {
-                        Operand::Register(reg) => self.registers[*reg] += 1,
-                        _ => panic!("Invalid operand for inc instruction"),
-                    }
+                Instruction::Inc(reg) => {
+                    self.registers[*reg] = self.registers[*reg]
+                        .checked_add(1)
+                        .ok_or(Error::NoSolution)?;
 
                     ins_index += 1;
                 }
-                Instruction::Dec(op) => {
-                    match op {
-                        Operand::Register(reg) => self.registers[*reg] -= 1,
-                        _ => panic!("Invalid operand for dec instruction"),
-                    }
+                Instruction::Dec(reg) => {
+                    self.registers[*reg] = self.registers[*reg]
+                        .checked_sub(1)
+                        .ok_or(Error::NoSolution)?;
 
                     ins_index += 1;
                 }
@@ -80,13 +97,19 @@ impl Cpu {
                     let op1_val = self.get_operand_value(op1);
 
                     if op1_val > 0 {
-                        ins_index = (ins_index as i64 + offset) as usize;
+                        // a jump before the first instruction halts the program
+                        match usize::try_from((ins_index as i64).saturating_add(offset)) {
+                            Ok(target) => ins_index = target,
+                            Err(_) => break,
+                        }
                     } else {
                         ins_index += 1;
                     }
                 }
             }
         }
+
+        Ok(())
     }
 
     fn get_operand_value(&self, operand: &Operand) -> i64 {
@@ -97,33 +120,50 @@ impl Cpu {
     }
 }
 
+#[derive(Clone)]
 enum Instruction {
-    Cpy(Operand, Operand),
-    Inc(Operand),
-    Dec(Operand),
+    // the register instructions store the index of their register
+    Cpy(Operand, usize),
+    Inc(usize),
+    Dec(usize),
     Jnz(Operand, Operand),
 }
 impl Instruction {
-    fn new(op: &str, operand1: &str, operand2: &str) -> Self {
-        match op {
-            "cpy" => Instruction::Cpy(Operand::new(operand1), Operand::new(operand2)),
-            "inc" => Instruction::Inc(Operand::new(operand1)),
-            "dec" => Instruction::Dec(Operand::new(operand1)),
-            "jnz" => Instruction::Jnz(Operand::new(operand1), Operand::new(operand2)),
-            _ => panic!("Unknown instruction: {}", op),
+    fn new(input: &str, line: &str) -> Result<Self, Error> {
+        match line.split(' ').collect::<Vec<_>>()[..] {
+            ["cpy", op1, op2] => Ok(Instruction::Cpy(
+                Operand::new(input, op1)?,
+                parse_register(input, op2)?,
+            )),
+            ["inc", op] => Ok(Instruction::Inc(parse_register(input, op)?)),
+            ["dec", op] => Ok(Instruction::Dec(parse_register(input, op)?)),
+            ["jnz", op1, op2] => Ok(Instruction::Jnz(
+                Operand::new(input, op1)?,
+                Operand::new(input, op2)?,
+            )),
+            _ => Err(Error::invalid_input(input, line, "expected an instruction")),
         }
     }
 }
 
+#[derive(Clone)]
 enum Operand {
     Register(usize),
     Value(i64),
 }
 impl Operand {
-    fn new(op: &str) -> Self {
+    fn new(input: &str, op: &str) -> Result<Self, Error> {
         match op.parse::<i64>() {
-            Ok(value) => Operand::Value(value),
-            Err(_) => Operand::Register(op.chars().next().unwrap() as usize - 'a' as usize),
+            Ok(value) => Ok(Operand::Value(value)),
+            Err(_) => parse_register(input, op).map(Operand::Register),
         }
     }
 }
+
+/// Parse the name of a register into its index.
+fn parse_register(input: &str, op: &str) -> Result<usize, Error> {
+    ["a", "b", "c", "d"]
+        .iter()
+        .position(|&reg| reg == op)
+        .or_invalid(input, op, "expected a register")
+}
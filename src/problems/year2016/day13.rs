@@ -1,24 +1,40 @@
-use crate::{Error, Solution};
+use crate::cancellation;
+use crate::input::parse_number;
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 use std::collections::{HashMap, HashSet, VecDeque};
 
-day!(Day13, 2016, 13, "A Maze of Twisty Little Cubicles");
+day!(
+    Day13,
+    2016,
+    13,
+    "A Maze of Twisty Little Cubicles",
+    difficulty = Easy,
+    tags = [Bitwise, Grid, Search]
+);
 
 impl Solution for Day13 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let favorite_number = input.trim().parse::<usize>().unwrap();
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::integer("x", "The X coordinate of the location to reach", 31),
+            Parameter::integer("y", "The Y coordinate of the location to reach", 39),
+        ]
+    }
+
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let end = (parameters.integer("x")?, parameters.integer("y")?);
+        let favorite_number = parse_number(input, input.trim())?;
         let mut maze = Maze::new(favorite_number);
-        Ok(maze.find_path(START, END).to_string())
+        Ok(maze.find_path(START, end)?.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let favorite_number = input.trim().parse::<usize>().unwrap();
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let favorite_number = parse_number(input, input.trim())?;
         let mut maze = Maze::new(favorite_number);
-        Ok(maze.reachable_locations(START, STEP_LIMIT).to_string())
+        Ok(maze.reachable_locations(START, STEP_LIMIT).into())
     }
 }
 
 const START: (usize, usize) = (1, 1);
-const END: (usize, usize) = (31, 39);
 const STEP_LIMIT: usize = 50;
 
 // true = open, false = wall
@@ -50,24 +66,22 @@ impl Maze {
     }
 
     fn check_position(&self, position: (usize, usize)) -> bool {
-        let mut value = position.0.pow(2)
-            + 3 * position.0
-            + 2 * position.0 * position.1
-            + position.1
-            + position.1.pow(2)
-            + self.favorite_number;
-        let mut number_of_ones = 0;
-
-        while value != 0 {
-            number_of_ones += value & 1;
-            value >>= 1;
-        }
-
-        number_of_ones % 2 == 0
+        // the formula is evaluated in 128 bits, so it only overflows for locations
+        // much farther than the search can reach, which are treated as walls
+        let (x, y) = (position.0 as u128, position.1 as u128);
+        let value = x
+            .checked_mul(x)
+            .and_then(|value| value.checked_add(3 * x))
+            .and_then(|value| value.checked_add(x.checked_mul(y)?.checked_mul(2)?))
+            .and_then(|value| value.checked_add(y))
+            .and_then(|value| value.checked_add(y.checked_mul(y)?))
+            .and_then(|value| value.checked_add(self.favorite_number as u128));
+
+        value.is_some_and(|value| value.count_ones() % 2 == 0)
     }
 
     /// Returns the number of steps to get from start to end
-    fn find_path(&mut self, start: (usize, usize), end: (usize, usize)) -> usize {
+    fn find_path(&mut self, start: (usize, usize), end: (usize, usize)) -> Result<usize, Error> {
         let start = (start.0 as isize, start.1 as isize);
         let end = (end.0 as isize, end.1 as isize);
         let mut visited = HashSet::new();
@@ -77,6 +91,8 @@ impl Maze {
         to_visit.push_back(start);
 
         'outer_loop: while !to_visit.is_empty() {
+            // the maze is endless, so the end might never be found
+            cancellation::check()?;
             let step_len = to_visit.len();
 
             for _ in 0..step_len {
@@ -114,7 +130,7 @@ impl Maze {
             steps += 1;
         }
 
-        steps
+        Ok(steps)
     }
 
     /// Returns the number of locations that can be reached in the given number of steps
@@ -1,5 +1,6 @@
+use crate::cancellation;
 use crate::math::HEX_DIGITS;
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
 use md5::{Digest, Md5};
 use regex::Regex;
@@ -7,21 +8,28 @@ use std::collections::VecDeque;
 use std::fmt::Write;
 use std::sync::LazyLock;
 
-day!(Day14, 2016, 14, "One-Time Pad");
+day!(
+    Day14,
+    2016,
+    14,
+    "One-Time Pad",
+    difficulty = Medium,
+    tags = [Hashing]
+);
 
 impl Solution for Day14 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         Ok(calculate_passwords(input, |hash_in, hash_out| {
             let mut hasher = Md5::new();
             hasher.update(hash_in);
 
             hash_out.clear();
             write!(hash_out, "{:x}", hasher.finalize()).unwrap();
-        })
-        .to_string())
+        })?
+        .into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         Ok(calculate_passwords(input, |hash_in, hash_out| {
             let mut hasher = Md5::new();
             let mut temp_hash = String::with_capacity(32);
@@ -37,15 +45,15 @@ impl Solution for Day14 {
 
             hash_out.clear();
             hash_out.push_str(&temp_hash);
-        })
-        .to_string())
+        })?
+        .into())
     }
 }
 
 const PASSWORDS_TO_FIND: u8 = 64;
 const ADDITIONAL_HASHES: usize = 2016;
 
-fn calculate_passwords<T: Fn(&str, &mut String)>(input: &str, hash_fn: T) -> u64 {
+fn calculate_passwords<T: Fn(&str, &mut String)>(input: &str, hash_fn: T) -> Result<u64, Error> {
     static RE_3: LazyLock<Vec<Regex>> = LazyLock::new(|| {
         let mut re_3 = Vec::new();
         for hex_digit in HEX_DIGITS {
@@ -79,6 +87,7 @@ fn calculate_passwords<T: Fn(&str, &mut String)>(input: &str, hash_fn: T) -> u64
             .filter_map(|v| v.front())
             .any(|&val| val < last_password)
     {
+        cancellation::check()?;
         hash_in.truncate(hash_in_len);
         write!(&mut hash_in, "{i}").unwrap();
 
@@ -128,5 +137,5 @@ fn calculate_passwords<T: Fn(&str, &mut String)>(input: &str, hash_fn: T) -> u64
 
     passwords_found.sort();
     passwords_found.truncate(PASSWORDS_TO_FIND as usize);
-    passwords_found[PASSWORDS_TO_FIND as usize - 1]
+    Ok(passwords_found[PASSWORDS_TO_FIND as usize - 1])
 }
@@ -1,42 +1,77 @@
+use crate::input::parse_number;
 use crate::math::chinese_remainder_theorem;
-use crate::{Error, Solution};
-use std::cmp::Reverse;
+use crate::{Answer, Error, Solution};
 
-day!(Day15, 2016, 15, "Timing is Everything");
+day!(
+    Day15,
+    2016,
+    15,
+    "Timing is Everything",
+    difficulty = Easy,
+    tags = [NumberTheory]
+);
 
 impl Solution for Day15 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let discs = parse_input(input);
-        Ok(solve(discs).to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let discs = parse_input(input)?;
+        solve(discs.into_iter())
+            .map(Answer::from)
+            .ok_or(Error::NoSolution)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let discs = parse_input(input).chain([(11, 0)]);
-        Ok(solve(discs).to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let discs = parse_input(input)?.into_iter().chain([(11, 0)]);
+        solve(discs).map(Answer::from).ok_or(Error::NoSolution)
     }
 }
 
-fn solve(discs: impl Iterator<Item = (usize, usize)>) -> u64 {
+fn solve(discs: impl Iterator<Item = (u64, u64)>) -> Option<u64> {
     let mut congruences = Vec::new();
     for (i, disc) in discs.enumerate() {
-        let rhs_value = (-(disc.1 as i64 + i as i64 + 1)).rem_euclid(disc.0 as i64);
-        congruences.push((rhs_value as u64, disc.0 as u64));
+        // the sum is computed in 128 bits, so it cannot overflow
+        let position = (u128::from(disc.1) + i as u128 + 1) % u128::from(disc.0);
+        let rhs_value = (u128::from(disc.0) - position) % u128::from(disc.0);
+        congruences.push((rhs_value as u64, disc.0));
     }
 
-    congruences.sort_by_key(|&(_, modulus)| Reverse(modulus));
     chinese_remainder_theorem(&congruences)
 }
 
-fn parse_input(input: &str) -> impl Iterator<Item = (usize, usize)> {
-    input.lines().map(|line| {
-        let positions = line.split_whitespace().nth(3).unwrap().parse().unwrap();
-        let start = line
-            .split_whitespace()
-            .nth(11)
-            .unwrap()
-            .trim_end_matches('.')
-            .parse()
-            .unwrap();
-        (positions, start)
-    })
+fn parse_input(input: &str) -> Result<Vec<(u64, u64)>, Error> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            let [
+                "Disc",
+                _,
+                "has",
+                positions,
+                "positions;",
+                "at",
+                "time=0,",
+                "it",
+                "is",
+                "at",
+                "position",
+                start,
+            ] = words[..]
+            else {
+                return Err(Error::invalid_input(input, line, "expected a disc"));
+            };
+            let positions = match parse_number(input, positions)? {
+                0 => {
+                    return Err(Error::invalid_input(
+                        input,
+                        positions,
+                        "expected a positive number",
+                    ));
+                }
+                positions => positions,
+            };
+            let start = parse_number(input, start.trim_end_matches('.'))?;
+            Ok((positions, start))
+        })
+        .collect()
 }
@@ -1,45 +1,78 @@
-use crate::{Error, Solution};
+use crate::cancellation;
+use crate::{Answer, Error, Parameter, Parameters, Solution, progress};
 
-day!(Day16, 2016, 16, "Dragon Checksum");
+day!(
+    Day16,
+    2016,
+    16,
+    "Dragon Checksum",
+    difficulty = Easy,
+    tags = [Strings]
+);
 
-impl Solution for Day16 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut data = parse_input(input);
-        generate_data(&mut data, LEN1);
-        generate_checksum(&mut data);
+/// The largest accepted length of the disk.
+/// The data is generated with a byte per bit and can double past the length before it is cut,
+/// so this keeps it at about two hundred megabytes.
+const MAX_LENGTH: usize = 100_000_000;
 
-        Ok(pretty_str(&data))
+impl Solution for Day16 {
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::integer(
+                "length1",
+                "The length of the disk to fill in the first part",
+                272,
+            ),
+            Parameter::integer(
+                "length2",
+                "The length of the disk to fill in the second part",
+                35651584,
+            ),
+        ]
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut data = parse_input(input);
-        generate_data(&mut data, LEN2);
-        generate_checksum(&mut data);
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        fill_disk(input, parameters.integer("length1")?)
+    }
 
-        Ok(pretty_str(&data))
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        fill_disk(input, parameters.integer("length2")?)
     }
 }
 
-const LEN1: usize = 272;
-const LEN2: usize = 35651584;
+/// Fill the disk of the given length and calculate the checksum.
+fn fill_disk(input: &str, len: usize) -> Result<Answer, Error> {
+    if !(1..=MAX_LENGTH).contains(&len) {
+        return Err(Error::InvalidParameter);
+    }
+    let mut data = parse_input(input)?;
+    generate_data(&mut data, len)?;
+    generate_checksum(&mut data);
+
+    Ok(pretty_str(&data).into())
+}
 
 /// Parse the input into a vector of bools
 /// true = 1
 /// false = 0
-fn parse_input(input: &str) -> Vec<bool> {
-    input
-        .trim()
-        .chars()
-        .map(|c| match c {
-            '0' => false,
-            '1' => true,
-            _ => panic!("Invalid input"),
+fn parse_input(input: &str) -> Result<Vec<bool>, Error> {
+    let data = input.trim();
+    data.char_indices()
+        .map(|(i, c)| match c {
+            '0' => Ok(false),
+            '1' => Ok(true),
+            _ => Err(Error::invalid_input(
+                input,
+                &data[i..],
+                "expected a binary digit",
+            )),
         })
         .collect()
 }
 
-fn generate_data(data: &mut Vec<bool>, len: usize) {
+fn generate_data(data: &mut Vec<bool>, len: usize) -> Result<(), Error> {
     while data.len() < len {
+        cancellation::check()?;
         let data_len = data.len();
 
         data.reserve_exact(data_len + 1);
@@ -48,18 +81,25 @@ fn generate_data(data: &mut Vec<bool>, len: usize) {
         for i in (0..data_len).rev() {
             data.push(!data[i]);
         }
+        progress::report("generating data", data.len() as f64 / len as f64);
     }
 
     data.truncate(len);
+    Ok(())
 }
 
 fn generate_checksum(data: &mut Vec<bool>) {
-    while data.len().is_multiple_of(2) {
+    let halvings = data.len().trailing_zeros();
+    for halving in 1.. {
+        if !data.len().is_multiple_of(2) {
+            break;
+        }
         for i in 0..(data.len() / 2) {
             let orig_loc = i * 2;
             data[i] = data[orig_loc] == data[orig_loc + 1];
         }
         data.truncate(data.len() / 2);
+        progress::report("checksum", f64::from(halving) / f64::from(halvings));
     }
 }
 
@@ -1,19 +1,22 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 use md5::{Digest, Md5};
 
-day!(Day17, 2016, 17, "Two Steps Forward");
+day!(
+    Day17,
+    2016,
+    17,
+    "Two Steps Forward",
+    difficulty = Medium,
+    tags = [Grid, Hashing, Search]
+);
 
 impl Solution for Day17 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(Room::new(input).shortest_exit_path())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        Room::new(input).shortest_exit_path().map(Answer::from)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        Ok(Room::new(input)
-            .longest_exit_path()
-            .chars()
-            .count()
-            .to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        Ok(Room::new(input).longest_exit_path()?.chars().count().into())
     }
 }
 
@@ -27,7 +30,7 @@ impl Room {
         }
     }
 
-    fn shortest_exit_path(&mut self) -> String {
+    fn shortest_exit_path(&mut self) -> Result<String, Error> {
         let mut shortest_path_length = usize::MAX;
         let mut shortest_path_hash = String::new();
         let mut path_hash = self.passcode.clone();
@@ -118,15 +121,15 @@ impl Room {
         );
 
         if shortest_path_length == usize::MAX {
-            panic!("No exit path found!");
+            Err(Error::NoSolution)
         } else {
-            shortest_path_hash
+            Ok(shortest_path_hash
                 .trim_start_matches(&self.passcode)
-                .to_string()
+                .to_string())
         }
     }
 
-    fn longest_exit_path(&mut self) -> String {
+    fn longest_exit_path(&mut self) -> Result<String, Error> {
         let mut longest_path_length = 0;
         let mut longest_path_hash = String::new();
         let mut path_hash = self.passcode.clone();
@@ -218,11 +221,11 @@ impl Room {
         );
 
         if longest_path_length == 0 {
-            panic!("No exit path found!");
+            Err(Error::NoSolution)
         } else {
-            longest_path_hash
+            Ok(longest_path_hash
                 .trim_start_matches(&self.passcode)
-                .to_string()
+                .to_string())
         }
     }
 }
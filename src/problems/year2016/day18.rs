@@ -1,14 +1,30 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 
-day!(Day18, 2016, 18, "Like a Rogue");
+day!(
+    Day18,
+    2016,
+    18,
+    "Like a Rogue",
+    difficulty = Easy,
+    tags = [CellularAutomaton]
+);
 
 impl Solution for Day18 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(count_safe_tiles(ROWS1, parse_input(input)).to_string())
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::integer("rows1", "The number of rows in the first part", 40),
+            Parameter::integer("rows2", "The number of rows in the second part", 400_000),
+        ]
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        Ok(count_safe_tiles(ROWS2, parse_input(input)).to_string())
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let rows = parameters.integer("rows1")?;
+        Ok(count_safe_tiles(rows, parse_input(input)?).into())
+    }
+
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let rows = parameters.integer("rows2")?;
+        Ok(count_safe_tiles(rows, parse_input(input)?).into())
     }
 }
 
@@ -18,8 +34,6 @@ const TRAP_PATTERNS: [[bool; 3]; 4] = [
     [true, false, false],
     [false, false, true],
 ];
-const ROWS1: usize = 40;
-const ROWS2: usize = 400_000;
 
 fn count_safe_tiles(rows: usize, mut row: Vec<bool>) -> usize {
     let mut safe_count = row.iter().filter(|&&b| !b).count();
@@ -47,6 +61,13 @@ fn count_safe_tiles(rows: usize, mut row: Vec<bool>) -> usize {
     safe_count
 }
 
-fn parse_input(input: &str) -> Vec<bool> {
-    input.trim().chars().map(|c| c == '^').collect()
+fn parse_input(input: &str) -> Result<Vec<bool>, Error> {
+    let row = input.trim();
+    row.char_indices()
+        .map(|(i, c)| match c {
+            '^' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(Error::invalid_input(input, &row[i..], "expected a tile")),
+        })
+        .collect()
 }
@@ -1,16 +1,22 @@
-use crate::{Error, Solution};
+use crate::input::parse_limited;
+use crate::{Answer, Error, Solution};
 
-day!(Day19, 2016, 19, "An Elephant Named Joseph");
+day!(
+    Day19,
+    2016,
+    19,
+    "An Elephant Named Joseph",
+    difficulty = Medium,
+    tags = [NumberTheory, Simulation]
+);
+
+/// The largest accepted number of elves.
+/// The circle stores the next elf of every elf, so this keeps it under 100 megabytes.
+const MAX_ELVES: usize = 10_000_000;
 
 impl Solution for Day19 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let elves = match input.trim().parse() {
-            Ok(num) => {
-                assert!(num > 0, "Number of elves must be greater than 0");
-                num
-            }
-            Err(_) => return Err(Error::NoSolution),
-        };
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let elves = parse_input(input)?;
 
         let mut circle = Vec::with_capacity(elves);
         for i in 0..(elves - 1) {
@@ -23,7 +29,7 @@ impl Solution for Day19 {
             let next_elf = circle[current_elf];
             let new_next_elf = circle[next_elf];
             if new_next_elf == current_elf {
-                return Ok((current_elf + 1).to_string());
+                return Ok((current_elf + 1).into());
             } else {
                 circle[current_elf] = new_next_elf;
                 current_elf = new_next_elf;
@@ -31,14 +37,11 @@ impl Solution for Day19 {
         }
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let elves = match input.trim().parse() {
-            Ok(num) => {
-                assert!(num > 0, "Number of elves must be greater than 0");
-                num
-            }
-            Err(_) => return Err(Error::NoSolution),
-        };
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let elves = parse_input(input)?;
+        if elves == 1 {
+            return Ok(1.into());
+        }
 
         let mut circle = Vec::with_capacity(elves);
         for i in 0..(elves - 1) {
@@ -58,6 +61,10 @@ impl Solution for Day19 {
             circle_len -= 1;
         }
 
-        Ok((opposite_elf + 1).to_string())
+        Ok((opposite_elf + 1).into())
     }
 }
+
+fn parse_input(input: &str) -> Result<usize, Error> {
+    parse_limited(input, input.trim(), 1..=MAX_ELVES)
+}
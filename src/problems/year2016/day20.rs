@@ -1,29 +1,48 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 
-day!(Day20, 2016, 20, "Firewall Rules");
+day!(
+    Day20,
+    2016,
+    20,
+    "Firewall Rules",
+    difficulty = Medium,
+    tags = [Ranges]
+);
 
 impl Solution for Day20 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let allowed_ips = find_allowed_ips(input);
-        Ok(allowed_ips[0].0.to_string())
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::integer(
+            "max",
+            "The highest IP address",
+            u32::MAX.into(),
+        )]
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut allowed_count = 0_u32;
-        let allowed_ips = find_allowed_ips(input);
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let allowed_ips = find_allowed_ips(input, parameters.integer("max")?)?;
+        allowed_ips
+            .first()
+            .map(|allowed_ip| Answer::from(allowed_ip.0))
+            .ok_or(Error::NoSolution)
+    }
+
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let mut allowed_count = 0_u64;
+        let allowed_ips = find_allowed_ips(input, parameters.integer("max")?)?;
 
         for allowed_ip_range in allowed_ips {
-            allowed_count += allowed_ip_range.1 - allowed_ip_range.0 + 1;
+            allowed_count += u64::from(allowed_ip_range.1 - allowed_ip_range.0) + 1;
         }
 
-        Ok(allowed_count.to_string())
+        Ok(allowed_count.into())
     }
 }
 
-fn find_allowed_ips(input: &str) -> Vec<(u32, u32)> {
-    let mut allowed_ips = vec![(0, u32::MAX)];
+fn find_allowed_ips(input: &str, max: u32) -> Result<Vec<(u32, u32)>, Error> {
+    let mut allowed_ips = vec![(0, max)];
 
-    for blocked_ip in parse_input(input) {
+    for blocked_ip in parse_input(input)? {
         let mut i = 0;
         while i < allowed_ips.len() {
             let allowed_ip = allowed_ips[i];
@@ -45,14 +64,27 @@ fn find_allowed_ips(input: &str) -> Vec<(u32, u32)> {
     }
 
     allowed_ips.sort_by_key(|(start, _)| *start);
-    allowed_ips
+    Ok(allowed_ips)
 }
 
-fn parse_input(input: &str) -> impl Iterator<Item = (u32, u32)> + '_ {
-    input.lines().map(|line| {
-        let (start, end) = line.split_once('-').unwrap();
-        let start = start.parse().unwrap();
-        let end = end.parse().unwrap();
-        (start, end)
-    })
+fn parse_input(input: &str) -> Result<Vec<(u32, u32)>, Error> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (start, end) = line
+                .split_once('-')
+                .or_invalid(input, line, "expected a range")?;
+            let start = parse_number(input, start)?;
+            let end = parse_number(input, end)?;
+            if start > end {
+                return Err(Error::invalid_input(
+                    input,
+                    line,
+                    "expected a non-empty range",
+                ));
+            }
+            Ok((start, end))
+        })
+        .collect()
 }
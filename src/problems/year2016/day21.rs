@@ -1,21 +1,54 @@
-use crate::{Error, Solution};
+use crate::input::parse_number;
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 
-day!(Day21, 2016, 21, "Scrambled Letters and Hash");
+day!(
+    Day21,
+    2016,
+    21,
+    "Scrambled Letters and Hash",
+    difficulty = Medium,
+    tags = [Strings]
+);
 
 impl Solution for Day21 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let computer = PasswordComputer::new(input);
-        Ok(computer.scramble(PASSWORD))
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::text(
+                "password",
+                "The password to scramble in the first part",
+                "abcdefgh",
+            ),
+            Parameter::text(
+                "scrambled",
+                "The scrambled password to unscramble in the second part",
+                "fbgdceah",
+            ),
+        ]
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let computer = PasswordComputer::new(input);
-        Ok(computer.unscramble(SCRAMBLED_PASSWORD))
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let password = parse_password(parameters.text("password")?)?;
+        let computer = PasswordComputer::new(input, password)?;
+        Ok(computer.scramble(password).into())
+    }
+
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let password = parse_password(parameters.text("scrambled")?)?;
+        let computer = PasswordComputer::new(input, password)?;
+        computer.unscramble(password).map(Answer::from)
     }
 }
 
-const PASSWORD: &str = "abcdefgh";
-const SCRAMBLED_PASSWORD: &str = "fbgdceah";
+/// Check that a password is not empty and has no repeated letters.
+fn parse_password(password: &str) -> Result<&str, Error> {
+    let mut letters = password.chars().collect::<Vec<_>>();
+    letters.sort_unstable();
+    letters.dedup();
+    if letters.is_empty() || letters.len() != password.chars().count() {
+        return Err(Error::InvalidParameter);
+    }
+    Ok(password)
+}
 
 enum Instruction {
     SwapPosition(usize, usize),
@@ -27,28 +60,50 @@ enum Instruction {
     Move(usize, usize),
 }
 impl Instruction {
-    fn from_str(s: &str) -> Self {
-        let words: Vec<&str> = s.split_whitespace().collect();
-        match words[0] {
-            "swap" => match words[1] {
-                "position" => {
-                    Instruction::SwapPosition(words[2].parse().unwrap(), words[5].parse().unwrap())
-                }
-                "letter" => Instruction::SwapLetter(
-                    words[2].chars().next().unwrap(),
-                    words[5].chars().next().unwrap(),
-                ),
-                _ => panic!("Invalid swap instruction"),
-            },
-            "rotate" => match words[1] {
-                "left" => Instruction::RotateLeft(words[2].parse().unwrap()),
-                "right" => Instruction::RotateRight(words[2].parse().unwrap()),
-                "based" => Instruction::RotateBasedOnLetter(words[6].chars().next().unwrap()),
-                _ => panic!("Invalid rotate instruction"),
+    fn new(input: &str, line: &str, password: &str) -> Result<Self, Error> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let position = |word: &str| match parse_number(input, word)? {
+            position if position < password.chars().count() => Ok(position),
+            _ => Err(Error::invalid_input(
+                input,
+                word,
+                "expected a position in the password",
+            )),
+        };
+        let steps = |word: &str| parse_number::<usize>(input, word);
+        let letter = |word: &str| match word.parse() {
+            Ok(letter) if password.contains(letter) => Ok(letter),
+            _ => Err(Error::invalid_input(
+                input,
+                word,
+                "expected a letter in the password",
+            )),
+        };
+
+        match words[..] {
+            ["swap", "position", x, "with", "position", y] => {
+                Ok(Instruction::SwapPosition(position(x)?, position(y)?))
+            }
+            ["swap", "letter", x, "with", "letter", y] => {
+                Ok(Instruction::SwapLetter(letter(x)?, letter(y)?))
+            }
+            ["rotate", "left", x, "step" | "steps"] => Ok(Instruction::RotateLeft(steps(x)?)),
+            ["rotate", "right", x, "step" | "steps"] => Ok(Instruction::RotateRight(steps(x)?)),
+            ["rotate", "based", "on", "position", "of", "letter", x] => {
+                Ok(Instruction::RotateBasedOnLetter(letter(x)?))
+            }
+            ["reverse", "positions", x, "through", y] => match (position(x)?, position(y)?) {
+                (x, y) if x <= y => Ok(Instruction::Reverse(x, y)),
+                _ => Err(Error::invalid_input(
+                    input,
+                    line,
+                    "expected an ascending range",
+                )),
             },
-            "reverse" => Instruction::Reverse(words[2].parse().unwrap(), words[4].parse().unwrap()),
-            "move" => Instruction::Move(words[2].parse().unwrap(), words[5].parse().unwrap()),
-            _ => panic!("Invalid instruction"),
+            ["move", "position", x, "to", "position", y] => {
+                Ok(Instruction::Move(position(x)?, position(y)?))
+            }
+            _ => Err(Error::invalid_input(input, line, "expected an instruction")),
         }
     }
 }
@@ -56,9 +111,13 @@ struct PasswordComputer {
     instructions: Vec<Instruction>,
 }
 impl PasswordComputer {
-    fn new(input: &str) -> Self {
-        let instructions = input.lines().map(Instruction::from_str).collect();
-        Self { instructions }
+    fn new(input: &str, password: &str) -> Result<Self, Error> {
+        let instructions = input
+            .trim()
+            .lines()
+            .map(|line| Instruction::new(input, line, password))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { instructions })
     }
 
     fn scramble(&self, password: &str) -> String {
@@ -72,8 +131,14 @@ impl PasswordComputer {
                     let pos2 = password.iter().position(|&c| c == y).unwrap();
                     password.swap(pos1, pos2);
                 }
-                Instruction::RotateLeft(x) => password.rotate_left(x),
-                Instruction::RotateRight(x) => password.rotate_right(x),
+                Instruction::RotateLeft(x) => {
+                    let len = password.len();
+                    password.rotate_left(x % len)
+                }
+                Instruction::RotateRight(x) => {
+                    let len = password.len();
+                    password.rotate_right(x % len)
+                }
                 Instruction::RotateBasedOnLetter(x) => {
                     let pos = password.iter().position(|&c| c == x).unwrap();
                     let mut amount = pos + 1;
@@ -94,7 +159,7 @@ impl PasswordComputer {
         password.into_iter().collect()
     }
 
-    fn unscramble(&self, password: &str) -> String {
+    fn unscramble(&self, password: &str) -> Result<String, Error> {
         let mut password = password.chars().collect::<Vec<char>>();
 
         for instruction in self.instructions.iter().rev() {
@@ -105,8 +170,14 @@ impl PasswordComputer {
                     let pos2 = password.iter().position(|&c| c == y).unwrap();
                     password.swap(pos1, pos2);
                 }
-                Instruction::RotateLeft(x) => password.rotate_right(x),
-                Instruction::RotateRight(x) => password.rotate_left(x),
+                Instruction::RotateLeft(x) => {
+                    let len = password.len();
+                    password.rotate_right(x % len)
+                }
+                Instruction::RotateRight(x) => {
+                    let len = password.len();
+                    password.rotate_left(x % len)
+                }
                 Instruction::RotateBasedOnLetter(x) => {
                     let mut correct_parent_rotations = Vec::with_capacity(password.len());
                     let all_parent_rotations = (0..password.len()).map(|i| {
@@ -127,12 +198,11 @@ impl PasswordComputer {
                             correct_parent_rotations.push(parent_rotation);
                         }
                     }
-                    assert_eq!(
-                        correct_parent_rotations.len(),
-                        1,
-                        "Multiple parent rotations found, cannot unscramble!"
-                    );
-                    password = correct_parent_rotations.into_iter().next().unwrap();
+                    // the rotation can only be undone if exactly one parent rotation matches
+                    if correct_parent_rotations.len() != 1 {
+                        return Err(Error::NoSolution);
+                    }
+                    password = correct_parent_rotations.swap_remove(0);
                 }
                 Instruction::Reverse(x, y) => password[x..=y].reverse(),
                 Instruction::Move(x, y) => {
@@ -142,6 +212,6 @@ impl PasswordComputer {
             }
         }
 
-        password.into_iter().collect()
+        Ok(password.into_iter().collect())
     }
 }
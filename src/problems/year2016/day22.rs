@@ -1,15 +1,23 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 use std::collections::VecDeque;
 
-day!(Day22, 2016, 22, "Grid Computing");
+day!(
+    Day22,
+    2016,
+    22,
+    "Grid Computing",
+    difficulty = Hard,
+    tags = [Grid, Search]
+);
 
 impl Solution for Day22 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(Grid::new(input).viable_pairs().to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        Ok(Grid::new(input)?.viable_pairs().into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        Ok(Grid::new(input).move_data_fewest_steps().to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        Ok(Grid::new(input)?.move_data_fewest_steps()?.into())
     }
 }
 
@@ -17,23 +25,45 @@ struct Grid {
     nodes: Vec<Vec<Node>>,
 }
 impl Grid {
-    fn new(input: &str) -> Self {
+    fn new(input: &str) -> Result<Self, Error> {
         let mut nodes_list = Vec::new();
-        for line in input.lines().skip(2) {
-            nodes_list.push(Node::new(line));
+        for line in input.trim().lines().skip(2) {
+            nodes_list.push(Node::new(input, line)?);
         }
 
-        let max_x = nodes_list.iter().map(|node| node.x).max().unwrap();
-        let max_y = nodes_list.iter().map(|node| node.y).max().unwrap();
+        let max_x = nodes_list
+            .iter()
+            .map(|node| node.x)
+            .max()
+            .ok_or(Error::NoSolution)?;
+        let max_y = nodes_list
+            .iter()
+            .map(|node| node.y)
+            .max()
+            .ok_or(Error::NoSolution)?;
+        // the grid is only allocated if it has as many nodes as the input
+        let grid_size = max_x
+            .checked_add(1)
+            .zip(max_y.checked_add(1))
+            .and_then(|(width, height)| width.checked_mul(height));
+        if grid_size != Some(nodes_list.len()) {
+            return Err(Error::NoSolution);
+        }
 
-        let mut nodes =
-            vec![vec![Node::new("/dev/grid/node-x0-y0 0T 0T 0T"); max_x + 1]; max_y + 1];
+        let empty = Node {
+            x: 0,
+            y: 0,
+            size: 0,
+            used: 0,
+            avail: 0,
+        };
+        let mut nodes = vec![vec![empty; max_x + 1]; max_y + 1];
 
         for node in nodes_list.drain(..) {
             nodes[node.y][node.x] = node;
         }
 
-        Self { nodes }
+        Ok(Self { nodes })
     }
 
     fn viable_pairs(&self) -> usize {
@@ -51,7 +81,7 @@ impl Grid {
         pairs
     }
 
-    fn move_data_fewest_steps(&self) -> i32 {
+    fn move_data_fewest_steps(&self) -> Result<i32, Error> {
         let mut grid = vec![vec![false; self.nodes[0].len()]; self.nodes.len()];
         let mut empty_node = [0, 0];
         let data_node = [0, grid[0].len() - 1];
@@ -61,14 +91,14 @@ impl Grid {
             .nodes
             .iter()
             .flat_map(|row| row.iter())
-            .map(|node| node.size)
-            .sum::<u32>()
-            / self.nodes.iter().flat_map(|row| row.iter()).count() as u32;
+            .map(|node| u64::from(node.size))
+            .sum::<u64>()
+            / self.nodes.iter().flat_map(|row| row.iter()).count() as u64;
         for row in self.nodes.iter() {
             for node in row.iter() {
                 if node.used == 0 {
                     empty_node = [node.y, node.x];
-                } else if node.size > node_avg_size {
+                } else if u64::from(node.size) > node_avg_size {
                     grid[node.y][node.x] = true;
                 }
             }
@@ -78,9 +108,9 @@ impl Grid {
             start: [usize; 2],
             end: [usize; 2],
             grid: &[Vec<bool>],
-        ) -> Vec<[usize; 2]> {
+        ) -> Result<Vec<[usize; 2]>, Error> {
             if start == end {
-                return Vec::new();
+                return Ok(Vec::new());
             }
 
             let mut visited = grid.to_vec();
@@ -130,7 +160,7 @@ impl Grid {
             }
 
             if distances[end[0]][end[1]].is_none() {
-                panic!("No path found!");
+                return Err(Error::NoSolution);
             }
 
             let mut path = Vec::new();
@@ -165,12 +195,16 @@ impl Grid {
 
             path.reverse();
 
-            path
+            Ok(path)
         }
 
-        fn shortest_path_length(start: [usize; 2], end: [usize; 2], grid: &[Vec<bool>]) -> i32 {
+        fn shortest_path_length(
+            start: [usize; 2],
+            end: [usize; 2],
+            grid: &[Vec<bool>],
+        ) -> Result<i32, Error> {
             if start == end {
-                return 0;
+                return Ok(0);
             }
 
             let mut visited = grid.to_vec();
@@ -182,7 +216,7 @@ impl Grid {
                 if tile[0] > 0 && !visited[tile[0] - 1][tile[1]] {
                     let next_tile = [tile[0] - 1, tile[1]];
                     if next_tile == end {
-                        return steps + 1;
+                        return Ok(steps + 1);
                     }
                     visited[next_tile[0]][next_tile[1]] = true;
                     queue.push_back((next_tile, steps + 1));
@@ -190,7 +224,7 @@ impl Grid {
                 if tile[0] < grid.len() - 1 && !visited[tile[0] + 1][tile[1]] {
                     let next_tile = [tile[0] + 1, tile[1]];
                     if next_tile == end {
-                        return steps + 1;
+                        return Ok(steps + 1);
                     }
                     visited[next_tile[0]][next_tile[1]] = true;
                     queue.push_back((next_tile, steps + 1));
@@ -198,7 +232,7 @@ impl Grid {
                 if tile[1] > 0 && !visited[tile[0]][tile[1] - 1] {
                     let next_tile = [tile[0], tile[1] - 1];
                     if next_tile == end {
-                        return steps + 1;
+                        return Ok(steps + 1);
                     }
                     visited[next_tile[0]][next_tile[1]] = true;
                     queue.push_back((next_tile, steps + 1));
@@ -206,28 +240,28 @@ impl Grid {
                 if tile[1] < grid[0].len() - 1 && !visited[tile[0]][tile[1] + 1] {
                     let next_tile = [tile[0], tile[1] + 1];
                     if next_tile == end {
-                        return steps + 1;
+                        return Ok(steps + 1);
                     }
                     visited[next_tile[0]][next_tile[1]] = true;
                     queue.push_back((next_tile, steps + 1));
                 }
             }
 
-            panic!("No path found!");
+            Err(Error::NoSolution)
         }
 
         let mut steps = 0;
-        let data_path = shortest_path(data_node, [0, 0], &grid);
+        let data_path = shortest_path(data_node, [0, 0], &grid)?;
 
         for i in 1..data_path.len() {
-            steps += shortest_path_length(empty_node, data_path[i], &grid);
+            steps += shortest_path_length(empty_node, data_path[i], &grid)?;
             grid[data_path[i][0]][data_path[i][1]] = true;
             grid[data_path[i - 1][0]][data_path[i - 1][1]] = false;
             empty_node = data_path[i - 1];
             steps += 1;
         }
 
-        steps
+        Ok(steps)
     }
 }
 
@@ -240,35 +274,30 @@ struct Node {
     avail: u32,
 }
 impl Node {
-    fn new(input: &str) -> Self {
-        let mut parts = input.split_whitespace();
+    fn new(input: &str, line: &str) -> Result<Self, Error> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let [path, size, used, avail, ..] = words[..] else {
+            return Err(Error::invalid_input(input, line, "expected a node"));
+        };
 
-        let path = parts.next().unwrap();
-        let mut path_parts = path.split('-');
-        let _ = path_parts.next().unwrap();
-        let x = path_parts
-            .next()
-            .unwrap()
-            .trim_start_matches('x')
-            .parse()
-            .unwrap();
-        let y = path_parts
+        let (x, y) = path
+            .rsplit('/')
             .next()
-            .unwrap()
-            .trim_start_matches('y')
-            .parse()
-            .unwrap();
+            .and_then(|name| name.strip_prefix("node-x"))
+            .and_then(|name| name.split_once("-y"))
+            .or_invalid(input, path, "expected a node path")?;
+        let terabytes = |word: &str| {
+            word.strip_suffix('T')
+                .or_invalid(input, word, "expected a size in terabytes")
+                .and_then(|word| parse_number(input, word))
+        };
 
-        let size = parts.next().unwrap().trim_end_matches('T').parse().unwrap();
-        let used = parts.next().unwrap().trim_end_matches('T').parse().unwrap();
-        let avail = parts.next().unwrap().trim_end_matches('T').parse().unwrap();
-
-        Self {
-            x,
-            y,
-            size,
-            used,
-            avail,
-        }
+        Ok(Self {
+            x: parse_number(input, x)?,
+            y: parse_number(input, y)?,
+            size: terabytes(size)?,
+            used: terabytes(used)?,
+            avail: terabytes(avail)?,
+        })
     }
 }
@@ -1,13 +1,39 @@
-use crate::{Error, Solution};
+use crate::cancellation;
+use crate::input::OrInvalid;
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 
-day!(Day23, 2016, 23, "Safe Cracking");
+day!(
+    Day23,
+    2016,
+    23,
+    "Safe Cracking",
+    difficulty = Hard,
+    tags = [Vm]
+);
 
 impl Solution for Day23 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(Keypad::new(input).execute(INPUT1).to_string())
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::integer(
+                "input1",
+                "The number of eggs in register a in the first part",
+                7,
+            ),
+            Parameter::integer(
+                "input2",
+                "The number of eggs in register a in the second part",
+                12,
+            ),
+        ]
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        Ok(Keypad::new(input)?
+            .execute(parameters.integer("input1")?)?
+            .into())
+    }
+
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
         // after analysing the input, there is obviously one multiplication loop that runs many times
         // I optimized it manually and then wrote a method to find it in the input
         // after the loop is found, it is optimized to multiplication
@@ -26,64 +52,63 @@ impl Solution for Day23 {
         // after the loop, the multiplier and temporary registers are cleared,
         // addend register is unchanged, and destination register is set to the multiplier * (addend + destination)
         // the search for loop is repeated each time tgl instruction actually changes something
-        Ok(Keypad::new(input).execute(INPUT2).to_string())
+        Ok(Keypad::new(input)?
+            .execute(parameters.integer("input2")?)?
+            .into())
     }
 }
 
-const INPUT1: i64 = 7;
-const INPUT2: i64 = 12;
-
 struct Keypad {
     registers: [i64; 4], // a, b, c, d
     instructions: Vec<Instruction>,
-    mul_loop: Loop,
+    /// The multiplication loop of the current instructions, if they have one.
+    mul_loop: Option<Loop>,
 }
 impl Keypad {
-    fn new(input: &str) -> Self {
+    fn new(input: &str) -> Result<Self, Error> {
         let registers = [0; 4];
         let instructions = input
             .trim()
             .lines()
-            .map(|line| {
-                let mut parts = line.split(' ');
-                let instruction = parts.next().unwrap();
-                let op1 = parts.next().unwrap();
-                let op2 = parts.next().unwrap_or("");
-
-                Instruction::new(instruction, op1, op2)
-            })
-            .collect();
-        let mul_loop = Loop {
-            start: 0,
-            end: 0,
-            destination: 0,
-            multiplier: 0,
-            addend: 0,
-            clear: 0,
-        };
-
+            .map(|line| Instruction::new(input, line))
+            .collect::<Result<_, _>>()?;
         let mut keypad = Self {
             registers,
             instructions,
-            mul_loop,
+            mul_loop: None,
         };
         keypad.calculate_loop();
-        keypad
+        Ok(keypad)
     }
 
-    fn execute(&mut self, n: i64) -> i64 {
+    /// Run the program with the number of eggs in register a.
+    /// # Returns
+    /// * The value of register a after the program halts or the [Error].
+    /// # Errors
+    /// * [Error::NoSolution] - A register overflows.
+    /// * [Error::Cancelled] or [Error::Timeout] - The program does not halt in time.
+    fn execute(&mut self, n: i64) -> Result<i64, Error> {
         self.registers[0] = n;
         let mut ins_ptr = 0;
 
-        while ins_ptr < self.instructions.len() {
-            if ins_ptr == self.mul_loop.start {
-                self.registers[self.mul_loop.destination] = self.registers
-                    [self.mul_loop.multiplier]
-                    * (self.registers[self.mul_loop.addend]
-                        + self.registers[self.mul_loop.destination]);
-                self.registers[self.mul_loop.multiplier] = 0;
-                self.registers[self.mul_loop.clear] = 0;
-                ins_ptr = self.mul_loop.end;
+        for step in 0usize.. {
+            if ins_ptr >= self.instructions.len() {
+                break;
+            }
+            if step % 1024 == 0 {
+                cancellation::check()?;
+            }
+
+            if let Some(mul_loop) = &self.mul_loop
+                && ins_ptr == mul_loop.start
+            {
+                self.registers[mul_loop.destination] = self.registers[mul_loop.addend]
+                    .checked_add(self.registers[mul_loop.destination])
+                    .and_then(|sum| sum.checked_mul(self.registers[mul_loop.multiplier]))
+                    .ok_or(Error::NoSolution)?;
+                self.registers[mul_loop.multiplier] = 0;
+                self.registers[mul_loop.clear] = 0;
+                ins_ptr = mul_loop.end;
                 continue;
             }
 
@@ -96,13 +121,17 @@ impl Keypad {
                 }
                 Instruction::Inc(op) => {
                     if let Operand::Register(reg) = op {
-                        self.registers[*reg] += 1;
+                        self.registers[*reg] = self.registers[*reg]
+                            .checked_add(1)
+                            .ok_or(Error::NoSolution)?;
                     }
                     ins_ptr += 1;
                 }
                 Instruction::Dec(op) => {
                     if let Operand::Register(reg) = op {
-                        self.registers[*reg] -= 1;
+                        self.registers[*reg] = self.registers[*reg]
+                            .checked_sub(1)
+                            .ok_or(Error::NoSolution)?;
                     }
                     ins_ptr += 1;
                 }
@@ -111,14 +140,18 @@ impl Keypad {
                     let offset = self.get_operand_value(op2);
 
                     if cond_val > 0 {
-                        ins_ptr = (ins_ptr as i64 + offset) as usize;
+                        // a jump before the first instruction halts the program
+                        match usize::try_from((ins_ptr as i64).saturating_add(offset)) {
+                            Ok(target) => ins_ptr = target,
+                            Err(_) => break,
+                        }
                     } else {
                         ins_ptr += 1;
                     }
                 }
                 Instruction::Tgl(op) => {
                     let offset = self.get_operand_value(op);
-                    let target = ins_ptr as i64 + offset;
+                    let target = (ins_ptr as i64).saturating_add(offset);
 
                     if target >= 0 && target < self.instructions.len() as i64 {
                         let new_ins = match self.instructions[target as usize] {
@@ -138,7 +171,7 @@ impl Keypad {
             }
         }
 
-        self.registers[0]
+        Ok(self.registers[0])
     }
 
     fn get_operand_value(&self, operand: &Operand) -> i64 {
@@ -149,7 +182,9 @@ impl Keypad {
     }
 
     fn calculate_loop(&mut self) {
-        for i in 0..(self.instructions.len() - 5) {
+        // a toggled instruction may also break the loop
+        self.mul_loop = None;
+        for i in 0..self.instructions.len().saturating_sub(5) {
             if let Instruction::Cpy(Operand::Register(addend), Operand::Register(clear)) =
                 self.instructions[i]
                 && let Instruction::Inc(Operand::Register(destination)) = self.instructions[i + 1]
@@ -172,14 +207,14 @@ impl Keypad {
                         if mul != multiplier {
                             continue;
                         }
-                        self.mul_loop = Loop {
+                        self.mul_loop = Some(Loop {
                             start: i,
                             end: i + 6,
                             destination,
                             multiplier,
                             addend,
                             clear,
-                        };
+                        });
                         break;
                     }
                 }
@@ -195,14 +230,21 @@ enum Instruction {
     Tgl(Operand),
 }
 impl Instruction {
-    fn new(op: &str, operand1: &str, operand2: &str) -> Self {
-        match op {
-            "cpy" => Self::Cpy(Operand::new(operand1), Operand::new(operand2)),
-            "inc" => Self::Inc(Operand::new(operand1)),
-            "dec" => Self::Dec(Operand::new(operand1)),
-            "jnz" => Self::Jnz(Operand::new(operand1), Operand::new(operand2)),
-            "tgl" => Self::Tgl(Operand::new(operand1)),
-            _ => panic!("Unknown instruction: {}", op),
+    fn new(input: &str, line: &str) -> Result<Self, Error> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words[..] {
+            ["cpy", op1, op2] => Ok(Self::Cpy(
+                Operand::new(input, op1)?,
+                Operand::new(input, op2)?,
+            )),
+            ["inc", op] => Ok(Self::Inc(Operand::new(input, op)?)),
+            ["dec", op] => Ok(Self::Dec(Operand::new(input, op)?)),
+            ["jnz", op1, op2] => Ok(Self::Jnz(
+                Operand::new(input, op1)?,
+                Operand::new(input, op2)?,
+            )),
+            ["tgl", op] => Ok(Self::Tgl(Operand::new(input, op)?)),
+            _ => Err(Error::invalid_input(input, line, "expected an instruction")),
         }
     }
 }
@@ -212,10 +254,14 @@ enum Operand {
     Value(i64),
 }
 impl Operand {
-    fn new(op: &str) -> Self {
+    fn new(input: &str, op: &str) -> Result<Self, Error> {
         match op.parse::<i64>() {
-            Ok(value) => Operand::Value(value),
-            Err(_) => Operand::Register(op.chars().next().unwrap() as usize - 'a' as usize),
+            Ok(value) => Ok(Operand::Value(value)),
+            Err(_) => ["a", "b", "c", "d"]
+                .iter()
+                .position(|&reg| reg == op)
+                .map(Operand::Register)
+                .or_invalid(input, op, "expected a register"),
         }
     }
 }
@@ -1,66 +1,97 @@
 use crate::graph::{Graph, Vertex};
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 use std::collections::VecDeque;
 
-day!(Day24, 2016, 24, "Air Duct Spelunking");
+day!(
+    Day24,
+    2016,
+    24,
+    "Air Duct Spelunking",
+    difficulty = Medium,
+    tags = [Combinatorics, Graph, Grid, Search]
+);
 
 impl Solution for Day24 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         // the problem is really
         // finding a minimal hamiltonian path with one edge fixed
         // or find the minimum among minimal hamiltonian paths between 2 fixed edges
         // while the second edge is fixed once as each vertex other than start
 
-        let (numbers_locs, mut graph) = parse_input(input);
+        let (numbers_locs, mut graph) = parse_input(input)?;
 
-        Ok((0..numbers_locs.len())
+        (0..numbers_locs.len())
             .skip(1)
-            .map(|num| {
+            .filter_map(|num| {
                 graph
                     .hamiltonian_path_fixed_ends_min(Vertex::new(0), Vertex::new(num))
-                    .0
+                    .map(|(distance, _)| distance)
             })
             .min()
-            .unwrap()
-            .to_string())
+            .map(Answer::from)
+            .ok_or(Error::NoSolution)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         // this is a simpler problem than part1
         // just find a hamiltonian cycle
 
-        let (_, graph) = parse_input(input);
-        Ok(graph.hamiltonian_cycle_min().0.to_string())
+        let (_, graph) = parse_input(input)?;
+        graph
+            .hamiltonian_cycle_min()
+            .map(|(distance, _)| distance.into())
+            .ok_or(Error::NoSolution)
     }
 }
 
 /// Parse input.
 /// Returns the vector of locations of each number (index)
 /// and the graph of distances between each number.
-fn parse_input(input: &str) -> (Vec<(usize, usize)>, Graph) {
+fn parse_input(input: &str) -> Result<(Vec<(usize, usize)>, Graph), Error> {
     let mut numbers_with_loc = Vec::new();
     let mut grid = Vec::new();
-    for (i, line) in input.lines().enumerate() {
+    for (i, line) in input.trim().lines().enumerate() {
         let mut row = Vec::new();
-        for (j, c) in line.chars().enumerate() {
+        for (j, c) in line.char_indices() {
             match c {
                 '#' => row.push(true),
                 '.' => row.push(false),
-                _ => {
-                    numbers_with_loc.push((c.to_digit(10).unwrap() as usize, (i, j)));
-                    row.push(false);
-                }
+                _ => match c.to_digit(10) {
+                    Some(number) => {
+                        numbers_with_loc.push((number as usize, (i, j)));
+                        row.push(false);
+                    }
+                    None => return Err(Error::invalid_input(input, &line[j..], "expected a tile")),
+                },
             }
         }
+        if grid
+            .first()
+            .is_some_and(|first: &Vec<bool>| first.len() != row.len())
+        {
+            return Err(Error::invalid_input(
+                input,
+                line,
+                "expected a rectangular grid",
+            ));
+        }
         grid.push(row);
     }
     numbers_with_loc.sort_by_key(|num_with_loc| num_with_loc.0);
+    // the numbers must be exactly 0..n so that they can be used as vertices
+    if numbers_with_loc
+        .iter()
+        .enumerate()
+        .any(|(i, num_with_loc)| num_with_loc.0 != i)
+    {
+        return Err(Error::NoSolution);
+    }
     let numbers_locs = numbers_with_loc
         .into_iter()
         .map(|num_with_loc| num_with_loc.1)
         .collect::<Vec<_>>();
 
-    let mut graph = Graph::with_capacity(numbers_locs.last().unwrap().0 + 1);
+    let mut graph = Graph::with_capacity(numbers_locs.len());
     for i in 0..numbers_locs.len() {
         graph.add_vertex(Vertex::new(i));
     }
@@ -72,7 +103,7 @@ fn parse_input(input: &str) -> (Vec<(usize, usize)>, Graph) {
         }
     }
 
-    (numbers_locs, graph)
+    Ok((numbers_locs, graph))
 }
 
 fn find_shortest_path(grid: &[Vec<bool>], start: (usize, usize), end: (usize, usize)) -> usize {
@@ -1,9 +1,17 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, end, parse_number};
+use crate::{Answer, Error, Solution};
 
-day!(Day25, 2016, 25, "Clock Signal");
+day!(
+    Day25,
+    2016,
+    25,
+    "Clock Signal",
+    difficulty = Medium,
+    tags = [Vm]
+);
 
 impl Solution for Day25 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         // input code is meant to be analyzed by hand
         // what code does is take a value, add some constant to it (found in the input),
         // and output its binary digits starting from the least significant bit
@@ -14,29 +22,40 @@ impl Solution for Day25 {
         // we need to find the first number of the form 1010...1010
         // greater than the constant and subtract the constant from it to get the solution
 
-        let constant = input
+        let factors = input
             .lines()
             .skip(1)
             .take(2)
             .map(|line| {
-                line.split_whitespace()
-                    .nth(1)
-                    .unwrap()
-                    .parse::<usize>()
-                    .unwrap()
+                let factor = line.split_whitespace().nth(1).or_invalid(
+                    input,
+                    line,
+                    "expected a copy instruction",
+                )?;
+                parse_number::<usize>(input, factor)
             })
-            .product::<usize>();
+            .collect::<Result<Vec<_>, _>>()?;
+        if factors.len() != 2 {
+            return Err(Error::invalid_input(
+                input,
+                end(input),
+                "expected two factors",
+            ));
+        }
+        let constant = factors
+            .into_iter()
+            .try_fold(1_usize, usize::checked_mul)
+            .ok_or(Error::NoSolution)?;
 
         let mut number = 0;
         while number < constant {
-            number <<= 2;
-            number |= 0b10;
+            number = number.checked_mul(4).ok_or(Error::NoSolution)? | 0b10;
         }
 
-        Ok((number - constant).to_string())
+        Ok((number - constant).into())
     }
 
-    fn part2(&self, _input: &str) -> Result<String, Error> {
-        Ok(String::from("Advent of Code 2016 solved!"))
+    fn part2(&self, _input: &str) -> Result<Answer, Error> {
+        Ok("Advent of Code 2016 solved!".into())
     }
 }
@@ -1,14 +1,18 @@
-use crate::{Error, Solution};
+use crate::input::OrInvalid;
+use crate::{Answer, Error, Solution};
 
-day!(Day01, 2017, 1, "Inverse Captcha");
+day!(
+    Day01,
+    2017,
+    1,
+    "Inverse Captcha",
+    difficulty = Easy,
+    tags = [Strings]
+);
 
 impl Solution for Day01 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let sequence = input
-            .trim()
-            .chars()
-            .map(|c| c.to_digit(10).unwrap())
-            .collect::<Vec<_>>();
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let sequence = parse_input(input)?;
         let mut sum: usize = 0;
 
         for (i, num) in sequence.iter().enumerate() {
@@ -17,15 +21,11 @@ impl Solution for Day01 {
             }
         }
 
-        Ok(sum.to_string())
+        Ok(sum.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let sequence = input
-            .trim()
-            .chars()
-            .map(|c| c.to_digit(10).unwrap())
-            .collect::<Vec<_>>();
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let sequence = parse_input(input)?;
         let halfway = sequence.len() >> 1;
         let mut sum: usize = 0;
 
@@ -35,6 +35,17 @@ impl Solution for Day01 {
             }
         }
 
-        Ok(sum.to_string())
+        Ok(sum.into())
     }
 }
+
+fn parse_input(input: &str) -> Result<Vec<u32>, Error> {
+    let sequence = input.trim();
+    sequence
+        .char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .or_invalid(input, &sequence[i..], "expected a digit")
+        })
+        .collect()
+}
@@ -1,42 +1,55 @@
-use crate::{Error, Solution};
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
 
-day!(Day02, 2017, 2, "Corruption Checksum");
+day!(
+    Day02,
+    2017,
+    2,
+    "Corruption Checksum",
+    difficulty = Easy,
+    tags = [NumberTheory]
+);
 
 impl Solution for Day02 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(parse_input(input)
-            .iter()
-            .map(|row| row.iter().max().unwrap() - row.iter().min().unwrap())
-            .sum::<u32>()
-            .to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        checksum(
+            parse_input(input)?
+                .iter()
+                .map(|row| row.iter().max().unwrap_or(&0) - row.iter().min().unwrap_or(&0)),
+        )
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        Ok(parse_input(input)
-            .iter()
-            .map(|row| {
-                for (i, num1) in row.iter().enumerate() {
-                    for (j, num2) in row.iter().enumerate() {
-                        if i != j && num1 % num2 == 0 {
-                            return num1 / num2;
-                        }
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        checksum(parse_input(input)?.iter().map(|row| {
+            for (i, num1) in row.iter().enumerate() {
+                for (j, num2) in row.iter().enumerate() {
+                    if i != j && *num2 != 0 && num1 % num2 == 0 {
+                        return num1 / num2;
                     }
                 }
-                0
-            })
-            .sum::<u32>()
-            .to_string())
+            }
+            0
+        }))
     }
 }
 
-fn parse_input(input: &str) -> Vec<Vec<u32>> {
+/// Sum the results of the rows.
+/// # Returns
+/// * The checksum or [Error::NoSolution] if it overflows.
+fn checksum(mut rows: impl Iterator<Item = u32>) -> Result<Answer, Error> {
+    rows.try_fold(0u32, |sum, row| sum.checked_add(row))
+        .map(Answer::from)
+        .ok_or(Error::NoSolution)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<u32>>, Error> {
     input
         .trim()
         .lines()
         .map(|line| {
             line.split_whitespace()
-                .map(|num| num.parse::<u32>().unwrap())
-                .collect::<Vec<u32>>()
+                .map(|num| parse_number(input, num))
+                .collect::<Result<Vec<u32>, _>>()
         })
-        .collect::<Vec<Vec<u32>>>()
+        .collect()
 }
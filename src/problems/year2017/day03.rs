@@ -1,12 +1,33 @@
-use crate::{Error, Solution};
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
 
-day!(Day03, 2017, 3, "Spiral Memory");
+day!(
+    Day03,
+    2017,
+    3,
+    "Spiral Memory",
+    difficulty = Medium,
+    tags = [Geometry, Grid]
+);
 
 impl Solution for Day03 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let wanted = input.trim().parse::<u32>().unwrap();
-        let n = (((wanted as f64 - 1.0).sqrt() + 1.0) / 2.0).floor() as i32;
-        let mut leftover = wanted as i32 - (2 * n - 1).pow(2) - 1;
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let wanted = parse_number::<u32>(input, input.trim())?;
+        match wanted {
+            0 => {
+                return Err(Error::invalid_input(
+                    input,
+                    input.trim(),
+                    "expected a square",
+                ));
+            }
+            // the first square is the access port itself
+            1 => return Ok(0.into()),
+            _ => {}
+        }
+        // the square fits into 32 bits, so the coordinates fit into 64 bits
+        let n = (((wanted as f64 - 1.0).sqrt() + 1.0) / 2.0).floor() as i64;
+        let mut leftover = i64::from(wanted) - (2 * n - 1).pow(2) - 1;
         let mut x = n;
         let mut y = 1 - n;
 
@@ -51,13 +72,13 @@ impl Solution for Day03 {
             x += leftover;
         }
 
-        Ok((x.abs() + y.abs()).to_string())
+        Ok((x.abs() + y.abs()).into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let limit: u32 = input.trim().parse().unwrap();
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let limit = u64::from(parse_number::<u32>(input, input.trim())?);
 
-        let mut table: Vec<Vec<u32>> = vec![vec![1]];
+        let mut table: Vec<Vec<u64>> = vec![vec![1]];
         let mut coords = [0, 0];
 
         loop {
@@ -128,7 +149,7 @@ impl Solution for Day03 {
                 };
 
                 if sum > limit {
-                    return Ok(sum.to_string());
+                    return Ok(sum.into());
                 } else {
                     table[new_coords[0]][new_coords[1]] = sum;
                     if new_coords[1] == table[0].len() - 1 {
@@ -1,9 +1,16 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
-day!(Day04, 2017, 4, "High-Entropy Passphrases");
+day!(
+    Day04,
+    2017,
+    4,
+    "High-Entropy Passphrases",
+    difficulty = Easy,
+    tags = [Strings]
+);
 
 impl Solution for Day04 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         Ok(input
             .trim()
             .lines()
@@ -15,10 +22,10 @@ impl Solution for Day04 {
                 original_len == words.len()
             })
             .count()
-            .to_string())
+            .into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         Ok(input
             .trim()
             .lines()
@@ -37,6 +44,6 @@ impl Solution for Day04 {
                 original_len == words.len()
             })
             .count()
-            .to_string())
+            .into())
     }
 }
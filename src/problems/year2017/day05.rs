@@ -1,29 +1,44 @@
-use crate::{Error, Solution};
-
-day!(Day05, 2017, 5, "A Maze of Twisty Trampolines, All Alike");
+use crate::cancellation;
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
+
+day!(
+    Day05,
+    2017,
+    5,
+    "A Maze of Twisty Trampolines, All Alike",
+    difficulty = Easy,
+    tags = [Simulation]
+);
 
 impl Solution for Day05 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut instructions = parse_input(input);
-        let mut index = 0;
-        let mut steps = 0;
-
-        while index >= 0 && index < instructions.len() as i32 {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut instructions = parse_input(input)?;
+        let mut index: i64 = 0;
+        let mut steps: u64 = 0;
+
+        while index >= 0 && index < instructions.len() as i64 {
+            if steps.is_multiple_of(1024) {
+                cancellation::check()?;
+            }
             let jump = instructions[index as usize];
             instructions[index as usize] += 1;
             index += jump;
             steps += 1;
         }
 
-        Ok(steps.to_string())
+        Ok(steps.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut instructions = parse_input(input);
-        let mut index = 0;
-        let mut steps = 0;
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut instructions = parse_input(input)?;
+        let mut index: i64 = 0;
+        let mut steps: u64 = 0;
 
-        while index >= 0 && index < instructions.len() as i32 {
+        while index >= 0 && index < instructions.len() as i64 {
+            if steps.is_multiple_of(1024) {
+                cancellation::check()?;
+            }
             let jump = instructions[index as usize];
             if jump >= 3 {
                 instructions[index as usize] -= 1;
@@ -34,14 +49,15 @@ impl Solution for Day05 {
             steps += 1;
         }
 
-        Ok(steps.to_string())
+        Ok(steps.into())
     }
 }
 
-fn parse_input(input: &str) -> Vec<i32> {
+/// Parse the jumps, which are stored in 64 bits, so that changing them and jumping cannot overflow.
+fn parse_input(input: &str) -> Result<Vec<i64>, Error> {
     input
         .trim()
         .lines()
-        .map(|line| line.trim().parse::<i32>().unwrap())
+        .map(|line| parse_number::<i32>(input, line.trim()).map(i64::from))
         .collect()
 }
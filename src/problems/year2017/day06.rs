@@ -1,16 +1,28 @@
-use crate::{Error, Solution};
+use crate::cancellation;
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 use std::collections::{HashMap, HashSet};
 
-day!(Day06, 2017, 6, "Memory Reallocation");
+day!(
+    Day06,
+    2017,
+    6,
+    "Memory Reallocation",
+    difficulty = Easy,
+    tags = [Cycles, Simulation]
+);
 
 impl Solution for Day06 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut memory_banks = parse_input(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut memory_banks = parse_input(input)?;
         let mut seen_states = HashSet::new();
         let mut cycles = 0;
 
         while !seen_states.contains(&memory_banks) {
-            seen_states.insert(memory_banks);
+            if cycles % 1024 == 0 {
+                cancellation::check()?;
+            }
+            seen_states.insert(memory_banks.clone());
 
             let mut max_index = 0;
             let mut max_value = memory_banks[0];
@@ -24,7 +36,7 @@ impl Solution for Day06 {
 
             let mut blocks = max_value;
             let mut index = max_index + 1;
-            if index >= NUM_BANKS {
+            if index >= memory_banks.len() {
                 index = 0;
             }
 
@@ -32,7 +44,7 @@ impl Solution for Day06 {
                 memory_banks[index] += 1;
                 blocks -= 1;
                 index += 1;
-                if index >= NUM_BANKS {
+                if index >= memory_banks.len() {
                     index = 0;
                 }
             }
@@ -40,16 +52,19 @@ impl Solution for Day06 {
             cycles += 1;
         }
 
-        Ok(cycles.to_string())
+        Ok(cycles.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut memory_banks = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut memory_banks = parse_input(input)?;
         let mut seen_states = HashMap::new();
         let mut cycles = 0;
 
         while !seen_states.contains_key(&memory_banks) {
-            seen_states.insert(memory_banks, cycles);
+            if cycles % 1024 == 0 {
+                cancellation::check()?;
+            }
+            seen_states.insert(memory_banks.clone(), cycles);
 
             let mut max_index = 0;
             let mut max_value = memory_banks[0];
@@ -63,7 +78,7 @@ impl Solution for Day06 {
 
             let mut blocks = max_value;
             let mut index = max_index + 1;
-            if index >= NUM_BANKS {
+            if index >= memory_banks.len() {
                 index = 0;
             }
 
@@ -71,7 +86,7 @@ impl Solution for Day06 {
                 memory_banks[index] += 1;
                 blocks -= 1;
                 index += 1;
-                if index >= NUM_BANKS {
+                if index >= memory_banks.len() {
                     index = 0;
                 }
             }
@@ -79,17 +94,21 @@ impl Solution for Day06 {
             cycles += 1;
         }
 
-        Ok((cycles - seen_states.get(&memory_banks).unwrap()).to_string())
+        Ok((cycles - seen_states.get(&memory_banks).unwrap()).into())
     }
 }
 
-const NUM_BANKS: usize = 16;
-
-fn parse_input(input: &str) -> [u16; NUM_BANKS] {
-    input
+fn parse_input(input: &str) -> Result<Vec<u16>, Error> {
+    let memory_banks = input
         .split_whitespace()
-        .map(|num| num.parse::<u16>().unwrap())
-        .collect::<Vec<_>>()
-        .try_into()
-        .unwrap()
+        .map(|num| parse_number(input, num))
+        .collect::<Result<Vec<_>, _>>()?;
+    // a single bank can end up with all the blocks
+    memory_banks
+        .iter()
+        .try_fold(0_u16, |total, &blocks| total.checked_add(blocks))
+        .or_invalid(input, input.trim(), "too many blocks")?;
+    (!memory_banks.is_empty())
+        .then_some(memory_banks)
+        .or_invalid(input, input.trim(), "expected memory banks")
 }
@@ -1,15 +1,23 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 use std::collections::HashMap;
 
-day!(Day07, 2017, 7, "Recursive Circus");
+day!(
+    Day07,
+    2017,
+    7,
+    "Recursive Circus",
+    difficulty = Medium,
+    tags = [Graph]
+);
 
 impl Solution for Day07 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(Structure::new(input).root().name().to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        Ok(Structure::new(input)?.root().name().into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        Ok(Structure::new(input).fixed_weight().to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        Ok(Structure::new(input)?.fixed_weight()?.into())
     }
 }
 
@@ -42,28 +50,33 @@ struct Structure<'a> {
     root: Program<'a>,
 }
 impl<'a> Structure<'a> {
-    fn new(input: &'a str) -> Self {
+    fn new(input: &'a str) -> Result<Self, Error> {
         let mut programs_with_children = Vec::new();
 
-        for line in input.lines() {
-            match line.split_once(" -> ") {
+        for line in input.trim().lines() {
+            let (name_weight, children) = match line.split_once(" -> ") {
                 Some((name_weight, children)) => {
-                    let (name, weight) = name_weight.split_once(" (").unwrap();
-                    let weight = weight.trim_end_matches(')').parse().unwrap();
-                    programs_with_children.push((
-                        Program::new(name, weight, None),
-                        Some(children.split(", ").collect::<Vec<_>>()),
-                    ));
+                    (name_weight, Some(children.split(", ").collect::<Vec<_>>()))
                 }
-                None => {
-                    let (name, weight) = line.split_once(" (").unwrap();
-                    let weight = weight.trim_end_matches(')').parse().unwrap();
-                    programs_with_children.push((Program::new(name, weight, None), None));
-                }
-            }
+                None => (line, None),
+            };
+            let (name, weight) = name_weight
+                .split_once(" (")
+                .and_then(|(name, weight)| Some((name, weight.strip_suffix(')')?)))
+                .or_invalid(input, name_weight, "expected a name and a weight")?;
+            let weight = parse_number(input, weight)?;
+            programs_with_children.push((Program::new(name, weight, None), children));
+        }
+
+        if programs_with_children.is_empty() {
+            return Err(Error::NoSolution);
         }
 
         while programs_with_children.len() > 1 {
+            // without a program holding nothing, the programs can't form a tower
+            if programs_with_children.iter().all(|(_, c)| c.is_some()) {
+                return Err(Error::NoSolution);
+            }
             let mut i = 0;
             while i < programs_with_children.len() && programs_with_children.len() != 1 {
                 if programs_with_children[i].1.is_none() {
@@ -72,22 +85,16 @@ impl<'a> Structure<'a> {
                     let parent_ind = programs_with_children
                         .iter()
                         .position(|(_, c)| {
-                            c.is_some() && c.as_ref().unwrap().contains(&this_program_name)
+                            c.as_ref().is_some_and(|c| c.contains(&this_program_name))
                         })
-                        .unwrap();
-                    programs_with_children[parent_ind].0.add_child(this_program);
-                    programs_with_children[parent_ind]
-                        .1
-                        .as_mut()
-                        .unwrap()
-                        .retain(|&c| c != this_program_name);
-                    if programs_with_children[parent_ind]
-                        .1
-                        .as_ref()
-                        .unwrap()
-                        .is_empty()
-                    {
-                        programs_with_children[parent_ind].1 = None;
+                        .ok_or(Error::NoSolution)?;
+                    let parent = &mut programs_with_children[parent_ind];
+                    parent.0.add_child(this_program);
+                    if let Some(children) = parent.1.as_mut() {
+                        children.retain(|&c| c != this_program_name);
+                        if children.is_empty() {
+                            parent.1 = None;
+                        }
                     }
                 } else {
                     i += 1;
@@ -95,27 +102,29 @@ impl<'a> Structure<'a> {
             }
         }
 
-        assert!(programs_with_children[0].1.is_none());
+        if programs_with_children[0].1.is_some() {
+            return Err(Error::NoSolution);
+        }
 
-        Self {
+        Ok(Self {
             root: programs_with_children.remove(0).0,
-        }
+        })
     }
 
-    fn fixed_weight(&self) -> u32 {
-        Self::check_balance(&self.root).1.unwrap()
+    fn fixed_weight(&self) -> Result<u32, Error> {
+        Self::check_balance(&self.root)?.1.ok_or(Error::NoSolution)
     }
 
-    fn check_balance(program: &Program) -> (u32, Option<u32>) {
+    fn check_balance(program: &Program) -> Result<(u32, Option<u32>), Error> {
         //! Returns the total weight of the program and the fixed weight if it was found
         //! If fixed weight is present the total weight is not calculated anymore and is therefore incorrect
 
         if let Some(children) = &program.children {
             let mut weights = Vec::new();
             for child in children {
-                let (weight, fixed_weight) = Self::check_balance(child);
+                let (weight, fixed_weight) = Self::check_balance(child)?;
                 if let Some(fixed_weight) = fixed_weight {
-                    return (0, Some(fixed_weight));
+                    return Ok((0, Some(fixed_weight)));
                 }
                 weights.push(weight);
             }
@@ -128,30 +137,36 @@ impl<'a> Structure<'a> {
                 for weight in &weights {
                     *appearances.entry(weight).or_insert(0) += 1;
                 }
-                assert_eq!(
-                    appearances.len(),
-                    2,
-                    "There should be only 2 different weights"
-                );
-                let (&&unbalanced_weight, _) =
-                    appearances.iter().find(|&(_, &count)| count == 1).unwrap();
-                let (&&balanced_weight, _) =
-                    appearances.iter().find(|&(_, &count)| count != 1).unwrap();
+                // there should be only 2 different weights
+                if appearances.len() != 2 {
+                    return Err(Error::NoSolution);
+                }
+                let (&&unbalanced_weight, _) = appearances
+                    .iter()
+                    .find(|&(_, &count)| count == 1)
+                    .ok_or(Error::NoSolution)?;
+                let (&&balanced_weight, _) = appearances
+                    .iter()
+                    .find(|&(_, &count)| count != 1)
+                    .ok_or(Error::NoSolution)?;
                 let unbalanced_index = weights
                     .iter()
                     .position(|&w| w == unbalanced_weight)
-                    .unwrap();
-                let offset = balanced_weight as i32 - unbalanced_weight as i32;
+                    .ok_or(Error::NoSolution)?;
+                let offset = balanced_weight as i64 - unbalanced_weight as i64;
 
-                (
-                    0,
-                    Some((children[unbalanced_index].weight as i32 + offset) as u32),
-                )
+                let fixed_weight = u32::try_from(children[unbalanced_index].weight as i64 + offset)
+                    .map_err(|_| Error::NoSolution)?;
+                Ok((0, Some(fixed_weight)))
             } else {
-                (program.weight + weights.iter().sum::<u32>(), None)
+                let total_weight = weights
+                    .iter()
+                    .try_fold(program.weight, |total, &weight| total.checked_add(weight))
+                    .ok_or(Error::NoSolution)?;
+                Ok((total_weight, None))
             }
         } else {
-            (program.weight, None)
+            Ok((program.weight, None))
         }
     }
 
@@ -1,19 +1,27 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 use std::collections::HashMap;
 
-day!(Day08, 2017, 8, "I Heard You Like Registers");
+day!(
+    Day08,
+    2017,
+    8,
+    "I Heard You Like Registers",
+    difficulty = Easy,
+    tags = [Parsing, Vm]
+);
 
 impl Solution for Day08 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut cpu = Cpu::new(input);
-        cpu.execute();
-        Ok(cpu.largest_register_value().to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut cpu = Cpu::new(input)?;
+        cpu.execute()?;
+        Ok(cpu.largest_register_value().into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut cpu = Cpu::new(input);
-        cpu.execute();
-        Ok(cpu.largest_register_value_ever().to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut cpu = Cpu::new(input)?;
+        cpu.execute()?;
+        Ok(cpu.largest_register_value_ever().into())
     }
 }
 
@@ -24,43 +32,38 @@ struct Cpu<'a> {
     max_reg_value_now: i64,
 }
 impl<'a> Cpu<'a> {
-    fn new(input: &'a str) -> Self {
+    fn new(input: &'a str) -> Result<Self, Error> {
         let registers = HashMap::new();
         let instructions = input
+            .trim()
             .lines()
-            .map(Instruction::new)
-            .collect::<Vec<Instruction>>();
+            .map(|line| Instruction::new(input, line))
+            .collect::<Result<Vec<Instruction>, _>>()?;
 
-        Self {
+        Ok(Self {
             registers,
             instructions,
             max_reg_value_ever: 0,
             max_reg_value_now: 0,
-        }
+        })
     }
 
-    fn execute(&mut self) {
+    fn execute(&mut self) -> Result<(), Error> {
         for instruction in &self.instructions {
             let cmp_reg_val = *self.registers.entry(instruction.cmp_register).or_insert(0);
-            let cmp_result = match instruction.cmp_type {
-                ">" => cmp_reg_val > instruction.cmp_value,
-                "<" => cmp_reg_val < instruction.cmp_value,
-                ">=" => cmp_reg_val >= instruction.cmp_value,
-                "<=" => cmp_reg_val <= instruction.cmp_value,
-                "==" => cmp_reg_val == instruction.cmp_value,
-                "!=" => cmp_reg_val != instruction.cmp_value,
-                _ => panic!("Invalid comparison type"),
-            };
-            if cmp_result {
+            if (instruction.cmp)(&cmp_reg_val, &instruction.cmp_value) {
                 let reg_val = self.registers.entry(instruction.register).or_insert(0);
-                *reg_val += instruction.value;
+                *reg_val = reg_val
+                    .checked_add(instruction.value)
+                    .ok_or(Error::NoSolution)?;
                 if *reg_val > self.max_reg_value_ever {
                     self.max_reg_value_ever = *reg_val;
                 }
             }
         }
 
-        self.max_reg_value_now = *self.registers.values().max().unwrap();
+        self.max_reg_value_now = self.registers.values().copied().max().unwrap_or(0);
+        Ok(())
     }
 
     fn largest_register_value(&self) -> i64 {
@@ -75,28 +78,64 @@ struct Instruction<'a> {
     register: &'a str,
     value: i64,
     cmp_register: &'a str,
-    cmp_type: &'a str,
+    cmp: fn(&i64, &i64) -> bool,
     cmp_value: i64,
 }
 impl<'a> Instruction<'a> {
-    fn new(instruction_str: &'a str) -> Self {
+    fn new(input: &str, instruction_str: &'a str) -> Result<Self, Error> {
         let instruction_parts = instruction_str.split_whitespace().collect::<Vec<&str>>();
-        let register = instruction_parts[0];
-        let value = match instruction_parts[1] {
-            "inc" => instruction_parts[2].parse::<i64>().unwrap(),
-            "dec" => -instruction_parts[2].parse::<i64>().unwrap(),
-            _ => panic!("Invalid instruction"),
+        let [
+            register,
+            operation,
+            value,
+            "if",
+            cmp_register,
+            cmp_type,
+            cmp_value,
+        ] = instruction_parts[..]
+        else {
+            return Err(Error::invalid_input(
+                input,
+                instruction_str,
+                "expected an instruction",
+            ));
         };
-        let cmp_register = instruction_parts[4];
-        let cmp_type = instruction_parts[5];
-        let cmp_value = instruction_parts[6].parse::<i64>().unwrap();
+        let value = match operation {
+            "inc" => parse_number::<i64>(input, value)?,
+            "dec" => parse_number::<i64>(input, value)?
+                .checked_neg()
+                .or_invalid(input, value, "expected a smaller number")?,
+            _ => {
+                return Err(Error::invalid_input(
+                    input,
+                    operation,
+                    "expected inc or dec",
+                ));
+            }
+        };
+        let cmp: fn(&i64, &i64) -> bool = match cmp_type {
+            ">" => i64::gt,
+            "<" => i64::lt,
+            ">=" => i64::ge,
+            "<=" => i64::le,
+            "==" => i64::eq,
+            "!=" => i64::ne,
+            _ => {
+                return Err(Error::invalid_input(
+                    input,
+                    cmp_type,
+                    "expected a comparison",
+                ));
+            }
+        };
+        let cmp_value = parse_number(input, cmp_value)?;
 
-        Self {
+        Ok(Self {
             register,
             value,
             cmp_register,
-            cmp_type,
+            cmp,
             cmp_value,
-        }
+        })
     }
 }
@@ -1,14 +1,22 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
-day!(Day09, 2017, 9, "Stream Processing");
+day!(
+    Day09,
+    2017,
+    9,
+    "Stream Processing",
+    difficulty = Easy,
+    tags = [Parsing]
+);
 
 impl Solution for Day09 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let stream = remove_cancelled_chars(input);
 
         let mut stack = Vec::new();
         let mut garbage = false;
         for c in stream.chars() {
+            #[allow(clippy::collapsible_match)]
             match c {
                 '{' => {
                     if !garbage {
@@ -17,10 +25,12 @@ impl Solution for Day09 {
                 }
                 '}' => {
                     if !garbage {
-                        let (_, score) = stack.pop().unwrap();
+                        let Some((_, score)) = stack.pop() else {
+                            return Err(Error::NoSolution);
+                        };
                         match stack.last_mut() {
                             Some(last) => last.1 += score,
-                            None => return Ok(score.to_string()),
+                            None => return Ok(score.into()),
                         }
                     }
                 }
@@ -33,7 +43,7 @@ impl Solution for Day09 {
         Err(Error::NoSolution)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let stream = remove_cancelled_chars(input);
         let mut garbage_chars = 0;
 
@@ -56,7 +66,7 @@ impl Solution for Day09 {
             }
         }
 
-        Ok(garbage_chars.to_string())
+        Ok(garbage_chars.into())
     }
 }
 
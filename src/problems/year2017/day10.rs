@@ -1,25 +1,45 @@
-use crate::{Error, Solution};
+use crate::input::parse_number;
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 use std::fmt::Write;
 
-day!(Day10, 2017, 10, "Knot Hash");
+day!(
+    Day10,
+    2017,
+    10,
+    "Knot Hash",
+    difficulty = Medium,
+    tags = [Hashing]
+);
 
 impl Solution for Day10 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::integer(
+            "size",
+            "The number of elements in the list of the first part",
+            LIST_SIZE as i64,
+        )]
+    }
+
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let size = parameters.integer("size")?;
+        if !(2..=LIST_SIZE).contains(&size) {
+            return Err(Error::InvalidParameter);
+        }
         let lengths = input
             .trim()
             .split(',')
-            .map(|num| num.parse::<u8>().unwrap())
-            .collect::<Vec<_>>();
-        let mut knot_hash = KnotHash::new();
+            .map(|num| parse_number::<u8>(input, num))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut knot_hash = KnotHash::with_size(size);
 
         knot_hash.round(&lengths);
 
-        Ok((knot_hash.list[0] as u16 * knot_hash.list[1] as u16).to_string())
+        Ok((knot_hash.list[0] as u16 * knot_hash.list[1] as u16).into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut knot_hash = KnotHash::new();
-        Ok(knot_hash.hash(input.trim()))
+        Ok(knot_hash.hash(input.trim()).into())
     }
 }
 
@@ -28,16 +48,18 @@ const ASCII_SUFFIX: [u8; 5] = [17, 31, 73, 47, 23];
 const ROUNDS: usize = 64;
 
 pub(crate) struct KnotHash {
-    list: [u8; LIST_SIZE],
+    list: Vec<u8>,
     current_position: usize,
     skip_size: usize,
 }
 impl KnotHash {
     pub(crate) fn new() -> Self {
-        let mut list = [0; LIST_SIZE];
-        for (i, item) in list.iter_mut().enumerate() {
-            *item = i as u8;
-        }
+        Self::with_size(LIST_SIZE)
+    }
+
+    /// Create a list of a smaller size, as in the example of the puzzle.
+    fn with_size(size: usize) -> Self {
+        let list = (0..size).map(|i| i as u8).collect();
         Self {
             list,
             current_position: 0,
@@ -48,15 +70,17 @@ impl KnotHash {
     fn execute_step(&mut self, len: u8) {
         // reverse order
         let mut start = self.current_position;
-        let mut end = start + len as usize - 1; // inclusive
-        while start < end {
-            self.list.swap(start % LIST_SIZE, end % LIST_SIZE);
+        let mut end = start + len as usize; // exclusive
+        while start + 1 < end {
+            let size = self.list.len();
+            self.list.swap(start % size, (end - 1) % size);
             start += 1;
             end -= 1;
         }
 
         // move current position
-        self.current_position = (self.current_position + len as usize + self.skip_size) % LIST_SIZE;
+        self.current_position =
+            (self.current_position + len as usize + self.skip_size) % self.list.len();
 
         // increment skip size
         self.skip_size += 1;
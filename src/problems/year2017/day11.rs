@@ -1,38 +1,49 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
-day!(Day11, 2017, 11, "Hex Ed");
+day!(
+    Day11,
+    2017,
+    11,
+    "Hex Ed",
+    difficulty = Easy,
+    tags = [Geometry, Grid]
+);
 
 impl Solution for Day11 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let mut x = 0i32;
         let mut y_half = 0i32;
 
-        input
-            .trim()
-            .split(',')
-            .for_each(|direction| process_direction(direction, &mut x, &mut y_half));
+        for direction in input.trim().split(',') {
+            process_direction(input, direction, &mut x, &mut y_half)?;
+        }
 
-        Ok(distance(x, y_half).to_string())
+        Ok(distance(x, y_half).into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut max_distance = 0;
         let mut x = 0i32;
         let mut y_half = 0i32;
 
         for direction in input.trim().split(',') {
-            process_direction(direction, &mut x, &mut y_half);
+            process_direction(input, direction, &mut x, &mut y_half)?;
             let distance = distance(x, y_half);
             if distance > max_distance {
                 max_distance = distance;
             }
         }
 
-        Ok(max_distance.to_string())
+        Ok(max_distance.into())
     }
 }
 
-fn process_direction(direction: &str, x: &mut i32, y_half: &mut i32) {
+fn process_direction(
+    input: &str,
+    direction: &str,
+    x: &mut i32,
+    y_half: &mut i32,
+) -> Result<(), Error> {
     match direction {
         "n" => *y_half += 2,
         "ne" => {
@@ -52,8 +63,15 @@ fn process_direction(direction: &str, x: &mut i32, y_half: &mut i32) {
             *x -= 1;
             *y_half += 1;
         }
-        _ => panic!("Invalid direction"),
+        _ => {
+            return Err(Error::invalid_input(
+                input,
+                direction,
+                "expected a direction",
+            ));
+        }
     }
+    Ok(())
 }
 
 fn distance(x: i32, y_half: i32) -> u32 {
@@ -1,10 +1,21 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 
-day!(Day12, 2017, 12, "Digital Plumber");
+day!(
+    Day12,
+    2017,
+    12,
+    "Digital Plumber",
+    difficulty = Easy,
+    tags = [Graph]
+);
 
 impl Solution for Day12 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut programs: Vec<Program> = input.lines().map(Program::new).collect();
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut programs = parse_input(input)?;
+        if programs.is_empty() {
+            return Err(Error::NoSolution);
+        }
         let mut to_visit = vec![0];
 
         while let Some(id) = to_visit.pop() {
@@ -16,15 +27,11 @@ impl Solution for Day12 {
             }
         }
 
-        Ok(programs
-            .into_iter()
-            .filter(|p| p.visited)
-            .count()
-            .to_string())
+        Ok(programs.into_iter().filter(|p| p.visited).count().into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut programs: Vec<Program> = input.lines().map(Program::new).collect();
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut programs = parse_input(input)?;
         let mut groups = 0;
 
         let mut to_visit = Vec::new();
@@ -43,27 +50,41 @@ impl Solution for Day12 {
             }
         }
 
-        Ok(groups.to_string())
+        Ok(groups.into())
     }
 }
 
+fn parse_input(input: &str) -> Result<Vec<Program>, Error> {
+    let lines = input.trim().lines().collect::<Vec<_>>();
+    lines
+        .iter()
+        .map(|line| Program::new(input, line, lines.len()))
+        .collect()
+}
+
 struct Program {
     visited: bool,
     pipes: Vec<usize>,
 }
 impl Program {
-    fn new(input: &str) -> Self {
-        let mut parts = input.split(" <-> ");
-        parts.next().unwrap();
-        let pipes = parts
-            .next()
-            .unwrap()
+    fn new(input: &str, line: &str, programs: usize) -> Result<Self, Error> {
+        let (_, pipes) =
+            line.split_once(" <-> ")
+                .or_invalid(input, line, "expected a program and its pipes")?;
+        let pipes = pipes
             .split(", ")
-            .map(|x| x.parse().unwrap())
-            .collect();
-        Self {
+            .map(|pipe| match parse_number(input, pipe)? {
+                id if id < programs => Ok(id),
+                _ => Err(Error::invalid_input(
+                    input,
+                    pipe,
+                    "expected a known program",
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
             visited: false,
             pipes,
-        }
+        })
     }
 }
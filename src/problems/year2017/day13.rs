@@ -1,14 +1,23 @@
-use crate::{Error, Solution};
+use crate::cancellation;
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 
-day!(Day13, 2017, 13, "Packet Scanners");
+day!(
+    Day13,
+    2017,
+    13,
+    "Packet Scanners",
+    difficulty = Medium,
+    tags = [NumberTheory]
+);
 
 impl Solution for Day13 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(Firewall::new(input).severity().to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        Firewall::new(input)?.severity().map(Answer::from)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        Ok(Firewall::new(input).safe_passage().to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        Firewall::new(input)?.safe_passage().map(Answer::from)
     }
 }
 
@@ -16,29 +25,42 @@ struct Firewall {
     layers: Vec<Layer>,
 }
 impl Firewall {
-    fn new(input: &str) -> Self {
-        let layers = input.lines().map(Layer::new).collect();
-        Self { layers }
+    fn new(input: &str) -> Result<Self, Error> {
+        let layers = input
+            .trim()
+            .lines()
+            .map(|line| Layer::new(input, line))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { layers })
     }
-    fn severity(&self) -> usize {
-        self.layers.iter().fold(0, |acc, layer| {
-            let time = layer.depth;
-            if time % layer.scanner_return_period == 0 {
-                acc + layer.depth * layer.range
-            } else {
-                acc
-            }
-        })
+    fn severity(&self) -> Result<usize, Error> {
+        self.layers
+            .iter()
+            .try_fold(0usize, |acc, layer| {
+                let time = layer.depth;
+                if time % layer.scanner_return_period == 0 {
+                    acc.checked_add(layer.depth.checked_mul(layer.range)?)
+                } else {
+                    Some(acc)
+                }
+            })
+            .ok_or(Error::NoSolution)
     }
-    fn safe_passage(&self) -> usize {
+    fn safe_passage(&self) -> Result<usize, Error> {
+        // a scanner with range 1 never leaves the top, so it catches the packet every time
+        if self.layers.iter().any(|layer| layer.range == 1) {
+            return Err(Error::NoSolution);
+        }
         'outer: for delay in 0.. {
+            if delay % 1024 == 0 {
+                cancellation::check()?;
+            }
             for layer in &self.layers {
-                let time = layer.depth + delay;
-                if time % layer.scanner_return_period == 0 {
+                if delay % layer.scanner_return_period == layer.caught_delay {
                     continue 'outer;
                 }
             }
-            return delay;
+            return Ok(delay);
         }
         unreachable!("The previous loop can only end by returning a value.")
     }
@@ -48,17 +70,38 @@ struct Layer {
     depth: usize,
     range: usize,
     scanner_return_period: usize,
+    /// The remainder of the delays, modulo the period of the scanner, which get the packet caught.
+    caught_delay: usize,
 }
 impl Layer {
-    fn new(layer: &str) -> Self {
-        let (depth, range) = layer.trim().split_once(": ").unwrap();
-        let depth = depth.parse().unwrap();
-        let range = range.parse().unwrap();
-        let scanner_return_period = (range - 1) * 2;
-        Self {
+    fn new(input: &str, layer: &str) -> Result<Self, Error> {
+        let (depth, range) = layer.trim().split_once(": ").or_invalid(
+            input,
+            layer,
+            "expected a depth and a range",
+        )?;
+        let depth = parse_number(input, depth)?;
+        let range = match parse_number::<usize>(input, range)? {
+            0 => {
+                return Err(Error::invalid_input(
+                    input,
+                    range,
+                    "expected a positive range",
+                ));
+            }
+            range => range,
+        };
+        let scanner_return_period = (range - 1)
+            .checked_mul(2)
+            .or_invalid(input, layer, "range is too large")?
+            .max(1);
+        let caught_delay =
+            (scanner_return_period - depth % scanner_return_period) % scanner_return_period;
+        Ok(Self {
             depth,
             range,
             scanner_return_period,
-        }
+            caught_delay,
+        })
     }
 }
@@ -1,11 +1,18 @@
 use super::day10::KnotHash;
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 use std::collections::VecDeque;
 
-day!(Day14, 2017, 14, "Disk Defragmentation");
+day!(
+    Day14,
+    2017,
+    14,
+    "Disk Defragmentation",
+    difficulty = Medium,
+    tags = [Graph, Grid, Hashing]
+);
 
 impl Solution for Day14 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let grid = generate_grid(input.trim());
         let mut count = 0;
 
@@ -17,10 +24,10 @@ impl Solution for Day14 {
             }
         }
 
-        Ok(count.to_string())
+        Ok(count.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut grid = generate_grid(input.trim());
         let mut region_count = 0;
 
@@ -50,7 +57,7 @@ impl Solution for Day14 {
             }
         }
 
-        Ok(region_count.to_string())
+        Ok(region_count.into())
     }
 }
 
@@ -1,10 +1,18 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 
-day!(Day15, 2017, 15, "Dueling Generators");
+day!(
+    Day15,
+    2017,
+    15,
+    "Dueling Generators",
+    difficulty = Easy,
+    tags = [Bitwise, NumberTheory]
+);
 
 impl Solution for Day15 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let (mut gen_a, mut gen_b) = parse_input(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let (mut gen_a, mut gen_b) = parse_input(input)?;
         let mut matches = 0;
 
         for _ in 0..ITERATIONS_1 {
@@ -16,11 +24,11 @@ impl Solution for Day15 {
             }
         }
 
-        Ok(matches.to_string())
+        Ok(matches.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let (mut gen_a, mut gen_b) = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let (mut gen_a, mut gen_b) = parse_input(input)?;
         let mut matches = 0;
 
         for _ in 0..ITERATIONS_2 {
@@ -38,7 +46,7 @@ impl Solution for Day15 {
             }
         }
 
-        Ok(matches.to_string())
+        Ok(matches.into())
     }
 }
 
@@ -50,19 +58,37 @@ const ITERATIONS_2: u32 = 5_000_000;
 const GEN_A_MULT: u64 = 4;
 const GEN_B_MULT: u64 = 8;
 
-fn parse_input(input: &str) -> (u64, u64) {
+fn parse_input(input: &str) -> Result<(u64, u64), Error> {
     let mut a = 0;
     let mut b = 0;
 
     for line in input.trim().lines() {
+        let value =
+            line.split_whitespace()
+                .last()
+                .or_invalid(input, line, "expected a starting value")?;
+        let value = match parse_number(input, value)? {
+            value if value < MOD => value,
+            _ => {
+                return Err(Error::invalid_input(
+                    input,
+                    value,
+                    "expected a smaller starting value",
+                ));
+            }
+        };
         if line.contains('A') {
-            a = line.split_whitespace().last().unwrap().parse().unwrap();
+            a = value;
         } else if line.contains('B') {
-            b = line.split_whitespace().last().unwrap().parse().unwrap();
+            b = value;
         } else {
-            panic!("Invalid input");
+            return Err(Error::invalid_input(
+                input,
+                line,
+                "expected generator A or B",
+            ));
         }
     }
 
-    (a, b)
+    Ok((a, b))
 }
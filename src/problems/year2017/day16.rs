@@ -1,24 +1,48 @@
-use crate::{Error, Solution};
+use crate::cancellation;
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 
-day!(Day16, 2017, 16, "Permutation Promenade");
+day!(
+    Day16,
+    2017,
+    16,
+    "Permutation Promenade",
+    difficulty = Medium,
+    tags = [Cycles, Strings]
+);
 
 impl Solution for Day16 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let instructions = parse_input(input);
-        let mut programs = INITIAL_PROGRAMS;
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::integer("programs", "The number of dancing programs", 16),
+            Parameter::integer(
+                "rounds",
+                "The number of dances in the second part",
+                1_000_000_000,
+            ),
+        ]
+    }
+
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let mut programs = initial_programs(parameters)?;
+        let instructions = parse_input(input, &programs)?;
 
         dance(&mut programs, &instructions);
 
-        Ok(programs.into_iter().collect())
+        Ok(programs.into_iter().collect::<String>().into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let instructions = parse_input(input);
-        let mut programs = INITIAL_PROGRAMS;
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let mut programs = initial_programs(parameters)?;
+        let instructions = parse_input(input, &programs)?;
+        let dance_rounds: usize = parameters.integer("rounds")?;
         let mut seen = Vec::new();
 
         while !seen.contains(&programs) {
-            seen.push(programs);
+            if seen.len() % 1024 == 0 {
+                cancellation::check()?;
+            }
+            seen.push(programs.clone());
             dance(&mut programs, &instructions);
         }
 
@@ -26,14 +50,17 @@ impl Solution for Day16 {
         let cycle_len = seen.len() - cycle_start;
         let front_len = cycle_start;
 
-        let mut dances_left = DANCE_ROUNDS - front_len;
+        if dance_rounds < front_len {
+            return Ok(seen[dance_rounds].iter().collect::<String>().into());
+        }
+        let mut dances_left = dance_rounds - front_len;
         dances_left %= cycle_len;
 
         for _ in 0..dances_left {
             dance(&mut programs, &instructions);
         }
 
-        Ok(programs.into_iter().collect())
+        Ok(programs.into_iter().collect::<String>().into())
     }
 }
 
@@ -43,40 +70,56 @@ enum Instruction {
     Partner(char, char),
 }
 
-const INITIAL_PROGRAMS: [char; 16] = [
-    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p',
-];
-const DANCE_ROUNDS: usize = 1_000_000_000;
+/// Line up the programs named by the first letters of the alphabet.
+fn initial_programs(parameters: &Parameters) -> Result<Vec<char>, Error> {
+    match parameters.integer("programs")? {
+        count @ 1..=26 => Ok(('a'..='z').take(count).collect()),
+        _ => Err(Error::InvalidParameter),
+    }
+}
+
+fn parse_input(input: &str, programs: &[char]) -> Result<Vec<Instruction>, Error> {
+    let position = |s: &str| match parse_number(input, s)? {
+        position if position < programs.len() => Ok(position),
+        _ => Err(Error::invalid_input(
+            input,
+            s,
+            "expected a position of a program",
+        )),
+    };
+    let program = |s: &str| match s.parse() {
+        Ok(program) if programs.contains(&program) => Ok(program),
+        _ => Err(Error::invalid_input(input, s, "expected a program")),
+    };
 
-fn parse_input(input: &str) -> Vec<Instruction> {
     let mut instructions = Vec::new();
     for ins in input.trim().split(',') {
-        let ins_type = ins.chars().next().unwrap();
-        let ins = &ins[1..];
+        let ins_type = ins.chars().next();
+        let args = ins.get(1..).unwrap_or_default();
         match ins_type {
-            's' => instructions.push(Instruction::Spin(ins.parse().unwrap())),
-            'x' => {
-                let (a, b) = ins.split_once('/').unwrap();
-                let a: usize = a.parse().unwrap();
-                let b: usize = b.parse().unwrap();
-                instructions.push(Instruction::Exchange(a, b));
+            Some('s') => instructions.push(Instruction::Spin(parse_number(input, args)?)),
+            Some('x') => {
+                let (a, b) =
+                    args.split_once('/')
+                        .or_invalid(input, ins, "expected two positions")?;
+                instructions.push(Instruction::Exchange(position(a)?, position(b)?));
             }
-            'p' => {
-                let (a, b) = ins.split_once('/').unwrap();
-                let a = a.chars().next().unwrap();
-                let b = b.chars().next().unwrap();
-                instructions.push(Instruction::Partner(a, b));
+            Some('p') => {
+                let (a, b) =
+                    args.split_once('/')
+                        .or_invalid(input, ins, "expected two programs")?;
+                instructions.push(Instruction::Partner(program(a)?, program(b)?));
             }
-            _ => panic!("Invalid instruction"),
+            _ => return Err(Error::invalid_input(input, ins, "expected a dance move")),
         }
     }
-    instructions
+    Ok(instructions)
 }
 
 fn dance(programs: &mut [char], instructions: &[Instruction]) {
     for ins in instructions {
         match ins {
-            Instruction::Spin(n) => programs.rotate_right(*n),
+            Instruction::Spin(n) => programs.rotate_right(*n % programs.len()),
             Instruction::Exchange(i, j) => {
                 (programs[*i], programs[*j]) = (programs[*j], programs[*i])
             }
@@ -1,17 +1,18 @@
-use crate::{Error, Solution};
+use crate::cancellation;
+use crate::{Answer, Error, Solution};
 use std::collections::{HashMap, VecDeque};
 
-day!(Day18, 2017, 18, "Duet");
+day!(Day18, 2017, 18, "Duet", difficulty = Medium, tags = [Vm]);
 
 impl Solution for Day18 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut cpu = Cpu::new(input);
-        Ok(cpu.simulate().to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut cpu = Cpu::new(input)?;
+        Ok(cpu.simulate()?.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut multi_cpu = MultiCpu::new(input);
-        Ok(multi_cpu.simulate().to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut multi_cpu = MultiCpu::new(input)?;
+        Ok(multi_cpu.simulate()?.into())
     }
 }
 
@@ -20,26 +21,33 @@ struct Cpu {
     instructions: Vec<Instruction>,
 }
 impl Cpu {
-    fn new(instructions: &str) -> Self {
+    fn new(input: &str) -> Result<Self, Error> {
         let registers = HashMap::new();
 
-        let instructions = instructions
+        let instructions = input
             .trim()
             .lines()
-            .map(Instruction::new)
-            .collect::<Vec<_>>();
+            .map(|line| Instruction::new(input, line))
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Self {
+        Ok(Self {
             registers,
             instructions,
-        }
+        })
     }
 
-    fn simulate(&mut self) -> i64 {
+    fn simulate(&mut self) -> Result<i64, Error> {
         let mut ins_ptr = 0;
         let mut last_freq = 0;
 
-        while ins_ptr < self.instructions.len() {
+        for step in 0usize.. {
+            if ins_ptr >= self.instructions.len() {
+                break;
+            }
+            if step % 1024 == 0 {
+                cancellation::check()?;
+            }
+
             match &self.instructions[ins_ptr] {
                 Instruction::Snd(oper1) => {
                     last_freq = match oper1 {
@@ -64,7 +72,8 @@ impl Cpu {
                             Operand::Value(i) => *i,
                             Operand::Register(r) => *self.registers.entry(*r).or_insert(0),
                         };
-                        *self.registers.entry(*r).or_insert(0) += val_y;
+                        let reg_val = self.registers.entry(*r).or_insert(0);
+                        *reg_val = reg_val.checked_add(val_y).ok_or(Error::NoSolution)?;
                     }
                     ins_ptr += 1;
                 }
@@ -74,7 +83,8 @@ impl Cpu {
                             Operand::Value(i) => *i,
                             Operand::Register(r) => *self.registers.entry(*r).or_insert(0),
                         };
-                        *self.registers.entry(*r).or_insert(0) *= val_y;
+                        let reg_val = self.registers.entry(*r).or_insert(0);
+                        *reg_val = reg_val.checked_mul(val_y).ok_or(Error::NoSolution)?;
                     }
                     ins_ptr += 1;
                 }
@@ -84,7 +94,8 @@ impl Cpu {
                             Operand::Value(i) => *i,
                             Operand::Register(r) => *self.registers.entry(*r).or_insert(0),
                         };
-                        *self.registers.entry(*r).or_insert(0) %= val_y;
+                        let reg_val = self.registers.entry(*r).or_insert(0);
+                        *reg_val = reg_val.checked_rem(val_y).ok_or(Error::NoSolution)?;
                     }
                     ins_ptr += 1;
                 }
@@ -108,11 +119,9 @@ impl Cpu {
                             Operand::Value(i) => *i,
                             Operand::Register(r) => *self.registers.entry(*r).or_insert(0),
                         };
-                        let temp_ptr = ins_ptr as i64 + val_y;
-                        if temp_ptr < 0 {
-                            break;
-                        } else {
-                            ins_ptr = temp_ptr as usize;
+                        match usize::try_from((ins_ptr as i64).saturating_add(val_y)) {
+                            Ok(target) => ins_ptr = target,
+                            Err(_) => break,
                         }
                     } else {
                         ins_ptr += 1;
@@ -121,7 +130,7 @@ impl Cpu {
             }
         }
 
-        last_freq
+        Ok(last_freq)
     }
 }
 struct MultiCpu {
@@ -131,35 +140,38 @@ struct MultiCpu {
     instructions: Vec<Instruction>,
 }
 impl MultiCpu {
-    fn new(instructions: &str) -> Self {
+    fn new(input: &str) -> Result<Self, Error> {
         let mut registers = [HashMap::new(), HashMap::new()];
         registers[0].insert('p', 0);
         registers[1].insert('p', 1);
         let ins_ptrs = [0, 0];
         let queues = [VecDeque::new(), VecDeque::new()];
 
-        let instructions = instructions
+        let instructions = input
             .trim()
             .lines()
-            .map(Instruction::new)
-            .collect::<Vec<_>>();
+            .map(|line| Instruction::new(input, line))
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Self {
+        Ok(Self {
             registers,
             ins_ptrs,
             queues,
             instructions,
-        }
+        })
     }
 
-    fn simulate(&mut self) -> u32 {
+    fn simulate(&mut self) -> Result<u32, Error> {
         let mut prog1_sends = 0;
         let mut turn = 0;
 
         // in the start, queues are empty, but the programs are not waiting, so loop shouldn't end
         let mut prog_waiting = [false; 2];
 
-        loop {
+        for step in 0usize.. {
+            if step % 1024 == 0 {
+                cancellation::check()?;
+            }
             if prog_waiting[turn]
                 && self.queues[turn].is_empty()
                 && self.queues[1 - turn].is_empty()
@@ -167,7 +179,14 @@ impl MultiCpu {
                 break;
             }
 
-            match &self.instructions[self.ins_ptrs[turn]] {
+            let Some(instruction) = self.instructions.get(self.ins_ptrs[turn]) else {
+                // the program terminated, so it won't receive any more values
+                self.queues[turn].clear();
+                prog_waiting[turn] = true;
+                turn = 1 - turn;
+                continue;
+            };
+            match instruction {
                 Instruction::Snd(oper1) => {
                     let val = match oper1 {
                         Operand::Value(i) => *i,
@@ -195,7 +214,8 @@ impl MultiCpu {
                             Operand::Value(i) => *i,
                             Operand::Register(r) => *self.registers[turn].entry(*r).or_insert(0),
                         };
-                        *self.registers[turn].entry(*r).or_insert(0) += val_y;
+                        let reg_val = self.registers[turn].entry(*r).or_insert(0);
+                        *reg_val = reg_val.checked_add(val_y).ok_or(Error::NoSolution)?;
                     }
                     self.ins_ptrs[turn] += 1;
                 }
@@ -205,7 +225,8 @@ impl MultiCpu {
                             Operand::Value(i) => *i,
                             Operand::Register(r) => *self.registers[turn].entry(*r).or_insert(0),
                         };
-                        *self.registers[turn].entry(*r).or_insert(0) *= val_y;
+                        let reg_val = self.registers[turn].entry(*r).or_insert(0);
+                        *reg_val = reg_val.checked_mul(val_y).ok_or(Error::NoSolution)?;
                     }
                     self.ins_ptrs[turn] += 1;
                 }
@@ -215,7 +236,8 @@ impl MultiCpu {
                             Operand::Value(i) => *i,
                             Operand::Register(r) => *self.registers[turn].entry(*r).or_insert(0),
                         };
-                        *self.registers[turn].entry(*r).or_insert(0) %= val_y;
+                        let reg_val = self.registers[turn].entry(*r).or_insert(0);
+                        *reg_val = reg_val.checked_rem(val_y).ok_or(Error::NoSolution)?;
                     }
                     self.ins_ptrs[turn] += 1;
                 }
@@ -224,7 +246,7 @@ impl MultiCpu {
                         if let Operand::Register(r) = oper1 {
                             self.registers[turn].insert(*r, val);
                         } else {
-                            panic!("Rcv instruction with value operand");
+                            return Err(Error::NoSolution);
                         }
                         self.ins_ptrs[turn] += 1;
                     } else {
@@ -242,11 +264,9 @@ impl MultiCpu {
                             Operand::Value(i) => *i,
                             Operand::Register(r) => *self.registers[turn].entry(*r).or_insert(0),
                         };
-                        let temp_ptr = self.ins_ptrs[turn] as i64 + val_y;
-                        if temp_ptr < 0 {
-                            break;
-                        } else {
-                            self.ins_ptrs[turn] = temp_ptr as usize;
+                        match usize::try_from((self.ins_ptrs[turn] as i64).saturating_add(val_y)) {
+                            Ok(target) => self.ins_ptrs[turn] = target,
+                            Err(_) => break,
                         }
                     } else {
                         self.ins_ptrs[turn] += 1;
@@ -255,7 +275,7 @@ impl MultiCpu {
             }
         }
 
-        prog1_sends
+        Ok(prog1_sends)
     }
 }
 enum Instruction {
@@ -268,36 +288,19 @@ enum Instruction {
     Jgz(Operand, Operand),
 }
 impl Instruction {
-    fn new(ins: &str) -> Self {
-        let mut split = ins.split_whitespace();
-
-        let op = split.next().unwrap();
-        let arg1 = Operand::new(split.next().unwrap());
+    fn new(input: &str, ins: &str) -> Result<Self, Error> {
+        let words = ins.split_whitespace().collect::<Vec<_>>();
+        let operand = |s| Operand::new(input, s);
 
-        match op {
-            "snd" => Self::Snd(arg1),
-            "set" => {
-                let arg2 = Operand::new(split.next().unwrap());
-                Self::Set(arg1, arg2)
-            }
-            "add" => {
-                let arg2 = Operand::new(split.next().unwrap());
-                Self::Add(arg1, arg2)
-            }
-            "mul" => {
-                let arg2 = Operand::new(split.next().unwrap());
-                Self::Mul(arg1, arg2)
-            }
-            "mod" => {
-                let arg2 = Operand::new(split.next().unwrap());
-                Self::Mod(arg1, arg2)
-            }
-            "rcv" => Self::Rcv(arg1),
-            "jgz" => {
-                let arg2 = Operand::new(split.next().unwrap());
-                Self::Jgz(arg1, arg2)
-            }
-            _ => panic!("Unknown instruction"),
+        match words[..] {
+            ["snd", x] => Ok(Self::Snd(operand(x)?)),
+            ["set", x, y] => Ok(Self::Set(operand(x)?, operand(y)?)),
+            ["add", x, y] => Ok(Self::Add(operand(x)?, operand(y)?)),
+            ["mul", x, y] => Ok(Self::Mul(operand(x)?, operand(y)?)),
+            ["mod", x, y] => Ok(Self::Mod(operand(x)?, operand(y)?)),
+            ["rcv", x] => Ok(Self::Rcv(operand(x)?)),
+            ["jgz", x, y] => Ok(Self::Jgz(operand(x)?, operand(y)?)),
+            _ => Err(Error::invalid_input(input, ins, "expected an instruction")),
         }
     }
 }
@@ -306,11 +309,17 @@ enum Operand {
     Value(i64),
 }
 impl Operand {
-    fn new(s: &str) -> Self {
+    fn new(input: &str, s: &str) -> Result<Self, Error> {
         if let Ok(i) = s.parse::<i64>() {
-            Operand::Value(i)
-        } else {
-            Operand::Register(s.chars().next().unwrap())
+            return Ok(Operand::Value(i));
+        }
+        match s.parse::<char>() {
+            Ok(register) if register.is_ascii_lowercase() => Ok(Operand::Register(register)),
+            _ => Err(Error::invalid_input(
+                input,
+                s,
+                "expected a register or a value",
+            )),
         }
     }
 }
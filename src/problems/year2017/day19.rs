@@ -1,19 +1,26 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 use itertools::Itertools;
 
-day!(Day19, 2017, 19, "A Series of Tubes");
+day!(
+    Day19,
+    2017,
+    19,
+    "A Series of Tubes",
+    difficulty = Easy,
+    tags = [Grid]
+);
 
 impl Solution for Day19 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let mut grid = Grid::new(input);
-        grid.process();
-        Ok(grid.letters)
+        grid.process()?;
+        Ok(grid.letters.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut grid = Grid::new(input);
-        grid.process();
-        Ok(grid.steps.to_string())
+        grid.process()?;
+        Ok(grid.steps.into())
     }
 }
 
@@ -34,35 +41,42 @@ impl Grid {
         }
     }
 
-    fn process(&mut self) {
-        let mut position = [0, self.tubes[0].iter().position(|&c| c == '|').unwrap()];
+    fn process(&mut self) -> Result<(), Error> {
+        let start = self
+            .tubes
+            .first()
+            .and_then(|row| row.iter().position(|&c| c == '|'))
+            .ok_or(Error::NoSolution)?;
+        let mut position = [0, start];
         let mut direction = 2; // 0: up, 1: right, 2: down, 3: left
 
-        while self.tubes[position[0]][position[1]] != ' ' {
+        while self.tile(position) != ' ' {
             self.steps += 1;
-            if self.tubes[position[0]][position[1]].is_alphabetic() {
-                self.letters.push(self.tubes[position[0]][position[1]]);
-            } else if self.tubes[position[0]][position[1]] == '+' {
+            let tile = self.tile(position);
+            if tile.is_alphabetic() {
+                self.letters.push(tile);
+            } else if tile == '+' {
+                // letters can also continue the path after a turn
                 let mut connections = [false; 4]; // up, right, down, left
 
                 // up
-                if position[0] > 0 && self.tubes[position[0] - 1][position[1]] == '|' {
+                if position[0] > 0
+                    && matches!(self.tile([position[0] - 1, position[1]]), '|' | 'A'..='Z')
+                {
                     connections[0] = true;
                 }
                 // right
-                if position[1] < self.tubes[position[0]].len() - 1
-                    && self.tubes[position[0]][position[1] + 1] == '-'
-                {
+                if matches!(self.tile([position[0], position[1] + 1]), '-' | 'A'..='Z') {
                     connections[1] = true;
                 }
                 // down
-                if position[0] < self.tubes.len() - 1
-                    && self.tubes[position[0] + 1][position[1]] == '|'
-                {
+                if matches!(self.tile([position[0] + 1, position[1]]), '|' | 'A'..='Z') {
                     connections[2] = true;
                 }
                 // left
-                if position[1] > 0 && self.tubes[position[0]][position[1] - 1] == '-' {
+                if position[1] > 0
+                    && matches!(self.tile([position[0], position[1] - 1]), '-' | 'A'..='Z')
+                {
                     connections[3] = true;
                 }
 
@@ -77,7 +91,7 @@ impl Grid {
                         }
                     })
                     .next()
-                    .unwrap();
+                    .ok_or(Error::NoSolution)?;
 
                 direction = next_direction;
             }
@@ -89,20 +103,8 @@ impl Grid {
                         break;
                     }
                 }
-                1 => {
-                    if position[1] < self.tubes[position[0]].len() - 1 {
-                        position[1] += 1
-                    } else {
-                        break;
-                    }
-                }
-                2 => {
-                    if position[0] < self.tubes.len() - 1 {
-                        position[0] += 1
-                    } else {
-                        break;
-                    }
-                }
+                1 => position[1] += 1,
+                2 => position[0] += 1,
                 3 => {
                     if position[1] > 0 {
                         position[1] -= 1
@@ -113,5 +115,16 @@ impl Grid {
                 _ => unreachable!(),
             }
         }
+
+        Ok(())
+    }
+
+    /// Get the tile at the position, treating everything outside the grid as empty.
+    fn tile(&self, position: [usize; 2]) -> char {
+        self.tubes
+            .get(position[0])
+            .and_then(|row| row.get(position[1]))
+            .copied()
+            .unwrap_or(' ')
     }
 }
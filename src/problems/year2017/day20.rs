@@ -1,38 +1,42 @@
-use crate::{Error, Solution};
-
-day!(Day20, 2017, 20, "Particle Swarm");
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
+
+day!(
+    Day20,
+    2017,
+    20,
+    "Particle Swarm",
+    difficulty = Medium,
+    tags = [Geometry, Simulation]
+);
 
 impl Solution for Day20 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         // comparing particles by their (manhattan distance) acceleration, velocity, and position
         // as t goes to infinity, the particle with the lowest acceleration will be the closest to the origin
         // if there are multiple particles with the same acceleration, the one with the lowest velocity will be the closest
         // if there are multiple particles with the same acceleration and velocity, the one with the lowest position will be the closest
         // sort the particles by acceleration, velocity, and position, and return the index of the first particle
 
-        let mut particles = input
-            .lines()
-            .map(Particle::new)
+        let mut particles = parse_input(input)?
+            .into_iter()
             .enumerate()
             .collect::<Vec<_>>();
         particles.sort_by_key(|(_, particle)| {
             (
-                particle.acceleration[0].abs()
-                    + particle.acceleration[1].abs()
-                    + particle.acceleration[2].abs(),
-                particle.velocity[0].abs()
-                    + particle.velocity[1].abs()
-                    + particle.velocity[2].abs(),
-                particle.position[0].abs()
-                    + particle.position[1].abs()
-                    + particle.position[2].abs(),
+                manhattan_length(particle.acceleration),
+                manhattan_length(particle.velocity),
+                manhattan_length(particle.position),
             )
         });
-        Ok(particles[0].0.to_string())
+        particles
+            .first()
+            .map(|(i, _)| Answer::from(*i))
+            .ok_or(Error::NoSolution)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let particles = input.lines().map(Particle::new).collect::<Vec<_>>();
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let particles = parse_input(input)?;
 
         // create collision table
         // axes are particles, and cells are the time at which the particles collide
@@ -94,10 +98,23 @@ impl Solution for Day20 {
             .into_iter()
             .filter(|particle| particle.iter().any(|x| x.is_some()))
             .count()
-            .to_string())
+            .into())
     }
 }
 
+/// Get the Manhattan length of a vector, which cannot overflow for 32-bit coordinates.
+fn manhattan_length(vector: [i32; 3]) -> i64 {
+    vector.iter().map(|&x| i64::from(x).abs()).sum()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Particle>, Error> {
+    input
+        .trim()
+        .lines()
+        .map(|line| Particle::new(input, line))
+        .collect()
+}
+
 /// A particle with position, velocity, and acceleration
 struct Particle {
     position: [i32; 3],
@@ -105,48 +122,28 @@ struct Particle {
     acceleration: [i32; 3],
 }
 impl Particle {
-    /// Creates a new particle from a string
-    fn new(input: &str) -> Self {
-        let mut parts = input.split(", ");
-        let mut position = parts
-            .next()
-            .unwrap()
-            .trim_start_matches("p=<")
-            .trim_end_matches('>')
-            .split(',')
-            .map(|x| x.parse::<i32>().unwrap());
-        let mut velocity = parts
-            .next()
-            .unwrap()
-            .trim_start_matches("v=<")
-            .trim_end_matches('>')
-            .split(',')
-            .map(|x| x.parse::<i32>().unwrap());
-        let mut acceleration = parts
-            .next()
-            .unwrap()
-            .trim_start_matches("a=<")
-            .trim_end_matches('>')
-            .split(',')
-            .map(|x| x.parse::<i32>().unwrap());
-
-        Self {
-            position: [
-                position.next().unwrap(),
-                position.next().unwrap(),
-                position.next().unwrap(),
-            ],
-            velocity: [
-                velocity.next().unwrap(),
-                velocity.next().unwrap(),
-                velocity.next().unwrap(),
-            ],
-            acceleration: [
-                acceleration.next().unwrap(),
-                acceleration.next().unwrap(),
-                acceleration.next().unwrap(),
-            ],
-        }
+    /// Creates a new particle from a line of the input
+    fn new(input: &str, line: &str) -> Result<Self, Error> {
+        let vector = |part: &str, prefix: &str| -> Result<[i32; 3], Error> {
+            part.strip_prefix(prefix)
+                .and_then(|part| part.strip_suffix('>'))
+                .or_invalid(input, part, "expected a vector")?
+                .split(',')
+                .map(|x| parse_number(input, x.trim()))
+                .collect::<Result<Vec<_>, _>>()?
+                .try_into()
+                .or_invalid(input, part, "expected 3 coordinates")
+        };
+
+        let [position, velocity, acceleration] = line.split(", ").collect::<Vec<_>>()[..] else {
+            return Err(Error::invalid_input(input, line, "expected a particle"));
+        };
+
+        Ok(Self {
+            position: vector(position, "p=<")?,
+            velocity: vector(velocity, "v=<")?,
+            acceleration: vector(acceleration, "a=<")?,
+        })
     }
 
     /// Find if and when this particle collides with another particle
@@ -161,9 +158,11 @@ impl Particle {
             // b = (v1 - v2) + a
             // c = p1 - p2
 
-            let a = (self.acceleration[i] - other.acceleration[i]) as f32 / 2.0;
-            let b = (self.velocity[i] - other.velocity[i]) as f32 + a;
-            let c = (self.position[i] - other.position[i]) as f32;
+            // the differences of 32-bit coordinates fit into 64 bits
+            let a =
+                (i64::from(self.acceleration[i]) - i64::from(other.acceleration[i])) as f32 / 2.0;
+            let b = (i64::from(self.velocity[i]) - i64::from(other.velocity[i])) as f32 + a;
+            let c = (i64::from(self.position[i]) - i64::from(other.position[i])) as f32;
 
             let d = b * b - 4.0 * a * c;
 
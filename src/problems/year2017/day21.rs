@@ -1,36 +1,54 @@
-use crate::{Error, Solution};
+use crate::input::OrInvalid;
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 use std::collections::HashMap;
 
-day!(Day21, 2017, 21, "Fractal Art");
+day!(
+    Day21,
+    2017,
+    21,
+    "Fractal Art",
+    difficulty = Hard,
+    tags = [CellularAutomaton, Grid]
+);
 
 impl Solution for Day21 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let (rules_2x2, rules_3x3) = parse_rules(input);
-        let mut grid = INITIAL_GRID
-            .into_iter()
-            .map(|row| row.to_vec())
-            .collect::<Vec<_>>();
-
-        for _ in 0..5 {
-            enhance_image(&mut grid, &rules_2x2, &rules_3x3);
-        }
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::integer(
+                "iterations1",
+                "The number of iterations in the first part",
+                5,
+            ),
+            Parameter::integer(
+                "iterations2",
+                "The number of iterations in the second part",
+                18,
+            ),
+        ]
+    }
 
-        Ok(grid.iter().flatten().filter(|&&b| b).count().to_string())
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        count_pixels(input, parameters.integer("iterations1")?)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let (rules_2x2, rules_3x3) = parse_rules(input);
-        let mut grid = INITIAL_GRID
-            .into_iter()
-            .map(|row| row.to_vec())
-            .collect::<Vec<_>>();
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        count_pixels(input, parameters.integer("iterations2")?)
+    }
+}
 
-        for _ in 0..18 {
-            enhance_image(&mut grid, &rules_2x2, &rules_3x3);
-        }
+/// Enhance the image the given number of times and count the pixels which are on.
+fn count_pixels(input: &str, iterations: usize) -> Result<Answer, Error> {
+    let (rules_2x2, rules_3x3) = parse_rules(input)?;
+    let mut grid = INITIAL_GRID
+        .into_iter()
+        .map(|row| row.to_vec())
+        .collect::<Vec<_>>();
 
-        Ok(grid.iter().flatten().filter(|&&b| b).count().to_string())
+    for _ in 0..iterations {
+        enhance_image(&mut grid, &rules_2x2, &rules_3x3)?;
     }
+
+    Ok(grid.iter().flatten().filter(|&&b| b).count().into())
 }
 
 type Rules2x2 = HashMap<[[bool; 2]; 2], [[bool; 3]; 3]>;
@@ -42,28 +60,20 @@ const INITIAL_GRID: [[bool; 3]; 3] = [
     [true, true, true],
 ];
 
-fn parse_rules(input: &str) -> (Rules2x2, Rules3x3) {
+fn parse_rules(input: &str) -> Result<(Rules2x2, Rules3x3), Error> {
     let mut rules_2x2 = HashMap::with_capacity(2_usize.pow(4));
     let mut rules_2x2_candidates = Vec::new();
     let mut rules_3x3 = HashMap::with_capacity(2_usize.pow(9));
     let mut rules_3x3_candidates = Vec::new();
 
-    for line in input.lines() {
+    for line in input.trim().lines() {
         match line.chars().filter(|&c| c == '/').count() {
             3 => {
-                let (key_str, value_str) = line.split_once(" => ").unwrap();
-                let mut key = [[false; 2]; 2];
-                let mut value = [[false; 3]; 3];
-                for (i, row) in key_str.split('/').enumerate() {
-                    for (j, c) in row.chars().enumerate() {
-                        key[i][j] = c == '#';
-                    }
-                }
-                for (i, row) in value_str.split('/').enumerate() {
-                    for (j, c) in row.chars().enumerate() {
-                        value[i][j] = c == '#';
-                    }
-                }
+                let (key_str, value_str) =
+                    line.split_once(" => ")
+                        .or_invalid(input, line, "expected a rule")?;
+                let key = parse_pattern(input, key_str)?;
+                let value = parse_pattern(input, value_str)?;
 
                 let flip_horizontal = |mut field: [[bool; 2]; 2]| -> [[bool; 2]; 2] {
                     for row in field.iter_mut() {
@@ -107,19 +117,11 @@ fn parse_rules(input: &str) -> (Rules2x2, Rules3x3) {
                 rules_2x2_candidates.push((flip_vertical(rot_3), value));
             }
             5 => {
-                let (key_str, value_str) = line.split_once(" => ").unwrap();
-                let mut key = [[false; 3]; 3];
-                let mut value = [[false; 4]; 4];
-                for (i, row) in key_str.split('/').enumerate() {
-                    for (j, c) in row.chars().enumerate() {
-                        key[i][j] = c == '#';
-                    }
-                }
-                for (i, row) in value_str.split('/').enumerate() {
-                    for (j, c) in row.chars().enumerate() {
-                        value[i][j] = c == '#';
-                    }
-                }
+                let (key_str, value_str) =
+                    line.split_once(" => ")
+                        .or_invalid(input, line, "expected a rule")?;
+                let key = parse_pattern(input, key_str)?;
+                let value = parse_pattern(input, value_str)?;
 
                 let flip_hor = |mut field: [[bool; 3]; 3]| -> [[bool; 3]; 3] {
                     for row in field.iter_mut() {
@@ -164,7 +166,13 @@ fn parse_rules(input: &str) -> (Rules2x2, Rules3x3) {
                 rules_3x3_candidates.push((flip_hor(rot_3), value));
                 rules_3x3_candidates.push((flip_ver(rot_3), value));
             }
-            _ => panic!("Invalid input"),
+            _ => {
+                return Err(Error::invalid_input(
+                    input,
+                    line,
+                    "expected a 2x2 or 3x3 rule",
+                ));
+            }
         }
     }
 
@@ -175,10 +183,36 @@ fn parse_rules(input: &str) -> (Rules2x2, Rules3x3) {
         rules_3x3.entry(rule.0).or_insert(rule.1);
     }
 
-    (rules_2x2, rules_3x3)
+    Ok((rules_2x2, rules_3x3))
+}
+
+fn parse_pattern<const N: usize>(input: &str, pattern: &str) -> Result<[[bool; N]; N], Error> {
+    let mut field = [[false; N]; N];
+    let rows = pattern.split('/').collect::<Vec<_>>();
+    if rows.len() != N || rows.iter().any(|row| row.len() != N) {
+        return Err(Error::invalid_input(
+            input,
+            pattern,
+            "expected a square pattern",
+        ));
+    }
+    for (i, row) in rows.into_iter().enumerate() {
+        for (j, c) in row.char_indices() {
+            field[i][j] = match c {
+                '#' => true,
+                '.' => false,
+                _ => return Err(Error::invalid_input(input, &row[j..], "expected a pixel")),
+            };
+        }
+    }
+    Ok(field)
 }
 
-fn enhance_image(grid: &mut Vec<Vec<bool>>, rules_2x2: &Rules2x2, rules_3x3: &Rules3x3) {
+fn enhance_image(
+    grid: &mut Vec<Vec<bool>>,
+    rules_2x2: &Rules2x2,
+    rules_3x3: &Rules3x3,
+) -> Result<(), Error> {
     if grid.len().is_multiple_of(2) {
         let new_size = grid.len() / 2 * 3;
         let mut new_grid = vec![vec![false; new_size]; new_size];
@@ -189,7 +223,7 @@ fn enhance_image(grid: &mut Vec<Vec<bool>>, rules_2x2: &Rules2x2, rules_3x3: &Ru
                     [grid[x][y], grid[x][y + 1]],
                     [grid[x + 1][y], grid[x + 1][y + 1]],
                 ];
-                let value = *rules_2x2.get(&key).unwrap();
+                let value = *rules_2x2.get(&key).ok_or(Error::NoSolution)?;
 
                 for k in 0..3 {
                     for l in 0..3 {
@@ -211,7 +245,7 @@ fn enhance_image(grid: &mut Vec<Vec<bool>>, rules_2x2: &Rules2x2, rules_3x3: &Ru
                     [grid[x + 1][y], grid[x + 1][y + 1], grid[x + 1][y + 2]],
                     [grid[x + 2][y], grid[x + 2][y + 1], grid[x + 2][y + 2]],
                 ];
-                let value = *rules_3x3.get(&key).unwrap();
+                let value = *rules_3x3.get(&key).ok_or(Error::NoSolution)?;
 
                 for k in 0..4 {
                     for l in 0..4 {
@@ -225,4 +259,6 @@ fn enhance_image(grid: &mut Vec<Vec<bool>>, rules_2x2: &Rules2x2, rules_3x3: &Ru
     } else {
         panic!("Invalid grid size");
     }
+
+    Ok(())
 }
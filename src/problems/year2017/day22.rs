@@ -1,55 +1,71 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 use std::collections::{HashMap, HashSet};
 
-day!(Day22, 2017, 22, "Sporifica Virus");
+day!(
+    Day22,
+    2017,
+    22,
+    "Sporifica Virus",
+    difficulty = Medium,
+    tags = [Grid, Simulation]
+);
 
 impl Solution for Day22 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut virus = Virus::new(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut virus = Virus::new(input)?;
         for _ in 0..10_000 {
             virus.burst();
         }
-        Ok(virus.infected_count.to_string())
+        Ok(virus.infected_count.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut virus_evolved = VirusEvolved::new(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut virus_evolved = VirusEvolved::new(input)?;
         for _ in 0..10_000_000 {
             virus_evolved.burst();
         }
-        Ok(virus_evolved.infected_count.to_string())
+        Ok(virus_evolved.infected_count.into())
     }
 }
 
-struct Virus {
-    infected_nodes: HashSet<(i32, i32)>,
-    current_node: (i32, i32),
-    direction: u8, // 0 = up, 1 = right, 2 = down, 3 = left
-    infected_count: u32,
-}
-impl Virus {
-    fn new(input: &str) -> Self {
-        let mut infected = HashSet::new();
+/// Parse the infected nodes, relative to the center of the grid.
+fn parse_input(input: &str) -> Result<HashSet<(i32, i32)>, Error> {
+    let mut infected = HashSet::new();
 
-        let center = (
-            input.lines().next().unwrap().chars().count() as i32 / 2,
-            input.lines().count() as i32 / 2,
-        );
+    let center = (
+        input.lines().next().unwrap_or_default().chars().count() as i32 / 2,
+        input.lines().count() as i32 / 2,
+    );
 
-        for (y, line) in input.lines().enumerate() {
-            for (x, c) in line.chars().enumerate() {
-                if c == '#' {
+    for (y, line) in input.lines().enumerate() {
+        for (x, (i, c)) in line.char_indices().enumerate() {
+            match c {
+                '#' => {
                     infected.insert((x as i32 - center.0, y as i32 - center.1));
                 }
+                '.' => {}
+                _ => return Err(Error::invalid_input(input, &line[i..], "expected a node")),
             }
         }
+    }
+
+    Ok(infected)
+}
 
-        Self {
-            infected_nodes: infected,
+struct Virus {
+    infected_nodes: HashSet<(i32, i32)>,
+    current_node: (i32, i32),
+    direction: u8, // 0 = up, 1 = right, 2 = down, 3 = left
+    infected_count: u32,
+}
+impl Virus {
+    fn new(input: &str) -> Result<Self, Error> {
+        Ok(Self {
+            infected_nodes: parse_input(input)?,
             current_node: (0, 0),
             direction: 0,
             infected_count: 0,
-        }
+        })
     }
 
     fn burst(&mut self) {
@@ -79,31 +95,18 @@ struct VirusEvolved {
     infected_count: u32,
 }
 impl VirusEvolved {
-    fn new(input: &str) -> Self {
-        let mut affected_nodes = HashMap::new();
+    fn new(input: &str) -> Result<Self, Error> {
+        let affected_nodes = parse_input(input)?
+            .into_iter()
+            .map(|node| (node, NodeState::Infected))
+            .collect();
 
-        let center = (
-            input.lines().next().unwrap().chars().count() as i32 / 2,
-            input.lines().count() as i32 / 2,
-        );
-
-        for (y, line) in input.lines().enumerate() {
-            for (x, c) in line.chars().enumerate() {
-                if c == '#' {
-                    affected_nodes.insert(
-                        (x as i32 - center.0, y as i32 - center.1),
-                        NodeState::Infected,
-                    );
-                }
-            }
-        }
-
-        Self {
+        Ok(Self {
             affected_nodes,
             current_node: (0, 0),
             direction: 0,
             infected_count: 0,
-        }
+        })
     }
 
     fn burst(&mut self) {
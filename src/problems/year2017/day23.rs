@@ -1,15 +1,24 @@
-use crate::{Error, Solution};
+use crate::cancellation;
+use crate::input::{OrInvalid, end, parse_number};
+use crate::{Answer, Error, Solution};
 use pmath::primes::is_prime;
 
-day!(Day23, 2017, 23, "Coprocessor Conflagration");
+day!(
+    Day23,
+    2017,
+    23,
+    "Coprocessor Conflagration",
+    difficulty = Hard,
+    tags = [NumberTheory, Vm]
+);
 
 impl Solution for Day23 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut cpu = Cpu::new(input);
-        Ok(cpu.simulate().to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut cpu = Cpu::new(input)?;
+        Ok(cpu.simulate()?.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         // after analyzing the instructions in the input, it is clear that the program
         // is counting the number of composite numbers between b and c with some step.
         // b is initialized to x * y + z
@@ -20,64 +29,48 @@ impl Solution for Day23 {
         // -w is found in the 8th instruction
         // -step is found in the 31st instruction
 
-        let mut x = 0;
-        let mut y = 0;
-        let mut z = 0;
-        let mut w = 0;
-        let mut step = 0;
+        let lines = input.trim().lines().collect::<Vec<_>>();
+        let value = |line: usize| -> Result<i64, Error> {
+            let ins =
+                lines
+                    .get(line - 1)
+                    .or_invalid(input, end(input), "expected more instructions")?;
+            let value =
+                ins.split_whitespace()
+                    .last()
+                    .or_invalid(input, ins, "expected an instruction")?;
+            parse_number(input, value)
+        };
 
-        for (i, ins) in input.trim().lines().enumerate() {
-            match i + 1 {
-                1 => {
-                    x = ins
-                        .split_whitespace()
-                        .last()
-                        .unwrap()
-                        .parse::<i64>()
-                        .unwrap()
-                }
-                5 => {
-                    y = ins
-                        .split_whitespace()
-                        .last()
-                        .unwrap()
-                        .parse::<i64>()
-                        .unwrap()
-                }
-                6 => {
-                    z = -ins
-                        .split_whitespace()
-                        .last()
-                        .unwrap()
-                        .parse::<i64>()
-                        .unwrap()
-                }
-                8 => {
-                    w = -ins
-                        .split_whitespace()
-                        .last()
-                        .unwrap()
-                        .parse::<i64>()
-                        .unwrap()
-                }
-                31 => {
-                    step = -ins
-                        .split_whitespace()
-                        .last()
-                        .unwrap()
-                        .parse::<i64>()
-                        .unwrap()
-                }
-                _ => {}
+        let negated = |line: usize| -> Result<i64, Error> {
+            value(line)?.checked_neg().ok_or(Error::NoSolution)
+        };
+
+        let x = value(1)?;
+        let y = value(5)?;
+        let z = negated(6)?;
+        let w = negated(8)?;
+        let step = negated(31)?;
+
+        if step <= 0 {
+            return Err(Error::NoSolution);
+        }
+        let b = x
+            .checked_mul(y)
+            .and_then(|b| b.checked_add(z))
+            .filter(|&b| b >= 0)
+            .ok_or(Error::NoSolution)?;
+        let c = b.checked_add(w).ok_or(Error::NoSolution)?;
+        let mut composites = 0;
+        for (i, number) in ((b as u64)..=(c as u64)).step_by(step as usize).enumerate() {
+            if i % 1024 == 0 {
+                cancellation::check()?;
+            }
+            if !is_prime(number).0 {
+                composites += 1;
             }
         }
-
-        let b = x * y + z;
-        Ok(((b as u64)..=((b + w) as u64))
-            .step_by(step as usize)
-            .filter(|&i| !is_prime(i).0)
-            .count()
-            .to_string())
+        Ok(composites.into())
     }
 }
 
@@ -86,19 +79,19 @@ struct Cpu<'a> {
     instructions: Vec<Instruction<'a>>,
 }
 impl<'a> Cpu<'a> {
-    fn new(instructions: &'a str) -> Self {
+    fn new(input: &'a str) -> Result<Self, Error> {
         let registers = [0; 8];
 
-        let instructions = instructions
+        let instructions = input
             .trim()
             .lines()
-            .map(Instruction::new)
-            .collect::<Vec<_>>();
+            .map(|line| Instruction::new(input, line))
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Self {
+        Ok(Self {
             registers,
             instructions,
-        }
+        })
     }
 
     fn get_reg(&self, r: char) -> i64 {
@@ -109,11 +102,23 @@ impl<'a> Cpu<'a> {
         &mut self.registers[r as usize - 'a' as usize]
     }
 
-    fn simulate(&mut self) -> u32 {
+    /// Run the program until it jumps outside of it.
+    /// # Returns
+    /// * The number of executed `mul` instructions or the [Error].
+    /// # Errors
+    /// * [Error::NoSolution] - A register overflows.
+    /// * [Error::Cancelled] or [Error::Timeout] - The program does not halt in time.
+    fn simulate(&mut self) -> Result<u64, Error> {
         let mut mul_count = 0;
         let mut ins_ptr = 0;
 
-        while ins_ptr < self.instructions.len() {
+        for step in 0usize.. {
+            if ins_ptr >= self.instructions.len() {
+                break;
+            }
+            if step % 1024 == 0 {
+                cancellation::check()?;
+            }
             match self.instructions[ins_ptr].op {
                 "set" => {
                     let y_val = match self.instructions[ins_ptr].arg2 {
@@ -131,7 +136,8 @@ impl<'a> Cpu<'a> {
                         Operand::Value(v) => v,
                     };
                     if let Operand::Register(r) = self.instructions[ins_ptr].arg1 {
-                        *self.get_reg_mut(r) -= y_val;
+                        let register = self.get_reg_mut(r);
+                        *register = register.checked_sub(y_val).ok_or(Error::NoSolution)?;
                     }
                     ins_ptr += 1;
                 }
@@ -141,7 +147,8 @@ impl<'a> Cpu<'a> {
                         Operand::Value(v) => v,
                     };
                     if let Operand::Register(r) = self.instructions[ins_ptr].arg1 {
-                        *self.get_reg_mut(r) *= y_val;
+                        let register = self.get_reg_mut(r);
+                        *register = register.checked_mul(y_val).ok_or(Error::NoSolution)?;
                     }
                     ins_ptr += 1;
                     mul_count += 1;
@@ -156,7 +163,7 @@ impl<'a> Cpu<'a> {
                             Operand::Register(r) => self.get_reg(r),
                             Operand::Value(v) => v,
                         };
-                        let temp_ptr = ins_ptr as i64 + y_val;
+                        let temp_ptr = (ins_ptr as i64).saturating_add(y_val);
                         if temp_ptr < 0 {
                             break;
                         } else {
@@ -166,11 +173,11 @@ impl<'a> Cpu<'a> {
                         ins_ptr += 1;
                     }
                 }
-                _ => panic!("Unknown instruction"),
+                _ => unreachable!("Instructions are validated when parsed"),
             }
         }
 
-        mul_count
+        Ok(mul_count)
     }
 }
 struct Instruction<'a> {
@@ -179,12 +186,15 @@ struct Instruction<'a> {
     arg2: Operand,
 }
 impl<'a> Instruction<'a> {
-    fn new(ins: &'a str) -> Self {
-        let mut split = ins.split_whitespace();
-        let op = split.next().unwrap();
-        let arg1 = Operand::new(split.next().unwrap());
-        let arg2 = Operand::new(split.next().unwrap());
-        Self { op, arg1, arg2 }
+    fn new(input: &str, ins: &'a str) -> Result<Self, Error> {
+        match ins.split_whitespace().collect::<Vec<_>>()[..] {
+            [op @ ("set" | "sub" | "mul" | "jnz"), arg1, arg2] => Ok(Self {
+                op,
+                arg1: Operand::new(input, arg1)?,
+                arg2: Operand::new(input, arg2)?,
+            }),
+            _ => Err(Error::invalid_input(input, ins, "expected an instruction")),
+        }
     }
 }
 enum Operand {
@@ -192,11 +202,17 @@ enum Operand {
     Value(i64),
 }
 impl Operand {
-    fn new(s: &str) -> Self {
+    fn new(input: &str, s: &str) -> Result<Self, Error> {
         if let Ok(i) = s.parse::<i64>() {
-            Operand::Value(i)
-        } else {
-            Operand::Register(s.chars().next().unwrap())
+            return Ok(Operand::Value(i));
+        }
+        match s.parse::<char>() {
+            Ok(register @ 'a'..='h') => Ok(Operand::Register(register)),
+            _ => Err(Error::invalid_input(
+                input,
+                s,
+                "expected a register or a value",
+            )),
         }
     }
 }
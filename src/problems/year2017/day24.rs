@@ -1,17 +1,23 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 use std::collections::HashMap;
 
-day!(Day24, 2017, 24, "Electromagnetic Moat");
+day!(
+    Day24,
+    2017,
+    24,
+    "Electromagnetic Moat",
+    difficulty = Medium,
+    tags = [Graph, Search]
+);
 
 impl Solution for Day24 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(Components::new(input).strongest_bridge().to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        Ok(Components::new(input)?.strongest_bridge().into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        Ok(Components::new(input)
-            .strongest_longest_bridge()
-            .to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        Ok(Components::new(input)?.strongest_longest_bridge().into())
     }
 }
 
@@ -20,17 +26,17 @@ struct Components {
     port_map: HashMap<u16, Vec<usize>>,
 }
 impl Components {
-    fn new(input: &str) -> Self {
+    fn new(input: &str) -> Result<Self, Error> {
         let components = input
             .trim()
             .lines()
             .map(|line| {
-                let mut line_parts = line.split('/');
-                let a = line_parts.next().unwrap().parse::<u16>().unwrap();
-                let b = line_parts.next().unwrap().parse::<u16>().unwrap();
-                (a, b)
+                let (a, b) =
+                    line.split_once('/')
+                        .or_invalid(input, line, "expected a component")?;
+                Ok((parse_number(input, a)?, parse_number(input, b)?))
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
 
         let mut port_map = HashMap::new();
         for (i, component) in components.iter().enumerate() {
@@ -38,13 +44,13 @@ impl Components {
             port_map.entry(component.1).or_insert(Vec::new()).push(i);
         }
 
-        Self {
+        Ok(Self {
             components,
             port_map,
-        }
+        })
     }
 
-    fn strongest_bridge(&self) -> u16 {
+    fn strongest_bridge(&self) -> u32 {
         let mut components_used = vec![false; self.components.len()];
         let mut max_strength = 0;
 
@@ -59,7 +65,7 @@ impl Components {
         max_strength
     }
 
-    fn strongest_longest_bridge(&self) -> u16 {
+    fn strongest_longest_bridge(&self) -> u32 {
         let mut components_used = vec![false; self.components.len()];
         let mut max_strength = 0;
         let mut max_length = 0;
@@ -79,23 +85,23 @@ impl Components {
     fn visit_bridges<T>(
         &self,
         current_port: u16,
-        current_strength: u16,
-        current_length: u8,
+        current_strength: u32,
+        current_length: usize,
         apply: &mut T,
         components_used: &mut [bool],
     ) where
-        T: FnMut(u16, u8),
+        T: FnMut(u32, usize),
     {
         apply(current_strength, current_length);
 
-        for next_component in self.port_map[&current_port].iter() {
+        for next_component in self.port_map.get(&current_port).into_iter().flatten() {
             if !components_used[*next_component] {
                 components_used[*next_component] = true;
                 let (port1, port2) = self.components[*next_component];
                 if port1 == current_port {
                     self.visit_bridges(
                         port2,
-                        current_strength + port1 + port2,
+                        current_strength + u32::from(port1) + u32::from(port2),
                         current_length + 1,
                         apply,
                         components_used,
@@ -103,7 +109,7 @@ impl Components {
                 } else {
                     self.visit_bridges(
                         port1,
-                        current_strength + port1 + port2,
+                        current_strength + u32::from(port1) + u32::from(port2),
                         current_length + 1,
                         apply,
                         components_used,
@@ -1,46 +1,54 @@
-use crate::{Error, Solution};
-use itertools::Itertools;
+use crate::cancellation;
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 use std::collections::HashSet;
 
-day!(Day25, 2017, 25, "The Halting Problem");
+day!(
+    Day25,
+    2017,
+    25,
+    "The Halting Problem",
+    difficulty = Easy,
+    tags = [Parsing, Simulation]
+);
 
 impl Solution for Day25 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut input = input.trim().lines();
-        let start_state = input
-            .next()
-            .unwrap()
-            .trim_start_matches("Begin in state ")
-            .trim_end_matches('.')
-            .chars()
-            .next()
-            .unwrap();
-        let steps = input
-            .next()
-            .unwrap()
-            .trim_start_matches("Perform a diagnostic checksum after ")
-            .trim_end_matches(" steps.")
-            .parse()
-            .unwrap();
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut lines = input.trim().lines();
+        let line = lines.next().unwrap_or_default();
+        let start_state = line
+            .strip_prefix("Begin in state ")
+            .and_then(|line| line.strip_suffix('.'))
+            .and_then(|name| name.parse::<char>().ok())
+            .or_invalid(input, line, "expected a starting state")?;
+        let line = lines.next().unwrap_or_default();
+        let steps = line
+            .strip_prefix("Perform a diagnostic checksum after ")
+            .and_then(|line| line.strip_suffix(" steps."))
+            .or_invalid(input, line, "expected a number of steps")?;
+        let steps = parse_number::<u64>(input, steps)?;
 
-        let input = input.join("\n");
-        let states = State::parse_states(&input);
+        let states = State::parse_states(input, lines)?;
 
         let mut storage = HashSet::new();
-        let mut cursor = 0;
+        // the cursor moves by one slot every step, so 64 bits are far more than the steps can reach
+        let mut cursor: i64 = 0;
         let mut state = states
             .iter()
             .position(|state| state.name == start_state)
-            .unwrap();
+            .ok_or(Error::NoSolution)?;
 
-        for _ in 0..steps {
+        for step in 0..steps {
+            if step % 1024 == 0 {
+                cancellation::check()?;
+            }
             if storage.contains(&cursor) {
                 // 1
 
                 if !states[state].write[1] {
                     storage.remove(&cursor);
                 }
-                cursor += states[state].move_direction[1];
+                cursor += i64::from(states[state].move_direction[1]);
                 state = states[state].next_state_index[1];
             } else {
                 // 0
@@ -48,16 +56,16 @@ impl Solution for Day25 {
                 if states[state].write[0] {
                     storage.insert(cursor);
                 }
-                cursor += states[state].move_direction[0];
+                cursor += i64::from(states[state].move_direction[0]);
                 state = states[state].next_state_index[0];
             }
         }
 
-        Ok(storage.len().to_string())
+        Ok(storage.len().into())
     }
 
-    fn part2(&self, _input: &str) -> Result<String, Error> {
-        Ok(String::from("Advent of Code 2017 solved!"))
+    fn part2(&self, _input: &str) -> Result<Answer, Error> {
+        Ok("Advent of Code 2017 solved!".into())
     }
 }
 
@@ -69,7 +77,10 @@ struct State {
     next_state_index: [usize; 2],
 }
 impl State {
-    fn parse_states(input: &str) -> Vec<Self> {
+    fn parse_states<'a>(
+        input: &str,
+        lines: impl Iterator<Item = &'a str>,
+    ) -> Result<Vec<Self>, Error> {
         let mut states = Vec::new();
 
         let mut name = ' ';
@@ -79,47 +90,36 @@ impl State {
         let next_state_index = [0; 2];
 
         let mut variant = 0;
-        for line in input.trim().lines() {
+        for line in lines.skip_while(|line| line.trim().is_empty()) {
             let line = line.trim();
-            if line.starts_with("In state ") {
-                name = line
-                    .trim_start_matches("In state ")
-                    .trim_end_matches(':')
-                    .chars()
-                    .next()
-                    .unwrap();
-            } else if line.starts_with("If the current value is ") {
-                let value = line
-                    .trim_start_matches("If the current value is ")
-                    .trim_end_matches(':')
-                    .parse::<u8>()
-                    .unwrap();
-                variant = value;
-            } else if line.starts_with("- Write the value ") {
-                let value = line
-                    .trim_start_matches("- Write the value ")
-                    .trim_end_matches('.')
-                    .parse::<u8>()
-                    .unwrap();
-                write[variant as usize] = value == 1;
-            } else if line.starts_with("- Move one slot to the ") {
-                move_direction[variant as usize] = match line
-                    .trim_start_matches("- Move one slot to the ")
-                    .trim_end_matches('.')
-                {
-                    "right" => 1,
-                    "left" => -1,
-                    _ => {
-                        panic!("Invalid move direction");
-                    }
+            if let Some(value) = line.strip_prefix("In state ") {
+                name = value
+                    .strip_suffix(':')
+                    .and_then(|value| value.parse().ok())
+                    .or_invalid(input, line, "expected a state")?;
+            } else if let Some(value) = line.strip_prefix("If the current value is ") {
+                variant = match value.strip_suffix(':') {
+                    Some("0") => 0,
+                    Some("1") => 1,
+                    _ => return Err(Error::invalid_input(input, line, "expected 0 or 1")),
+                };
+            } else if let Some(value) = line.strip_prefix("- Write the value ") {
+                write[variant] = match value.strip_suffix('.') {
+                    Some("0") => false,
+                    Some("1") => true,
+                    _ => return Err(Error::invalid_input(input, line, "expected 0 or 1")),
+                };
+            } else if let Some(value) = line.strip_prefix("- Move one slot to the ") {
+                move_direction[variant] = match value.strip_suffix('.') {
+                    Some("right") => 1,
+                    Some("left") => -1,
+                    _ => return Err(Error::invalid_input(input, line, "expected left or right")),
                 };
-            } else if line.starts_with("- Continue with state ") {
-                next_state[variant as usize] = line
-                    .trim_start_matches("- Continue with state ")
-                    .trim_end_matches('.')
-                    .chars()
-                    .next()
-                    .unwrap();
+            } else if let Some(value) = line.strip_prefix("- Continue with state ") {
+                next_state[variant] = value
+                    .strip_suffix('.')
+                    .and_then(|value| value.parse().ok())
+                    .or_invalid(input, line, "expected a state")?;
             } else if line.is_empty() {
                 states.push(State {
                     name,
@@ -128,6 +128,12 @@ impl State {
                     next_state,
                     next_state_index,
                 });
+            } else {
+                return Err(Error::invalid_input(
+                    input,
+                    line,
+                    "expected a state description",
+                ));
             }
         }
 
@@ -140,16 +146,14 @@ impl State {
         });
 
         for i in 0..states.len() {
-            states[i].next_state_index[0] = states
-                .iter()
-                .position(|other| states[i].next_state[0] == other.name)
-                .unwrap();
-            states[i].next_state_index[1] = states
-                .iter()
-                .position(|other| states[i].next_state[1] == other.name)
-                .unwrap();
+            for variant in 0..2 {
+                states[i].next_state_index[variant] = states
+                    .iter()
+                    .position(|other| states[i].next_state[variant] == other.name)
+                    .ok_or(Error::NoSolution)?;
+            }
         }
 
-        states
+        Ok(states)
     }
 }
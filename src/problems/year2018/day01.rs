@@ -1,35 +1,74 @@
-use crate::{Error, Solution};
-use std::collections::HashSet;
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
-day!(Day01, 2018, 1, "Chronal Calibration");
+day!(
+    Day01,
+    2018,
+    1,
+    "Chronal Calibration",
+    difficulty = Easy,
+    tags = [Simulation]
+);
 
 impl Solution for Day01 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let input = parse_input(input);
-        Ok(input.iter().sum::<isize>().to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let input = parse_input(input)?;
+        Ok(input.iter().sum::<i128>().into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let input = parse_input(input);
-        let mut i = 0;
-        let mut encountered_frequencies: HashSet<isize> = HashSet::new();
-        let mut frequency = 0;
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let changes = parse_input(input)?;
 
-        loop {
-            frequency += input[i];
-            if encountered_frequencies.insert(frequency) {
-                i = (i + 1) % input.len();
-            } else {
-                return Ok(frequency.to_string());
+        // the frequencies reached in the first pass, starting with the starting frequency
+        let mut frequencies = vec![0];
+        let mut encountered_frequencies = HashSet::from([0]);
+        for change in changes {
+            let frequency = frequencies[frequencies.len() - 1] + change;
+            if !encountered_frequencies.insert(frequency) {
+                return Ok(frequency.into());
             }
+            frequencies.push(frequency);
         }
+
+        // every later pass reaches the frequencies of the first pass shifted by the total change,
+        // so a frequency repeats the closest one in the direction of the shift
+        // which differs from it by a multiple of the shift
+        let shift = frequencies[frequencies.len() - 1];
+        if shift == 0 {
+            return Err(Error::NoSolution);
+        }
+        let mut classes = HashMap::<i128, BTreeSet<i128>>::new();
+        for &frequency in &frequencies {
+            classes
+                .entry(frequency.rem_euclid(shift.abs()))
+                .or_default()
+                .insert(frequency);
+        }
+        frequencies[1..]
+            .iter()
+            .enumerate()
+            .filter_map(|(step, &frequency)| {
+                let class = &classes[&frequency.rem_euclid(shift.abs())];
+                let repeated = if shift > 0 {
+                    class.range(frequency + 1..).next()
+                } else {
+                    class.range(..frequency).next_back()
+                }?;
+                Some(((repeated - frequency) / shift, step, *repeated))
+            })
+            .min()
+            .map(|(_, _, repeated)| repeated.into())
+            .ok_or(Error::NoSolution)
     }
 }
 
-fn parse_input(input: &str) -> Vec<isize> {
+/// Parse the changes of the frequency.
+/// The changes are 64-bit numbers, which are summed in 128 bits, so that the frequencies cannot overflow.
+fn parse_input(input: &str) -> Result<Vec<i128>, Error> {
     input
         .trim()
         .lines()
-        .map(|line| line.parse::<isize>().unwrap())
+        .map(|line| parse_number::<i64>(input, line).map(i128::from))
         .collect()
 }
@@ -1,10 +1,17 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 use std::collections::HashMap;
 
-day!(Day02, 2018, 2, "Inventory Management System");
+day!(
+    Day02,
+    2018,
+    2,
+    "Inventory Management System",
+    difficulty = Easy,
+    tags = [Strings]
+);
 
 impl Solution for Day02 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let parsed_input: Vec<HashMap<char, u32>> = input
             .trim()
             .lines()
@@ -39,10 +46,10 @@ impl Solution for Day02 {
             }
         }
 
-        Ok((two_count * three_count).to_string())
+        Ok((two_count * three_count).into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut ids = input.trim().lines().collect::<Vec<_>>();
         let different_chars = |input1: &str, input2: &str| {
             let mut count: u32 = 0;
@@ -54,6 +61,10 @@ impl Solution for Day02 {
             count
         };
 
+        if ids.len() < 2 {
+            return Err(Error::NoSolution);
+        }
+
         while ids.len() > 2 {
             let id = ids.pop().unwrap();
             for id2 in ids.iter() {
@@ -63,7 +74,8 @@ impl Solution for Day02 {
                         .zip(id2.chars())
                         .filter(|(char1, char2)| char1 == char2)
                         .map(|(char1, _)| char1)
-                        .collect());
+                        .collect::<String>()
+                        .into());
                 }
             }
         }
@@ -74,7 +86,8 @@ impl Solution for Day02 {
                 .zip(ids[1].chars())
                 .filter(|(char1, char2)| char1 == char2)
                 .map(|(char1, _)| char1)
-                .collect())
+                .collect::<String>()
+                .into())
         } else {
             Err(Error::NoSolution)
         }
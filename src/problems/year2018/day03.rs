@@ -1,10 +1,18 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_limited};
+use crate::{Answer, Error, Solution};
 
-day!(Day03, 2018, 3, "No Matter How You Slice It");
+day!(
+    Day03,
+    2018,
+    3,
+    "No Matter How You Slice It",
+    difficulty = Easy,
+    tags = [Geometry, Grid]
+);
 
 impl Solution for Day03 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let fabric = fabric_with_claims(parse_input(input));
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let fabric = fabric_with_claims(&parse_input(input)?);
 
         let mut count = 0;
         for row in fabric.into_iter() {
@@ -15,13 +23,14 @@ impl Solution for Day03 {
             }
         }
 
-        Ok(count.to_string())
+        Ok(count.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let fabric = fabric_with_claims(parse_input(input));
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let claims = parse_input(input)?;
+        let fabric = fabric_with_claims(&claims);
 
-        for (i, claim) in parse_input(input).enumerate() {
+        for (i, claim) in claims.iter().enumerate() {
             let x_start = claim[0];
             let y_start = claim[1];
             let x_end = x_start + claim[2];
@@ -31,7 +40,7 @@ impl Solution for Day03 {
                 .iter()
                 .all(|row| row[x_start..x_end].iter().all(|&cell| cell == 1))
             {
-                return Ok((i + 1).to_string());
+                return Ok((i + 1).into());
             }
         }
 
@@ -41,7 +50,7 @@ impl Solution for Day03 {
 
 const FABRIC_SIZE: usize = 1000;
 
-fn fabric_with_claims(claims: impl Iterator<Item = [usize; 4]>) -> Vec<[u8; FABRIC_SIZE]> {
+fn fabric_with_claims(claims: &[[usize; 4]]) -> Vec<[u8; FABRIC_SIZE]> {
     let mut fabric = vec![[0_u8; FABRIC_SIZE]; FABRIC_SIZE];
 
     for claim in claims {
@@ -59,16 +68,40 @@ fn fabric_with_claims(claims: impl Iterator<Item = [usize; 4]>) -> Vec<[u8; FABR
     fabric
 }
 
-fn parse_input(input: &str) -> impl Iterator<Item = [usize; 4]> {
-    input.lines().map(|line| {
-        let mut arr = [0_usize; 4];
-        let mut i = 0;
-        for elem in line.split(&[' ', ',', 'x', ':']) {
-            if let Ok(num) = elem.parse() {
-                arr[i] = num;
-                i += 1;
+fn parse_input(input: &str) -> Result<Vec<[usize; 4]>, Error> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (_, claim) = line
+                .split_once(" @ ")
+                .or_invalid(input, line, "expected a claim")?;
+            let (position, size) = claim.split_once(": ").or_invalid(
+                input,
+                claim,
+                "expected a position and a size",
+            )?;
+            let (x, y) =
+                position
+                    .split_once(',')
+                    .or_invalid(input, position, "expected a position")?;
+            let (width, height) =
+                size.split_once('x')
+                    .or_invalid(input, size, "expected a size")?;
+            let arr = [
+                parse_limited(input, x, 0..=FABRIC_SIZE)?,
+                parse_limited(input, y, 0..=FABRIC_SIZE)?,
+                parse_limited(input, width, 0..=FABRIC_SIZE)?,
+                parse_limited(input, height, 0..=FABRIC_SIZE)?,
+            ];
+            if arr[0] + arr[2] > FABRIC_SIZE || arr[1] + arr[3] > FABRIC_SIZE {
+                return Err(Error::invalid_input(
+                    input,
+                    claim,
+                    "expected a claim inside the fabric",
+                ));
             }
-        }
-        arr
-    })
+            Ok(arr)
+        })
+        .collect()
 }
@@ -1,15 +1,22 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
-day!(Day05, 2018, 5, "Alchemical Reduction");
+day!(
+    Day05,
+    2018,
+    5,
+    "Alchemical Reduction",
+    difficulty = Easy,
+    tags = [Strings]
+);
 
 impl Solution for Day05 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let mut polymer = input.trim().chars().collect::<Vec<_>>();
         react(&mut polymer);
-        Ok(polymer.len().to_string())
+        Ok(polymer.len().into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let polymer = input.trim().chars().collect::<Vec<_>>();
         let mut minimum = usize::MAX;
 
@@ -21,13 +28,13 @@ impl Solution for Day05 {
             minimum = minimum.min(removed_polymer.len());
         }
 
-        Ok(minimum.to_string())
+        Ok(minimum.into())
     }
 }
 
 /// Fully react polymer
 fn react(polymer: &mut Vec<char>) {
-    loop {
+    while !polymer.is_empty() {
         let mut i = 0; // read ptr
         let mut j = 0; // write ptr
 
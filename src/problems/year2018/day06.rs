@@ -1,15 +1,44 @@
+use crate::input::{OrInvalid, parse_limited};
 use crate::math::manhattan_distance;
-use crate::{Error, Solution};
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 
-day!(Day06, 2018, 6, "Chronal Coordinates");
+day!(
+    Day06,
+    2018,
+    6,
+    "Chronal Coordinates",
+    difficulty = Medium,
+    tags = [Geometry, Grid]
+);
+
+/// The largest accepted coordinate.
+/// The grid has a location for every pair of coordinates up to the largest ones,
+/// so this keeps it at a million locations.
+const MAX_COORDINATE: usize = 1_000;
 
 impl Solution for Day06 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let coords = parse_input(input);
-        let max_x = coords.iter().map(|(x, _)| *x).max().unwrap();
-        let max_y = coords.iter().map(|(_, y)| *y).max().unwrap();
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::integer(
+            "distance",
+            "The total distance to all coordinates which the region stays below",
+            10000,
+        )]
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let coords = parse_input(input)?;
+        let max_x = coords
+            .iter()
+            .map(|(x, _)| *x)
+            .max()
+            .ok_or(Error::NoSolution)?;
+        let max_y = coords
+            .iter()
+            .map(|(_, y)| *y)
+            .max()
+            .ok_or(Error::NoSolution)?;
 
         let mut grid = vec![vec![None; max_y + 1]; max_x + 1];
         for (i, row) in grid.iter_mut().enumerate() {
@@ -44,17 +73,17 @@ impl Solution for Day06 {
         }
 
         let mut infinite_areas = HashSet::new();
-        for cell in grid.first().unwrap().iter().flatten() {
+        for cell in grid.first().into_iter().flatten().flatten() {
             infinite_areas.insert(*cell);
         }
-        for cell in grid.last().unwrap().iter().flatten() {
+        for cell in grid.last().into_iter().flatten().flatten() {
             infinite_areas.insert(*cell);
         }
         for row in grid.iter() {
-            if let Some(cell) = row.first().unwrap() {
+            if let Some(Some(cell)) = row.first() {
                 infinite_areas.insert(*cell);
             }
-            if let Some(cell) = row.last().unwrap() {
+            if let Some(Some(cell)) = row.last() {
                 infinite_areas.insert(*cell);
             }
         }
@@ -68,13 +97,26 @@ impl Solution for Day06 {
             }
         }
 
-        Ok(areas.into_values().max().unwrap().to_string())
+        areas
+            .into_values()
+            .max()
+            .map(Answer::from)
+            .ok_or(Error::NoSolution)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let coords = parse_input(input);
-        let max_x = coords.iter().map(|(x, _)| *x).max().unwrap();
-        let max_y = coords.iter().map(|(_, y)| *y).max().unwrap();
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let threshold: u64 = parameters.integer("distance")?;
+        let coords = parse_input(input)?;
+        let max_x = coords
+            .iter()
+            .map(|(x, _)| *x)
+            .max()
+            .ok_or(Error::NoSolution)?;
+        let max_y = coords
+            .iter()
+            .map(|(_, y)| *y)
+            .max()
+            .ok_or(Error::NoSolution)?;
 
         let mut area_size = 0;
         for i in 0..=max_x {
@@ -84,30 +126,32 @@ impl Solution for Day06 {
                     let dist =
                         manhattan_distance((i as i64, j as i64), (coord.0 as i64, coord.1 as i64));
                     total_distances += dist;
-                    if total_distances >= PART2_THRESHOLD {
+                    if total_distances >= threshold {
                         break;
                     }
                 }
-                if total_distances < PART2_THRESHOLD {
+                if total_distances < threshold {
                     area_size += 1;
                 }
             }
         }
 
-        Ok(area_size.to_string())
+        Ok(area_size.into())
     }
 }
 
-const PART2_THRESHOLD: u64 = 10000;
-
-fn parse_input(input: &str) -> Vec<(usize, usize)> {
+fn parse_input(input: &str) -> Result<Vec<(usize, usize)>, Error> {
     input
+        .trim()
         .lines()
         .map(|line| {
-            let mut parts = line.split(',');
-            let x = parts.next().unwrap().trim().parse().unwrap();
-            let y = parts.next().unwrap().trim().parse().unwrap();
-            (x, y)
+            let (x, y) = line
+                .split_once(',')
+                .or_invalid(input, line, "expected a coordinate")?;
+            Ok((
+                parse_limited(input, x.trim(), 0..=MAX_COORDINATE)?,
+                parse_limited(input, y.trim(), 0..=MAX_COORDINATE)?,
+            ))
         })
         .collect()
 }
@@ -1,24 +1,32 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, end, parse_number};
+use crate::{Answer, Error, Solution};
 
-day!(Day08, 2018, 8, "Memory Maneuver");
+day!(
+    Day08,
+    2018,
+    8,
+    "Memory Maneuver",
+    difficulty = Easy,
+    tags = [Graph, Parsing]
+);
 
 impl Solution for Day08 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let license_file = input
             .split_whitespace()
-            .map(|x| x.parse::<u8>().unwrap())
-            .collect::<Vec<u8>>();
-        let root = Node::build(&license_file, 0).0;
-        Ok(root.sum_metadata().to_string())
+            .map(|x| parse_number(input, x))
+            .collect::<Result<Vec<u8>, _>>()?;
+        let root = Node::build(input, &license_file, 0)?.0;
+        Ok(root.sum_metadata().into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let license_file = input
             .split_whitespace()
-            .map(|x| x.parse::<u8>().unwrap())
-            .collect::<Vec<u8>>();
-        let root = Node::build(&license_file, 0).0;
-        Ok(root.node_value().to_string())
+            .map(|x| parse_number(input, x))
+            .collect::<Result<Vec<u8>, _>>()?;
+        let root = Node::build(input, &license_file, 0)?.0;
+        Ok(root.node_value().ok_or(Error::NoSolution)?.into())
     }
 }
 
@@ -28,22 +36,28 @@ struct Node {
     node_value: Option<u32>,
 }
 impl Node {
-    fn build(license_file: &[u8], mut i: usize) -> (Self, usize) {
-        let children_count = license_file[i] as usize;
-        let metadata_count = license_file[i + 1] as usize;
+    fn build(input: &str, license_file: &[u8], mut i: usize) -> Result<(Self, usize), Error> {
+        let number = |i: usize| {
+            license_file
+                .get(i)
+                .copied()
+                .or_invalid(input, end(input), "expected more numbers")
+        };
+        let children_count = number(i)? as usize;
+        let metadata_count = number(i + 1)? as usize;
         i += 2;
 
         let mut children = Vec::new();
         let mut metadata = Vec::new();
 
         for _ in 0..children_count {
-            let (child, new_i) = Self::build(license_file, i);
+            let (child, new_i) = Self::build(input, license_file, i)?;
             children.push(child);
             i = new_i;
         }
 
         for _ in 0..metadata_count {
-            metadata.push(license_file[i]);
+            metadata.push(number(i)?);
             i += 1;
         }
 
@@ -53,7 +67,7 @@ impl Node {
             node_value: None,
         };
 
-        (node, i)
+        Ok((node, i))
     }
 
     fn sum_metadata(&self) -> u32 {
@@ -61,19 +75,26 @@ impl Node {
             + self.children.iter().map(|x| x.sum_metadata()).sum::<u32>()
     }
 
-    fn node_value(&self) -> u32 {
+    /// Returns the value of the node, or `None` if it overflows.
+    fn node_value(&self) -> Option<u32> {
         if let Some(value) = self.node_value {
-            value
+            Some(value)
         } else if self.children.is_empty() {
-            self.metadata.iter().map(|x| *x as u32).sum()
+            Some(self.metadata.iter().map(|x| *x as u32).sum())
         } else {
-            let mut sum = 0;
+            // the children are valued once, as the metadata can reference them many times
+            let children_values = self
+                .children
+                .iter()
+                .map(Self::node_value)
+                .collect::<Option<Vec<_>>>()?;
+            let mut sum: u32 = 0;
             for &meta_value in &self.metadata {
                 if (meta_value as usize) <= self.children.len() && meta_value != 0 {
-                    sum += self.children[meta_value as usize - 1].node_value();
+                    sum = sum.checked_add(children_values[meta_value as usize - 1])?;
                 }
             }
-            sum
+            Some(sum)
         }
     }
 }
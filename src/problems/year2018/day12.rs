@@ -1,24 +1,45 @@
-use crate::{Error, Solution};
+use crate::cancellation;
+use crate::input::OrInvalid;
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 use std::collections::HashMap;
 
-day!(Day12, 2018, 12, "Subterranean Sustainability");
+day!(
+    Day12,
+    2018,
+    12,
+    "Subterranean Sustainability",
+    difficulty = Medium,
+    tags = [CellularAutomaton, Cycles]
+);
 
 impl Solution for Day12 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut pots = Pots::new(input);
-        pots.simulate_generations(GENERATIONS1);
-        Ok(pots.sum().to_string())
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::integer(
+                "generations1",
+                "The number of generations in the first part",
+                20,
+            ),
+            Parameter::integer(
+                "generations2",
+                "The number of generations in the second part",
+                50_000_000_000,
+            ),
+        ]
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut pots = Pots::new(input);
-        pots.simulate_generations(GENERATIONS2);
-        Ok(pots.sum().to_string())
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let mut pots = Pots::new(input)?;
+        pots.simulate_generations(parameters.integer("generations1")?)?;
+        Ok(pots.sum().into())
     }
-}
 
-const GENERATIONS1: u64 = 20;
-const GENERATIONS2: u64 = 50_000_000_000;
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let mut pots = Pots::new(input)?;
+        pots.simulate_generations(parameters.integer("generations2")?)?;
+        Ok(pots.sum().into())
+    }
+}
 
 struct Pots {
     relevant: Vec<i64>,
@@ -28,16 +49,19 @@ struct Pots {
     rules: HashMap<[bool; 5], bool>,
 }
 impl Pots {
-    fn new(input: &str) -> Self {
-        let mut lines = input.lines();
+    fn new(input: &str) -> Result<Self, Error> {
+        let mut lines = input.trim().lines();
 
-        let mut initial_state: Vec<bool> = lines
-            .next()
-            .unwrap()
-            .trim_start_matches("initial state: ")
-            .chars()
-            .map(|c| c == '#')
-            .collect();
+        let mut initial_state = parse_pots(
+            input,
+            lines
+                .next()
+                .unwrap_or_default()
+                .trim_start_matches("initial state: "),
+        )?;
+        if !initial_state.contains(&true) {
+            return Err(Error::NoSolution);
+        }
         let mut first_pot = 0;
         while !initial_state[0] {
             initial_state.remove(0);
@@ -57,22 +81,28 @@ impl Pots {
 
         let mut rules = HashMap::new();
         for line in lines {
-            let (key_str, val_str) = line.split_once(" => ").unwrap();
-            let mut key = [false; 5];
-            for (i, c) in key_str.chars().enumerate() {
-                key[i] = c == '#';
-            }
-            let val = val_str == "#";
+            let (key_str, val_str) =
+                line.split_once(" => ")
+                    .or_invalid(input, line, "expected a rule")?;
+            let key = parse_pots(input, key_str)?.try_into().or_invalid(
+                input,
+                key_str,
+                "expected 5 pots",
+            )?;
+            let val = match parse_pots(input, val_str)?[..] {
+                [val] => val,
+                _ => return Err(Error::invalid_input(input, val_str, "expected a pot")),
+            };
             rules.insert(key, val);
         }
 
-        Self {
+        Ok(Self {
             relevant,
             first_pot,
             old_relevant: Vec::new(),
             old_first_pot: 0,
             rules,
-        }
+        })
     }
 
     fn sum(&self) -> i64 {
@@ -89,7 +119,12 @@ impl Pots {
         self.old_first_pot = self.first_pot;
 
         self.relevant.clear();
-        for i in (self.old_relevant.first().unwrap() - 2)..(self.old_relevant.last().unwrap() + 2) {
+        let (Some(&first), Some(&last)) = (self.old_relevant.first(), self.old_relevant.last())
+        else {
+            // there are no plants left
+            return;
+        };
+        for i in (first - 2)..(last + 2) {
             let mut key = [false; 5];
             for (ind, j) in (-2..=2).enumerate() {
                 key[ind] = self.old_relevant.contains(&(i + j));
@@ -99,13 +134,22 @@ impl Pots {
             }
         }
 
-        let norm = 0 - self.relevant[0];
+        let Some(&norm) = self.relevant.first() else {
+            return;
+        };
+        let norm = -norm;
         self.relevant.iter_mut().for_each(|i| *i += norm);
         self.first_pot -= norm;
     }
 
-    fn simulate_generations(&mut self, mut generations: u64) {
+    /// Simulate the generations, skipping them once the plants only shift.
+    /// # Errors
+    /// * [Error::Cancelled] or [Error::Timeout] - The plants do not settle in time.
+    fn simulate_generations(&mut self, mut generations: u64) -> Result<(), Error> {
         while generations > 0 {
+            if generations.is_multiple_of(1024) {
+                cancellation::check()?;
+            }
             self.next_generation();
             generations -= 1;
             if self.old_relevant == self.relevant {
@@ -115,5 +159,16 @@ impl Pots {
                 break;
             }
         }
+        Ok(())
     }
 }
+
+fn parse_pots(input: &str, pots: &str) -> Result<Vec<bool>, Error> {
+    pots.char_indices()
+        .map(|(i, c)| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(Error::invalid_input(input, &pots[i..], "expected a pot")),
+        })
+        .collect()
+}
@@ -1,21 +1,31 @@
-use crate::{Error, Solution};
-
-day!(Day01, 2019, 1, "The Tyranny of the Rocket Equation");
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
+
+day!(
+    Day01,
+    2019,
+    1,
+    "The Tyranny of the Rocket Equation",
+    difficulty = Easy,
+    tags = []
+);
 
 impl Solution for Day01 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut sum = 0;
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut sum: u64 = 0;
 
         for num in input.trim().lines() {
-            sum += num.parse::<u64>().unwrap() / 3;
+            sum = sum
+                .checked_add(parse_number::<u64>(input, num)? / 3)
+                .ok_or(Error::NoSolution)?;
             sum = sum.saturating_sub(2);
         }
 
-        Ok(sum.to_string())
+        Ok(sum.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut sum = 0;
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut sum: u64 = 0;
         let fuel_for_fuel = |mut fuel: u64| {
             let mut sum = 0;
 
@@ -29,10 +39,13 @@ impl Solution for Day01 {
         };
 
         for num in input.trim().lines() {
-            let module_fuel = (num.parse::<u64>().unwrap() / 3).saturating_sub(2);
-            sum += module_fuel + fuel_for_fuel(module_fuel);
+            let module_fuel = (parse_number::<u64>(input, num)? / 3).saturating_sub(2);
+            // the fuel for the fuel is less than half of the fuel, so only the total can overflow
+            sum = sum
+                .checked_add(module_fuel + fuel_for_fuel(module_fuel))
+                .ok_or(Error::NoSolution)?;
         }
 
-        Ok(sum.to_string())
+        Ok(sum.into())
     }
 }
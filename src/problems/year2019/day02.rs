@@ -1,33 +1,46 @@
-use crate::{Error, Solution};
+use crate::input::{end, parse_number};
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 use itertools::Itertools;
 
-day!(Day02, 2019, 2, "1202 Program Alarm");
+day!(
+    Day02,
+    2019,
+    2,
+    "1202 Program Alarm",
+    difficulty = Easy,
+    tags = [Vm]
+);
 
 impl Solution for Day02 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut array = parse_input(input);
-        array[1] = 12;
-        array[2] = 2;
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::integer("noun", "The value at address 1 in the first part", 12),
+            Parameter::integer("verb", "The value at address 2 in the first part", 2),
+        ]
+    }
+
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let mut array = parse_input(input)?;
+        array[1] = parameters.integer("noun")?;
+        array[2] = parameters.integer("verb")?;
 
-        simulate(&mut array);
+        simulate(&mut array)?;
 
-        Ok(array[0].to_string())
+        Ok(array[0].into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let wanted_output = 19690720;
 
-        let input_array = parse_input(input);
+        let input_array = parse_input(input)?;
 
         for (m, n) in (0..100).cartesian_product(0..100) {
             let mut array = input_array.clone();
             array[1] = m;
             array[2] = n;
 
-            simulate(&mut array);
-
-            if array[0] == wanted_output {
-                return Ok((100 * m + n).to_string());
+            if simulate(&mut array).is_ok() && array[0] == wanted_output {
+                return Ok((100 * m + n).into());
             }
         }
 
@@ -35,31 +48,37 @@ impl Solution for Day02 {
     }
 }
 
-fn simulate(array: &mut [usize]) {
+fn simulate(array: &mut [usize]) -> Result<(), Error> {
+    let read = |array: &[usize], pos: usize| array.get(pos).copied().ok_or(Error::NoSolution);
+
     for i in (0..(array.len() - 3)).step_by(4) {
-        match array[i] {
-            1 => {
-                let pos1 = array[i + 1];
-                let pos2 = array[i + 2];
-                let pos3 = array[i + 3];
-                array[pos3] = array[pos1] + array[pos2];
-            }
-            2 => {
-                let pos1 = array[i + 1];
-                let pos2 = array[i + 2];
-                let pos3 = array[i + 3];
-                array[pos3] = array[pos1] * array[pos2];
-            }
+        let operation = match array[i] {
+            1 => usize::checked_add,
+            2 => usize::checked_mul,
             99 => break,
-            _ => panic!("Invalid opcode"),
-        }
+            _ => return Err(Error::NoSolution),
+        };
+        let value1 = read(array, array[i + 1])?;
+        let value2 = read(array, array[i + 2])?;
+        let result = operation(value1, value2).ok_or(Error::NoSolution)?;
+        *array.get_mut(array[i + 3]).ok_or(Error::NoSolution)? = result;
     }
+
+    Ok(())
 }
 
-fn parse_input(input: &str) -> Vec<usize> {
-    input
+fn parse_input(input: &str) -> Result<Vec<usize>, Error> {
+    let array = input
         .trim()
         .split(',')
-        .map(|x| x.parse::<usize>().unwrap())
-        .collect()
+        .map(|x| parse_number(input, x.trim()))
+        .collect::<Result<Vec<usize>, _>>()?;
+    if array.len() < 4 {
+        return Err(Error::invalid_input(
+            input,
+            end(input),
+            "expected at least 4 numbers",
+        ));
+    }
+    Ok(array)
 }
@@ -1,59 +1,87 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, end, parse_limited};
+use crate::{Answer, Error, Solution};
 use std::cmp::{max, min};
 use std::collections::{HashMap, HashSet};
 
-day!(Day03, 2019, 3, "Crossed Wires");
+day!(
+    Day03,
+    2019,
+    3,
+    "Crossed Wires",
+    difficulty = Easy,
+    tags = [Geometry, Grid]
+);
+
+/// The largest accepted distance of a move.
+/// Wires running along each other cross at every location they share,
+/// so this bounds the crossings stored for a single overlap.
+const MAX_DISTANCE: i32 = 1 << 16;
 
 impl Solution for Day03 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let (line1, line2) = input.trim().split_once('\n').unwrap();
-        let wire1 = Wire::new(line1);
-        let wire2 = Wire::new(line2);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let (wire1, wire2) = parse_input(input)?;
         let intersections = wire1.intersections(&wire2);
 
         Ok(intersections
             .iter()
-            .map(|(x, y)| x.abs() + y.abs())
+            .map(|(x, y)| u64::from(x.unsigned_abs()) + u64::from(y.unsigned_abs()))
             .min()
-            .unwrap()
-            .to_string())
+            .ok_or(Error::NoSolution)?
+            .into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let (line1, line2) = input.trim().split_once('\n').unwrap();
-        let wire1 = Wire::new(line1);
-        let wire2 = Wire::new(line2);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let (wire1, wire2) = parse_input(input)?;
         let intersection_steps = wire1.intersections_steps(&wire2);
 
         Ok(intersection_steps
             .iter()
-            .map(|(steps_1, steps_2)| steps_1 + steps_2)
+            .map(|&(steps_1, steps_2)| steps_1 + steps_2)
             .min()
-            .unwrap()
-            .to_string())
+            .ok_or(Error::NoSolution)?
+            .into())
     }
 }
 
+fn parse_input(input: &str) -> Result<(Wire, Wire), Error> {
+    let (line1, line2) =
+        input
+            .trim()
+            .split_once('\n')
+            .or_invalid(input, end(input), "expected two wires")?;
+    Ok((Wire::new(input, line1)?, Wire::new(input, line2)?))
+}
+
 struct Wire {
     points: Vec<(i32, i32)>,
 }
 impl Wire {
-    fn new(moves: &str) -> Self {
-        let mut points = vec![(0, 0)];
+    fn new(input: &str, moves: &str) -> Result<Self, Error> {
+        let mut points: Vec<(i32, i32)> = vec![(0, 0)];
 
         for next_move in moves.trim().split(',') {
-            let (x, y) = points.last().unwrap();
-            let (x, y) = match next_move.chars().next().unwrap() {
-                'U' => (*x, y + next_move[1..].parse::<i32>().unwrap()),
-                'D' => (*x, y - next_move[1..].parse::<i32>().unwrap()),
-                'L' => (x - next_move[1..].parse::<i32>().unwrap(), *y),
-                'R' => (x + next_move[1..].parse::<i32>().unwrap(), *y),
-                _ => panic!("Invalid direction"),
+            let (x, y) = points[points.len() - 1];
+            let mut chars = next_move.chars();
+            let direction = chars.next();
+            let distance = parse_limited(input, chars.as_str(), 0..=MAX_DISTANCE)?;
+            let (x, y) = match direction {
+                Some('U') => (Some(x), y.checked_add(distance)),
+                Some('D') => (Some(x), y.checked_sub(distance)),
+                Some('L') => (x.checked_sub(distance), Some(y)),
+                Some('R') => (x.checked_add(distance), Some(y)),
+                _ => {
+                    return Err(Error::invalid_input(
+                        input,
+                        next_move,
+                        "expected a direction",
+                    ));
+                }
             };
-            points.push((x, y));
+            let point = x.zip(y).or_invalid(input, next_move, "wire is too long")?;
+            points.push(point);
         }
 
-        Self { points }
+        Ok(Self { points })
     }
 
     #[allow(clippy::collapsible_else_if)]
@@ -70,12 +98,10 @@ impl Wire {
                 if self_pt1.0 == self_pt2.0 {
                     if other_pt1.0 == other_pt2.0 {
                         if self_pt1.0 == other_pt1.0 {
-                            for k in self_pt1.1..=self_pt2.1 {
-                                for l in other_pt1.1..=other_pt2.1 {
-                                    if k == l {
-                                        crossings.push((self_pt1.0, k));
-                                    }
-                                }
+                            let from = max(self_pt1.1, other_pt1.1);
+                            let to = min(self_pt2.1, other_pt2.1);
+                            for k in from..=to {
+                                crossings.push((self_pt1.0, k));
                             }
                         }
                     } else {
@@ -98,12 +124,10 @@ impl Wire {
                         }
                     } else {
                         if self_pt1.1 == other_pt1.1 {
-                            for k in self_pt1.0..=self_pt2.0 {
-                                for l in other_pt1.0..=other_pt2.0 {
-                                    if k == l {
-                                        crossings.push((k, self_pt1.1));
-                                    }
-                                }
+                            let from = max(self_pt1.0, other_pt1.0);
+                            let to = min(self_pt2.0, other_pt2.0);
+                            for k in from..=to {
+                                crossings.push((k, self_pt1.1));
                             }
                         }
                     }
@@ -116,7 +140,7 @@ impl Wire {
         crossings
     }
 
-    fn intersections_steps(&self, other: &Self) -> Vec<(u32, u32)> {
+    fn intersections_steps(&self, other: &Self) -> Vec<(u64, u64)> {
         let mut intersections = HashSet::new();
         for inter in self.intersections(other) {
             intersections.insert(inter);
@@ -133,11 +157,11 @@ impl Wire {
     }
 
     #[allow(clippy::collapsible_else_if)]
-    fn measure_steps(intersections: &HashSet<(i32, i32)>, wire: &Self) -> HashMap<(i32, i32), u32> {
+    fn measure_steps(intersections: &HashSet<(i32, i32)>, wire: &Self) -> HashMap<(i32, i32), u64> {
         let mut steps = HashMap::new();
         let mut total_steps = 0;
 
-        let mut check_insert = |point: (i32, i32), total_steps: u32| {
+        let mut check_insert = |point: (i32, i32), total_steps: u64| {
             if intersections.contains(&point) && !steps.contains_key(&point) {
                 steps.insert(point, total_steps);
             }
@@ -1,12 +1,19 @@
-use crate::{Error, Solution};
-use itertools::Itertools;
+use crate::input::OrInvalid;
+use crate::{Answer, Error, Solution};
 use std::collections::HashSet;
 
-day!(Day04, 2019, 4, "Secure Container");
+day!(
+    Day04,
+    2019,
+    4,
+    "Secure Container",
+    difficulty = Easy,
+    tags = [Combinatorics, Strings]
+);
 
 impl Solution for Day04 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let (start, end) = parse_input(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let (start, end) = parse_input(input)?;
         let mut current = start;
         let mut count = 0;
 
@@ -29,11 +36,11 @@ impl Solution for Day04 {
             }
         }
 
-        Ok(count.to_string())
+        Ok(count.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let (start, end) = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let (start, end) = parse_input(input)?;
         let mut current = start;
         let mut count = 0;
 
@@ -67,23 +74,25 @@ impl Solution for Day04 {
             }
         }
 
-        Ok(count.to_string())
+        Ok(count.into())
     }
 }
 
-fn parse_input(input: &str) -> ([u8; 6], [u8; 6]) {
-    input
-        .trim()
-        .split('-')
-        .map(|s| {
-            s.chars()
-                .map(|c| c.to_digit(10).unwrap() as u8)
-                .collect::<Vec<u8>>()
-                .try_into()
-                .unwrap()
-        })
-        .collect_tuple()
-        .unwrap()
+fn parse_input(input: &str) -> Result<([u8; 6], [u8; 6]), Error> {
+    let password = |s: &str| -> Result<[u8; 6], Error> {
+        s.chars()
+            .map(|c| c.to_digit(10).map(|digit| digit as u8))
+            .collect::<Option<Vec<u8>>>()
+            .and_then(|digits| digits.try_into().ok())
+            .or_invalid(input, s, "expected a six-digit number")
+    };
+
+    let (start, end) =
+        input
+            .trim()
+            .split_once('-')
+            .or_invalid(input, input.trim(), "expected a range")?;
+    Ok((password(start)?, password(end)?))
 }
 
 fn first_increasing_pass(password: &mut [u8]) {
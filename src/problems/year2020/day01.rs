@@ -1,30 +1,42 @@
-use crate::{Error, Solution};
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
 
-day!(Day01, 2020, 1, "Report Repair");
+day!(
+    Day01,
+    2020,
+    1,
+    "Report Repair",
+    difficulty = Easy,
+    tags = [Combinatorics]
+);
 
 impl Solution for Day01 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let set = store_input_in_set(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let set = store_input_in_set(input)?;
         for num in &set {
-            let diff = 2020 - num;
+            let Some(diff) = 2020_usize.checked_sub(*num) else {
+                continue;
+            };
             if set.contains(&diff) {
-                return Ok((num * diff).to_string());
+                return Ok((num * diff).into());
             }
         }
         Err(Error::NoSolution)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let set = store_input_in_set(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let set = store_input_in_set(input)?;
         for (i, num1) in set.iter().enumerate() {
-            let diff1 = 2020 - num1;
+            let Some(diff1) = 2020_usize.checked_sub(*num1) else {
+                continue;
+            };
             for (j, num2) in set.iter().enumerate() {
                 if i == j || num2 > &diff1 {
                     continue;
                 }
                 let diff2 = diff1 - num2;
                 if set.contains(&diff2) {
-                    return Ok((num1 * num2 * diff2).to_string());
+                    return Ok((num1 * num2 * diff2).into());
                 }
             }
         }
@@ -32,10 +44,10 @@ impl Solution for Day01 {
     }
 }
 
-fn store_input_in_set(input: &str) -> Vec<usize> {
+fn store_input_in_set(input: &str) -> Result<Vec<usize>, Error> {
     let mut set = Vec::new();
     for line in input.trim().lines() {
-        set.push(line.parse::<usize>().unwrap());
+        set.push(parse_number(input, line)?);
     }
-    set
+    Ok(set)
 }
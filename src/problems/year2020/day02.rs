@@ -1,10 +1,18 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 
-day!(Day02, 2020, 2, "Password Philosophy");
+day!(
+    Day02,
+    2020,
+    2,
+    "Password Philosophy",
+    difficulty = Easy,
+    tags = [Parsing, Strings]
+);
 
 impl Solution for Day02 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let passwords = parse_input(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let passwords = parse_input(input)?;
         Ok(passwords
             .iter()
             .filter(|(min, max, character, password)| {
@@ -12,34 +20,48 @@ impl Solution for Day02 {
                 (*min..=*max).contains(&count)
             })
             .count()
-            .to_string())
+            .into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let passwords = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let passwords = parse_input(input)?;
         Ok(passwords
             .iter()
             .filter(|(min, max, character, password)| {
-                (password.chars().nth(min - 1).unwrap() == *character)
-                    ^ (password.chars().nth(max - 1).unwrap() == *character)
+                let at = |position: usize| {
+                    position
+                        .checked_sub(1)
+                        .and_then(|i| password.chars().nth(i))
+                };
+                (at(*min) == Some(*character)) ^ (at(*max) == Some(*character))
             })
             .count()
-            .to_string())
+            .into())
     }
 }
 
-fn parse_input(input: &str) -> Vec<(usize, usize, char, &str)> {
+fn parse_input(input: &str) -> Result<Vec<(usize, usize, char, &str)>, Error> {
     input
         .trim()
         .lines()
         .map(|line| {
-            let mut split = line.split(' ');
-            let mut range = split.next().unwrap().split('-');
-            let min = range.next().unwrap().parse::<usize>().unwrap();
-            let max = range.next().unwrap().parse::<usize>().unwrap();
-            let character = split.next().unwrap().chars().next().unwrap();
-            let password = split.next().unwrap();
-            (min, max, character, password)
+            let [range, character, password] = line.split(' ').collect::<Vec<_>>()[..] else {
+                return Err(Error::invalid_input(
+                    input,
+                    line,
+                    "expected a policy and a password",
+                ));
+            };
+            let (min, max) = range
+                .split_once('-')
+                .or_invalid(input, range, "expected a range")?;
+            let min = parse_number(input, min)?;
+            let max = parse_number(input, max)?;
+            let character = character
+                .strip_suffix(':')
+                .and_then(|character| character.parse().ok())
+                .or_invalid(input, character, "expected a character")?;
+            Ok((min, max, character, password))
         })
         .collect()
 }
@@ -1,20 +1,27 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
-day!(Day03, 2020, 3, "Toboggan Trajectory");
+day!(
+    Day03,
+    2020,
+    3,
+    "Toboggan Trajectory",
+    difficulty = Easy,
+    tags = [Grid]
+);
 
 impl Solution for Day03 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let map = parse_input(input);
-        Ok(count_trees(SLOPE_PART1, &map).to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let map = parse_input(input)?;
+        Ok(count_trees(SLOPE_PART1, &map).into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let map = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let map = parse_input(input)?;
         Ok(SLOPES_PART2
             .into_iter()
             .map(|slope| count_trees(slope, &map))
             .product::<u64>()
-            .to_string())
+            .into())
     }
 }
 
@@ -38,10 +45,26 @@ fn count_trees(slope: [usize; 2], map: &[Vec<bool>]) -> u64 {
     tree_count
 }
 
-fn parse_input(input: &str) -> Vec<Vec<bool>> {
-    input
+fn parse_input(input: &str) -> Result<Vec<Vec<bool>>, Error> {
+    let map = input
         .trim()
         .lines()
-        .map(|line| line.chars().map(|c| c == '#').collect())
-        .collect()
+        .map(|line| {
+            line.char_indices()
+                .map(|(i, c)| match c {
+                    '#' => Ok(true),
+                    '.' => Ok(false),
+                    _ => Err(Error::invalid_input(input, &line[i..], "expected a square")),
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    match map.first() {
+        Some(row) if !row.is_empty() && map.iter().all(|other| other.len() == row.len()) => Ok(map),
+        _ => Err(Error::invalid_input(
+            input,
+            input.trim(),
+            "expected a rectangular map",
+        )),
+    }
 }
@@ -1,28 +1,28 @@
-use crate::{Error, Solution};
-
-day!(Day04, 2020, 4, "Passport Processing");
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
+
+day!(
+    Day04,
+    2020,
+    4,
+    "Passport Processing",
+    difficulty = Easy,
+    tags = [Parsing]
+);
 
 impl Solution for Day04 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let passports = parse_input(input);
-        Ok(passports
-            .iter()
-            .filter(|p| p.is_valid1())
-            .count()
-            .to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let passports = parse_input(input)?;
+        Ok(passports.iter().filter(|p| p.is_valid1()).count().into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let passports = parse_input(input);
-        Ok(passports
-            .iter()
-            .filter(|p| p.is_valid2())
-            .count()
-            .to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let passports = parse_input(input)?;
+        Ok(passports.iter().filter(|p| p.is_valid2()).count().into())
     }
 }
 
-fn parse_input<'a>(input: &'a str) -> Vec<Passport<'a>> {
+fn parse_input<'a>(input: &'a str) -> Result<Vec<Passport<'a>>, Error> {
     let input_lines = input.trim().lines();
     let mut passports = Vec::new();
 
@@ -33,27 +33,28 @@ fn parse_input<'a>(input: &'a str) -> Vec<Passport<'a>> {
             passport = Passport::new();
         } else {
             for element in line.split_whitespace() {
-                let mut element = element.split(':');
-                let key = element.next().unwrap();
-                let value = element.next().unwrap();
+                let (key, value) =
+                    element
+                        .split_once(':')
+                        .or_invalid(input, element, "expected a field")?;
 
                 match key {
-                    "byr" => passport.byr = Some(value.parse().unwrap()),
-                    "iyr" => passport.iyr = Some(value.parse().unwrap()),
-                    "eyr" => passport.eyr = Some(value.parse().unwrap()),
+                    "byr" => passport.byr = Some(parse_number(input, value)?),
+                    "iyr" => passport.iyr = Some(parse_number(input, value)?),
+                    "eyr" => passport.eyr = Some(parse_number(input, value)?),
                     "hgt" => passport.hgt = Some(value),
                     "hcl" => passport.hcl = Some(value),
                     "ecl" => passport.ecl = Some(value),
                     "pid" => passport.pid = Some(value),
                     "cid" => passport.cid = Some(value),
-                    _ => panic!("Invalid key"),
+                    _ => return Err(Error::invalid_input(input, key, "expected a field name")),
                 }
             }
         }
     }
     passports.push(passport);
 
-    passports
+    Ok(passports)
 }
 
 struct Passport<'a> {
@@ -123,12 +124,12 @@ impl Passport<'_> {
         //     If in, the number must be at least 59 and at most 76.
         if let Some(hgt) = self.hgt {
             if hgt.ends_with("cm") {
-                let hgt: i32 = hgt.trim_end_matches("cm").parse().unwrap();
+                let hgt = hgt.trim_end_matches("cm").parse::<i32>().unwrap_or(0);
                 if !(150..=193).contains(&hgt) {
                     return false;
                 }
             } else if hgt.ends_with("in") {
-                let hgt: i32 = hgt.trim_end_matches("in").parse().unwrap();
+                let hgt = hgt.trim_end_matches("in").parse::<i32>().unwrap_or(0);
                 if !(59..=76).contains(&hgt) {
                     return false;
                 }
@@ -1,29 +1,35 @@
-use crate::{Error, Solution};
+use crate::input::OrInvalid;
+use crate::{Answer, Error, Solution};
 
-day!(Day05, 2020, 5, "Binary Boarding");
+day!(
+    Day05,
+    2020,
+    5,
+    "Binary Boarding",
+    difficulty = Easy,
+    tags = [Bitwise]
+);
 
 impl Solution for Day05 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(input
-            .lines()
-            .map(BoardingPass::new)
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        parse_input(input)?
+            .iter()
             .map(|b_pass| b_pass.seat_id())
             .max()
-            .unwrap()
-            .to_string())
+            .map(Answer::from)
+            .ok_or(Error::NoSolution)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut taken_seats = input
-            .lines()
-            .map(BoardingPass::new)
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut taken_seats = parse_input(input)?
+            .iter()
             .map(|b_pass| b_pass.seat_id())
             .collect::<Vec<_>>();
         taken_seats.sort();
 
-        for i in 0..(taken_seats.len() - 1) {
+        for i in 0..taken_seats.len().saturating_sub(1) {
             if taken_seats[i + 1] - taken_seats[i] == 2 {
-                return Ok((taken_seats[i] + 1).to_string());
+                return Ok((taken_seats[i] + 1).into());
             }
         }
 
@@ -31,13 +37,24 @@ impl Solution for Day05 {
     }
 }
 
+fn parse_input(input: &str) -> Result<Vec<BoardingPass>, Error> {
+    input
+        .trim()
+        .lines()
+        .map(|line| BoardingPass::new(input, line))
+        .collect()
+}
+
 struct BoardingPass {
     row: u8,
     col: u8,
 }
 impl BoardingPass {
-    fn new(position: &str) -> Self {
-        let (row_ins, col_ins) = position.split_at(7);
+    fn new(input: &str, position: &str) -> Result<Self, Error> {
+        let (row_ins, col_ins) = position
+            .split_at_checked(7)
+            .filter(|(_, col_ins)| col_ins.len() == 3)
+            .or_invalid(input, position, "expected 10 characters")?;
 
         let mut row_range = 0..128;
         let mut col_range = 0..8;
@@ -46,7 +63,7 @@ impl BoardingPass {
             match c {
                 'F' => row_range.end = row_range.start + (row_range.end - row_range.start) / 2,
                 'B' => row_range.start += (row_range.end - row_range.start) / 2,
-                _ => panic!("Invalid character in row instruction"),
+                _ => return Err(Error::invalid_input(input, row_ins, "expected F or B")),
             }
         }
 
@@ -54,17 +71,17 @@ impl BoardingPass {
             match c {
                 'L' => col_range.end = col_range.start + (col_range.end - col_range.start) / 2,
                 'R' => col_range.start += (col_range.end - col_range.start) / 2,
-                _ => panic!("Invalid character in column instruction"),
+                _ => return Err(Error::invalid_input(input, col_ins, "expected L or R")),
             }
         }
 
         assert_eq!(row_range.len(), 1);
         assert_eq!(col_range.len(), 1);
 
-        Self {
+        Ok(Self {
             row: row_range.start,
             col: col_range.start,
-        }
+        })
     }
     fn seat_id(&self) -> u16 {
         self.row as u16 * 8 + self.col as u16
@@ -1,9 +1,16 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
-day!(Day06, 2020, 6, "Custom Customs");
+day!(
+    Day06,
+    2020,
+    6,
+    "Custom Customs",
+    difficulty = Easy,
+    tags = [Strings]
+);
 
 impl Solution for Day06 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let mut group_answers = [false; 26];
         let mut counts_sum = 0;
 
@@ -12,17 +19,17 @@ impl Solution for Day06 {
                 counts_sum += group_answers.iter().filter(|&&x| x).count();
                 group_answers = [false; 26];
             } else {
-                for c in line.chars() {
-                    group_answers[(c as u32 - 'a' as u32) as usize] = true;
+                for answer in line.char_indices() {
+                    group_answers[question(input, line, answer)?] = true;
                 }
             }
         }
         counts_sum += group_answers.iter().filter(|&&x| x).count();
 
-        Ok(counts_sum.to_string())
+        Ok(counts_sum.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut group_answers = [0; 26];
         let mut group_size = 0;
         let mut counts_sum = 0;
@@ -33,14 +40,25 @@ impl Solution for Day06 {
                 group_answers = [0; 26];
                 group_size = 0;
             } else {
-                for c in line.chars() {
-                    group_answers[(c as u32 - 'a' as u32) as usize] += 1;
+                for answer in line.char_indices() {
+                    group_answers[question(input, line, answer)?] += 1;
                 }
                 group_size += 1;
             }
         }
         counts_sum += group_answers.iter().filter(|&&x| x == group_size).count();
 
-        Ok(counts_sum.to_string())
+        Ok(counts_sum.into())
+    }
+}
+
+fn question(input: &str, line: &str, (i, c): (usize, char)) -> Result<usize, Error> {
+    match c {
+        'a'..='z' => Ok((c as u8 - b'a') as usize),
+        _ => Err(Error::invalid_input(
+            input,
+            &line[i..],
+            "expected a question",
+        )),
     }
 }
@@ -1,16 +1,24 @@
-use crate::{Error, Solution};
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
 
-day!(Day08, 2020, 8, "Handheld Halting");
+day!(
+    Day08,
+    2020,
+    8,
+    "Handheld Halting",
+    difficulty = Easy,
+    tags = [Vm]
+);
 
 impl Solution for Day08 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut console = Console::new(input);
-        Ok(console.execute().to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut console = Console::new(input)?;
+        Ok(console.execute().into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut console = Console::new(input);
-        Ok(console.execute_fixed().to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut console = Console::new(input)?;
+        Ok(console.execute_fixed()?.into())
     }
 }
 
@@ -20,12 +28,16 @@ enum InstructionType {
     Nop,
 }
 impl InstructionType {
-    fn new(ins_type: &str) -> Self {
+    fn new(input: &str, ins_type: &str) -> Result<Self, Error> {
         match ins_type {
-            "acc" => Self::Acc,
-            "jmp" => Self::Jmp,
-            "nop" => Self::Nop,
-            _ => panic!("Invalid instruction encountered"),
+            "acc" => Ok(Self::Acc),
+            "jmp" => Ok(Self::Jmp),
+            "nop" => Ok(Self::Nop),
+            _ => Err(Error::invalid_input(
+                input,
+                ins_type,
+                "expected an operation",
+            )),
         }
     }
 }
@@ -35,15 +47,21 @@ struct Instruction {
     executions: u8,
 }
 impl Instruction {
-    fn new(instruction: &str) -> Self {
-        let mut ins_info = instruction.split_whitespace();
-        let instruction_type = InstructionType::new(ins_info.next().unwrap());
-        let value = ins_info.next().unwrap().parse().unwrap();
-        Self {
+    fn new(input: &str, instruction: &str) -> Result<Self, Error> {
+        let [ins_type, value] = instruction.split_whitespace().collect::<Vec<_>>()[..] else {
+            return Err(Error::invalid_input(
+                input,
+                instruction,
+                "expected an instruction",
+            ));
+        };
+        let instruction_type = InstructionType::new(input, ins_type)?;
+        let value = parse_number(input, value)?;
+        Ok(Self {
             instruction_type,
             value,
             executions: 0,
-        }
+        })
     }
     fn reset(&mut self) {
         self.executions = 0;
@@ -52,37 +70,43 @@ impl Instruction {
 struct Console {
     instructions: Vec<Instruction>,
     ins_ptr: usize,
-    accumulator: i32,
+    accumulator: i64,
 }
 impl Console {
-    fn new(input: &str) -> Self {
-        let instructions = input.lines().map(Instruction::new).collect();
-        Self {
+    fn new(input: &str) -> Result<Self, Error> {
+        let instructions = input
+            .trim()
+            .lines()
+            .map(|line| Instruction::new(input, line))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
             instructions,
             ins_ptr: 0,
             accumulator: 0,
-        }
+        })
     }
-    fn execute(&mut self) -> i32 {
+    fn execute(&mut self) -> i64 {
         while self.ins_ptr < self.instructions.len()
             && self.instructions[self.ins_ptr].executions < 1
         {
             self.instructions[self.ins_ptr].executions += 1;
             match self.instructions[self.ins_ptr].instruction_type {
                 InstructionType::Acc => {
-                    self.accumulator += self.instructions[self.ins_ptr].value;
+                    self.accumulator += i64::from(self.instructions[self.ins_ptr].value);
                     self.ins_ptr += 1;
                 }
                 InstructionType::Jmp => {
-                    self.ins_ptr =
-                        (self.ins_ptr as i32 + self.instructions[self.ins_ptr].value) as usize
+                    // a jump before the first instruction ends the program like a jump after the last one
+                    let target =
+                        self.ins_ptr as i64 + i64::from(self.instructions[self.ins_ptr].value);
+                    self.ins_ptr = usize::try_from(target).unwrap_or(usize::MAX);
                 }
                 InstructionType::Nop => self.ins_ptr += 1,
             }
         }
         self.accumulator
     }
-    fn execute_fixed(&mut self) -> i32 {
+    fn execute_fixed(&mut self) -> Result<i64, Error> {
         for i in 0..self.instructions.len() {
             match self.instructions[i].instruction_type {
                 InstructionType::Jmp => {
@@ -96,7 +120,7 @@ impl Console {
             self.reset();
             let result = self.execute();
             if self.ins_ptr == self.instructions.len() {
-                return result;
+                return Ok(result);
             }
             match self.instructions[i].instruction_type {
                 InstructionType::Jmp => {
@@ -108,7 +132,7 @@ impl Console {
                 _ => (),
             }
         }
-        panic!("No solution found");
+        Err(Error::NoSolution)
     }
     fn reset(&mut self) {
         self.ins_ptr = 0;
@@ -1,39 +1,51 @@
-use crate::{Error, Solution};
+use crate::input::parse_number;
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 
-day!(Day09, 2020, 9, "Encoding Error");
+day!(
+    Day09,
+    2020,
+    9,
+    "Encoding Error",
+    difficulty = Easy,
+    tags = [Combinatorics]
+);
 
 impl Solution for Day09 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let data = input
-            .lines()
-            .map(|line| line.parse::<u64>().unwrap())
-            .collect::<Vec<_>>();
-        let i = find_invalid_number_pos(&data)?;
-        Ok(data[i].to_string())
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::integer(
+            "preamble",
+            "The number of previous numbers which every number is checked against",
+            25,
+        )]
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let data = input
-            .lines()
-            .map(|line| line.parse::<u64>().unwrap())
-            .collect::<Vec<_>>();
-        let i = find_invalid_number_pos(&data)?;
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let data = parse_input(input)?;
+        let i = find_invalid_number_pos(&data, parameters.integer("preamble")?)?;
+        Ok(data[i].into())
+    }
+
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let data = parse_input(input)?;
+        let i = find_invalid_number_pos(&data, parameters.integer("preamble")?)?;
         let mut ptr1 = 0;
         let mut ptr2 = 0;
-        let mut sum = data[0];
+        // the sums of the ranges of 64-bit numbers are computed in 128 bits, so they cannot overflow
+        let target = u128::from(data[i]);
+        let mut sum = u128::from(data[0]);
 
-        while sum != data[i] {
-            if sum < data[i] {
+        while sum != target {
+            if sum < target {
                 ptr2 += 1;
-                sum += data[ptr2];
+                sum += u128::from(data[ptr2]);
             } else {
                 #[allow(clippy::collapsible_else_if)]
                 if ptr1 == ptr2 {
                     ptr1 += 1;
                     ptr2 += 1;
-                    sum = data[ptr1];
+                    sum = u128::from(data[ptr1]);
                 } else {
-                    sum -= data[ptr1];
+                    sum -= u128::from(data[ptr1]);
                     ptr1 += 1;
                 }
             }
@@ -42,17 +54,25 @@ impl Solution for Day09 {
         let min = data[ptr1..=ptr2].iter().min().unwrap();
         let max = data[ptr1..=ptr2].iter().max().unwrap();
 
-        Ok((min + max).to_string())
+        Ok((i128::from(*min) + i128::from(*max)).into())
     }
 }
 
-fn find_invalid_number_pos(data: &[u64]) -> Result<usize, Error> {
-    'outer: for i in 25..data.len() {
-        let prev_25 = &data[i - 25..i];
-        for &addend1 in prev_25 {
+fn parse_input(input: &str) -> Result<Vec<u64>, Error> {
+    input
+        .trim()
+        .lines()
+        .map(|line| parse_number(input, line))
+        .collect()
+}
+
+fn find_invalid_number_pos(data: &[u64], preamble: usize) -> Result<usize, Error> {
+    'outer: for i in preamble..data.len() {
+        let previous = &data[i - preamble..i];
+        for &addend1 in previous {
             if let Some(addend2) = data[i].checked_sub(addend1)
                 && addend1 != addend2
-                && prev_25.contains(&addend2)
+                && previous.contains(&addend2)
             {
                 continue 'outer;
             }
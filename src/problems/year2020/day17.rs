@@ -1,22 +1,24 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 use ndlife::Life;
 use std::collections::HashSet;
 
-day!(Day17, 2020, 17, "Conway Cubes");
+day!(
+    Day17,
+    2020,
+    17,
+    "Conway Cubes",
+    difficulty = Medium,
+    tags = [CellularAutomaton, Grid]
+);
 
 impl Solution for Day17 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let birth_rules = [3].into_iter().collect();
         let survival_rules = [2, 3].into_iter().collect();
-        let mut alive_cells = HashSet::new();
-
-        for (y, line) in input.trim().lines().enumerate() {
-            for (x, c) in line.chars().enumerate() {
-                if c == '#' {
-                    alive_cells.insert([x as i64, y as i64, 0]);
-                }
-            }
-        }
+        let alive_cells = parse_input(input)?
+            .into_iter()
+            .map(|(x, y)| [x as i64, y as i64, 0])
+            .collect::<HashSet<_>>();
 
         let mut life =
             Life::new_with_alive_cells(birth_rules, survival_rules, alive_cells).unwrap();
@@ -24,21 +26,16 @@ impl Solution for Day17 {
             life.next_generation();
         }
 
-        Ok(life.alive_cells().len().to_string())
+        Ok(life.alive_cells().len().into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let birth_rules = [3].into_iter().collect();
         let survival_rules = [2, 3].into_iter().collect();
-        let mut alive_cells = HashSet::new();
-
-        for (y, line) in input.trim().lines().enumerate() {
-            for (x, c) in line.chars().enumerate() {
-                if c == '#' {
-                    alive_cells.insert([x as i64, y as i64, 0, 0]);
-                }
-            }
-        }
+        let alive_cells = parse_input(input)?
+            .into_iter()
+            .map(|(x, y)| [x as i64, y as i64, 0, 0])
+            .collect::<HashSet<_>>();
 
         let mut life =
             Life::new_with_alive_cells(birth_rules, survival_rules, alive_cells).unwrap();
@@ -46,6 +43,21 @@ impl Solution for Day17 {
             life.next_generation();
         }
 
-        Ok(life.alive_cells().len().to_string())
+        Ok(life.alive_cells().len().into())
+    }
+}
+
+/// Parse the positions of the active cubes in the initial slice.
+fn parse_input(input: &str) -> Result<Vec<(usize, usize)>, Error> {
+    let mut active = Vec::new();
+    for (y, line) in input.trim().lines().enumerate() {
+        for (x, (i, c)) in line.char_indices().enumerate() {
+            match c {
+                '#' => active.push((x, y)),
+                '.' => {}
+                _ => return Err(Error::invalid_input(input, &line[i..], "expected a cube")),
+            }
+        }
     }
+    Ok(active)
 }
@@ -1,39 +1,39 @@
-use crate::{Error, Solution};
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
 
-day!(Day01, 2021, 1, "Sonar Sweep");
+day!(Day01, 2021, 1, "Sonar Sweep", difficulty = Easy, tags = []);
 
 impl Solution for Day01 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let mut prev = usize::MAX;
         let mut count = 0;
 
         for line in input.trim().lines() {
-            let num = line.parse::<usize>().unwrap();
+            let num = parse_number::<usize>(input, line)?;
             if num > prev {
                 count += 1;
             }
             prev = num;
         }
 
-        Ok(count.to_string())
+        Ok(count.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let input: Vec<usize> = input
             .trim()
             .lines()
-            .map(|line| line.parse::<usize>().unwrap())
-            .collect();
+            .map(|line| parse_number(input, line))
+            .collect::<Result<_, _>>()?;
         let mut count = 0;
 
-        for i in 0..(input.len() - 3) {
-            if (input[i + 1] + input[i + 2] + input[i + 3])
-                > (input[i] + input[i + 1] + input[i + 2])
-            {
+        for i in 0..input.len().saturating_sub(3) {
+            // the windows share two measurements, so only the ones they don't share are compared
+            if input[i + 3] > input[i] {
                 count += 1;
             }
         }
 
-        Ok(count.to_string())
+        Ok(count.into())
     }
 }
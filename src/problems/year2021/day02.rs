@@ -1,49 +1,86 @@
-use crate::{Error, Solution};
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
+use std::str::FromStr;
 
-day!(Day02, 2021, 2, "Dive!");
+day!(
+    Day02,
+    2021,
+    2,
+    "Dive!",
+    difficulty = Easy,
+    tags = [Parsing, Simulation]
+);
 
 impl Solution for Day02 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut forward = 0;
-        let mut depth = 0;
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut forward: u64 = 0;
+        let mut depth: u64 = 0;
 
         for line in input.trim().lines() {
-            let mut iter = line.split_whitespace();
-            let command_type = iter.next().unwrap();
-            let amount: u64 = iter.next().unwrap().parse().unwrap();
+            let (command_type, amount) = parse_command::<u64>(input, line)?;
 
             match command_type {
-                "forward" => forward += amount,
-                "down" => depth += amount,
+                "forward" => forward = forward.checked_add(amount).ok_or(Error::NoSolution)?,
+                "down" => depth = depth.checked_add(amount).ok_or(Error::NoSolution)?,
                 "up" => depth = depth.saturating_sub(amount),
-                _ => panic!("Invalid command type"),
+                _ => {
+                    return Err(Error::invalid_input(
+                        input,
+                        command_type,
+                        "expected a command",
+                    ));
+                }
             }
         }
 
-        Ok((forward * depth).to_string())
+        forward
+            .checked_mul(depth)
+            .map(Answer::from)
+            .ok_or(Error::NoSolution)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut forward = 0;
-        let mut depth = 0;
-        let mut aim = 0;
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut forward: i64 = 0;
+        let mut depth: i64 = 0;
+        let mut aim: i64 = 0;
 
         for line in input.trim().lines() {
-            let mut iter = line.split_whitespace();
-            let command_type = iter.next().unwrap();
-            let amount: i64 = iter.next().unwrap().parse().unwrap();
+            let (command_type, amount) = parse_command::<i64>(input, line)?;
 
             match command_type {
                 "forward" => {
-                    forward += amount;
-                    depth += aim * amount;
+                    forward = forward.checked_add(amount).ok_or(Error::NoSolution)?;
+                    depth = aim
+                        .checked_mul(amount)
+                        .and_then(|change| depth.checked_add(change))
+                        .ok_or(Error::NoSolution)?;
+                }
+                "down" => aim = aim.checked_add(amount).ok_or(Error::NoSolution)?,
+                "up" => aim = aim.checked_sub(amount).ok_or(Error::NoSolution)?,
+                _ => {
+                    return Err(Error::invalid_input(
+                        input,
+                        command_type,
+                        "expected a command",
+                    ));
                 }
-                "down" => aim += amount,
-                "up" => aim -= amount,
-                _ => panic!("Invalid command type"),
             }
         }
 
-        Ok((forward * depth).to_string())
+        forward
+            .checked_mul(depth)
+            .map(Answer::from)
+            .ok_or(Error::NoSolution)
+    }
+}
+
+fn parse_command<'a, T: FromStr>(input: &str, line: &'a str) -> Result<(&'a str, T), Error> {
+    match line.split_whitespace().collect::<Vec<_>>()[..] {
+        [command_type, amount] => Ok((command_type, parse_number(input, amount)?)),
+        _ => Err(Error::invalid_input(
+            input,
+            line,
+            "expected a command and an amount",
+        )),
     }
 }
@@ -1,10 +1,17 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
-day!(Day03, 2021, 3, "Binary Diagnostic");
+day!(
+    Day03,
+    2021,
+    3,
+    "Binary Diagnostic",
+    difficulty = Easy,
+    tags = [Bitwise]
+);
 
 impl Solution for Day03 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let data = parse_input(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let data = parse_input(input)?;
         let mut gamma: u32 = 0;
 
         // for each column, count the number of 1s and 0s
@@ -38,16 +45,19 @@ impl Solution for Day03 {
             inverse_gamma |= 1;
         }
 
-        Ok((gamma * (gamma ^ inverse_gamma)).to_string())
+        Ok((gamma * (gamma ^ inverse_gamma)).into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let data = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let data = parse_input(input)?;
 
         // oxygen
         let mut data_oxygen = data.clone();
         let mut i = 0;
         while data_oxygen.len() != 1 {
+            if i >= data[0].len() {
+                return Err(Error::NoSolution);
+            }
             let mut count = 0;
             for data_line in &data_oxygen {
                 if data_line[i] {
@@ -69,9 +79,13 @@ impl Solution for Day03 {
         }
 
         // carbon dioxide
+        let width = data[0].len();
         let mut data_carbon = data;
         let mut i = 0;
         while data_carbon.len() != 1 {
+            if i >= width {
+                return Err(Error::NoSolution);
+            }
             let mut count = 0;
             for data_line in &data_carbon {
                 if data_line[i] {
@@ -92,25 +106,35 @@ impl Solution for Day03 {
             }
         }
 
-        Ok((oxygen_value * carbon_value).to_string())
+        Ok((oxygen_value * carbon_value).into())
     }
 }
 
-fn parse_input(input: &str) -> Vec<Vec<bool>> {
+fn parse_input(input: &str) -> Result<Vec<Vec<bool>>, Error> {
     // store each line as a vector of bools
     // true is a 1, false is a 0
 
-    input
+    let data = input
         .trim()
         .lines()
         .map(|line| {
-            line.chars()
-                .map(|c| match c {
-                    '1' => true,
-                    '0' => false,
-                    _ => panic!("Invalid character"),
+            line.char_indices()
+                .map(|(i, c)| match c {
+                    '1' => Ok(true),
+                    '0' => Ok(false),
+                    _ => Err(Error::invalid_input(input, &line[i..], "expected a bit")),
                 })
-                .collect()
+                .collect::<Result<Vec<_>, _>>()
         })
-        .collect()
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // all numbers must have the same number of bits
+    match data.first() {
+        Some(first) if data.iter().all(|data_row| data_row.len() == first.len()) => Ok(data),
+        _ => Err(Error::invalid_input(
+            input,
+            input.trim(),
+            "expected numbers of equal width",
+        )),
+    }
 }
@@ -1,10 +1,18 @@
-use crate::{Error, Solution};
-
-day!(Day04, 2021, 4, "Giant Squid");
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
+
+day!(
+    Day04,
+    2021,
+    4,
+    "Giant Squid",
+    difficulty = Easy,
+    tags = [Grid, Simulation]
+);
 
 impl Solution for Day04 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let (draws, mut boards) = parse_input(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let (draws, mut boards) = parse_input(input)?;
 
         for draw in draws {
             // Mark the number on the boards
@@ -22,15 +30,15 @@ impl Solution for Day04 {
             }
 
             if let Some(board) = bingo_board {
-                return Ok((draw * boards[board].sum_unmarked()).to_string());
+                return boards[board].score(draw);
             }
         }
 
         Err(Error::NoSolution)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let (draws, mut boards) = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let (draws, mut boards) = parse_input(input)?;
 
         // Mark the numbers on the boards
         for draw in draws {
@@ -45,7 +53,7 @@ impl Solution for Day04 {
 
             // if there is a single board left and it has a bingo, return the result
             if boards.len() == 1 && boards[0].bingo() {
-                return Ok((draw * boards[0].sum_unmarked()).to_string());
+                return boards[0].score(draw);
             }
         }
 
@@ -53,16 +61,14 @@ impl Solution for Day04 {
     }
 }
 
-fn parse_input(input: &str) -> (Vec<u64>, Vec<Board>) {
+fn parse_input(input: &str) -> Result<(Vec<u64>, Vec<Board>), Error> {
     let mut draws = Vec::new();
     let mut boards = Vec::new();
 
     let mut lines = input.trim().lines();
-    lines
-        .next()
-        .unwrap()
-        .split(',')
-        .for_each(|n| draws.push(n.parse::<u64>().unwrap()));
+    for n in lines.next().unwrap_or_default().split(',') {
+        draws.push(parse_number(input, n)?);
+    }
     lines.next();
 
     let mut board = Board::new(Vec::new());
@@ -73,13 +79,23 @@ fn parse_input(input: &str) -> (Vec<u64>, Vec<Board>) {
         } else {
             let mut row = Vec::new();
             for n in line.split_whitespace() {
-                row.push((n.parse::<u64>().unwrap(), false));
+                row.push((parse_number(input, n)?, false));
+            }
+            if board
+                .values
+                .first()
+                .is_some_and(|first| first.len() != row.len())
+            {
+                return Err(Error::invalid_input(input, line, "expected a square board"));
             }
             board.values.push(row);
         }
     }
 
-    (draws, boards)
+    boards.push(board);
+
+    boards.retain(|board| !board.values.is_empty());
+    Ok((draws, boards))
 }
 
 // (number, is_marked)
@@ -118,15 +134,19 @@ impl Board {
         false
     }
 
-    fn sum_unmarked(&self) -> u64 {
-        let mut sum = 0;
+    /// Returns the score of the board after the last drawn number,
+    /// or [Error::NoSolution] if it overflows.
+    fn score(&self, draw: u64) -> Result<Answer, Error> {
+        let mut sum: u64 = 0;
         for row in &self.values {
             for col in row {
                 if !col.1 {
-                    sum += col.0;
+                    sum = sum.checked_add(col.0).ok_or(Error::NoSolution)?;
                 }
             }
         }
-        sum
+        sum.checked_mul(draw)
+            .map(Answer::from)
+            .ok_or(Error::NoSolution)
     }
 }
@@ -1,12 +1,24 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_limited};
+use crate::{Answer, Error, Solution};
 use std::cmp::{max, min};
 use std::iter::zip;
 
-day!(Day05, 2021, 5, "Hydrothermal Venture");
+day!(
+    Day05,
+    2021,
+    5,
+    "Hydrothermal Venture",
+    difficulty = Easy,
+    tags = [Geometry, Grid]
+);
+
+/// The largest accepted coordinate.
+/// The vents are counted on a grid reaching the largest coordinates, so this keeps it at a million cells.
+const MAX_COORDINATE: u32 = 1_000;
 
 impl Solution for Day05 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut lines = parse_input(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut lines = parse_input(input)?;
 
         // eliminate diagonal lines
         lines.retain(|line| line[0][0] == line[1][0] || line[0][1] == line[1][1]);
@@ -23,11 +35,11 @@ impl Solution for Day05 {
         }
 
         // count how many points are covered by 2 or more lines
-        Ok(covered_by_2(&grid).to_string())
+        Ok(covered_by_2(&grid).into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let lines = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let lines = parse_input(input)?;
 
         // find the size of the grid
         let grid_size = grid_size(&lines);
@@ -41,26 +53,32 @@ impl Solution for Day05 {
         }
 
         // count how many points are covered by 2 or more lines
-        Ok(covered_by_2(&grid).to_string())
+        Ok(covered_by_2(&grid).into())
     }
 }
 
-fn parse_input(input: &str) -> Vec<[[u32; 2]; 2]> {
+fn parse_input(input: &str) -> Result<Vec<[[u32; 2]; 2]>, Error> {
     let mut lines = Vec::new();
 
     for line in input.trim().lines() {
         let mut coords = [[0; 2]; 2];
 
-        for (i, coord_pair) in line.split("->").enumerate() {
-            let (coord_x, coord_y) = coord_pair.split_once(',').unwrap();
-            coords[i][0] = coord_x.trim().parse().unwrap();
-            coords[i][1] = coord_y.trim().parse().unwrap();
+        let (start, end) =
+            line.split_once("->")
+                .or_invalid(input, line, "expected a line segment")?;
+        for (i, coord_pair) in [start, end].into_iter().enumerate() {
+            let (coord_x, coord_y) =
+                coord_pair
+                    .split_once(',')
+                    .or_invalid(input, coord_pair, "expected a point")?;
+            coords[i][0] = parse_limited(input, coord_x.trim(), 0..=MAX_COORDINATE)?;
+            coords[i][1] = parse_limited(input, coord_y.trim(), 0..=MAX_COORDINATE)?;
         }
 
         lines.push(coords);
     }
 
-    lines
+    Ok(lines)
 }
 
 fn covered_by_2(grid: &Vec<Vec<u32>>) -> u32 {
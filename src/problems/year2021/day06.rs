@@ -1,22 +1,30 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 
-day!(Day06, 2021, 6, "Lanternfish");
+day!(
+    Day06,
+    2021,
+    6,
+    "Lanternfish",
+    difficulty = Easy,
+    tags = [DynamicProgramming, Simulation]
+);
 
 impl Solution for Day06 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut lanternfish = Lanternfish::new(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut lanternfish = Lanternfish::new(input)?;
         for _ in 0..DAYS_1 {
             lanternfish.simulate_day();
         }
-        Ok(lanternfish.fish_count().to_string())
+        Ok(lanternfish.fish_count().into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut lanternfish = Lanternfish::new(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut lanternfish = Lanternfish::new(input)?;
         for _ in 0..DAYS_2 {
             lanternfish.simulate_day();
         }
-        Ok(lanternfish.fish_count().to_string())
+        Ok(lanternfish.fish_count().into())
     }
 }
 
@@ -28,16 +36,17 @@ struct Lanternfish {
     fish_counts: [u64; 9],
 }
 impl Lanternfish {
-    fn new(input: &str) -> Self {
+    fn new(input: &str) -> Result<Self, Error> {
         let mut fish_counts = [0; 9];
-        for fish_state in input
-            .trim()
-            .split(',')
-            .map(|num_str| num_str.parse::<usize>().unwrap())
-        {
-            fish_counts[fish_state] += 1;
+        for num_str in input.trim().split(',') {
+            let fish_state = parse_number::<usize>(input, num_str)?;
+            *fish_counts.get_mut(fish_state).or_invalid(
+                input,
+                num_str,
+                "expected a timer up to 8",
+            )? += 1;
         }
-        Self { fish_counts }
+        Ok(Self { fish_counts })
     }
 
     fn fish_count(&self) -> u64 {
@@ -1,11 +1,19 @@
-use crate::{Error, Solution};
+use crate::input::OrInvalid;
+use crate::{Answer, Error, Solution};
 use std::cmp::Reverse;
 
-day!(Day09, 2021, 9, "Smoke Basin");
+day!(
+    Day09,
+    2021,
+    9,
+    "Smoke Basin",
+    difficulty = Easy,
+    tags = [Grid, Search]
+);
 
 impl Solution for Day09 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let cave = parse_input(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let cave = parse_input(input)?;
         let mut risks_sum = 0;
 
         for (i, row) in cave.iter().enumerate() {
@@ -29,11 +37,11 @@ impl Solution for Day09 {
             }
         }
 
-        Ok(risks_sum.to_string())
+        Ok(risks_sum.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let cave = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let cave = parse_input(input)?;
         let mut visited_tiles = vec![vec![false; cave[0].len()]; cave.len()];
         let mut basin_sizes = Vec::new();
 
@@ -74,19 +82,37 @@ impl Solution for Day09 {
             }
         }
 
+        if basin_sizes.len() < 3 {
+            return Err(Error::NoSolution);
+        }
         basin_sizes.sort_by_key(|&x| Reverse(x));
-        Ok((basin_sizes[0] * basin_sizes[1] * basin_sizes[2]).to_string())
+        Ok((basin_sizes[0] * basin_sizes[1] * basin_sizes[2]).into())
     }
 }
 
-fn parse_input(input: &str) -> Vec<Vec<u8>> {
+fn parse_input(input: &str) -> Result<Vec<Vec<u8>>, Error> {
     let mut cave = Vec::new();
-    input.lines().for_each(|line| {
+    for line in input.trim().lines() {
         cave.push(
-            line.chars()
-                .map(|c| c.to_digit(10).unwrap() as u8)
-                .collect::<Vec<_>>(),
-        )
-    });
-    cave
+            line.char_indices()
+                .map(|(i, c)| {
+                    c.to_digit(10).map(|height| height as u8).or_invalid(
+                        input,
+                        &line[i..],
+                        "expected a height",
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        );
+    }
+    match cave.first() {
+        Some(row) if !row.is_empty() && cave.iter().all(|other| other.len() == row.len()) => {
+            Ok(cave)
+        }
+        _ => Err(Error::invalid_input(
+            input,
+            input.trim(),
+            "expected a rectangular map",
+        )),
+    }
 }
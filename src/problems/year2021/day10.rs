@@ -1,10 +1,17 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 use std::collections::HashMap;
 
-day!(Day10, 2021, 10, "Syntax Scoring");
+day!(
+    Day10,
+    2021,
+    10,
+    "Syntax Scoring",
+    difficulty = Easy,
+    tags = [Parsing, Strings]
+);
 
 impl Solution for Day10 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let mut score_table = HashMap::with_capacity(4);
         score_table.insert(')', 3);
         score_table.insert(']', 57);
@@ -14,50 +21,43 @@ impl Solution for Day10 {
         let mut stack = Vec::new();
         let mut error_score = 0;
 
-        for line in input.lines() {
+        for line in input.trim().lines() {
             stack.clear();
-            for c in line.chars() {
+            for (i, c) in line.char_indices() {
                 if OPEN_BRACES.contains(&c) {
                     stack.push(c);
-                } else if CLOSE_BRACES.contains(&c) {
-                    if let Some(last) = stack.pop() {
-                        if last != OPEN_BRACES[CLOSE_BRACES.iter().position(|&x| x == c).unwrap()] {
-                            error_score += score_table.get(&c).unwrap();
-                            break;
-                        }
-                    } else {
-                        panic!("Empty stack");
+                } else if let Some(pos) = CLOSE_BRACES.iter().position(|&x| x == c) {
+                    // a closing brace with nothing left to close also corrupts the line
+                    if stack.pop() != Some(OPEN_BRACES[pos]) {
+                        error_score += score_table.get(&c).unwrap();
+                        break;
                     }
                 } else {
-                    panic!("Invalid character: {}", c);
+                    return Err(Error::invalid_input(input, &line[i..], "expected a brace"));
                 }
             }
             // if the stack is not clear here, the line is incomplete, and we are ignoring that
         }
 
-        Ok(error_score.to_string())
+        Ok(error_score.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut completion_scores = Vec::new();
 
         let mut stack = Vec::new();
-        'outer: for line in input.lines() {
+        'outer: for line in input.trim().lines() {
             stack.clear();
 
-            for c in line.chars() {
+            for (i, c) in line.char_indices() {
                 if OPEN_BRACES.contains(&c) {
                     stack.push(c);
-                } else if CLOSE_BRACES.contains(&c) {
-                    if let Some(last) = stack.pop() {
-                        if last != OPEN_BRACES[CLOSE_BRACES.iter().position(|&x| x == c).unwrap()] {
-                            continue 'outer; // ignore incomplete lines
-                        }
-                    } else {
-                        panic!("Empty stack");
+                } else if let Some(pos) = CLOSE_BRACES.iter().position(|&x| x == c) {
+                    if stack.pop() != Some(OPEN_BRACES[pos]) {
+                        continue 'outer; // ignore corrupted lines
                     }
                 } else {
-                    panic!("Invalid character: {}", c);
+                    return Err(Error::invalid_input(input, &line[i..], "expected a brace"));
                 }
             }
 
@@ -73,7 +73,11 @@ impl Solution for Day10 {
         }
 
         completion_scores.sort();
-        Ok(completion_scores[completion_scores.len() / 2].to_string())
+        completion_scores
+            .get(completion_scores.len() / 2)
+            .copied()
+            .map(Answer::from)
+            .ok_or(Error::NoSolution)
     }
 }
 
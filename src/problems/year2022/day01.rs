@@ -1,30 +1,48 @@
-use crate::{Error, Solution};
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
 
-day!(Day01, 2022, 1, "Calorie Counting");
+day!(
+    Day01,
+    2022,
+    1,
+    "Calorie Counting",
+    difficulty = Easy,
+    tags = []
+);
 
 impl Solution for Day01 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let elf_calories = count_calories(input);
-        Ok(elf_calories[elf_calories.len() - 1].to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let elf_calories = count_calories(input)?;
+        elf_calories
+            .last()
+            .copied()
+            .map(Answer::from)
+            .ok_or(Error::NoSolution)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let elf_calories = count_calories(input);
-        Ok(elf_calories[(elf_calories.len() - 3)..]
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let elf_calories = count_calories(input)?;
+        if elf_calories.len() < 3 {
+            return Err(Error::NoSolution);
+        }
+        elf_calories[(elf_calories.len() - 3)..]
             .iter()
-            .sum::<usize>()
-            .to_string())
+            .try_fold(0usize, |sum, &calories| sum.checked_add(calories))
+            .map(Answer::from)
+            .ok_or(Error::NoSolution)
     }
 }
 
-fn count_calories(input: &str) -> Vec<usize> {
+fn count_calories(input: &str) -> Result<Vec<usize>, Error> {
     let mut elf_calories = vec![];
-    let mut curr_calorie = 0;
+    let mut curr_calorie: usize = 0;
     let mut elf = false;
     for line in input.lines() {
         if !line.is_empty() {
             elf = true;
-            curr_calorie += line.parse::<usize>().unwrap();
+            curr_calorie = curr_calorie
+                .checked_add(parse_number(input, line)?)
+                .ok_or(Error::NoSolution)?;
         } else {
             elf_calories.push(curr_calorie);
             curr_calorie = 0;
@@ -35,5 +53,5 @@ fn count_calories(input: &str) -> Vec<usize> {
         elf_calories.push(curr_calorie);
     }
     elf_calories.sort();
-    elf_calories
+    Ok(elf_calories)
 }
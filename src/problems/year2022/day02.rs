@@ -1,73 +1,55 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
-day!(Day02, 2022, 2, "Rock Paper Scissors");
+day!(
+    Day02,
+    2022,
+    2,
+    "Rock Paper Scissors",
+    difficulty = Easy,
+    tags = [Parsing]
+);
 
 impl Solution for Day02 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let mut score = 0;
-        let mut game_values: Vec<&str>;
 
-        for game in input.lines() {
-            if !game.is_empty() {
-                game_values = game.split(' ').collect();
-                score += match game_values[1] {
-                    "X" => 1,
-                    "Y" => 2,
-                    "Z" => 3,
-                    _ => panic!(),
-                };
-                if (game_values[0] == "A" && game_values[1] == "Y")
-                    || (game_values[0] == "B" && game_values[1] == "Z")
-                    || (game_values[0] == "C") && game_values[1] == "X"
-                {
-                    score += 6;
-                } else if (game_values[0] == "A" && game_values[1] == "X")
-                    || (game_values[0] == "B" && game_values[1] == "Y")
-                    || (game_values[0] == "C") && game_values[1] == "Z"
-                {
-                    score += 3;
-                }
-            }
+        for (opponent, response) in parse_input(input)? {
+            // the second column is the shape to play
+            score += response + 1;
+            // 0 for a loss, 1 for a draw, 2 for a win
+            score += (response + 4 - opponent) % 3 * 3;
         }
-        Ok(score.to_string())
+        Ok(score.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut score = 0;
-        let mut game_values: Vec<&str>;
 
-        for game in input.lines() {
-            if !game.is_empty() {
-                game_values = game.split(' ').collect();
-                score += match game_values[1] {
-                    "X" => 0,
-                    "Y" => 3,
-                    "Z" => 6,
-                    _ => panic!(),
-                };
-                score += match game_values[1] {
-                    "X" => match game_values[0] {
-                        "A" => 3,
-                        "B" => 1,
-                        "C" => 2,
-                        _ => panic!(),
-                    },
-                    "Y" => match game_values[0] {
-                        "A" => 1,
-                        "B" => 2,
-                        "C" => 3,
-                        _ => panic!(),
-                    },
-                    "Z" => match game_values[0] {
-                        "A" => 2,
-                        "B" => 3,
-                        "C" => 1,
-                        _ => panic!(),
-                    },
-                    _ => panic!(),
-                };
-            }
+        for (opponent, outcome) in parse_input(input)? {
+            // the second column is the outcome of the round
+            score += outcome * 3;
+            // the shape that loses, draws or wins against the opponent
+            score += (opponent + outcome + 2) % 3 + 1;
         }
-        Ok(score.to_string())
+        Ok(score.into())
     }
 }
+
+/// Parse the strategy guide into pairs of column values, each in the range 0..3.
+fn parse_input(input: &str) -> Result<Vec<(u32, u32)>, Error> {
+    input
+        .lines()
+        .filter(|game| !game.is_empty())
+        .map(|game| match game.split(' ').collect::<Vec<_>>()[..] {
+            [first, second] => {
+                let opponent = ["A", "B", "C"].iter().position(|&x| x == first);
+                let response = ["X", "Y", "Z"].iter().position(|&x| x == second);
+                match (opponent, response) {
+                    (Some(opponent), Some(response)) => Ok((opponent as u32, response as u32)),
+                    _ => Err(Error::invalid_input(input, game, "expected a round")),
+                }
+            }
+            _ => Err(Error::invalid_input(input, game, "expected a round")),
+        })
+        .collect()
+}
@@ -1,11 +1,18 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
-day!(Day03, 2022, 3, "Rucksack Reorganization");
+day!(
+    Day03,
+    2022,
+    3,
+    "Rucksack Reorganization",
+    difficulty = Easy,
+    tags = [Strings]
+);
 
 impl Solution for Day03 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let mut result: u64 = 0;
-        for line in input.lines() {
+        for line in parse_input(input)? {
             if !line.is_empty() {
                 let (first, last) = line.split_at(line.chars().count() / 2);
                 for character in first.chars() {
@@ -16,13 +23,13 @@ impl Solution for Day03 {
                 }
             }
         }
-        Ok(result.to_string())
+        Ok(result.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut result: u64 = 0;
         let mut curr_group_members: Vec<&str> = vec![];
-        for line in input.lines() {
+        for line in parse_input(input)? {
             if curr_group_members.len() == 3 {
                 for character in curr_group_members[0].chars() {
                     if curr_group_members[1].contains(character)
@@ -47,10 +54,20 @@ impl Solution for Day03 {
                 }
             }
         }
-        Ok(result.to_string())
+        Ok(result.into())
     }
 }
 
+fn parse_input(input: &str) -> Result<Vec<&str>, Error> {
+    let rucksacks = input.trim().lines().collect::<Vec<_>>();
+    for line in &rucksacks {
+        if let Some(i) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+            return Err(Error::invalid_input(input, &line[i..], "expected an item"));
+        }
+    }
+    Ok(rucksacks)
+}
+
 const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
 
 fn letter_value(letter: char) -> u64 {
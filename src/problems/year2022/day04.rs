@@ -1,27 +1,40 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 
-day!(Day04, 2022, 4, "Camp Cleanup");
+day!(
+    Day04,
+    2022,
+    4,
+    "Camp Cleanup",
+    difficulty = Easy,
+    tags = [Ranges]
+);
 
 impl Solution for Day04 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(process_input(input).0.to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        Ok(process_input(input)?.0.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        Ok(process_input(input).1.to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        Ok(process_input(input)?.1.into())
     }
 }
 
-fn process_input(input: &str) -> (usize, usize) {
+fn process_input(input: &str) -> Result<(usize, usize), Error> {
     let mut contain: usize = 0;
     let mut overlap: usize = 0;
     let mut elves: Vec<usize>;
     for line in input.trim().lines() {
         elves = vec![];
-        for elf in line.split(',') {
-            for sectors in elf.split('-') {
-                elves.push(sectors.parse::<usize>().unwrap())
-            }
+        let (first, second) =
+            line.split_once(',')
+                .or_invalid(input, line, "expected a pair of elves")?;
+        for elf in [first, second] {
+            let (start, end) =
+                elf.split_once('-')
+                    .or_invalid(input, elf, "expected a range of sections")?;
+            elves.push(parse_number(input, start)?);
+            elves.push(parse_number(input, end)?);
         }
         if ((elves[0] <= elves[2]) && (elves[1] >= elves[3]))
             || ((elves[2] <= elves[0]) && (elves[3] >= elves[1]))
@@ -35,5 +48,5 @@ fn process_input(input: &str) -> (usize, usize) {
         }
     }
 
-    (contain, overlap)
+    Ok((contain, overlap))
 }
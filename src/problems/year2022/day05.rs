@@ -1,93 +1,99 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, end, parse_limited, parse_number};
+use crate::{Answer, Error, Solution};
 
-day!(Day05, 2022, 5, "Supply Stacks");
+day!(
+    Day05,
+    2022,
+    5,
+    "Supply Stacks",
+    difficulty = Easy,
+    tags = [Parsing, Simulation]
+);
 
 impl Solution for Day05 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let num_of_piles = count_piles(input);
-        let mut piles: Vec<Vec<char>> = vec![];
-        for _ in 0..num_of_piles {
-            piles.push(vec![]);
-        }
-        let mut move_vec: Vec<&str>;
-        for line in input.trim_end().lines() {
-            if line.contains('[') {
-                for (ind, character) in line.chars().enumerate() {
-                    if ind % 4 == 1 && character != ' ' {
-                        piles[ind / 4].insert(0, character);
-                    }
-                    if ind % 4 == 1 && (ind / 4) + 1 == num_of_piles {
-                        break;
-                    }
-                }
-            } else if line.contains("move") {
-                move_vec = line.trim().split(' ').collect();
-                for _ in 0..move_vec[1].parse::<usize>().unwrap() {
-                    let character: char = piles[move_vec[3].parse::<usize>().unwrap() - 1]
-                        [piles[move_vec[3].parse::<usize>().unwrap() - 1].len() - 1];
-                    piles[move_vec[5].parse::<usize>().unwrap() - 1].push(character);
-                    piles[move_vec[3].parse::<usize>().unwrap() - 1].pop();
-                }
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let (mut piles, moves) = parse_input(input)?;
+        for [count, from, to] in moves {
+            for _ in 0..count {
+                let character = piles[from].pop().ok_or(Error::NoSolution)?;
+                piles[to].push(character);
             }
         }
-        let mut output: String = String::new();
-        for pile in piles {
-            output.push(pile[pile.len() - 1]);
-        }
-        Ok(output.to_string())
+        top_crates(&piles).map(Answer::from)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let num_of_piles = count_piles(input);
-        let mut piles: Vec<Vec<char>> = vec![];
-        for _ in 0..num_of_piles {
-            piles.push(vec![]);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let (mut piles, moves) = parse_input(input)?;
+        for [count, from, to] in moves {
+            let split_ind = piles[from]
+                .len()
+                .checked_sub(count)
+                .ok_or(Error::NoSolution)?;
+            let moved = piles[from].split_off(split_ind);
+            piles[to].extend(moved);
         }
-        let mut move_vec: Vec<&str>;
-        for line in input.trim_end().lines() {
-            if line.contains('[') {
-                for (ind, character) in line.chars().enumerate() {
-                    if ind % 4 == 1 && character != ' ' {
-                        piles[ind / 4].insert(0, character);
-                    }
-                    if ind % 4 == 1 && (ind / 4) + 1 == num_of_piles {
-                        break;
-                    }
+        top_crates(&piles).map(Answer::from)
+    }
+}
+
+/// Parse the starting piles (bottom crate first) and the moves as `[count, from, to]`,
+/// with the piles indexed from 0.
+type Piles = Vec<Vec<char>>;
+
+fn parse_input(input: &str) -> Result<(Piles, Vec<[usize; 3]>), Error> {
+    let num_of_piles = count_piles(input)?;
+    let mut piles: Vec<Vec<char>> = vec![vec![]; num_of_piles];
+    let mut moves = vec![];
+    for line in input.trim_end().lines() {
+        if line.contains('[') {
+            for (ind, character) in line.chars().enumerate() {
+                if ind % 4 == 1 && character != ' ' {
+                    piles
+                        .get_mut(ind / 4)
+                        .or_invalid(input, line, "expected a crate in a pile")?
+                        .insert(0, character);
                 }
-            } else if line.contains("move") {
-                move_vec = line.trim().split(' ').collect();
-                let insert_ind = piles[move_vec[5].parse::<usize>().unwrap() - 1].len();
-                for _ in 0..move_vec[1].parse::<usize>().unwrap() {
-                    let character: char = piles[move_vec[3].parse::<usize>().unwrap() - 1]
-                        [piles[move_vec[3].parse::<usize>().unwrap() - 1].len() - 1];
-                    piles[move_vec[5].parse::<usize>().unwrap() - 1].insert(insert_ind, character);
-                    piles[move_vec[3].parse::<usize>().unwrap() - 1].pop();
+            }
+        } else if line.contains("move") {
+            match line.trim().split(' ').collect::<Vec<_>>()[..] {
+                ["move", count, "from", from, "to", to] => {
+                    let pile = |pile: &str| {
+                        let index = parse_number::<usize>(input, pile)?;
+                        if (1..=num_of_piles).contains(&index) {
+                            Ok(index - 1)
+                        } else {
+                            Err(Error::invalid_input(input, pile, "expected a pile"))
+                        }
+                    };
+                    moves.push([parse_number(input, count)?, pile(from)?, pile(to)?]);
                 }
+                _ => return Err(Error::invalid_input(input, line, "expected a move")),
             }
         }
-        let mut output: String = String::new();
-        for pile_part in piles {
-            output.push(pile_part[pile_part.len() - 1]);
-        }
-        Ok(output.to_string())
     }
+    Ok((piles, moves))
 }
 
-fn count_piles(input: &str) -> usize {
+fn count_piles(input: &str) -> Result<usize, Error> {
     // loops until it finds the line with the pile numbers and reads the last number
 
-    let mut num_of_piles: usize = 0;
     for line in input.trim().lines() {
         if !line.contains('[') {
-            num_of_piles = line
-                .trim()
-                .rsplit_once(' ')
-                .unwrap()
-                .1
-                .parse::<usize>()
-                .unwrap();
-            break;
+            let last = line.split_whitespace().last().unwrap_or(line);
+            // every pile is numbered on the line, so there can't be more piles than characters
+            return parse_limited(input, last, 0..=line.len());
         }
     }
-    num_of_piles
+    Err(Error::invalid_input(
+        input,
+        end(input),
+        "expected pile numbers",
+    ))
+}
+
+fn top_crates(piles: &[Vec<char>]) -> Result<String, Error> {
+    piles
+        .iter()
+        .map(|pile| pile.last().ok_or(Error::NoSolution))
+        .collect()
 }
@@ -1,23 +1,37 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
-day!(Day06, 2022, 6, "Tuning Trouble");
+day!(
+    Day06,
+    2022,
+    6,
+    "Tuning Trouble",
+    difficulty = Easy,
+    tags = [Strings]
+);
 
 impl Solution for Day06 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut characters = 4;
-        while !start_of_packet(&input[(characters - 4)..characters]) {
-            characters += 1;
-        }
-        Ok(characters.to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        find_marker(input, 4)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut characters = 14;
-        while !start_of_packet(&input[(characters - 14)..characters]) {
-            characters += 1;
-        }
-        Ok(characters.to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        find_marker(input, 14)
+    }
+}
+
+fn find_marker(input: &str, size: usize) -> Result<Answer, Error> {
+    let signal = input.trim();
+    if let Some(i) = signal.find(|c: char| !c.is_ascii_lowercase()) {
+        return Err(Error::invalid_input(
+            input,
+            &signal[i..],
+            "expected a letter",
+        ));
     }
+    (size..=signal.len())
+        .find(|&characters| start_of_packet(&signal[(characters - size)..characters]))
+        .map(Answer::from)
+        .ok_or(Error::NoSolution)
 }
 
 fn start_of_packet(inp: &str) -> bool {
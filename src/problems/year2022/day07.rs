@@ -1,76 +1,89 @@
-use crate::{Error, Solution};
-
-day!(Day07, 2022, 7, "No Space Left On Device");
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
+
+day!(
+    Day07,
+    2022,
+    7,
+    "No Space Left On Device",
+    difficulty = Easy,
+    tags = [Graph, Parsing]
+);
 
 impl Solution for Day07 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let base_dir = parse_input(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let base_dir = parse_input(input)?;
         let mut total_size = 0;
-        part1_recursion(&base_dir, &mut total_size);
-        Ok(total_size.to_string())
+        part1_recursion(&base_dir, &mut total_size)?;
+        Ok(total_size.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let base_dir = parse_input(input);
-        let needed_space = 30_000_000 - (70_000_000 - base_dir.size());
-        let mut min_del = 70_000_001;
-        part2_recursion(&base_dir, needed_space, &mut min_del);
-        Ok(min_del.to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let base_dir = parse_input(input)?;
+        // the used space is at most the size of the disk when it is more than the free space needed
+        let needed_space = base_dir.size()?.saturating_sub(70_000_000 - 30_000_000);
+        let mut min_del = u64::MAX;
+        part2_recursion(&base_dir, needed_space, &mut min_del)?;
+        Ok(min_del.into())
     }
 }
 
-fn part1_recursion(folder: &Folder, total_size: &mut u64) {
-    let folder_size = folder.size();
+fn part1_recursion(folder: &Folder, total_size: &mut u64) -> Result<(), Error> {
+    let folder_size = folder.size()?;
     if folder_size <= 100_000 {
-        *total_size += folder_size;
+        *total_size = total_size
+            .checked_add(folder_size)
+            .ok_or(Error::NoSolution)?;
     }
     for subfolder in &folder.folders {
-        part1_recursion(subfolder, total_size);
+        part1_recursion(subfolder, total_size)?;
     }
+    Ok(())
 }
 
-fn part2_recursion(folder: &Folder, needed_space: u64, min_del: &mut u64) {
-    let folder_size = folder.size();
+fn part2_recursion(folder: &Folder, needed_space: u64, min_del: &mut u64) -> Result<(), Error> {
+    let folder_size = folder.size()?;
     if (folder_size >= needed_space) && (folder_size < *min_del) {
         *min_del = folder_size;
     }
     for subfolder in &folder.folders {
-        part2_recursion(subfolder, needed_space, min_del);
+        part2_recursion(subfolder, needed_space, min_del)?;
     }
+    Ok(())
 }
 
-fn parse_input(input: &str) -> Folder {
+fn parse_input(input: &str) -> Result<Folder, Error> {
     let mut base_dir = Folder::new("/".to_string());
 
     let mut current_location: Vec<String> = vec!["/".to_string()];
 
     for line in input.trim().lines() {
-        let line_contents: Vec<&str> = line.trim().split(' ').collect();
-
-        if line_contents[0] == String::from('$') {
-            if line_contents[1] == "cd" {
-                if line_contents[2] == ".." {
-                    if current_location.len() > 1 {
-                        current_location.pop();
-                    }
-                } else if line_contents[2] == "/" {
-                    current_location = vec!["/".to_string()];
-                } else {
-                    current_location.push(line_contents[2].to_string());
+        match line.trim().split(' ').collect::<Vec<_>>()[..] {
+            ["$", "cd", ".."] => {
+                if current_location.len() > 1 {
+                    current_location.pop();
                 }
             }
-        } else if line_contents[0] == "dir" {
-            base_dir.new_entity(&current_location, line_contents[1].to_string(), None);
-        } else {
-            base_dir.new_entity(
+            ["$", "cd", "/"] => current_location = vec!["/".to_string()],
+            ["$", "cd", name] => current_location.push(name.to_string()),
+            ["$", "ls"] => {}
+            ["dir", name] => base_dir.new_entity(&current_location, name.to_string(), None),
+            [size, name] => base_dir.new_entity(
                 &current_location,
-                line_contents[1].to_string(),
-                Some(line_contents[0].parse::<u64>().unwrap()),
-            );
+                name.to_string(),
+                Some(parse_number(input, size)?),
+            ),
+            _ => {
+                return Err(Error::invalid_input(
+                    input,
+                    line,
+                    "expected a command or an entry",
+                ));
+            }
         }
     }
 
-    base_dir
+    Ok(base_dir)
 }
 
 struct File {
@@ -121,8 +134,16 @@ impl Folder {
         }
     }
 
-    fn size(&self) -> u64 {
-        self.files.iter().map(|f| f.size).sum::<u64>()
-            + self.folders.iter().map(|f| f.size()).sum::<u64>()
+    /// Returns the total size of the files in the folder and its subfolders,
+    /// or [Error::NoSolution] if it overflows.
+    fn size(&self) -> Result<u64, Error> {
+        let mut size: u64 = 0;
+        for file_size in self.files.iter().map(|f| Ok(f.size)) {
+            size = size.checked_add(file_size?).ok_or(Error::NoSolution)?;
+        }
+        for folder_size in self.folders.iter().map(Folder::size) {
+            size = size.checked_add(folder_size?).ok_or(Error::NoSolution)?;
+        }
+        Ok(size)
     }
 }
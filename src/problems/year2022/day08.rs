@@ -1,10 +1,18 @@
-use crate::{Error, Solution};
+use crate::input::OrInvalid;
+use crate::{Answer, Error, Solution};
 
-day!(Day08, 2022, 8, "Treetop Tree House");
+day!(
+    Day08,
+    2022,
+    8,
+    "Treetop Tree House",
+    difficulty = Easy,
+    tags = [Grid]
+);
 
 impl Solution for Day08 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let forest = parse_forest(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let forest = parse_forest(input)?;
         let mut visible_trees = 0;
         for x in 0..forest.len() {
             for y in 0..forest[x].len() {
@@ -59,11 +67,11 @@ impl Solution for Day08 {
             }
         }
 
-        Ok(visible_trees.to_string())
+        Ok(visible_trees.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let forest = parse_forest(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let forest = parse_forest(input)?;
 
         let mut max_scenic_score = 1;
         for x in 0..forest.len() {
@@ -118,19 +126,29 @@ impl Solution for Day08 {
                 }
             }
         }
-        Ok(max_scenic_score.to_string())
+        Ok(max_scenic_score.into())
     }
 }
 
-fn parse_forest(input: &str) -> Vec<Vec<u8>> {
-    let mut forest = vec![];
+fn parse_forest(input: &str) -> Result<Vec<Vec<u8>>, Error> {
+    let mut forest: Vec<Vec<u8>> = vec![];
     let mut row;
     for line in input.trim().lines() {
         row = vec![];
-        for tree in line.chars() {
-            row.push(tree.to_digit(10).unwrap().try_into().unwrap())
+        for (i, tree) in line.char_indices() {
+            row.push(
+                tree.to_digit(10)
+                    .or_invalid(input, &line[i..], "expected a tree height")? as u8,
+            )
+        }
+        if forest.first().is_some_and(|first| first.len() != row.len()) {
+            return Err(Error::invalid_input(
+                input,
+                line,
+                "expected a rectangular forest",
+            ));
         }
         forest.push(row);
     }
-    forest
+    Ok(forest)
 }
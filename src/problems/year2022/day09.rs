@@ -1,18 +1,26 @@
-use crate::{Error, Solution};
+use crate::cancellation;
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
 
-day!(Day09, 2022, 9, "Rope Bridge");
+day!(
+    Day09,
+    2022,
+    9,
+    "Rope Bridge",
+    difficulty = Medium,
+    tags = [Grid, Simulation]
+);
 
 impl Solution for Day09 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let mut head_pos: [i64; 2] = [0; 2];
         let mut tail_pos: [i64; 2] = [0; 2];
         let mut visited: Vec<[i64; 2]> = vec![tail_pos];
 
-        for command in input.trim().lines() {
-            let mut splitt = command.split(' ');
-            let side = splitt.next().unwrap();
-            let mut steps: i64 = splitt.next().unwrap().parse::<i64>().unwrap();
+        for (side, mut steps) in parse_input(input)? {
             while steps != 0 {
+                // the visited positions are searched linearly, so a long motion takes a while
+                cancellation::check()?;
                 match side {
                     "L" => {
                         head_pos[0] -= 1;
@@ -54,29 +62,28 @@ impl Solution for Day09 {
                             visited.push(tail_pos);
                         }
                     }
-                    _ => panic!(),
+                    _ => unreachable!(),
                 }
                 steps -= 1;
             }
         }
-        Ok(visited.len().to_string())
+        Ok(visited.len().into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut knot_pos: [[i64; 2]; 10] = [[0; 2]; 10];
         let mut visited: Vec<[i64; 2]> = vec![knot_pos[0]];
-        for command in input.trim().lines() {
-            let mut splitt = command.split(' ');
-            let side = splitt.next().unwrap();
-            let mut steps: i64 = splitt.next().unwrap().parse::<i64>().unwrap();
+        for (side, mut steps) in parse_input(input)? {
             while steps != 0 {
+                // the visited positions are searched linearly, so a long motion takes a while
+                cancellation::check()?;
                 let mut temp_pos = knot_pos[0];
                 match side {
                     "L" => knot_pos[0][0] -= 1,
                     "R" => knot_pos[0][0] += 1,
                     "U" => knot_pos[0][1] += 1,
                     "D" => knot_pos[0][1] -= 1,
-                    _ => panic!(),
+                    _ => unreachable!(),
                 }
                 for i in 1..10 {
                     if ((knot_pos[i - 1][0] - knot_pos[i][0]).abs() > 1)
@@ -133,6 +140,17 @@ impl Solution for Day09 {
                 steps -= 1;
             }
         }
-        Ok(visited.len().to_string())
+        Ok(visited.len().into())
     }
 }
+
+fn parse_input(input: &str) -> Result<Vec<(&str, u32)>, Error> {
+    input
+        .trim()
+        .lines()
+        .map(|command| match command.split(' ').collect::<Vec<_>>()[..] {
+            [side @ ("L" | "R" | "U" | "D"), steps] => Ok((side, parse_number(input, steps)?)),
+            _ => Err(Error::invalid_input(input, command, "expected a motion")),
+        })
+        .collect()
+}
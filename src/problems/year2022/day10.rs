@@ -1,64 +1,74 @@
-use crate::{Error, Solution};
-use advent_of_code_ocr::parse_string_to_letters;
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
 
-day!(Day10, 2022, 10, "Cathode-Ray Tube");
+day!(
+    Day10,
+    2022,
+    10,
+    "Cathode-Ray Tube",
+    difficulty = Easy,
+    tags = [Vm]
+);
 
 impl Solution for Day10 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut register_state = [1; 2];
-        let mut result = 0;
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut register_state: [i64; 2] = [1; 2];
+        let mut result: i64 = 0;
         let wanted_cycles = [20, 60, 100, 140, 180, 220];
         let mut wanted_cycle_ind = 0;
         let mut cycle = 0;
-        for command in input.trim().lines() {
-            let mut command_split = command.split(' ');
-            match command_split.next().unwrap() {
-                "noop" => {
+        for command in parse_input(input)? {
+            match command {
+                None => {
                     cycle += 1;
                 }
-                "addx" => {
+                Some(value) => {
                     cycle += 2;
                     register_state[0] = register_state[1];
-                    register_state[1] += command_split.next().unwrap().parse::<i32>().unwrap();
+                    register_state[1] = register_state[1]
+                        .checked_add(i64::from(value))
+                        .ok_or(Error::NoSolution)?;
                 }
-                _ => panic!(),
             }
             if cycle >= wanted_cycles[wanted_cycle_ind] - 1 {
-                if cycle == wanted_cycles[wanted_cycle_ind] - 1 {
-                    result += register_state[1] * wanted_cycles[wanted_cycle_ind];
+                let register = if cycle == wanted_cycles[wanted_cycle_ind] - 1 {
+                    register_state[1]
                 } else {
-                    result += register_state[0] * wanted_cycles[wanted_cycle_ind];
-                }
+                    register_state[0]
+                };
+                result = register
+                    .checked_mul(wanted_cycles[wanted_cycle_ind])
+                    .and_then(|strength| result.checked_add(strength))
+                    .ok_or(Error::NoSolution)?;
                 wanted_cycle_ind += 1;
                 if wanted_cycle_ind > wanted_cycles.len() - 1 {
                     break;
                 }
             }
         }
-        Ok(result.to_string())
+        Ok(result.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut register_state: i32 = 1;
-        let mut cycle: i32 = 0;
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        // the register only changes on the 240 drawn cycles, so it can't overflow 64 bits
+        let mut register_state: i64 = 1;
+        let mut cycle: i64 = 0;
         let mut render: String = String::new();
-        let mut commands = input.trim().lines();
-        let mut prev_command: [i32; 2] = [0; 2];
+        let mut commands = parse_input(input)?.into_iter();
+        let mut prev_command: [i64; 2] = [0; 2];
         while cycle < 240 {
             if prev_command[0] == 0 {
-                let mut command_split = commands.next().unwrap().split(' ');
-                match command_split.next().unwrap() {
-                    "noop" => {
+                match commands.next().ok_or(Error::NoSolution)? {
+                    None => {
                         prev_command[0] = 1;
                         register_state += prev_command[1];
                         prev_command[1] = 0;
                     }
-                    "addx" => {
+                    Some(value) => {
                         prev_command[0] = 2;
                         register_state += prev_command[1];
-                        prev_command[1] = command_split.next().unwrap().parse::<i32>().unwrap();
+                        prev_command[1] = i64::from(value);
                     }
-                    _ => panic!(),
                 }
             }
 
@@ -72,15 +82,27 @@ impl Solution for Day10 {
             cycle += 1;
         }
 
-        let mut result = String::new();
-        let mut output: String = render;
-        for _ in (40..241).step_by(40) {
-            let splitt = output.split_at(40);
-            result.push_str(splitt.0);
-            result.push('\n');
-            output = splitt.1.to_string();
-        }
-
-        Ok(parse_string_to_letters(result.trim()))
+        let rows = (0..render.len())
+            .step_by(40)
+            .map(|start| render[start..start + 40].to_string())
+            .collect();
+        Ok(Answer::from_screen(rows))
     }
 }
+
+/// Parse the program, with `None` for a `noop` and the value of an `addx`.
+fn parse_input(input: &str) -> Result<Vec<Option<i32>>, Error> {
+    input
+        .trim()
+        .lines()
+        .map(|command| match command.split(' ').collect::<Vec<_>>()[..] {
+            ["noop"] => Ok(None),
+            ["addx", value] => Ok(Some(parse_number(input, value)?)),
+            _ => Err(Error::invalid_input(
+                input,
+                command,
+                "expected an instruction",
+            )),
+        })
+        .collect()
+}
@@ -1,24 +1,32 @@
-use crate::{Error, Solution};
-
-day!(Day11, 2022, 11, "Monkey in the Middle");
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
+
+day!(
+    Day11,
+    2022,
+    11,
+    "Monkey in the Middle",
+    difficulty = Medium,
+    tags = [NumberTheory, Simulation]
+);
 
 impl Solution for Day11 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut monkeys = parse_monkeys(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut monkeys = parse_monkeys(input)?;
 
         for _ in 0..20 {
             for monkey_ind in 0..monkeys.len() {
-                monkeys[monkey_ind].inspected_items += monkeys[monkey_ind].items.len() as u128;
+                monkeys[monkey_ind].inspected_items += monkeys[monkey_ind].items.len() as u64;
 
                 for item_ind in 0..monkeys[monkey_ind].items.len() {
                     let new_data = monkeys[monkey_ind]
-                        .throw(monkeys[monkey_ind].operation(monkeys[monkey_ind].items[item_ind]));
+                        .throw(monkeys[monkey_ind].operation(monkeys[monkey_ind].items[item_ind])?);
                     let new_item = new_data.0;
                     let new_location = new_data.1;
                     monkeys
                         .iter_mut()
                         .find(|m| m.id == new_location)
-                        .unwrap()
+                        .ok_or(Error::NoSolution)?
                         .items
                         .push(new_item);
                 }
@@ -27,39 +35,35 @@ impl Solution for Day11 {
             }
         }
 
-        let first_max = monkeys
-            .iter()
-            .max_by_key(|m| m.inspected_items)
-            .unwrap()
-            .inspected_items;
-        let second_max = monkeys
-            .iter()
-            .filter(|m| m.inspected_items != first_max)
-            .max_by_key(|m| m.inspected_items)
-            .unwrap()
-            .inspected_items;
-
-        Ok((first_max * second_max).to_string())
+        monkey_business(&monkeys)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut monkeys = parse_monkeys(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut monkeys = parse_monkeys(input)?;
 
-        let common_divisor: u128 = monkeys.iter().map(|m| m.throw[0]).product();
+        let common_divisor = monkeys
+            .iter()
+            .try_fold(1u128, |product, m| product.checked_mul(m.throw[0]))
+            .ok_or(Error::NoSolution)?;
+        if common_divisor == 0 {
+            // a monkey without a test
+            return Err(Error::NoSolution);
+        }
 
         for _ in 0..10_000 {
             for monkey_ind in 0..monkeys.len() {
-                monkeys[monkey_ind].inspected_items += monkeys[monkey_ind].items.len() as u128;
+                monkeys[monkey_ind].inspected_items += monkeys[monkey_ind].items.len() as u64;
 
                 for item_ind in 0..monkeys[monkey_ind].items.len() {
-                    let new_data = monkeys[monkey_ind]
-                        .throw2(monkeys[monkey_ind].operation(monkeys[monkey_ind].items[item_ind]));
+                    let new_data = monkeys[monkey_ind].throw2(
+                        monkeys[monkey_ind].operation(monkeys[monkey_ind].items[item_ind])?,
+                    );
                     let new_item = new_data.0;
                     let new_location = new_data.1;
                     monkeys
                         .iter_mut()
                         .find(|m| m.id == new_location)
-                        .unwrap()
+                        .ok_or(Error::NoSolution)?
                         .items
                         .push(new_item);
                 }
@@ -72,60 +76,84 @@ impl Solution for Day11 {
             }
         }
 
-        let first_max = monkeys
-            .iter()
-            .max_by_key(|m| m.inspected_items)
-            .unwrap()
-            .inspected_items;
-        let second_max = monkeys
-            .iter()
-            .filter(|m| m.inspected_items != first_max)
-            .max_by_key(|m| m.inspected_items)
-            .unwrap()
-            .inspected_items;
+        monkey_business(&monkeys)
+    }
+}
 
-        Ok((first_max * second_max).to_string())
+/// Multiply the number of items inspected by the two most active monkeys.
+fn monkey_business(monkeys: &[Monkey]) -> Result<Answer, Error> {
+    let mut inspected_items = monkeys
+        .iter()
+        .map(|m| m.inspected_items)
+        .collect::<Vec<_>>();
+    inspected_items.sort_by(|a, b| b.cmp(a));
+    match inspected_items[..] {
+        [first_max, second_max, ..] => Ok((i128::from(first_max) * i128::from(second_max)).into()),
+        _ => Err(Error::NoSolution),
     }
 }
 
-fn parse_monkeys<'a>(input: &'a str) -> Vec<Monkey<'a>> {
-    let mut line_contents: Vec<&str> = Vec::new();
+fn parse_monkeys<'a>(input: &'a str) -> Result<Vec<Monkey<'a>>, Error> {
     let mut monkeys: Vec<Monkey> = Vec::new();
     let mut current_monkey = Monkey::new();
     for line in input.trim().lines() {
-        line_contents.clear();
-        line_contents.extend(line.trim().split(' '));
-        match line_contents[0].trim_matches(':') {
-            "" => {
+        match line.trim().split(' ').collect::<Vec<_>>()[..] {
+            [""] => {
                 monkeys.push(current_monkey);
                 current_monkey = Monkey::new();
             }
-            "Monkey" => {
-                current_monkey.id = line_contents[1].trim_matches(':').parse().unwrap();
+            ["Monkey", id] => {
+                current_monkey.id = parse_number(input, id.trim_matches(':'))?;
             }
-            "Starting" => {
-                current_monkey.items = line_contents[2..]
+            ["Starting", "items:", ref items @ ..] => {
+                current_monkey.items = items
                     .iter()
-                    .map(|t| t.trim_matches(',').parse().unwrap())
-                    .collect();
+                    .map(|t| parse_number(input, t.trim_matches(',')))
+                    .collect::<Result<_, _>>()?;
             }
-            "Operation" => {
-                current_monkey.operation_opts =
-                    [line_contents[3], line_contents[4], line_contents[5]];
+            [
+                "Operation:",
+                "new",
+                "=",
+                first,
+                operator @ ("+" | "*"),
+                second,
+            ] => {
+                for operand in [first, second] {
+                    if operand != "old" {
+                        parse_number::<u128>(input, operand)?;
+                    }
+                }
+                current_monkey.operation_opts = [first, operator, second];
             }
-            "Test" => current_monkey.throw[0] = line_contents[3].parse().unwrap(),
-            "If" => {
-                if line_contents[1].trim_matches(':') == "true" {
-                    current_monkey.throw[1] = line_contents[5].trim_matches(',').parse().unwrap();
-                } else {
-                    current_monkey.throw[2] = line_contents[5].trim_matches(',').parse().unwrap();
+            ["Test:", "divisible", "by", divisor] => {
+                current_monkey.throw[0] = parse_number(input, divisor)?;
+                if current_monkey.throw[0] == 0 {
+                    return Err(Error::invalid_input(
+                        input,
+                        divisor,
+                        "expected a positive divisor",
+                    ));
                 }
             }
-            _ => panic!("unexpected line!"),
+            ["If", condition, "throw", "to", "monkey", target] => {
+                let branch = ["true:", "false:"]
+                    .iter()
+                    .position(|&x| x == condition)
+                    .or_invalid(input, condition, "expected a condition")?;
+                current_monkey.throw[branch + 1] = parse_number(input, target)?;
+            }
+            _ => {
+                return Err(Error::invalid_input(
+                    input,
+                    line,
+                    "expected a monkey description",
+                ));
+            }
         }
     }
     monkeys.push(current_monkey);
-    monkeys
+    Ok(monkeys)
 }
 
 struct Monkey<'a> {
@@ -133,7 +161,7 @@ struct Monkey<'a> {
     items: Vec<u128>,
     operation_opts: [&'a str; 3],
     throw: [u128; 3], // div, true, false
-    inspected_items: u128,
+    inspected_items: u64,
 }
 
 impl<'a> Monkey<'a> {
@@ -147,28 +175,25 @@ impl<'a> Monkey<'a> {
         }
     }
 
-    fn operation(&self, x: u128) -> u128 {
-        if self.operation_opts[1] == "+" {
-            (if self.operation_opts[0] == "old" {
-                x
-            } else {
-                self.operation_opts[0].parse().unwrap()
-            }) + (if self.operation_opts[2] == "old" {
+    /// Apply the operation of the monkey to a worry level.
+    /// # Returns
+    /// * The new worry level or [Error::NoSolution] if it overflows.
+    fn operation(&self, x: u128) -> Result<u128, Error> {
+        let operand = |opt: &str| {
+            if opt == "old" {
                 x
             } else {
-                self.operation_opts[2].parse().unwrap()
-            })
+                opt.parse().unwrap()
+            }
+        };
+        let first = operand(self.operation_opts[0]);
+        let second = operand(self.operation_opts[2]);
+        if self.operation_opts[1] == "+" {
+            first.checked_add(second)
         } else {
-            (if self.operation_opts[0] == "old" {
-                x
-            } else {
-                self.operation_opts[0].parse().unwrap()
-            }) * (if self.operation_opts[2] == "old" {
-                x
-            } else {
-                self.operation_opts[2].parse().unwrap()
-            })
+            first.checked_mul(second)
         }
+        .ok_or(Error::NoSolution)
     }
 
     fn throw(&self, x: u128) -> (u128, u128) {
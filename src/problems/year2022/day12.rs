@@ -1,17 +1,25 @@
-use crate::{Error, Solution};
+use crate::input::end;
+use crate::{Answer, Error, Solution};
 use std::collections::VecDeque;
 
-day!(Day12, 2022, 12, "Hill Climbing Algorithm");
+day!(
+    Day12,
+    2022,
+    12,
+    "Hill Climbing Algorithm",
+    difficulty = Easy,
+    tags = [Grid, Search]
+);
 
 impl Solution for Day12 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let (height_map, loc_start, loc_end) = parse_input(input);
-        Ok(dijkstra_1(&height_map, loc_start, loc_end).to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let (height_map, loc_start, loc_end) = parse_input(input)?;
+        Ok(dijkstra_1(&height_map, loc_start, loc_end).into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let (height_map, _, loc_end) = parse_input(input);
-        Ok(dijkstra_2(&height_map, loc_end, 0).to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let (height_map, _, loc_end) = parse_input(input)?;
+        Ok(dijkstra_2(&height_map, loc_end, 0).into())
     }
 }
 
@@ -150,24 +158,56 @@ fn char_value(mut character: char) -> u32 {
     character as u32 - 'a' as u32
 }
 
-fn parse_input(input: &str) -> (Vec<Vec<u32>>, [usize; 2], [usize; 2]) {
+type HeightMap = (Vec<Vec<u32>>, [usize; 2], [usize; 2]);
+
+fn parse_input(input: &str) -> Result<HeightMap, Error> {
     let mut height_map: Vec<Vec<u32>> = vec![];
 
-    let mut loc_start: [usize; 2] = [0; 2];
-    let mut loc_end: [usize; 2] = [0; 2];
+    let mut loc_start: Option<[usize; 2]> = None;
+    let mut loc_end: Option<[usize; 2]> = None;
 
     for (curr_row, line) in input.trim().lines().enumerate() {
         let mut current_row: Vec<u32> = vec![];
-        for (curr_col, x) in line.chars().enumerate() {
-            if x == 'S' {
-                loc_start = [curr_row, curr_col];
-            } else if x == 'E' {
-                loc_end = [curr_row, curr_col];
+        for (curr_col, (i, x)) in line.char_indices().enumerate() {
+            match x {
+                'S' | 'E' => {
+                    let location = if x == 'S' {
+                        &mut loc_start
+                    } else {
+                        &mut loc_end
+                    };
+                    if location.replace([curr_row, curr_col]).is_some() {
+                        return Err(Error::invalid_input(
+                            input,
+                            &line[i..],
+                            "expected a single location",
+                        ));
+                    }
+                }
+                'a'..='z' => {}
+                _ => return Err(Error::invalid_input(input, &line[i..], "expected a height")),
             }
             current_row.push(char_value(x));
         }
+        if height_map
+            .first()
+            .is_some_and(|first| first.len() != current_row.len())
+        {
+            return Err(Error::invalid_input(
+                input,
+                line,
+                "expected a rectangular map",
+            ));
+        }
         height_map.push(current_row);
     }
 
-    (height_map, loc_start, loc_end)
+    match (loc_start, loc_end) {
+        (Some(loc_start), Some(loc_end)) => Ok((height_map, loc_start, loc_end)),
+        _ => Err(Error::invalid_input(
+            input,
+            end(input),
+            "expected a start and an end",
+        )),
+    }
 }
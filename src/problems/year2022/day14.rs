@@ -1,24 +1,39 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_limited};
+use crate::{Answer, Error, Solution};
 use regex::Regex;
 use std::cmp::{max, min};
 use std::fmt::Write;
 
-day!(Day14, 2022, 14, "Regolith Reservoir");
+day!(
+    Day14,
+    2022,
+    14,
+    "Regolith Reservoir",
+    difficulty = Medium,
+    tags = [Grid, Simulation]
+);
+
+/// The largest accepted coordinate.
+/// The cave is a grid down to the floor and as wide as the sand can pile up on it,
+/// which is twice as wide as it is deep, so this keeps it at about eight million cells.
+const MAX_COORDINATE: i32 = 2000;
 
 impl Solution for Day14 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(Cave::new(input).simulate_sand().to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        Ok(Cave::new(input)?.simulate_sand().into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut input = input.trim().to_owned();
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let re = Regex::new(r",(\d+)").unwrap();
         let max_y = re
-            .captures_iter(&input)
-            .map(|cap| cap.get(1).unwrap().as_str().parse::<i32>().unwrap())
+            .captures_iter(input)
+            .map(|cap| parse_limited(input, cap.get(1).unwrap().as_str(), 0..=MAX_COORDINATE))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
             .max()
-            .unwrap();
+            .ok_or(Error::NoSolution)?;
         let platform_y = max_y + 2;
+        let mut input = input.trim_end().to_owned();
         writeln!(
             &mut input,
             "\n{},{} -> {},{}",
@@ -28,7 +43,7 @@ impl Solution for Day14 {
             platform_y
         )
         .unwrap();
-        Ok(Cave::new(&input).simulate_sand().to_string())
+        Ok(Cave::new(&input)?.simulate_sand().into())
     }
 }
 
@@ -41,17 +56,31 @@ struct Cave {
 }
 impl Cave {
     /// Create a new cave from the input
-    fn new(input: &str) -> Self {
+    fn new(input: &str) -> Result<Self, Error> {
         let mut structures = Vec::new();
         let mut max_y = 0;
         let mut min_x = SAND_DROP_X;
         let mut max_x = SAND_DROP_X;
-        for line in input.lines() {
-            let mut structure = Vec::new();
+        for line in input.trim().lines() {
+            let mut structure: Vec<(i32, i32)> = Vec::new();
             for point in line.split(" -> ") {
-                let (x, y) = point.trim().split_once(',').unwrap();
-                let x = x.parse::<i32>().unwrap();
-                let y = y.parse::<i32>().unwrap();
+                let (x, y) =
+                    point
+                        .trim()
+                        .split_once(',')
+                        .or_invalid(input, point, "expected a point")?;
+                let x = parse_limited(input, x, 0..=MAX_COORDINATE)?;
+                let y = parse_limited(input, y, 0..=MAX_COORDINATE)?;
+                if structure
+                    .last()
+                    .is_some_and(|&(last_x, last_y)| last_x != x && last_y != y)
+                {
+                    return Err(Error::invalid_input(
+                        input,
+                        point,
+                        "expected a straight line",
+                    ));
+                }
                 if x < min_x {
                     min_x = x;
                 }
@@ -89,10 +118,10 @@ impl Cave {
             }
         }
 
-        Self {
+        Ok(Self {
             grid,
             x_offset: min_x,
-        }
+        })
     }
 
     /// Drop a new grain of sand
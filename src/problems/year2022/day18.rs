@@ -1,11 +1,19 @@
-use crate::{Error, Solution};
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
 use std::collections::BTreeSet;
 
-day!(Day18, 2022, 18, "Boiling Boulders");
+day!(
+    Day18,
+    2022,
+    18,
+    "Boiling Boulders",
+    difficulty = Medium,
+    tags = [Geometry, Search]
+);
 
 impl Solution for Day18 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let cubes = parse_input(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let cubes = parse_input(input)?;
 
         let mut cubes_count = 0;
         for cube_i in &cubes {
@@ -50,11 +58,11 @@ impl Solution for Day18 {
             }
         }
 
-        Ok(result.to_string())
+        Ok(result.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut cubes = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut cubes = parse_input(input)?;
         let mut result = 0;
 
         // we will do flood fill from outside, and each time adjacent cubes are found, we will add their face to the result
@@ -134,20 +142,22 @@ impl Solution for Day18 {
             }
         }
 
-        Ok(result.to_string())
+        Ok(result.into())
     }
 }
 
-fn parse_input(input: &str) -> Vec<Vec<Vec<Position>>> {
+fn parse_input(input: &str) -> Result<Vec<Vec<Vec<Position>>>, Error> {
     let mut cubes_coords = input
         .trim()
         .lines()
-        .map(|line| {
-            line.split(',')
-                .map(|coord| coord.parse::<usize>().unwrap())
-                .collect::<Vec<_>>()
+        .map(|line| match line.split(',').collect::<Vec<_>>()[..] {
+            [x, y, z] => [x, y, z]
+                .into_iter()
+                .map(|coord| parse_number::<u8>(input, coord).map(usize::from))
+                .collect::<Result<Vec<_>, _>>(),
+            _ => Err(Error::invalid_input(input, line, "expected a cube")),
         })
-        .collect::<Vec<Vec<usize>>>();
+        .collect::<Result<Vec<Vec<usize>>, _>>()?;
 
     // offset all cubes by 1 in each direction
     // because we want the first row, column and slice to be empty for part 2
@@ -158,7 +168,7 @@ fn parse_input(input: &str) -> Vec<Vec<Vec<Position>>> {
     }
 
     // find max coord to determine size of grid
-    let mut max = cubes_coords[0][0];
+    let mut max = 0;
     for coords in &cubes_coords {
         for coord in coords {
             if *coord > max {
@@ -177,7 +187,7 @@ fn parse_input(input: &str) -> Vec<Vec<Vec<Position>>> {
         cubes[coords[0]][coords[1]][coords[2]] = Position::Cube;
     }
 
-    cubes
+    Ok(cubes)
 }
 
 #[derive(Copy, Clone, Eq, PartialEq)]
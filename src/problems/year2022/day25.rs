@@ -1,69 +1,75 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
-day!(Day25, 2022, 25, "Full of Hot Air");
+day!(
+    Day25,
+    2022,
+    25,
+    "Full of Hot Air",
+    difficulty = Easy,
+    tags = [NumberTheory]
+);
 
 impl Solution for Day25 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let mut sum: i64 = 0;
         for line in input.trim().split('\n') {
-            sum += snafu_2_dec(line);
+            sum = sum
+                .checked_add(snafu_2_dec(input, line)?)
+                .ok_or(Error::NoSolution)?;
         }
-        Ok(dec_2_snafu(sum))
+        dec_2_snafu(sum).map(Answer::from)
     }
 
-    fn part2(&self, _input: &str) -> Result<String, Error> {
-        Ok(String::from("Advent of Code 2022 solved!"))
+    fn part2(&self, _input: &str) -> Result<Answer, Error> {
+        Ok("Advent of Code 2022 solved!".into())
     }
 }
 
-fn snafu_2_dec(num: &str) -> i64 {
-    let mut curr_place_val: i64 = 1;
+fn snafu_2_dec(input: &str, num: &str) -> Result<i64, Error> {
     let mut resulting_dec: i64 = 0;
-    for place in num.chars().rev() {
-        match place {
-            '-' => resulting_dec -= curr_place_val,
-            '=' => resulting_dec -= 2 * curr_place_val,
-            '0' => (),
-            '1' => resulting_dec += curr_place_val,
-            '2' => resulting_dec += 2 * curr_place_val,
-            _ => panic!(),
-        }
-        curr_place_val *= 5;
+    for (i, place) in num.char_indices() {
+        let digit = match place {
+            '-' => -1,
+            '=' => -2,
+            '0' => 0,
+            '1' => 1,
+            '2' => 2,
+            _ => {
+                return Err(Error::invalid_input(
+                    input,
+                    &num[i..],
+                    "expected a SNAFU digit",
+                ));
+            }
+        };
+        resulting_dec = resulting_dec
+            .checked_mul(5)
+            .and_then(|shifted| shifted.checked_add(digit))
+            .ok_or(Error::NoSolution)?;
     }
-    resulting_dec
+    Ok(resulting_dec)
 }
 
-fn dec_2_snafu(mut num: i64) -> String {
-    let mut result: String = String::new();
-    let mut working_place: i64 = 5_i64.pow((((num + 1) as f64).log(5.0).ceil() as u32) - 1);
+fn dec_2_snafu(mut num: i64) -> Result<String, Error> {
+    if num <= 0 {
+        // only positive fuel requirements can be converted
+        return Err(Error::NoSolution);
+    }
 
-    num *= -1;
+    // the digits from the lowest place, where the remainders 3 and 4 borrow from the next place
+    let mut result = Vec::new();
     while num != 0 {
-        let mut smallest_diff = num.abs();
-        let mut smallest_diff_num = 0;
-        for x in -2..3 {
-            if x != 0 && smallest_diff > (x * working_place + num).abs() {
-                smallest_diff = (x * working_place + num).abs();
-                smallest_diff_num = x;
-            }
-        }
-
-        match smallest_diff_num {
-            -2 => result.push('='),
-            -1 => result.push('-'),
-            0 => result.push('0'),
-            1 => result.push('1'),
-            2 => result.push('2'),
-            _ => panic!(),
-        }
-
-        num += smallest_diff_num * working_place;
-        working_place /= 5;
-    }
-    while working_place >= 1 {
-        result.push('0');
-        working_place /= 5;
+        let digit = match num % 5 {
+            0 => '0',
+            1 => '1',
+            2 => '2',
+            3 => '=',
+            4 => '-',
+            _ => unreachable!(),
+        };
+        result.push(digit);
+        num = num / 5 + i64::from(num % 5 > 2);
     }
 
-    result
+    Ok(result.into_iter().rev().collect())
 }
@@ -1,36 +1,43 @@
-use crate::{Error, Solution};
+use crate::input::OrInvalid;
+use crate::{Answer, Error, Solution};
 
-day!(Day01, 2023, 1, "Trebuchet?!");
+day!(
+    Day01,
+    2023,
+    1,
+    "Trebuchet?!",
+    difficulty = Easy,
+    tags = [Strings]
+);
 
 impl Solution for Day01 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let mut sum = 0;
         let lines = parse_input(input);
         for line in lines {
             let mut digits_iterator = line.chars().filter_map(|c| c.to_digit(10));
-            let mut digits_iterator_back = digits_iterator.clone();
-            sum += digits_iterator.next().unwrap() as usize * 10
-                + digits_iterator_back.next_back().unwrap() as usize;
+            let front_digit = digits_iterator
+                .next()
+                .or_invalid(input, line, "expected a digit")?;
+            let back_digit = digits_iterator.next_back().unwrap_or(front_digit);
+            sum += front_digit as usize * 10 + back_digit as usize;
         }
-        Ok(sum.to_string())
+        Ok(sum.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut sum = 0;
         let lines = parse_input(input);
 
         for line in lines {
-            let mut digits_iterator = line
-                .chars()
-                .enumerate()
+            let digits = line
+                .char_indices()
                 .filter(|(_, c)| c.is_ascii_digit())
-                .map(|(pos, c)| (pos, c.to_digit(10).unwrap()));
+                .map(|(pos, c)| (pos, c.to_digit(10).unwrap()))
+                .collect::<Vec<_>>();
 
-            let (front_digit_pos, mut front_digit) = digits_iterator.next().unwrap();
-            let (mut back_digit_pos, mut back_digit) = (front_digit_pos, front_digit);
-            for digit in digits_iterator {
-                (back_digit_pos, back_digit) = digit;
-            }
+            let mut front_digit = digits.first().copied();
+            let mut back_digit = digits.last().copied();
 
             let mut letter_digits_front: [Option<usize>; 9] = [None; 9];
             let mut letter_digits_back: [Option<usize>; 9] = [None; 9];
@@ -54,21 +61,26 @@ impl Solution for Day01 {
                 .max_by_key(|(_, pos)| *pos);
 
             if let Some((digit, i)) = front_spelled_digit
-                && i < front_digit_pos
+                && front_digit.is_none_or(|(front_digit_pos, _)| i < front_digit_pos)
             {
-                front_digit = digit as u32;
+                front_digit = Some((i, digit as u32));
             }
 
             if let Some((digit, i)) = back_spelled_digit
-                && i > back_digit_pos
+                && back_digit.is_none_or(|(back_digit_pos, _)| i > back_digit_pos)
             {
-                back_digit = digit as u32;
+                back_digit = Some((i, digit as u32));
             }
 
-            sum += front_digit as usize * 10 + back_digit as usize;
+            match (front_digit, back_digit) {
+                (Some((_, front_digit)), Some((_, back_digit))) => {
+                    sum += front_digit as usize * 10 + back_digit as usize;
+                }
+                _ => return Err(Error::invalid_input(input, line, "expected a digit")),
+            }
         }
 
-        Ok(sum.to_string())
+        Ok(sum.into())
     }
 }
 
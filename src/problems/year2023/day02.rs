@@ -1,60 +1,80 @@
-use crate::{Error, Solution};
-
-day!(Day02, 2023, 2, "Cube Conundrum");
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
+
+day!(
+    Day02,
+    2023,
+    2,
+    "Cube Conundrum",
+    difficulty = Easy,
+    tags = [Parsing]
+);
 
 impl Solution for Day02 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut sum = 0;
-        let games = parse_input(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut sum: u64 = 0;
+        let games = parse_input(input)?;
 
         for game in games {
             if game.is_possible() {
-                sum += game.id;
+                sum += u64::from(game.id);
             }
         }
 
-        Ok(sum.to_string())
+        Ok(sum.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut sum = 0;
-        let games = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut sum: u64 = 0;
+        let games = parse_input(input)?;
 
         for game in games {
-            let fewest_cubes = game.fewest_cubes();
-            sum += fewest_cubes.iter().product::<u32>();
+            let power = game
+                .fewest_cubes()
+                .iter()
+                .try_fold(1u64, |power, &cubes| power.checked_mul(u64::from(cubes)));
+            sum = power
+                .and_then(|power| sum.checked_add(power))
+                .ok_or(Error::NoSolution)?;
         }
 
-        Ok(sum.to_string())
+        Ok(sum.into())
     }
 }
 
-fn parse_input(input: &str) -> Vec<Game> {
+fn parse_input(input: &str) -> Result<Vec<Game>, Error> {
     let mut games = Vec::new();
 
     for line in input.trim().lines() {
-        let (game_id, draws) = line.split_once(':').unwrap();
-        let game_id = game_id.trim_start_matches("Game ").parse::<u32>().unwrap();
+        let (game_id, draws) = line
+            .split_once(':')
+            .or_invalid(input, line, "expected a game")?;
+        let game_id =
+            game_id
+                .strip_prefix("Game ")
+                .or_invalid(input, game_id, "expected a game id")?;
+        let game_id = parse_number::<u32>(input, game_id)?;
 
         let mut game = Game::new(game_id);
         for draw in draws.split(';') {
             let mut values = [0; 3];
 
             for value in draw.split(',') {
-                let val_num = value
-                    .split_whitespace()
-                    .next()
-                    .unwrap()
-                    .parse::<u32>()
-                    .unwrap();
-                if value.contains("green") {
-                    values[1] = val_num;
-                } else if value.contains("blue") {
-                    values[2] = val_num;
-                } else if value.contains("red") {
-                    values[0] = val_num;
-                } else {
-                    panic!("Invalid color!");
+                match value.split_whitespace().collect::<Vec<_>>()[..] {
+                    [val_num, color @ ("red" | "green" | "blue")] => {
+                        let color_ind = ["red", "green", "blue"]
+                            .iter()
+                            .position(|&x| x == color)
+                            .unwrap();
+                        values[color_ind] = parse_number(input, val_num)?;
+                    }
+                    _ => {
+                        return Err(Error::invalid_input(
+                            input,
+                            value,
+                            "expected cubes of a color",
+                        ));
+                    }
                 }
             }
 
@@ -64,7 +84,7 @@ fn parse_input(input: &str) -> Vec<Game> {
         games.push(game);
     }
 
-    games
+    Ok(games)
 }
 
 const CUBE_AMOUNTS: [u32; 3] = [12, 13, 14]; // RGB
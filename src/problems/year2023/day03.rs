@@ -1,14 +1,28 @@
-use crate::{Error, Solution};
-
-day!(Day03, 2023, 3, "Gear Ratios");
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
+
+day!(
+    Day03,
+    2023,
+    3,
+    "Gear Ratios",
+    difficulty = Easy,
+    tags = [Grid, Parsing]
+);
 
 impl Solution for Day03 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(Schematic::new(input).sum_part_numbers().to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        Ok(Schematic::new(input)?
+            .sum_part_numbers()
+            .ok_or(Error::NoSolution)?
+            .into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        Ok(Schematic::new(input).sum_of_gear_ratios().to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        Ok(Schematic::new(input)?
+            .sum_of_gear_ratios()
+            .ok_or(Error::NoSolution)?
+            .into())
     }
 }
 
@@ -18,17 +32,25 @@ struct Schematic {
     part_numbers: Vec<(u64, usize, usize, usize)>, // (number, row, start, end)
 }
 impl Schematic {
-    fn new(input: &str) -> Self {
+    fn new(input: &str) -> Result<Self, Error> {
+        // make sure all the numbers fit, so they can be parsed again from the scheme
+        for number in input
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|number| !number.is_empty())
+        {
+            parse_number::<u64>(input, number)?;
+        }
+
         let scheme = input
             .trim()
             .lines()
             .map(|line| line.chars().collect())
             .collect();
-        Self {
+        Ok(Self {
             scheme,
             generated_part_numbers: false,
             part_numbers: Vec::new(),
-        }
+        })
     }
 
     /// Get the character at the given coordinates.
@@ -97,13 +119,13 @@ impl Schematic {
         self.generated_part_numbers = true;
     }
 
-    fn sum_part_numbers(&mut self) -> u64 {
+    /// Returns the sum of the part numbers, or `None` if it overflows.
+    fn sum_part_numbers(&mut self) -> Option<u64> {
         self.generate_part_numbers();
 
         self.part_numbers
             .iter()
-            .map(|(number, _, _, _)| number)
-            .sum::<u64>()
+            .try_fold(0u64, |sum, (number, _, _, _)| sum.checked_add(*number))
     }
 
     fn adjacent_symbols(&self, row: usize, start: usize, end: usize) -> u64 {
@@ -129,22 +151,23 @@ impl Schematic {
         count
     }
 
-    fn sum_of_gear_ratios(&mut self) -> u64 {
+    /// Returns the sum of the gear ratios, or `None` if it overflows.
+    fn sum_of_gear_ratios(&mut self) -> Option<u64> {
         self.generate_part_numbers();
-        let mut sum = 0;
+        let mut sum: u64 = 0;
 
         for (i, row) in self.scheme.iter().enumerate() {
             for (j, elem) in row.iter().enumerate() {
                 if *elem == '*' {
                     let numbers_around = self.numbers_around(i, j);
                     if numbers_around.len() == 2 {
-                        sum += numbers_around[0] * numbers_around[1];
+                        sum = sum.checked_add(numbers_around[0].checked_mul(numbers_around[1])?)?;
                     }
                 }
             }
         }
 
-        sum
+        Some(sum)
     }
 
     fn numbers_around(&self, i: usize, j: usize) -> Vec<u64> {
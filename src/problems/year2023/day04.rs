@@ -1,60 +1,85 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 
-day!(Day04, 2023, 4, "Scratchcards");
+day!(
+    Day04,
+    2023,
+    4,
+    "Scratchcards",
+    difficulty = Easy,
+    tags = [DynamicProgramming]
+);
 
 impl Solution for Day04 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let cards = parse_input(input);
-        let mut point_sum = 0;
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let cards = parse_input(input)?;
+        let mut point_sum: u64 = 0;
         for card in cards {
-            point_sum += card.points();
+            point_sum = card
+                .points()
+                .and_then(|points| point_sum.checked_add(points))
+                .ok_or(Error::NoSolution)?;
         }
-        Ok(point_sum.to_string())
+        Ok(point_sum.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut cards = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut cards = parse_input(input)?;
 
         for i in 0..cards.len() {
             let next_card_count = cards[i].winning_num_count();
             let new_copies = cards[i].copies;
 
-            #[allow(clippy::needless_range_loop)]
             for j in (i + 1)..=(i + next_card_count as usize) {
-                cards[j].copies += new_copies;
+                // cards never make you copy a card past the end of the table
+                let card = cards.get_mut(j).ok_or(Error::NoSolution)?;
+                card.copies = card
+                    .copies
+                    .checked_add(new_copies)
+                    .ok_or(Error::NoSolution)?;
             }
         }
 
-        Ok(cards.iter().map(|c| c.copies).sum::<u32>().to_string())
+        cards
+            .iter()
+            .try_fold(0u64, |sum, c| sum.checked_add(c.copies))
+            .map(Answer::from)
+            .ok_or(Error::NoSolution)
     }
 }
 
-fn parse_input(input: &str) -> Vec<Scratchcard> {
+fn parse_input(input: &str) -> Result<Vec<Scratchcard>, Error> {
     let mut cards = Vec::new();
 
     for line in input.trim().lines() {
-        let (_, cards_part) = line.split_once(':').unwrap();
+        let (_, cards_part) = line
+            .split_once(':')
+            .or_invalid(input, line, "expected a card")?;
 
-        let (winning_cards_part, my_cards_part) = cards_part.split_once('|').unwrap();
+        let (winning_cards_part, my_cards_part) = cards_part.split_once('|').or_invalid(
+            input,
+            cards_part,
+            "expected two lists of numbers",
+        )?;
         let winning_numbers = winning_cards_part
             .split_whitespace()
-            .map(|n| n.parse::<u32>().unwrap())
-            .collect::<Vec<u32>>();
+            .map(|n| parse_number(input, n))
+            .collect::<Result<Vec<u32>, _>>()?;
         let my_numbers = my_cards_part
             .split_whitespace()
-            .map(|n| n.parse::<u32>().unwrap())
-            .collect::<Vec<u32>>();
+            .map(|n| parse_number(input, n))
+            .collect::<Result<Vec<u32>, _>>()?;
 
         cards.push(Scratchcard::new(winning_numbers, my_numbers));
     }
 
-    cards
+    Ok(cards)
 }
 
 struct Scratchcard {
     winning_numbers: Vec<u32>,
     my_numbers: Vec<u32>,
-    copies: u32,
+    copies: u64,
 }
 impl Scratchcard {
     fn new(winning_numbers: Vec<u32>, my_numbers: Vec<u32>) -> Self {
@@ -75,10 +100,11 @@ impl Scratchcard {
         same_numbers
     }
 
-    fn points(&self) -> u32 {
+    fn points(&self) -> Option<u64> {
         // points = floor(2^(n-1)) where n is the number of same numbers
         // can be simplified to 2^n / 2 (because this is an integer division)
         // which can be simplified to (1 << n) >> 1
-        (1 << self.winning_num_count()) >> 1
+        let points = 1u128.checked_shl(self.winning_num_count())? >> 1;
+        u64::try_from(points).ok()
     }
 }
@@ -1,19 +1,34 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, end, parse_number};
+use crate::{Answer, Error, ParsedSolution};
 use itertools::Itertools;
 
-day!(Day05, 2023, 5, "If You Give A Seed A Fertilizer");
+day!(
+    Day05,
+    2023,
+    5,
+    "If You Give A Seed A Fertilizer",
+    difficulty = Hard,
+    tags = [Ranges]
+);
 
-impl Solution for Day05 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(Garden::new(input).closest_location1().to_string())
+impl ParsedSolution for Day05 {
+    type Input = Garden;
+
+    fn parse(&self, input: &str) -> Result<Garden, Error> {
+        Garden::new(input)
+    }
+
+    fn part1(&self, garden: &Garden) -> Result<Answer, Error> {
+        Ok(garden.closest_location1()?.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        Ok(Garden::new(input).closest_location2().to_string())
+    fn part2(&self, garden: &Garden) -> Result<Answer, Error> {
+        Ok(garden.closest_location2()?.into())
     }
 }
 
-struct Garden {
+/// The seeds to be planted and the almanac maps.
+pub struct Garden {
     seeds: Vec<u64>,
     seed_to_soil_map: Vec<Map>,
     soil_to_fertilizer_map: Vec<Map>,
@@ -24,139 +39,42 @@ struct Garden {
     humidity_to_location_map: Vec<Map>,
 }
 impl Garden {
-    fn new(input: &str) -> Self {
-        let mut input = input.trim().lines();
-
-        let seeds_str = input.next().unwrap().trim_start_matches("seeds:").trim();
+    fn new(input: &str) -> Result<Self, Error> {
+        let mut lines = input.trim().lines();
+
+        let seeds_line = lines.next().unwrap_or_default();
+        let seeds_str = seeds_line.strip_prefix("seeds:").or_invalid(
+            input,
+            seeds_line,
+            "expected a list of seeds",
+        )?;
         let seeds = seeds_str
             .split_whitespace()
-            .map(|s| s.parse::<u64>().unwrap())
-            .collect::<Vec<_>>();
-
-        let mut line = "";
-        while !line.starts_with("seed-to-soil map:") {
-            line = input.next().unwrap();
-        }
-        line = input.next().unwrap();
-        let mut seed_to_soil_map = Vec::new();
-        while !line.is_empty() {
-            let (dest, src, len) = line.split_whitespace().collect_tuple().unwrap();
-            seed_to_soil_map.push(Map::new(
-                src.parse().unwrap(),
-                dest.parse().unwrap(),
-                len.parse().unwrap(),
-            ));
-            line = input.next().unwrap();
-        }
-
-        while !line.starts_with("soil-to-fertilizer map:") {
-            line = input.next().unwrap();
-        }
-        line = input.next().unwrap();
-        let mut soil_to_fertilizer_map = Vec::new();
-        while !line.is_empty() {
-            let (dest, src, len) = line.split_whitespace().collect_tuple().unwrap();
-            soil_to_fertilizer_map.push(Map::new(
-                src.parse().unwrap(),
-                dest.parse().unwrap(),
-                len.parse().unwrap(),
-            ));
-            line = input.next().unwrap();
-        }
-
-        while !line.starts_with("fertilizer-to-water map:") {
-            line = input.next().unwrap();
-        }
-        line = input.next().unwrap();
-        let mut fertilizer_to_water_map = Vec::new();
-        while !line.is_empty() {
-            let (dest, src, len) = line.split_whitespace().collect_tuple().unwrap();
-            fertilizer_to_water_map.push(Map::new(
-                src.parse().unwrap(),
-                dest.parse().unwrap(),
-                len.parse().unwrap(),
-            ));
-            line = input.next().unwrap();
-        }
-
-        while !line.starts_with("water-to-light map:") {
-            line = input.next().unwrap();
-        }
-        line = input.next().unwrap();
-        let mut water_to_light_map = Vec::new();
-        while !line.is_empty() {
-            let (dest, src, len) = line.split_whitespace().collect_tuple().unwrap();
-            water_to_light_map.push(Map::new(
-                src.parse().unwrap(),
-                dest.parse().unwrap(),
-                len.parse().unwrap(),
-            ));
-            line = input.next().unwrap();
-        }
-
-        while !line.starts_with("light-to-temperature map:") {
-            line = input.next().unwrap();
-        }
-        line = input.next().unwrap();
-        let mut light_to_temperature_map = Vec::new();
-        while !line.is_empty() {
-            let (dest, src, len) = line.split_whitespace().collect_tuple().unwrap();
-            light_to_temperature_map.push(Map::new(
-                src.parse().unwrap(),
-                dest.parse().unwrap(),
-                len.parse().unwrap(),
-            ));
-            line = input.next().unwrap();
-        }
+            .map(|s| parse_number::<u64>(input, s))
+            .collect::<Result<Vec<_>, _>>()?;
 
-        while !line.starts_with("temperature-to-humidity map:") {
-            line = input.next().unwrap();
-        }
-        line = input.next().unwrap();
-        let mut temperature_to_humidity_map = Vec::new();
-        while !line.is_empty() {
-            let (dest, src, len) = line.split_whitespace().collect_tuple().unwrap();
-            temperature_to_humidity_map.push(Map::new(
-                src.parse().unwrap(),
-                dest.parse().unwrap(),
-                len.parse().unwrap(),
-            ));
-            line = input.next().unwrap();
-        }
-
-        while !line.starts_with("humidity-to-location map:") {
-            line = input.next().unwrap();
-        }
-        line = input.next().unwrap();
-        let mut humidity_to_location_map = Vec::new();
-        while !line.is_empty() {
-            let (dest, src, len) = line.split_whitespace().collect_tuple().unwrap();
-            humidity_to_location_map.push(Map::new(
-                src.parse().unwrap(),
-                dest.parse().unwrap(),
-                len.parse().unwrap(),
-            ));
-            line = input.next().unwrap_or("");
-        }
-
-        Self {
+        Ok(Self {
             seeds,
-            seed_to_soil_map,
-            soil_to_fertilizer_map,
-            fertilizer_to_water_map,
-            water_to_light_map,
-            light_to_temperature_map,
-            temperature_to_humidity_map,
-            humidity_to_location_map,
-        }
+            seed_to_soil_map: parse_map(input, &mut lines, "seed-to-soil map:")?,
+            soil_to_fertilizer_map: parse_map(input, &mut lines, "soil-to-fertilizer map:")?,
+            fertilizer_to_water_map: parse_map(input, &mut lines, "fertilizer-to-water map:")?,
+            water_to_light_map: parse_map(input, &mut lines, "water-to-light map:")?,
+            light_to_temperature_map: parse_map(input, &mut lines, "light-to-temperature map:")?,
+            temperature_to_humidity_map: parse_map(
+                input,
+                &mut lines,
+                "temperature-to-humidity map:",
+            )?,
+            humidity_to_location_map: parse_map(input, &mut lines, "humidity-to-location map:")?,
+        })
     }
 
-    fn closest_location1(&self) -> u64 {
+    fn closest_location1(&self) -> Result<u64, Error> {
         self.seeds
             .iter()
             .map(|&seed| self.seed_to_location(seed))
             .min()
-            .unwrap()
+            .ok_or(Error::NoSolution)
     }
 
     fn seed_to_location(&self, seed: u64) -> u64 {
@@ -178,10 +96,11 @@ impl Garden {
         value
     }
 
-    fn closest_location2(&self) -> u64 {
+    fn closest_location2(&self) -> Result<u64, Error> {
         let mut intervals = Vec::new();
         for (start, len) in self.seeds.iter().tuples() {
-            intervals.push(Interval::new(*start, *start + *len));
+            let end = start.checked_add(*len).ok_or(Error::NoSolution)?;
+            intervals.push(Interval::new(*start, end));
         }
 
         intervals = Self::calculate_map_intervals(intervals, &self.seed_to_soil_map);
@@ -196,7 +115,7 @@ impl Garden {
             .into_iter()
             .map(|interval| interval.start)
             .min()
-            .unwrap()
+            .ok_or(Error::NoSolution)
     }
 
     fn calculate_map_intervals(mut intervals: Vec<Interval>, maps: &[Map]) -> Vec<Interval> {
@@ -289,6 +208,44 @@ impl Garden {
         output_intervals
     }
 }
+/// Parse the map following the given header, up to the next empty line.
+fn parse_map<'a>(
+    input: &str,
+    lines: &mut impl Iterator<Item = &'a str>,
+    header: &str,
+) -> Result<Vec<Map>, Error> {
+    if !lines.any(|line| line.starts_with(header)) {
+        return Err(Error::invalid_input(input, end(input), "expected a map"));
+    }
+
+    let mut maps = Vec::new();
+    for line in lines.take_while(|line| !line.is_empty()) {
+        match line.split_whitespace().collect::<Vec<_>>()[..] {
+            [dest, src, len] => {
+                let range_len = parse_number(input, len)?;
+                if range_len == 0 {
+                    return Err(Error::invalid_input(
+                        input,
+                        len,
+                        "expected a positive length",
+                    ));
+                }
+                let src_start = parse_number::<u64>(input, src)?;
+                let dest_start = parse_number::<u64>(input, dest)?;
+                // the ranges are handled by their ends, so those have to be numbers too
+                if src_start.checked_add(range_len).is_none()
+                    || dest_start.checked_add(range_len).is_none()
+                {
+                    return Err(Error::invalid_input(input, line, "range is too long"));
+                }
+                maps.push(Map::new(src_start, dest_start, range_len));
+            }
+            _ => return Err(Error::invalid_input(input, line, "expected a mapping")),
+        }
+    }
+    Ok(maps)
+}
+
 struct Map {
     src_start: u64,
     dest_start: u64,
@@ -1,56 +1,64 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 use std::iter::zip;
 
-day!(Day06, 2023, 6, "Wait For It");
+day!(
+    Day06,
+    2023,
+    6,
+    "Wait For It",
+    difficulty = Easy,
+    tags = [Search]
+);
 
 impl Solution for Day06 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let races = parse_input(input);
-        Ok(races
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let races = parse_input(input)?;
+        races
             .into_iter()
-            .map(|(time, distance)| possible_victories(time, distance))
-            .product::<u64>()
-            .to_string())
+            .try_fold(1u64, |product, (time, distance)| {
+                product.checked_mul(possible_victories(time, distance))
+            })
+            .map(Answer::from)
+            .ok_or(Error::NoSolution)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let races = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let races = parse_input(input)?;
         let time = races
             .iter()
             .map(|(time, _)| time.to_string())
             .collect::<String>()
             .parse()
-            .unwrap();
+            .map_err(|_| Error::NoSolution)?;
         let distance = races
             .iter()
             .map(|(_, distance)| distance.to_string())
             .collect::<String>()
             .parse()
-            .unwrap();
-        Ok(possible_victories(time, distance).to_string())
+            .map_err(|_| Error::NoSolution)?;
+        Ok(possible_victories(time, distance).into())
     }
 }
 
 const ACCELERATION: u64 = 1;
 
-fn parse_input(input: &str) -> Vec<(u64, u64)> {
-    let mut input = input.trim().lines();
-    let time_line = input
-        .next()
-        .unwrap()
-        .trim_start_matches("Time:")
-        .split_whitespace();
-    let distance_line = input
-        .next()
-        .unwrap()
-        .trim_start_matches("Distance:")
-        .split_whitespace();
+fn parse_input(input: &str) -> Result<Vec<(u64, u64)>, Error> {
+    let mut lines = input.trim().lines();
+    let mut next_line = |prefix| {
+        let line = lines.next().unwrap_or_default();
+        line.strip_prefix(prefix)
+            .map(|line| line.split_whitespace())
+            .or_invalid(input, line, "expected a list of races")
+    };
+    let time_line = next_line("Time:")?;
+    let distance_line = next_line("Distance:")?;
 
     let mut input_cases = Vec::new();
     for (time, distance) in zip(time_line, distance_line) {
-        input_cases.push((time.parse().unwrap(), distance.parse().unwrap()));
+        input_cases.push((parse_number(input, time)?, parse_number(input, distance)?));
     }
-    input_cases
+    Ok(input_cases)
 }
 
 fn possible_victories(time: u64, distance: u64) -> u64 {
@@ -70,11 +78,18 @@ fn possible_victories(time: u64, distance: u64) -> u64 {
     // if the roots are itself integers, they shouldn't be counted
     // (because the distance is equal to the maximum distance)
 
-    let mut root_high = (-((ACCELERATION * time) as f64)
-        - (((ACCELERATION * time).pow(2) - 4 * ACCELERATION * distance) as f64).sqrt())
+    // without real roots the record can't be beaten
+    // computed with 128 bits, so the squares of the times fit
+    let Some(discriminant) = u128::from(ACCELERATION * time)
+        .pow(2)
+        .checked_sub(4 * u128::from(ACCELERATION) * u128::from(distance))
+    else {
+        return 0;
+    };
+
+    let mut root_high = (-((ACCELERATION * time) as f64) - (discriminant as f64).sqrt())
         / (-2.0 * ACCELERATION as f64);
-    let mut root_low = (-((ACCELERATION * time) as f64)
-        + (((ACCELERATION * time).pow(2) - 4 * ACCELERATION * distance) as f64).sqrt())
+    let mut root_low = (-((ACCELERATION * time) as f64) + (discriminant as f64).sqrt())
         / (-2.0 * ACCELERATION as f64);
 
     if (root_low - root_low.round()).abs() <= 10e-8 {
@@ -88,5 +103,7 @@ fn possible_victories(time: u64, distance: u64) -> u64 {
         root_high = root_high.floor();
     }
 
-    root_high as u64 - root_low as u64 + 1
+    (root_high as u64)
+        .saturating_add(1)
+        .saturating_sub(root_low as u64)
 }
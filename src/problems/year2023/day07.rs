@@ -1,52 +1,50 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 use itertools::Itertools;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::sync::LazyLock;
 
-day!(Day07, 2023, 7, "Camel Cards");
+day!(
+    Day07,
+    2023,
+    7,
+    "Camel Cards",
+    difficulty = Medium,
+    tags = [Strings]
+);
 
 impl Solution for Day07 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let mut hands = input
             .trim()
             .lines()
             .map(|hand| {
-                let mut hand = Hand::new(hand);
+                let mut hand = Hand::new(input, hand)?;
                 hand.calc_dec_val1();
-                hand
+                Ok(hand)
             })
-            .collect_vec();
+            .collect::<Result<Vec<_>, Error>>()?;
 
         hands.sort();
 
-        Ok(hands
-            .into_iter()
-            .enumerate()
-            .map(|(i, hand)| hand.bid * (i as u64 + 1))
-            .sum::<u64>()
-            .to_string())
+        Ok(total_winnings(&hands)?.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut hands = input
             .trim()
             .lines()
             .map(|hand| {
-                let mut hand = Hand::new(hand);
+                let mut hand = Hand::new(input, hand)?;
                 hand.calc_dec_val2();
-                hand
+                Ok(hand)
             })
-            .collect_vec();
+            .collect::<Result<Vec<_>, Error>>()?;
 
         hands.sort();
 
-        Ok(hands
-            .into_iter()
-            .enumerate()
-            .map(|(i, hand)| hand.bid * (i as u64 + 1))
-            .sum::<u64>()
-            .to_string())
+        Ok(total_winnings(&hands)?.into())
     }
 }
 
@@ -89,20 +87,45 @@ static CARD_ORDER_2: LazyLock<HashMap<char, u64>> = LazyLock::new(|| {
     map
 });
 
+/// Get the sum of the bids multiplied by their rank.
+/// # Arguments
+/// * `hands` - The hands sorted from the weakest to the strongest.
+/// # Returns
+/// * The total winnings or [Error::NoSolution] if they overflow.
+fn total_winnings(hands: &[Hand]) -> Result<u64, Error> {
+    hands
+        .iter()
+        .zip(1u64..)
+        .try_fold(0u64, |sum, (hand, rank)| {
+            sum.checked_add(hand.bid.checked_mul(rank)?)
+        })
+        .ok_or(Error::NoSolution)
+}
+
 struct Hand<'a> {
     cards: &'a str,
     bid: u64,
     dec_val: u64,
 }
 impl<'a> Hand<'a> {
-    fn new(hand: &'a str) -> Self {
-        let (cards, bid) = hand.split_whitespace().collect_tuple().unwrap();
+    fn new(input: &str, hand: &'a str) -> Result<Self, Error> {
+        let (cards, bid) = hand.split_whitespace().collect_tuple().or_invalid(
+            input,
+            hand,
+            "expected cards and a bid",
+        )?;
+        if cards.chars().count() != 5 {
+            return Err(Error::invalid_input(input, cards, "expected five cards"));
+        }
+        if let Some(i) = cards.find(|card| !CARD_ORDER_1.contains_key(&card)) {
+            return Err(Error::invalid_input(input, &cards[i..], "expected a card"));
+        }
 
-        Self {
+        Ok(Self {
             cards,
-            bid: bid.parse::<u64>().unwrap(),
+            bid: parse_number(input, bid)?,
             dec_val: 0,
-        }
+        })
     }
 
     fn calc_dec_val1(&mut self) {
@@ -174,7 +197,7 @@ impl HandTypes {
             [1, 2, 2] => HandTypes::TwoPair,
             [1, 1, 1, 2] => HandTypes::OnePair,
             [1, 1, 1, 1, 1] => HandTypes::HighCard,
-            _ => panic!("Invalid hand"),
+            _ => unreachable!(),
         }
     }
 
@@ -204,7 +227,7 @@ impl HandTypes {
                 [1, 2, 2] => HandTypes::TwoPair,
                 [1, 1, 1, 2] => HandTypes::OnePair,
                 [1, 1, 1, 1, 1] => HandTypes::HighCard,
-                _ => panic!("Invalid hand"),
+                _ => unreachable!(),
             };
             if type_value > best_hand_type {
                 best_hand_type = type_value;
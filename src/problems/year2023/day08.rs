@@ -1,17 +1,25 @@
-use crate::{Error, Solution};
-use pmath::lcm_multiple;
-
-day!(Day08, 2023, 8, "Haunted Wasteland");
+use crate::input::OrInvalid;
+use crate::{Answer, Error, Solution};
+use pmath::gcd;
+
+day!(
+    Day08,
+    2023,
+    8,
+    "Haunted Wasteland",
+    difficulty = Medium,
+    tags = [Cycles, Graph, NumberTheory]
+);
 
 impl Solution for Day08 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let tree = Tree::new(input);
-        Ok(tree.simulate_1().to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let tree = Tree::new(input)?;
+        Ok(tree.simulate_1()?.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let tree = Tree::new(input);
-        Ok(tree.simulate_2().to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let tree = Tree::new(input)?;
+        Ok(tree.simulate_2()?.into())
     }
 }
 
@@ -27,77 +35,82 @@ impl<'a> Node<'a> {
 }
 struct Tree<'a> {
     nodes: Vec<Node<'a>>,
-    root: usize,
+    root: Option<usize>,
     instructions: Vec<bool>, // false = left, true = right
 }
 impl<'a> Tree<'a> {
-    fn new(input: &'a str) -> Self {
+    fn new(input: &'a str) -> Result<Self, Error> {
         let mut input_lines = input.trim().lines();
-        let instructions = input_lines
-            .next()
-            .unwrap()
-            .chars()
-            .map(|c| match c {
-                'L' => false,
-                'R' => true,
-                _ => panic!("Invalid input"),
+        let instructions_line = input_lines.next().unwrap_or_default();
+        let instructions = instructions_line
+            .char_indices()
+            .map(|(i, c)| match c {
+                'L' => Ok(false),
+                'R' => Ok(true),
+                _ => Err(Error::invalid_input(
+                    input,
+                    &instructions_line[i..],
+                    "expected a direction",
+                )),
             })
-            .collect::<Vec<bool>>();
+            .collect::<Result<Vec<bool>, _>>()?;
+        if instructions.is_empty() {
+            return Err(Error::invalid_input(
+                input,
+                instructions_line,
+                "expected instructions",
+            ));
+        }
 
         input_lines.next(); // Skip empty line
 
         let mut nodes_input = Vec::new();
         for line in input_lines {
-            let (node_id, left_right) = line.split_once(" = ").unwrap();
+            let (node_id, left_right) =
+                line.split_once(" = ")
+                    .or_invalid(input, line, "expected a node")?;
             let (left, right) = left_right
                 .trim_start_matches('(')
                 .trim_end_matches(')')
                 .split_once(", ")
-                .unwrap();
+                .or_invalid(input, left_right, "expected a pair of nodes")?;
             nodes_input.push((node_id, left, right));
         }
 
+        let position = |node_id: &str| {
+            nodes_input
+                .iter()
+                .position(|(id, _, _)| *id == node_id)
+                .or_invalid(input, node_id, "expected a known node")
+        };
         let mut nodes = Vec::new();
         for node_info in &nodes_input {
             nodes.push(Node::new(
                 node_info.0,
-                nodes_input
-                    .iter()
-                    .position(|(id, _, _)| id == &node_info.1)
-                    .unwrap(),
-                nodes_input
-                    .iter()
-                    .position(|(id, _, _)| id == &node_info.2)
-                    .unwrap(),
+                position(node_info.1)?,
+                position(node_info.2)?,
             ));
         }
 
-        let root = nodes_input
-            .iter()
-            .position(|(id, _, _)| id == &"AAA")
-            .unwrap();
+        let root = nodes_input.iter().position(|(id, _, _)| id == &"AAA");
 
-        Self {
+        Ok(Self {
             nodes,
             root,
             instructions,
-        }
+        })
     }
 
-    fn simulate_1(&self) -> u64 {
-        let mut steps = 0;
-        let mut current_node = self.root;
-        while self.nodes[current_node].id != "ZZZ" {
-            match self.instructions[steps as usize % self.instructions.len()] {
-                false => current_node = self.nodes[current_node].left,
-                true => current_node = self.nodes[current_node].right,
-            }
-            steps += 1;
+    fn simulate_1(&self) -> Result<u64, Error> {
+        if !self.nodes.iter().any(|node| node.id == "ZZZ") {
+            return Err(Error::NoSolution);
         }
-        steps
+
+        let root = self.root.ok_or(Error::NoSolution)?;
+        self.steps_to_end(root, |id| id == "ZZZ")
     }
 
-    fn simulate_2(&self) -> u64 {
+    fn simulate_2(&self) -> Result<u64, Error> {
         // let's make some assumptions about input (which turn out to be true):
         // - for each starting node that ends with 'A', there is a unique ending node that ends with 'Z' that forms a unique cycle
         // - each cycle starts immediately after starting node, therefore the length of the cycle is the length of the path to the ending node
@@ -108,21 +121,36 @@ impl<'a> Tree<'a> {
 
         for i in 0..self.nodes.len() {
             if self.nodes[i].id.ends_with('A') {
-                let mut steps = 0;
-                let mut current_node = i;
-
-                while !self.nodes[current_node].id.ends_with('Z') {
-                    match self.instructions[steps as usize % self.instructions.len()] {
-                        false => current_node = self.nodes[current_node].left,
-                        true => current_node = self.nodes[current_node].right,
-                    }
-                    steps += 1;
-                }
-
-                cycle_lens.push(steps);
+                cycle_lens.push(self.steps_to_end(i, |id| id.ends_with('Z'))?);
             }
         }
 
-        lcm_multiple(&cycle_lens)
+        if cycle_lens.is_empty() {
+            return Err(Error::NoSolution);
+        }
+        cycle_lens
+            .into_iter()
+            .try_fold(1, |lcm, len| (lcm / gcd(lcm, len)).checked_mul(len))
+            .ok_or(Error::NoSolution)
+    }
+
+    /// Count the steps from the start to the first node accepted as the end.
+    fn steps_to_end(&self, start: usize, is_end: impl Fn(&str) -> bool) -> Result<u64, Error> {
+        // after visiting every node at every instruction, the walk can only repeat itself
+        let states = (self.nodes.len() * self.instructions.len()) as u64;
+
+        let mut steps = 0;
+        let mut current_node = start;
+        while !is_end(self.nodes[current_node].id) {
+            if steps > states {
+                return Err(Error::NoSolution);
+            }
+            match self.instructions[steps as usize % self.instructions.len()] {
+                false => current_node = self.nodes[current_node].left,
+                true => current_node = self.nodes[current_node].right,
+            }
+            steps += 1;
+        }
+        Ok(steps)
     }
 }
@@ -1,40 +1,51 @@
-use crate::{Error, Solution};
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
 
-day!(Day09, 2023, 9, "Mirage Maintenance");
+day!(
+    Day09,
+    2023,
+    9,
+    "Mirage Maintenance",
+    difficulty = Easy,
+    tags = []
+);
 
 impl Solution for Day09 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let data = parse_input(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let data = parse_input(input)?;
         Ok(data
             .into_iter()
             .map(|row| predict_next(&row))
-            .sum::<i64>()
-            .to_string())
+            .try_fold(0_i64, |sum, value| sum.checked_add(value?))
+            .ok_or(Error::NoSolution)?
+            .into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let data = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let data = parse_input(input)?;
         Ok(data
             .into_iter()
             .map(|row| predict_prev(&row))
-            .sum::<i64>()
-            .to_string())
+            .try_fold(0_i64, |sum, value| sum.checked_add(value?))
+            .ok_or(Error::NoSolution)?
+            .into())
     }
 }
 
-fn parse_input(input: &str) -> Vec<Vec<i64>> {
+fn parse_input(input: &str) -> Result<Vec<Vec<i64>>, Error> {
     input
         .trim()
         .lines()
         .map(|line| {
             line.split_whitespace()
-                .map(|num| num.parse::<i64>().unwrap())
-                .collect::<Vec<_>>()
+                .map(|num| parse_number::<i64>(input, num))
+                .collect::<Result<Vec<_>, _>>()
         })
         .collect()
 }
 
-fn predict_next(data: &[i64]) -> i64 {
+/// Returns the next value, or `None` if the differences overflow.
+fn predict_next(data: &[i64]) -> Option<i64> {
     let mut last_values = Vec::new();
     let mut working_values = data.to_vec();
 
@@ -42,16 +53,19 @@ fn predict_next(data: &[i64]) -> i64 {
         last_values.push(working_values[working_values.len() - 1]);
 
         for i in 0..(working_values.len() - 1) {
-            working_values[i] = working_values[i + 1] - working_values[i];
+            working_values[i] = working_values[i + 1].checked_sub(working_values[i])?;
         }
 
         working_values.pop();
     }
 
-    last_values.into_iter().sum()
+    last_values
+        .into_iter()
+        .try_fold(0_i64, |sum, value| sum.checked_add(value))
 }
 
-fn predict_prev(data: &[i64]) -> i64 {
+/// Returns the previous value, or `None` if the differences overflow.
+fn predict_prev(data: &[i64]) -> Option<i64> {
     let mut first_values = Vec::new();
     let mut working_values = data.to_vec();
 
@@ -59,16 +73,14 @@ fn predict_prev(data: &[i64]) -> i64 {
         first_values.push(working_values[0]);
 
         for i in 0..(working_values.len() - 1) {
-            working_values[i] = working_values[i + 1] - working_values[i];
+            working_values[i] = working_values[i + 1].checked_sub(working_values[i])?;
         }
 
         working_values.pop();
     }
 
-    let mut result = 0;
     first_values
         .into_iter()
         .rev()
-        .for_each(|val| result = val - result);
-    result
+        .try_fold(0_i64, |result, val| val.checked_sub(result))
 }
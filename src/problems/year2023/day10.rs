@@ -1,25 +1,33 @@
-use crate::{Error, Solution};
+use crate::input::end;
+use crate::{Answer, Error, Solution};
 use std::collections::{BTreeSet, HashMap, HashSet};
 use std::sync::LazyLock;
 
-day!(Day10, 2023, 10, "Pipe Maze");
+day!(
+    Day10,
+    2023,
+    10,
+    "Pipe Maze",
+    difficulty = Medium,
+    tags = [Geometry, Graph, Grid]
+);
 
 impl Solution for Day10 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let field = parse_input(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let field = parse_input(input)?;
 
         // loop will have an even number of pipes, so we can divide by 2
         // (that is because for every move,
         // up/down/left/right we must do its opposite to get back to S)
-        Ok((find_loop(&field).len() / 2).to_string())
+        Ok((find_loop(&field)?.len() / 2).into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut field = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut field = parse_input(input)?;
 
-        let loop_tiles = find_loop(&field);
+        let loop_tiles = find_loop(&field)?;
 
-        let (s_coord, s_type) = determine_s(&field);
+        let (s_coord, s_type) = determine_s(&field)?;
         field[s_coord.0][s_coord.1] = s_type;
 
         // expand loop tiles to 3x3 so that there are no "hidden" spaces between pipes,
@@ -66,7 +74,7 @@ impl Solution for Day10 {
             }
         }
 
-        Ok(inside_count.to_string())
+        Ok(inside_count.into())
     }
 }
 
@@ -88,12 +96,40 @@ static PIPE_EXPANSIONS: LazyLock<HashMap<char, [[char; 3]; 3]>> = LazyLock::new(
     map
 });
 
-fn parse_input(input: &str) -> Vec<Vec<char>> {
-    input
-        .trim()
-        .lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect::<Vec<Vec<char>>>()
+fn parse_input(input: &str) -> Result<Vec<Vec<char>>, Error> {
+    let mut field: Vec<Vec<char>> = Vec::new();
+    let mut start_found = false;
+
+    for line in input.trim().lines() {
+        for (i, c) in line.char_indices() {
+            match c {
+                'S' if start_found => {
+                    return Err(Error::invalid_input(
+                        input,
+                        &line[i..],
+                        "expected a single start",
+                    ));
+                }
+                'S' => start_found = true,
+                '|' | '-' | 'L' | 'J' | 'F' | '7' | '.' => {}
+                _ => return Err(Error::invalid_input(input, &line[i..], "expected a tile")),
+            }
+        }
+        let row = line.chars().collect::<Vec<char>>();
+        if field.first().is_some_and(|first| first.len() != row.len()) {
+            return Err(Error::invalid_input(
+                input,
+                line,
+                "expected a rectangular field",
+            ));
+        }
+        field.push(row);
+    }
+
+    if !start_found {
+        return Err(Error::invalid_input(input, end(input), "expected a start"));
+    }
+    Ok(field)
 }
 
 fn find_start(field: &[Vec<char>]) -> (usize, usize) {
@@ -104,70 +140,82 @@ fn find_start(field: &[Vec<char>]) -> (usize, usize) {
             }
         }
     }
-    panic!("No start found")
+    unreachable!()
 }
 
-fn find_adjacent_pipes(pipe: (usize, usize), field: &[Vec<char>]) -> [(usize, usize); 2] {
-    match field[pipe.0][pipe.1] {
+/// Finds the two pipes connected to the given pipe.
+/// Returns [Error::NoSolution] if the pipe is outside the field or doesn't belong to a loop.
+fn find_adjacent_pipes(
+    pipe: (usize, usize),
+    field: &[Vec<char>],
+) -> Result<[(usize, usize); 2], Error> {
+    // pipes on the edge may point outside the field, which wraps around to an invalid index
+    let tile = field
+        .get(pipe.0)
+        .and_then(|row| row.get(pipe.1))
+        .ok_or(Error::NoSolution)?;
+    match tile {
         'S' => {
-            let mut found = 0;
-            let mut result = [(0, 0); 2];
+            let mut result = Vec::new();
 
             if pipe.0 > 0 && ['|', 'F', '7'].contains(&field[pipe.0 - 1][pipe.1]) {
-                result[found] = (pipe.0 - 1, pipe.1);
-                found += 1;
+                result.push((pipe.0 - 1, pipe.1));
             }
 
             if pipe.0 < field.len() - 1 && ['|', 'J', 'L'].contains(&field[pipe.0 + 1][pipe.1]) {
-                result[found] = (pipe.0 + 1, pipe.1);
-                found += 1;
+                result.push((pipe.0 + 1, pipe.1));
             }
 
             if pipe.1 > 0 && ['-', 'L', 'F'].contains(&field[pipe.0][pipe.1 - 1]) {
-                result[found] = (pipe.0, pipe.1 - 1);
-                found += 1;
+                result.push((pipe.0, pipe.1 - 1));
             }
 
             if pipe.1 < field[0].len() - 1 && ['-', '7', 'J'].contains(&field[pipe.0][pipe.1 + 1]) {
-                result[found] = (pipe.0, pipe.1 + 1);
+                result.push((pipe.0, pipe.1 + 1));
             }
 
-            result
+            match result[..] {
+                [first, second] => Ok([first, second]),
+                _ => Err(Error::NoSolution),
+            }
         }
-        '|' => [(pipe.0 - 1, pipe.1), (pipe.0 + 1, pipe.1)],
-        '-' => [(pipe.0, pipe.1 - 1), (pipe.0, pipe.1 + 1)],
-        'L' => [(pipe.0, pipe.1 + 1), (pipe.0 - 1, pipe.1)],
-        'J' => [(pipe.0, pipe.1 - 1), (pipe.0 - 1, pipe.1)],
-        'F' => [(pipe.0, pipe.1 + 1), (pipe.0 + 1, pipe.1)],
-        '7' => [(pipe.0, pipe.1 - 1), (pipe.0 + 1, pipe.1)],
-        _ => panic!("Invalid pipe!"),
+        '|' => Ok([(pipe.0.wrapping_sub(1), pipe.1), (pipe.0 + 1, pipe.1)]),
+        '-' => Ok([(pipe.0, pipe.1.wrapping_sub(1)), (pipe.0, pipe.1 + 1)]),
+        'L' => Ok([(pipe.0, pipe.1 + 1), (pipe.0.wrapping_sub(1), pipe.1)]),
+        'J' => Ok([
+            (pipe.0, pipe.1.wrapping_sub(1)),
+            (pipe.0.wrapping_sub(1), pipe.1),
+        ]),
+        'F' => Ok([(pipe.0, pipe.1 + 1), (pipe.0 + 1, pipe.1)]),
+        '7' => Ok([(pipe.0, pipe.1.wrapping_sub(1)), (pipe.0 + 1, pipe.1)]),
+        _ => Err(Error::NoSolution),
     }
 }
 
-fn find_loop(field: &[Vec<char>]) -> HashSet<(usize, usize)> {
+fn find_loop(field: &[Vec<char>]) -> Result<HashSet<(usize, usize)>, Error> {
     let mut visited = HashSet::new();
 
     let mut current = find_start(field);
-    let mut next = find_adjacent_pipes(current, field);
+    let mut next = find_adjacent_pipes(current, field)?;
     visited.insert(current);
 
     while !(visited.contains(&next[0]) && visited.contains(&next[1])) {
         if visited.contains(&next[0]) {
             current = next[1];
             visited.insert(current);
-            next = find_adjacent_pipes(current, field);
+            next = find_adjacent_pipes(current, field)?;
         } else {
             current = next[0];
             visited.insert(current);
-            next = find_adjacent_pipes(current, field);
+            next = find_adjacent_pipes(current, field)?;
         }
     }
 
-    visited
+    Ok(visited)
 }
 
 /// Finds the S and returns its coordinates and type
-fn determine_s(field: &[Vec<char>]) -> ((usize, usize), char) {
+fn determine_s(field: &[Vec<char>]) -> Result<((usize, usize), char), Error> {
     let s_coord = find_start(field);
 
     let mut up = false;
@@ -192,23 +240,23 @@ fn determine_s(field: &[Vec<char>]) -> ((usize, usize), char) {
         right = true;
     }
 
-    let mut s_type = ' ';
-
-    if up && down {
-        s_type = '|';
+    let s_type = if up && down {
+        '|'
     } else if left && right {
-        s_type = '-';
+        '-'
     } else if up && right {
-        s_type = 'L';
+        'L'
     } else if up && left {
-        s_type = 'J';
+        'J'
     } else if down && left {
-        s_type = '7';
+        '7'
     } else if down && right {
-        s_type = 'F';
-    }
+        'F'
+    } else {
+        return Err(Error::NoSolution);
+    };
 
-    (s_coord, s_type)
+    Ok((s_coord, s_type))
 }
 
 /// Expands each tile to 3x3, only loop tiles are considered, others are considered empty
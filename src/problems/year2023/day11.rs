@@ -1,36 +1,60 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 
-day!(Day11, 2023, 11, "Cosmic Expansion");
+day!(
+    Day11,
+    2023,
+    11,
+    "Cosmic Expansion",
+    difficulty = Easy,
+    tags = [Geometry, Grid]
+);
 
 impl Solution for Day11 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let sky_map = parse_input(input);
-        Ok(sum_of_distances(&sky_map, EXPANSION_1).to_string())
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::integer(
+            "expansion",
+            "The factor by which empty rows and columns expand in the second part",
+            1_000_000,
+        )]
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let sky_map = parse_input(input);
-        Ok(sum_of_distances(&sky_map, EXPANSION_2).to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let sky_map = parse_input(input)?;
+        Ok(sum_of_distances(&sky_map, EXPANSION_1).into())
+    }
+
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let sky_map = parse_input(input)?;
+        Ok(sum_of_distances(&sky_map, parameters.integer("expansion")?).into())
     }
 }
 
 const EXPANSION_1: u64 = 2;
-const EXPANSION_2: u64 = 1_000_000;
-
-fn parse_input(input: &str) -> Vec<Vec<bool>> {
-    input
-        .trim()
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| match c {
-                    '.' => false,
-                    '#' => true,
-                    _ => panic!("Invalid input"),
-                })
-                .collect()
-        })
-        .collect()
+
+fn parse_input(input: &str) -> Result<Vec<Vec<bool>>, Error> {
+    let mut sky_map: Vec<Vec<bool>> = Vec::new();
+    for line in input.trim().lines() {
+        let row = line
+            .char_indices()
+            .map(|(i, c)| match c {
+                '.' => Ok(false),
+                '#' => Ok(true),
+                _ => Err(Error::invalid_input(input, &line[i..], "expected a pixel")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if sky_map
+            .first()
+            .is_some_and(|first| first.len() != row.len())
+        {
+            return Err(Error::invalid_input(
+                input,
+                line,
+                "expected a rectangular image",
+            ));
+        }
+        sky_map.push(row);
+    }
+    Ok(sky_map)
 }
 
 fn sum_of_distances(map: &[Vec<bool>], expansion: u64) -> u64 {
@@ -53,14 +77,14 @@ fn sum_of_distances(map: &[Vec<bool>], expansion: u64) -> u64 {
     }
 
     // find empty cols
-    for j in 0..map[0].len() {
+    for j in 0..map.first().map_or(0, Vec::len) {
         if map.iter().all(|row| !row[j]) {
             empty_cols.push(j);
         }
     }
 
     // calculate the distance between each pair of stars
-    for i in 0..(stars.len() - 1) {
+    for i in 0..stars.len() {
         for j in (i + 1)..stars.len() {
             let (mut x1, mut y1) = stars[i];
             let (mut x2, mut y2) = stars[j];
@@ -1,19 +1,26 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 use std::collections::HashSet;
 
-day!(Day14, 2023, 14, "Parabolic Reflector Dish");
+day!(
+    Day14,
+    2023,
+    14,
+    "Parabolic Reflector Dish",
+    difficulty = Medium,
+    tags = [Cycles, Grid, Simulation]
+);
 
 impl Solution for Day14 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut platform = Platform::new(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut platform = Platform::new(input)?;
         platform.tilt_up();
-        Ok(platform.load().to_string())
+        Ok(platform.load().into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut platform = Platform::new(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut platform = Platform::new(input)?;
         platform.spin_cycle();
-        Ok(platform.load().to_string())
+        Ok(platform.load().into())
     }
 }
 
@@ -30,23 +37,32 @@ struct Platform {
     rocks: Vec<Vec<RockType>>,
 }
 impl Platform {
-    fn new(input: &str) -> Self {
+    fn new(input: &str) -> Result<Self, Error> {
         let rocks = input
             .trim()
             .lines()
             .map(|line| {
-                line.chars()
-                    .map(|c| match c {
-                        'O' => RockType::Round,
-                        '#' => RockType::Cube,
-                        '.' => RockType::Empty,
-                        _ => panic!("Invalid rock type"),
+                line.char_indices()
+                    .map(|(i, c)| match c {
+                        'O' => Ok(RockType::Round),
+                        '#' => Ok(RockType::Cube),
+                        '.' => Ok(RockType::Empty),
+                        _ => Err(Error::invalid_input(input, &line[i..], "expected a rock")),
                     })
-                    .collect()
+                    .collect::<Result<Vec<_>, _>>()
             })
-            .collect();
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Self { rocks }
+        match rocks.first() {
+            Some(row) if !row.is_empty() && rocks.iter().all(|other| other.len() == row.len()) => {
+                Ok(Self { rocks })
+            }
+            _ => Err(Error::invalid_input(
+                input,
+                input.trim(),
+                "expected a rectangular platform",
+            )),
+        }
     }
 
     fn tilt_up(&mut self) {
@@ -136,8 +152,8 @@ impl Platform {
         let mut load = 0;
 
         for (i, row) in self.rocks.iter().enumerate() {
-            load += (row.iter().filter(|&&rock| rock == RockType::Round).count() * (row.len() - i))
-                as u64;
+            load += (row.iter().filter(|&&rock| rock == RockType::Round).count()
+                * (self.rocks.len() - i)) as u64;
         }
 
         load
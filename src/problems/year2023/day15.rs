@@ -1,61 +1,86 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_limited};
+use crate::{Answer, Error, Solution};
 
-day!(Day15, 2023, 15, "Lens Library");
+day!(
+    Day15,
+    2023,
+    15,
+    "Lens Library",
+    difficulty = Easy,
+    tags = [Hashing]
+);
+
+/// The largest focal length of a lens, as the lenses range from 1 through 9.
+const MAX_FOCAL_LENGTH: u32 = 9;
 
 impl Solution for Day15 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         Ok(input
             .trim()
             .split(',')
-            .map(calculate_hash)
-            .sum::<u32>()
-            .to_string())
+            .map(|step| calculate_hash(input, step))
+            .sum::<Result<u32, _>>()?
+            .into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut hashmap = vec![vec![]; 256];
 
         for op in input.trim().split(',') {
-            update_hashmap(&mut hashmap, op);
+            update_hashmap(input, &mut hashmap, op)?;
         }
 
-        let mut focusing_power = 0;
+        let mut focusing_power: u64 = 0;
 
         for (i, box_i) in hashmap.into_iter().enumerate() {
             for (j, lenses) in box_i.into_iter().enumerate() {
-                focusing_power += (i as u32 + 1) * (j as u32 + 1) * lenses.1;
+                focusing_power += (i as u64 + 1) * (j as u64 + 1) * u64::from(lenses.1);
             }
         }
 
-        Ok(focusing_power.to_string())
+        Ok(focusing_power.into())
     }
 }
 
-fn calculate_hash(input: &str) -> u32 {
+fn calculate_hash(input: &str, step: &str) -> Result<u32, Error> {
     let mut current_value = 0;
 
-    for c in input.trim().chars() {
-        assert!(c.is_ascii(), "Input must be ascii");
+    let step = step.trim();
+    for (i, c) in step.char_indices() {
+        if !c.is_ascii() {
+            return Err(Error::invalid_input(
+                input,
+                &step[i..],
+                "expected an ASCII character",
+            ));
+        }
         current_value += c as u32;
         current_value *= 17;
         current_value %= 256;
     }
 
-    current_value
+    Ok(current_value)
 }
 
-fn update_hashmap<'a>(hashmap: &mut [Vec<(&'a str, u32)>], operation: &'a str) {
+fn update_hashmap<'a>(
+    input: &str,
+    hashmap: &mut [Vec<(&'a str, u32)>],
+    operation: &'a str,
+) -> Result<(), Error> {
     if operation.contains('-') {
         let label = operation.trim_end_matches('-');
-        let curr_box = &mut hashmap[calculate_hash(label) as usize];
+        let curr_box = &mut hashmap[calculate_hash(input, label)? as usize];
 
         if let Some(ind) = curr_box.iter().position(|(l, _)| l == &label) {
             curr_box.remove(ind);
         }
     } else {
-        let (label, val) = operation.split_once('=').unwrap();
-        let val = val.parse::<u32>().unwrap();
-        let curr_box = &mut hashmap[calculate_hash(label) as usize];
+        let (label, val) =
+            operation
+                .split_once('=')
+                .or_invalid(input, operation, "expected an operation")?;
+        let val = parse_limited(input, val, 1..=MAX_FOCAL_LENGTH)?;
+        let curr_box = &mut hashmap[calculate_hash(input, label)? as usize];
 
         if let Some(ind) = curr_box.iter().position(|(l, _)| l == &label) {
             curr_box[ind].1 = val;
@@ -63,4 +88,5 @@ fn update_hashmap<'a>(hashmap: &mut [Vec<(&'a str, u32)>], operation: &'a str) {
             curr_box.push((label, val));
         }
     }
+    Ok(())
 }
@@ -1,71 +1,108 @@
+use crate::input::OrInvalid;
 use crate::math::{boundary_points, interior_points};
-use crate::{Error, Solution};
-use itertools::Itertools;
+use crate::{Answer, Error, Solution};
 
-day!(Day18, 2023, 18, "Lavaduct Lagoon");
+day!(
+    Day18,
+    2023,
+    18,
+    "Lavaduct Lagoon",
+    difficulty = Medium,
+    tags = [Geometry]
+);
 
 impl Solution for Day18 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let points = parse_input1(input);
-        Ok(calculate_volume(&points).to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let points = parse_input1(input)?;
+        Ok(calculate_volume(&points)?.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let points = parse_input2(input);
-        Ok(calculate_volume(&points).to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let points = parse_input2(input)?;
+        Ok(calculate_volume(&points)?.into())
     }
 }
 
-fn calculate_volume(points: &[(i64, i64)]) -> i64 {
+fn calculate_volume(points: &[(i64, i64)]) -> Result<i64, Error> {
+    // the plan has to describe a closed loop
+    if points.len() < 2 || points[0] == points[points.len() - 1] {
+        return Err(Error::NoSolution);
+    }
+
     let boundary_points = boundary_points(points);
-    boundary_points + interior_points(points, boundary_points)
+    Ok(boundary_points + interior_points(points, boundary_points))
+}
+
+/// Parse the length of a trench, which has to be positive.
+fn parse_length(input: &str, val: &str, radix: u32) -> Result<i64, Error> {
+    match i64::from_str_radix(val, radix) {
+        Ok(val) if val > 0 => Ok(val),
+        _ => Err(Error::invalid_input(
+            input,
+            val,
+            "expected a positive length",
+        )),
+    }
 }
 
-fn parse_input1(input: &str) -> Vec<(i64, i64)> {
+fn parse_input1(input: &str) -> Result<Vec<(i64, i64)>, Error> {
     let mut points = Vec::new();
 
     let mut curr_point = (0, 0);
     for line in input.trim().lines() {
-        let (ins, val, _) = line.split_whitespace().collect_tuple().unwrap();
-        let val = val.parse::<i64>().unwrap();
+        let [ins, val, _] = line.split_whitespace().collect::<Vec<_>>()[..] else {
+            return Err(Error::invalid_input(
+                input,
+                line,
+                "expected a dig instruction",
+            ));
+        };
+        let val = parse_length(input, val, 10)?;
 
         curr_point = match ins {
             "R" => (curr_point.0 + val, curr_point.1),
             "L" => (curr_point.0 - val, curr_point.1),
             "U" => (curr_point.0, curr_point.1 + val),
             "D" => (curr_point.0, curr_point.1 - val),
-            _ => panic!("Invalid instruction"),
+            _ => return Err(Error::invalid_input(input, ins, "expected a direction")),
         };
 
         points.push(curr_point);
     }
 
-    points
+    Ok(points)
 }
 
-fn parse_input2(input: &str) -> Vec<(i64, i64)> {
+fn parse_input2(input: &str) -> Result<Vec<(i64, i64)>, Error> {
     let mut points = Vec::new();
 
     let mut curr_point = (0, 0);
     for line in input.trim().lines() {
-        let (_, _, hex_color) = line.split_whitespace().collect_tuple().unwrap();
+        let [_, _, hex_color] = line.split_whitespace().collect::<Vec<_>>()[..] else {
+            return Err(Error::invalid_input(
+                input,
+                line,
+                "expected a dig instruction",
+            ));
+        };
         let hex_color = hex_color.trim_start_matches("(#").trim_end_matches(')');
 
-        let (_, ins, val) = hex_color.rsplitn(3, "").collect_tuple().unwrap();
-
-        let ins = ins.chars().next().unwrap();
-        let val = i64::from_str_radix(val, 16).unwrap();
+        let (val, ins) =
+            hex_color
+                .split_at_checked(5)
+                .or_invalid(input, hex_color, "expected a color")?;
+        let val = parse_length(input, val, 16)?;
 
         curr_point = match ins {
-            '0' => (curr_point.0 + val, curr_point.1),
-            '2' => (curr_point.0 - val, curr_point.1),
-            '3' => (curr_point.0, curr_point.1 + val),
-            '1' => (curr_point.0, curr_point.1 - val),
-            _ => panic!("Invalid instruction"),
+            "0" => (curr_point.0 + val, curr_point.1),
+            "2" => (curr_point.0 - val, curr_point.1),
+            "3" => (curr_point.0, curr_point.1 + val),
+            "1" => (curr_point.0, curr_point.1 - val),
+            _ => return Err(Error::invalid_input(input, ins, "expected a direction")),
         };
 
         points.push(curr_point);
     }
 
-    points
+    Ok(points)
 }
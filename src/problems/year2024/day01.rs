@@ -1,40 +1,62 @@
-use crate::{Error, Solution};
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
 use std::collections::HashMap;
 
-day!(Day01, 2024, 1, "Historian Hysteria");
+day!(
+    Day01,
+    2024,
+    1,
+    "Historian Hysteria",
+    difficulty = Easy,
+    tags = []
+);
 
 impl Solution for Day01 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let mut list1 = Vec::new();
         let mut list2 = Vec::new();
-        input.lines().for_each(|line| {
-            let mut line_split = line.split_whitespace();
-            list1.push(line_split.next().unwrap().parse::<u32>().unwrap());
-            list2.push(line_split.next().unwrap().parse::<u32>().unwrap());
-        });
+        for (num1, num2) in parse_input(input)? {
+            list1.push(num1);
+            list2.push(num2);
+        }
         list1.sort();
         list2.sort();
         Ok(list1
             .into_iter()
             .zip(list2)
-            .map(|(a, b)| a.abs_diff(b))
-            .sum::<u32>()
-            .to_string())
+            .map(|(a, b)| u64::from(a.abs_diff(b)))
+            .sum::<u64>()
+            .into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut list1 = Vec::new();
         let mut map2 = HashMap::new();
-        input.lines().for_each(|line| {
-            let mut line_split = line.split_whitespace();
-            list1.push(line_split.next().unwrap().parse::<u32>().unwrap());
-            let num2 = line_split.next().unwrap().parse::<u32>().unwrap();
-            *map2.entry(num2).or_insert(0) += 1;
-        });
+        for (num1, num2) in parse_input(input)? {
+            list1.push(num1);
+            *map2.entry(num2).or_insert(0_u64) += 1;
+        }
         Ok(list1
             .into_iter()
-            .map(|n| n * map2.get(&n).unwrap_or(&0))
-            .sum::<u32>()
-            .to_string())
+            .map(|n| u64::from(n) * map2.get(&n).unwrap_or(&0))
+            .sum::<u64>()
+            .into())
     }
 }
+
+fn parse_input(input: &str) -> Result<Vec<(u32, u32)>, Error> {
+    input
+        .trim()
+        .lines()
+        .map(
+            |line| match line.split_whitespace().collect::<Vec<_>>()[..] {
+                [num1, num2] => Ok((parse_number(input, num1)?, parse_number(input, num2)?)),
+                _ => Err(Error::invalid_input(
+                    input,
+                    line,
+                    "expected a pair of locations",
+                )),
+            },
+        )
+        .collect()
+}
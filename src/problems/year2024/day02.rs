@@ -1,26 +1,28 @@
-use crate::{Error, Solution};
+use crate::input::parse_number;
+use crate::{Answer, Error, Solution};
 use std::ops::RangeInclusive;
 
-day!(Day02, 2024, 2, "Red-Nosed Reports");
+day!(
+    Day02,
+    2024,
+    2,
+    "Red-Nosed Reports",
+    difficulty = Easy,
+    tags = []
+);
 
 impl Solution for Day02 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(input
-            .lines()
-            .filter(|line| is_safe(line.split_whitespace().map(|x| x.parse::<u32>().unwrap())))
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        Ok(parse_input(input)?
+            .into_iter()
+            .filter(|report| is_safe(report.iter().copied()))
             .count()
-            .to_string())
+            .into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mut safe_count = 0;
-        let mut line_elements = Vec::new();
-        for line in input.lines() {
-            line_elements.clear();
-            line.split_whitespace()
-                .map(|x| x.parse::<u32>().unwrap())
-                .for_each(|n| line_elements.push(n));
-
+        for mut line_elements in parse_input(input)? {
             for i in 0..line_elements.len() {
                 let removed_element = line_elements.remove(i);
                 if is_safe(line_elements.iter().copied()) {
@@ -30,10 +32,21 @@ impl Solution for Day02 {
                 line_elements.insert(i, removed_element);
             }
         }
-        Ok(safe_count.to_string())
+        Ok(safe_count.into())
     }
 }
 
+fn parse_input(input: &str) -> Result<Vec<Vec<u32>>, Error> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|x| parse_number(input, x))
+                .collect()
+        })
+        .collect()
+}
+
 const DIFFERENCE_BOUNDS: RangeInclusive<u32> = 1..=3;
 
 fn is_safe<T: IntoIterator<Item = u32>>(readings: T) -> bool {
@@ -1,15 +1,22 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 use regex::Regex;
 use std::sync::LazyLock;
 
-day!(Day03, 2024, 3, "Mull It Over");
+day!(
+    Day03,
+    2024,
+    3,
+    "Mull It Over",
+    difficulty = Easy,
+    tags = [Parsing, Strings]
+);
 
 impl Solution for Day03 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(execute_muls(input).to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        Ok(execute_muls(input).into())
     }
 
-    fn part2(&self, mut input: &str) -> Result<String, Error> {
+    fn part2(&self, mut input: &str) -> Result<Answer, Error> {
         let mut total_result = 0;
 
         let do_re = Regex::new(r"do\(\)").unwrap(); // do()
@@ -33,7 +40,7 @@ impl Solution for Day03 {
             };
         }
 
-        Ok(total_result.to_string())
+        Ok(total_result.into())
     }
 }
 
@@ -1,12 +1,19 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
-day!(Day04, 2024, 4, "Ceres Search");
+day!(
+    Day04,
+    2024,
+    4,
+    "Ceres Search",
+    difficulty = Easy,
+    tags = [Grid, Strings]
+);
 
 impl Solution for Day04 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
         let xmas_word = ['X', 'M', 'A', 'S'];
 
-        let text = parse_input(input);
+        let text = parse_input(input)?;
         let mut xmas_count = 0;
 
         for (i, row) in text.iter().enumerate() {
@@ -72,13 +79,13 @@ impl Solution for Day04 {
             }
         }
 
-        Ok(xmas_count.to_string())
+        Ok(xmas_count.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
         let mas_word = [['M', '.', 'M'], ['.', 'A', '.'], ['S', '.', 'S']];
 
-        let text = parse_input(input);
+        let text = parse_input(input)?;
         let mut mas_count = 0;
 
         for (i, row) in text.iter().enumerate() {
@@ -126,7 +133,7 @@ impl Solution for Day04 {
             }
         }
 
-        Ok(mas_count.to_string())
+        Ok(mas_count.into())
     }
 }
 
@@ -144,6 +151,18 @@ where
     check_fn(0, 2) && check_fn(1, 1) && check_fn(2, 0) && check_fn(2, 2)
 }
 
-fn parse_input(input: &str) -> Vec<Vec<char>> {
-    input.lines().map(|line| line.chars().collect()).collect()
+fn parse_input(input: &str) -> Result<Vec<Vec<char>>, Error> {
+    let mut text: Vec<Vec<char>> = Vec::new();
+    for line in input.lines() {
+        let row = line.chars().collect::<Vec<_>>();
+        if text.first().is_some_and(|first| first.len() != row.len()) {
+            return Err(Error::invalid_input(
+                input,
+                line,
+                "expected a rectangular word search",
+            ));
+        }
+        text.push(row);
+    }
+    Ok(text)
 }
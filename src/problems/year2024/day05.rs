@@ -1,11 +1,19 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 use std::collections::HashSet;
 
-day!(Day05, 2024, 5, "Print Queue");
+day!(
+    Day05,
+    2024,
+    5,
+    "Print Queue",
+    difficulty = Medium,
+    tags = [Graph]
+);
 
 impl Solution for Day05 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let (rules_set, updates) = parse_input(input);
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let (rules_set, updates) = parse_input(input)?;
         let mut middle_page_sum = 0;
 
         for update in updates.iter() {
@@ -14,11 +22,11 @@ impl Solution for Day05 {
             }
         }
 
-        Ok(middle_page_sum.to_string())
+        Ok(middle_page_sum.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let (rules_set, mut updates) = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let (rules_set, mut updates) = parse_input(input)?;
         let mut middle_page_sum = 0;
 
         for update in updates.iter_mut() {
@@ -38,24 +46,32 @@ impl Solution for Day05 {
             }
         }
 
-        Ok(middle_page_sum.to_string())
+        Ok(middle_page_sum.into())
     }
 }
 
-fn parse_input(input: &str) -> (HashSet<(u16, u16)>, Vec<Vec<u16>>) {
+type Rules = HashSet<(u16, u16)>;
+
+fn parse_input(input: &str) -> Result<(Rules, Vec<Vec<u16>>), Error> {
     let mut rules_set = HashSet::new();
     let mut updates = Vec::new();
 
     for line in input.lines() {
         if line.contains('|') {
-            let mut parts = line.split('|').map(|n| n.parse::<u16>().unwrap());
-            rules_set.insert((parts.next().unwrap(), parts.next().unwrap()));
+            let (before, after) =
+                line.split_once('|')
+                    .or_invalid(input, line, "expected a rule")?;
+            rules_set.insert((parse_number(input, before)?, parse_number(input, after)?));
         } else if line.contains(',') {
-            updates.push(line.split(',').map(|n| n.parse::<u16>().unwrap()).collect());
+            updates.push(
+                line.split(',')
+                    .map(|n| parse_number(input, n))
+                    .collect::<Result<_, _>>()?,
+            );
         }
     }
 
-    (rules_set, updates)
+    Ok((rules_set, updates))
 }
 
 fn update_correctly_ordered(rules: &HashSet<(u16, u16)>, update: &[u16]) -> bool {
@@ -1,32 +1,34 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, end};
+use crate::{Answer, Error, Solution};
 use std::collections::HashSet;
 
-day!(Day06, 2024, 6, "Guard Gallivant");
+day!(
+    Day06,
+    2024,
+    6,
+    "Guard Gallivant",
+    difficulty = Medium,
+    tags = [Grid, Simulation]
+);
 
 impl Solution for Day06 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let (mut location, mut grid) = parse_input(input);
-
-        while let Some(new_location) = simulate_move(&mut grid, location) {
-            location = new_location;
-        }
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let (location, mut grid) = parse_input(input)?;
+        patrol(&mut grid, location)?;
 
         Ok(grid
             .into_iter()
             .flat_map(|row| row.into_iter())
             .filter(|&c| c == 'X')
             .count()
-            .to_string())
+            .into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let (start_location, mut grid) = parse_input(input);
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let (start_location, mut grid) = parse_input(input)?;
         let obstacle_grid = {
             let mut grid = grid.clone();
-            let mut location = start_location;
-            while let Some(new_location) = simulate_move(&mut grid, location) {
-                location = new_location;
-            }
+            patrol(&mut grid, start_location)?;
             grid[start_location.i][start_location.j] = '.'; // can't put an obstacle on the starting location
             grid
         };
@@ -60,53 +62,67 @@ impl Solution for Day06 {
             }
         }
 
-        Ok(distinct_obstacles_locations.to_string())
+        Ok(distinct_obstacles_locations.into())
     }
 }
 
-fn parse_input(input: &str) -> (Location, Vec<Vec<char>>) {
-    let mut grid = Vec::new();
-    let mut location = Location {
-        i: 0,
-        j: 0,
-        direction: Direction::Up,
-    };
+fn parse_input(input: &str) -> Result<(Location, Vec<Vec<char>>), Error> {
+    let mut grid: Vec<Vec<char>> = Vec::new();
+    let mut location = None;
 
     for (i, line) in input.lines().enumerate() {
         let mut row = Vec::new();
-        for (j, c) in line.chars().enumerate() {
-            match c {
-                '#' | '.' => row.push(c),
-                '^' => {
-                    location.i = i;
-                    location.j = j;
-                    row.push('X');
-                }
-                'v' => {
-                    location.i = i;
-                    location.j = j;
-                    location.direction = Direction::Down;
-                    row.push('X');
-                }
-                '<' => {
-                    location.i = i;
-                    location.j = j;
-                    location.direction = Direction::Left;
-                    row.push('X');
-                }
-                '>' => {
-                    location.i = i;
-                    location.j = j;
-                    location.direction = Direction::Right;
-                    row.push('X');
+        for (j, (pos, c)) in line.char_indices().enumerate() {
+            let direction = match c {
+                '#' | '.' => {
+                    row.push(c);
+                    continue;
                 }
-                _ => panic!("Invalid character in input"),
+                '^' => Direction::Up,
+                'v' => Direction::Down,
+                '<' => Direction::Left,
+                '>' => Direction::Right,
+                _ => return Err(Error::invalid_input(input, &line[pos..], "expected a tile")),
+            };
+            if location.is_some() {
+                return Err(Error::invalid_input(
+                    input,
+                    &line[pos..],
+                    "expected a single guard",
+                ));
             }
+            location = Some(Location { i, j, direction });
+            row.push('X');
+        }
+        if grid.first().is_some_and(|first| first.len() != row.len()) {
+            return Err(Error::invalid_input(
+                input,
+                line,
+                "expected a rectangular map",
+            ));
         }
         grid.push(row);
     }
 
-    (location, grid)
+    let location = location.or_invalid(input, end(input), "expected a guard")?;
+    Ok((location, grid))
+}
+
+/// Move the guard until they leave the map.
+/// # Returns
+/// * Nothing or the [Error].
+/// # Errors
+/// * [Error::NoSolution] - The guard is stuck in a loop.
+fn patrol(grid: &mut [Vec<char>], mut location: Location) -> Result<(), Error> {
+    let mut visited_locations = HashSet::new();
+    while let Some(new_location) = simulate_move(grid, location) {
+        visited_locations.insert(location);
+        if visited_locations.contains(&new_location) {
+            return Err(Error::NoSolution);
+        }
+        location = new_location;
+    }
+    Ok(())
 }
 
 fn simulate_move(grid: &mut [Vec<char>], mut location: Location) -> Option<Location> {
@@ -1,32 +1,49 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 
-day!(Day07, 2024, 7, "Bridge Repair");
+day!(
+    Day07,
+    2024,
+    7,
+    "Bridge Repair",
+    difficulty = Easy,
+    tags = [Combinatorics, Search]
+);
 
 impl Solution for Day07 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(solve(input, check_equation).to_string())
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        Ok(solve(input, check_equation)?.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        Ok(solve(input, check_equation2).to_string())
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        Ok(solve(input, check_equation2)?.into())
     }
 }
 
-fn solve<T: Fn(u64, u64, &[u64]) -> bool>(input: &str, check: T) -> u64 {
+fn solve<T: Fn(u64, u64, &[u64]) -> bool>(input: &str, check: T) -> Result<u64, Error> {
     let mut calibration_result = 0;
 
     let mut tmp_values = Vec::new();
     for line in input.lines() {
-        let (target, values) = line.split_once(":").unwrap();
-        let target = target.parse::<u64>().unwrap();
+        let (target, values) =
+            line.split_once(":")
+                .or_invalid(input, line, "expected an equation")?;
+        let target = parse_number::<u64>(input, target)?;
         tmp_values.clear();
-        tmp_values.extend(values.split_whitespace().map(|x| x.parse::<u64>().unwrap()));
-        if check(tmp_values[0], target, &tmp_values[1..]) {
-            calibration_result += target;
+        for x in values.split_whitespace() {
+            tmp_values.push(parse_number(input, x)?);
+        }
+        let [first, ref rest @ ..] = tmp_values[..] else {
+            return Err(Error::invalid_input(input, values, "expected a number"));
+        };
+        if check(first, target, rest) {
+            calibration_result = target
+                .checked_add(calibration_result)
+                .ok_or(Error::NoSolution)?;
         }
     }
 
-    calibration_result
+    Ok(calibration_result)
 }
 fn check_equation(current: u64, target: u64, values: &[u64]) -> bool {
     if values.is_empty() {
@@ -34,12 +51,13 @@ fn check_equation(current: u64, target: u64, values: &[u64]) -> bool {
     } else if current > target {
         false
     } else {
-        let next_plus = current + values[0];
-        let next_times = current * values[0];
+        // the overflowing results are larger than the target
+        let next_plus = current.checked_add(values[0]);
+        let next_times = current.checked_mul(values[0]);
         let next_values = &values[1..];
 
-        check_equation(next_times, target, next_values)
-            || check_equation(next_plus, target, next_values)
+        next_times.is_some_and(|next| check_equation(next, target, next_values))
+            || next_plus.is_some_and(|next| check_equation(next, target, next_values))
     }
 }
 fn check_equation2(current: u64, target: u64, values: &[u64]) -> bool {
@@ -48,13 +66,17 @@ fn check_equation2(current: u64, target: u64, values: &[u64]) -> bool {
     } else if current > target {
         false
     } else {
-        let next_concat = current * 10u64.pow(values[0].ilog10() + 1) + values[0];
-        let next_times = current * values[0];
-        let next_plus = current + values[0];
+        // the overflowing results are larger than the target
+        let next_concat = 10u64
+            .checked_pow(values[0].checked_ilog10().unwrap_or(0) + 1)
+            .and_then(|shift| current.checked_mul(shift))
+            .and_then(|shifted| shifted.checked_add(values[0]));
+        let next_times = current.checked_mul(values[0]);
+        let next_plus = current.checked_add(values[0]);
         let next_values = &values[1..];
 
-        check_equation2(next_concat, target, next_values)
-            || check_equation2(next_times, target, next_values)
-            || check_equation2(next_plus, target, next_values)
+        next_concat.is_some_and(|next| check_equation2(next, target, next_values))
+            || next_times.is_some_and(|next| check_equation2(next, target, next_values))
+            || next_plus.is_some_and(|next| check_equation2(next, target, next_values))
     }
 }
@@ -1,34 +1,48 @@
-use crate::{Error, Solution};
+use crate::input::OrInvalid;
+use crate::{Answer, Error, Solution};
 use std::cmp::Ordering;
 use std::iter;
 
-day!(Day09, 2024, 9, "Disk Fragmenter");
+day!(
+    Day09,
+    2024,
+    9,
+    "Disk Fragmenter",
+    difficulty = Medium,
+    tags = [Simulation]
+);
 
 impl Solution for Day09 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        Ok(compress1(input)
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        Ok(compress1(parse_input(input)?)
             .enumerate()
             .map(|(i, block)| i as u64 * block as u64)
             .sum::<u64>()
-            .to_string())
+            .into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        Ok(compress2(input)
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        Ok(compress2(parse_input(input)?)
             .enumerate()
             .map(|(i, block)| i as u64 * block.unwrap_or(0) as u64)
             .sum::<u64>()
-            .to_string())
+            .into())
     }
 }
 
-fn compress1(disc: &str) -> impl Iterator<Item = u32> + use<> {
-    let mut disc = disc
-        .trim()
-        .chars()
-        .map(|c| c.to_digit(10).unwrap() as u8)
-        .collect::<Vec<_>>();
-    if disc.len() % 2 == 0 {
+fn parse_input(input: &str) -> Result<Vec<u8>, Error> {
+    let disc = input.trim();
+    disc.char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .map(|size| size as u8)
+                .or_invalid(input, &disc[i..], "expected a digit")
+        })
+        .collect()
+}
+
+fn compress1(mut disc: Vec<u8>) -> impl Iterator<Item = u32> + use<> {
+    if disc.len().is_multiple_of(2) {
         disc.pop();
     }
     let mut i = 0;
@@ -60,13 +74,8 @@ fn compress1(disc: &str) -> impl Iterator<Item = u32> + use<> {
     })
 }
 
-fn compress2(disc: &str) -> impl Iterator<Item = Option<u32>> + use<> {
-    let mut disc = disc
-        .trim()
-        .chars()
-        .map(|c| c.to_digit(10).unwrap() as u8)
-        .collect::<Vec<_>>();
-    if disc.len() % 2 == 0 {
+fn compress2(mut disc: Vec<u8>) -> impl Iterator<Item = Option<u32>> + use<> {
+    if disc.len().is_multiple_of(2) {
         disc.pop();
     }
     let mut files = Vec::with_capacity(disc.len() / 2 + 1);
@@ -94,6 +103,10 @@ fn compress2(disc: &str) -> impl Iterator<Item = Option<u32>> + use<> {
     let mut fb_next = [0; 9];
     fn fb_next_update_index(index: usize, fb_next: &mut [usize; 9], free_space: &[(u32, u8)]) {
         let start = fb_next[index];
+        if start == usize::MAX {
+            // no free block of this size is left
+            return;
+        }
         fb_next[index] = usize::MAX;
         for (i, free_block) in free_space[start..].iter().enumerate() {
             if free_block.1 as usize > index {
@@ -1,22 +1,46 @@
-use crate::{Error, Solution};
+use crate::cancellation;
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Parameter, Parameters, Solution};
 use std::cmp::Ordering;
 use std::sync::LazyLock;
 
-day!(Day14, 2024, 14, "Restroom Redoubt");
+day!(
+    Day14,
+    2024,
+    14,
+    "Restroom Redoubt",
+    difficulty = Medium,
+    tags = [Grid, Simulation]
+);
+
+/// The largest accepted width and height of the space.
+/// The second part draws the robots on a grid of the space for every second until they repeat,
+/// so this keeps the grid at a million cells.
+const MAX_SIZE: i32 = 1_000;
 
 impl Solution for Day14 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let robots = input.lines().map(Robot::from_string).collect::<Vec<_>>();
-        let mut q1 = 0;
-        let mut q2 = 0;
-        let mut q3 = 0;
-        let mut q4 = 0;
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::integer("width", "The width of the space", 101),
+            Parameter::integer("height", "The height of the space", 103),
+            Parameter::integer("seconds", "The number of seconds in the first part", 100),
+        ]
+    }
+
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let space = Space::new(parameters)?;
+        let robots = parse_input(input, &space)?;
+        let seconds = parameters.integer("seconds")?;
+        let mut q1: u64 = 0;
+        let mut q2: u64 = 0;
+        let mut q3: u64 = 0;
+        let mut q4: u64 = 0;
 
         for mut robot in robots {
-            robot.simulate(100);
+            robot.simulate(seconds, &space);
 
-            match robot.x.cmp(&((GRID_WIDTH / 2) as i32)) {
-                Ordering::Less => match robot.y.cmp(&((GRID_HEIGHT / 2) as i32)) {
+            match robot.x.cmp(&(space.width / 2)) {
+                Ordering::Less => match robot.y.cmp(&(space.height / 2)) {
                     Ordering::Less => {
                         q2 += 1;
                     }
@@ -28,7 +52,7 @@ impl Solution for Day14 {
 
                 Ordering::Equal => {} // ignore middle
 
-                Ordering::Greater => match robot.y.cmp(&((GRID_HEIGHT / 2) as i32)) {
+                Ordering::Greater => match robot.y.cmp(&(space.height / 2)) {
                     Ordering::Less => {
                         q1 += 1;
                     }
@@ -40,18 +64,21 @@ impl Solution for Day14 {
             }
         }
 
-        Ok((q1 * q2 * q3 * q4).to_string())
+        Ok((q1 * q2 * q3 * q4).into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut robots = input.lines().map(Robot::from_string).collect::<Vec<_>>();
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let space = Space::new(parameters)?;
+        let mut robots = parse_input(input, &space)?;
 
-        let mut grid = vec![[false; GRID_WIDTH]; GRID_HEIGHT];
+        let mut grid = vec![vec![false; space.width as usize]; space.height as usize];
         for robot in &robots {
             grid[robot.y as usize][robot.x as usize] = true;
         }
 
-        for i in 0.. {
+        // the robots are back in their starting positions after this many seconds
+        for i in 0..i64::from(space.width) * i64::from(space.height) {
+            cancellation::check()?;
             // To speed up finding a Christmas tree, we need to find a grid where more than 50%
             // of the cells have neighbors. When we find such a grid, only then we check if it contains
             // a Christmas tree
@@ -74,22 +101,33 @@ impl Solution for Day14 {
             }
             let neighbor_percentage = neighbors as f32 / (neighbors + solo) as f32;
             if neighbor_percentage > 0.5 && contains_christmas_tree(&grid) {
-                return Ok(i.to_string());
+                return Ok(i.into());
             }
 
-            grid.fill([false; GRID_WIDTH]);
+            grid.iter_mut().for_each(|row| row.fill(false));
             for robot in robots.iter_mut() {
-                robot.simulate(1);
+                robot.simulate(1, &space);
                 grid[robot.y as usize][robot.x as usize] = true;
             }
         }
 
-        unreachable!()
+        Err(Error::NoSolution)
     }
 }
 
-const GRID_WIDTH: usize = 101;
-const GRID_HEIGHT: usize = 103;
+/// The space in which the robots move, wrapping around its edges.
+struct Space {
+    width: i32,
+    height: i32,
+}
+impl Space {
+    fn new(parameters: &Parameters) -> Result<Self, Error> {
+        match (parameters.integer("width")?, parameters.integer("height")?) {
+            (width @ 1..=MAX_SIZE, height @ 1..=MAX_SIZE) => Ok(Self { width, height }),
+            _ => Err(Error::InvalidParameter),
+        }
+    }
+}
 
 struct Robot {
     x: i32,
@@ -98,28 +136,50 @@ struct Robot {
     vy: i32,
 }
 impl Robot {
-    fn from_string(input: &str) -> Self {
-        let (pos, vel) = input.trim().split_once(' ').unwrap();
+    fn from_string(input: &str, line: &str, space: &Space) -> Result<Self, Error> {
+        let (pos, vel) = line
+            .trim()
+            .split_once(' ')
+            .or_invalid(input, line, "expected a robot")?;
         let pos = pos.trim_start_matches("p=");
         let vel = vel.trim_start_matches("v=");
-        let (x, y) = pos.split_once(',').unwrap();
-        let (vx, vy) = vel.split_once(',').unwrap();
-        Self {
-            x: x.parse().unwrap(),
-            y: y.parse().unwrap(),
-            vx: vx.parse().unwrap(),
-            vy: vy.parse().unwrap(),
+        let (x, y) = pos
+            .split_once(',')
+            .or_invalid(input, pos, "expected a position")?;
+        let (vx, vy) = vel
+            .split_once(',')
+            .or_invalid(input, vel, "expected a velocity")?;
+        let robot = Self {
+            x: parse_number(input, x)?,
+            y: parse_number(input, y)?,
+            vx: parse_number(input, vx)?,
+            vy: parse_number(input, vy)?,
+        };
+        if !(0..space.width).contains(&robot.x) || !(0..space.height).contains(&robot.y) {
+            return Err(Error::invalid_input(
+                input,
+                pos,
+                "expected a position in the grid",
+            ));
         }
+        Ok(robot)
     }
-    fn simulate(&mut self, move_count: i32) {
-        let dx = self.vx * move_count;
-        let dy = self.vy * move_count;
-        self.x = (self.x + dx).rem_euclid(GRID_WIDTH as i32);
-        self.y = (self.y + dy).rem_euclid(GRID_HEIGHT as i32);
+    fn simulate(&mut self, move_count: i32, space: &Space) {
+        let dx = i64::from(self.vx) * i64::from(move_count);
+        let dy = i64::from(self.vy) * i64::from(move_count);
+        self.x = (i64::from(self.x) + dx).rem_euclid(i64::from(space.width)) as i32;
+        self.y = (i64::from(self.y) + dy).rem_euclid(i64::from(space.height)) as i32;
     }
 }
 
-fn contains_christmas_tree(grid: &[[bool; GRID_WIDTH]]) -> bool {
+fn parse_input(input: &str, space: &Space) -> Result<Vec<Robot>, Error> {
+    input
+        .lines()
+        .map(|line| Robot::from_string(input, line, space))
+        .collect()
+}
+
+fn contains_christmas_tree(grid: &[Vec<bool>]) -> bool {
     static CHRISTMAS_TREE: LazyLock<Vec<Vec<bool>>> = LazyLock::new(|| {
         let tree_str = "
         ###############################
@@ -167,8 +227,8 @@ fn contains_christmas_tree(grid: &[[bool; GRID_WIDTH]]) -> bool {
         tree
     });
 
-    for x in 0..GRID_WIDTH - CHRISTMAS_TREE[0].len() {
-        for y in 0..GRID_HEIGHT - CHRISTMAS_TREE.len() {
+    for x in 0..grid[0].len().saturating_sub(CHRISTMAS_TREE[0].len()) {
+        for y in 0..grid.len().saturating_sub(CHRISTMAS_TREE.len()) {
             let mut found = true;
             'outer: for (i, row) in CHRISTMAS_TREE.iter().enumerate() {
                 for (j, &cell) in row.iter().enumerate() {
@@ -1,42 +1,68 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Parameter, Parameters, Solution, progress};
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 
-day!(Day18, 2024, 18, "RAM Run");
+day!(
+    Day18,
+    2024,
+    18,
+    "RAM Run",
+    difficulty = Easy,
+    tags = [Grid, Search]
+);
+
+/// The largest accepted width and height of the memory space.
+/// The memory space and the distances searched in it are stored as grids,
+/// so this keeps them at a million locations.
+const MAX_SIZE: usize = 1_000;
 
 impl Solution for Day18 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut grid = vec![[true; 71]; 71];
-        for line in input.lines().take(1024) {
-            let (n1, n2) = line.split_once(',').unwrap();
-            let n1 = n1.parse::<usize>().unwrap(); // distance from left
-            let n2 = n2.parse::<usize>().unwrap(); // distance from top
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::integer("size", "The width and height of the memory space", 71),
+            Parameter::integer(
+                "bytes",
+                "The number of bytes fallen in the first part",
+                1024,
+            ),
+        ]
+    }
+
+    fn part1_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let (size, bytes) = parse_parameters(parameters)?;
+        let mut grid = vec![vec![true; size]; size];
+        for line in input.lines().take(bytes) {
+            let (n1, n2) = parse_byte(input, line, size)?;
             grid[n2][n1] = false;
         }
         let start = (0, 0);
-        let target = (70, 70);
+        let target = (size - 1, size - 1);
 
-        Ok(dijkstra(&grid, start, target).unwrap().to_string())
+        dijkstra(&grid, start, target)
+            .map(Answer::from)
+            .ok_or(Error::NoSolution)
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut grid = vec![[true; 71]; 71];
+    fn part2_with(&self, input: &str, parameters: &Parameters) -> Result<Answer, Error> {
+        let (size, bytes) = parse_parameters(parameters)?;
+        let mut grid = vec![vec![true; size]; size];
+        let total = input.lines().count().saturating_sub(bytes);
         let mut lines = input.lines();
-        for _ in 0..1024 {
+        for _ in 0..bytes {
             if let Some(line) = lines.next() {
-                let (n1, n2) = line.split_once(',').unwrap();
-                let n1 = n1.parse::<usize>().unwrap(); // distance from left
-                let n2 = n2.parse::<usize>().unwrap(); // distance from top
+                let (n1, n2) = parse_byte(input, line, size)?;
                 grid[n2][n1] = false;
             }
         }
-        for line in lines {
-            let (n1, n2) = line.split_once(',').unwrap();
-            let n1 = n1.parse::<usize>().unwrap(); // distance from left
-            let n2 = n2.parse::<usize>().unwrap(); // distance from top
+        for (i, line) in lines.enumerate() {
+            if i % 32 == 0 {
+                progress::report("dropping bytes", i as f64 / total as f64);
+            }
+            let (n1, n2) = parse_byte(input, line, size)?;
             grid[n2][n1] = false;
-            if dijkstra(&grid, (0, 0), (70, 70)).is_none() {
-                return Ok(format!("{},{}", n1, n2));
+            if dijkstra(&grid, (0, 0), (size - 1, size - 1)).is_none() {
+                return Ok(format!("{},{}", n1, n2).into());
             }
         }
 
@@ -44,11 +70,31 @@ impl Solution for Day18 {
     }
 }
 
-fn dijkstra<const N: usize>(
-    grid: &[[bool; N]],
-    start: (usize, usize),
-    target: (usize, usize),
-) -> Option<usize> {
+/// Get the size of the memory space and the number of bytes fallen in the first part.
+fn parse_parameters(parameters: &Parameters) -> Result<(usize, usize), Error> {
+    match parameters.integer("size")? {
+        size @ 1..=MAX_SIZE => Ok((size, parameters.integer("bytes")?)),
+        _ => Err(Error::InvalidParameter),
+    }
+}
+
+/// Parse the coordinates of a falling byte as the distances from the left and from the top.
+fn parse_byte(input: &str, line: &str, size: usize) -> Result<(usize, usize), Error> {
+    let (n1, n2) = line
+        .split_once(',')
+        .or_invalid(input, line, "expected a position")?;
+    let coordinate = |fragment| match parse_number::<usize>(input, fragment)? {
+        n if n < size => Ok(n),
+        _ => Err(Error::invalid_input(
+            input,
+            fragment,
+            "expected a coordinate in the memory space",
+        )),
+    };
+    Ok((coordinate(n1)?, coordinate(n2)?))
+}
+
+fn dijkstra(grid: &[Vec<bool>], start: (usize, usize), target: (usize, usize)) -> Option<usize> {
     #[derive(Eq, PartialEq)]
     struct Node {
         coords: (usize, usize),
@@ -65,7 +111,7 @@ fn dijkstra<const N: usize>(
         }
     }
 
-    let mut weights = vec![[usize::MAX; N]; grid.len()];
+    let mut weights = vec![vec![usize::MAX; grid[0].len()]; grid.len()];
     weights[start.0][start.1] = 0;
     let mut min_heap = BinaryHeap::new();
     min_heap.push(Node {
@@ -102,7 +148,7 @@ fn dijkstra<const N: usize>(
                 weight: node.weight + 1,
             });
         }
-        if j < N - 1 && grid[i][j + 1] && node.weight + 1 < weights[i][j + 1] {
+        if j < grid[i].len() - 1 && grid[i][j + 1] && node.weight + 1 < weights[i][j + 1] {
             weights[i][j + 1] = node.weight + 1;
             min_heap.push(Node {
                 coords: (i, j + 1),
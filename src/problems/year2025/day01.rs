@@ -1,32 +1,37 @@
-use crate::{Error, Solution};
+use crate::input::{OrInvalid, parse_number};
+use crate::{Answer, Error, Solution};
 
-day!(Day01, 2025, 1, "Secret Entrance");
+day!(
+    Day01,
+    2025,
+    1,
+    "Secret Entrance",
+    difficulty = Easy,
+    tags = [NumberTheory, Simulation]
+);
 
 impl Solution for Day01 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let mut count = 0;
-        let mut value = 50;
-        for rotation in input.lines() {
-            let direction = &rotation[0..1];
-            let amount: i32 = rotation[1..].parse().unwrap();
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let mut count: u64 = 0;
+        // the dial stays below 100, so a rotation of at most `u32::MAX` can't overflow it
+        let mut value: i64 = 50;
+        for (direction, amount) in parse_input(input)? {
             match direction {
                 "L" => value = (value - amount).rem_euclid(100),
                 "R" => value = (value + amount).rem_euclid(100),
-                _ => panic!("Invalid direction"),
+                _ => unreachable!(),
             }
             if value == 0 {
                 count += 1;
             }
         }
-        Ok(count.to_string())
+        Ok(count.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut count = 0;
-        let mut value = 50;
-        for rotation in input.lines() {
-            let direction = &rotation[0..1];
-            let amount: i32 = rotation[1..].parse().unwrap();
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut count: i64 = 0;
+        let mut value: i64 = 50;
+        for (direction, amount) in parse_input(input)? {
             match direction {
                 "L" => {
                     let mut new_value = value - amount;
@@ -46,9 +51,29 @@ impl Solution for Day01 {
                     count += value / 100;
                     value %= 100;
                 },
-                _ => panic!("Invalid direction"),
+                _ => unreachable!(),
             }
         }
-        Ok(count.to_string())
+        Ok(count.into())
     }
 }
+
+fn parse_input(input: &str) -> Result<Vec<(&str, i64)>, Error> {
+    input
+        .lines()
+        .map(|rotation| {
+            let (direction, amount) =
+                rotation
+                    .split_at_checked(1)
+                    .or_invalid(input, rotation, "expected a rotation")?;
+            if direction != "L" && direction != "R" {
+                return Err(Error::invalid_input(
+                    input,
+                    direction,
+                    "expected a direction",
+                ));
+            }
+            Ok((direction, i64::from(parse_number::<u32>(input, amount)?)))
+        })
+        .collect()
+}
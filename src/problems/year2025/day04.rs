@@ -1,10 +1,17 @@
-use crate::{Error, Solution};
+use crate::{Answer, Error, Solution};
 
-day!(Day04, 2025, 4, "Printing Department");
+day!(
+    Day04,
+    2025,
+    4,
+    "Printing Department",
+    difficulty = Easy,
+    tags = [Grid, Simulation]
+);
 
 impl Solution for Day04 {
-    fn part1(&self, input: &str) -> Result<String, Error> {
-        let grid = input.lines().map(|line| line.chars().map(|c| c == '@').collect::<Vec<_>>()).collect::<Vec<_>>();
+    fn part1(&self, input: &str) -> Result<Answer, Error> {
+        let grid = parse_input(input)?;
         let mut count = 0;
         for r in 0..grid.len() {
             for c in 0..grid[r].len() {
@@ -30,11 +37,11 @@ impl Solution for Day04 {
                 }
             }
         }
-        Ok(count.to_string())
+        Ok(count.into())
     }
 
-    fn part2(&self, input: &str) -> Result<String, Error> {
-        let mut grid = input.lines().map(|line| line.chars().map(|c| c == '@').collect::<Vec<_>>()).collect::<Vec<_>>();
+    fn part2(&self, input: &str) -> Result<Answer, Error> {
+        let mut grid = parse_input(input)?;
         let mut removed = 0;
         let mut changed = true;
         while changed {
@@ -66,6 +73,29 @@ impl Solution for Day04 {
                 }
             }
         }
-        Ok(removed.to_string())
+        Ok(removed.into())
     }
 }
+
+fn parse_input(input: &str) -> Result<Vec<Vec<bool>>, Error> {
+    let mut grid: Vec<Vec<bool>> = Vec::new();
+    for line in input.lines() {
+        let row = line
+            .char_indices()
+            .map(|(i, c)| match c {
+                '@' => Ok(true),
+                '.' => Ok(false),
+                _ => Err(Error::invalid_input(input, &line[i..], "expected a tile")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if grid.first().is_some_and(|first| first.len() != row.len()) {
+            return Err(Error::invalid_input(
+                input,
+                line,
+                "expected a rectangular grid",
+            ));
+        }
+        grid.push(row);
+    }
+    Ok(grid)
+}
@@ -0,0 +1,53 @@
+//! Progress reporting of long-running solutions.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+thread_local! {
+    /// The reporter of the challenge being solved on this thread.
+    static CURRENT: RefCell<Option<Arc<dyn Progress>>> = const { RefCell::new(None) };
+}
+
+/// A trait receiving the progress of solving a challenge, e.g. to show a progress bar.
+///
+/// Long-running solutions report the name of their current phase
+/// and the fraction of the phase which is done.
+/// Closures taking the phase and the fraction implement this trait.
+pub trait Progress: Send + Sync {
+    /// Receive the progress of solving a challenge.
+    /// # Arguments
+    /// * `phase` - The name of the current phase, e.g. `"checksum"`.
+    /// * `fraction` - The fraction of the phase which is done, between `0.0` and `1.0`.
+    fn report(&self, phase: &str, fraction: f64);
+}
+impl<F: Fn(&str, f64) + Send + Sync> Progress for F {
+    fn report(&self, phase: &str, fraction: f64) {
+        self(phase, fraction)
+    }
+}
+
+/// Run the function with the progress reported by [report] on this thread sent to the reporter.
+pub(crate) fn run_with<T>(progress: Arc<dyn Progress>, function: impl FnOnce() -> T) -> T {
+    /// Restores the previous reporter, even if the function panics.
+    struct Restore(Option<Arc<dyn Progress>>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT.set(self.0.take());
+        }
+    }
+
+    let _restore = Restore(CURRENT.replace(Some(progress)));
+    function()
+}
+
+/// Report the progress of the challenge being solved on this thread.
+/// Long-running solutions call this at most a few hundred times per phase.
+/// # Arguments
+/// * `phase` - The name of the current phase.
+/// * `fraction` - The fraction of the phase which is done, clamped between `0.0` and `1.0`.
+pub(crate) fn report(phase: &str, fraction: f64) {
+    // the reporter is cloned out, so that it can solve challenges itself
+    if let Some(progress) = CURRENT.with_borrow(Option::clone) {
+        progress.report(phase, fraction.clamp(0.0, 1.0));
+    }
+}
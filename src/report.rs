@@ -0,0 +1,241 @@
+//! Solving every available challenge from a directory of inputs.
+
+use crate::{AdventOfCode, Answer, CatalogYear, Error, measure};
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, ErrorKind};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+use std::{fs, slice, thread};
+
+/// A report of solving every challenge with an input in a directory.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Report {
+    /// The solved parts, sorted by year, day and part in ascending order.
+    pub entries: Vec<ReportEntry>,
+    /// The wall-clock time taken to solve all parts.
+    pub total_time: Duration,
+}
+impl Report {
+    /// Solve every available part of the challenges with an input in the directory on a thread pool.
+    /// The inputs are read from `yearYYYY/dayDD.txt` files and days without an input file are skipped.
+    /// Solutions which poll for cancellation stop after the time limit with [Error::Timeout],
+    /// the others run to completion. A part which panics is reported with [Error::Panicked].
+    /// # Arguments
+    /// * `advent_of_code` - The [AdventOfCode] solving the challenges.
+    /// * `inputs` - The directory with the inputs.
+    /// * `timeout` - The time after which the solutions polling for cancellation stop.
+    /// # Returns
+    /// * The new [Report] or the [io::Error].
+    /// # Errors
+    /// * [io::Error] - An input file exists but cannot be read.
+    pub fn solve<T: AdventOfCode + ?Sized>(
+        advent_of_code: &T,
+        inputs: &Path,
+        timeout: Duration,
+    ) -> io::Result<Self> {
+        Self::solve_years(
+            advent_of_code,
+            &advent_of_code.catalog().years,
+            inputs,
+            timeout,
+        )
+    }
+
+    /// Solve every available part of the challenges of a year with an input in the directory
+    /// on a thread pool.
+    /// The inputs are read from `yearYYYY/dayDD.txt` files and days without an input file are skipped.
+    /// Solutions which poll for cancellation stop after the time limit with [Error::Timeout],
+    /// the others run to completion. A part which panics is reported with [Error::Panicked].
+    /// # Arguments
+    /// * `advent_of_code` - The [AdventOfCode] solving the challenges.
+    /// * `year` - The identifier of the year of the challenges to solve.
+    /// * `inputs` - The directory with the inputs.
+    /// * `timeout` - The time after which the solutions polling for cancellation stop.
+    /// # Returns
+    /// * The new [Report], which is empty for an unavailable year, or the [io::Error].
+    /// # Errors
+    /// * [io::Error] - An input file exists but cannot be read.
+    pub fn solve_year<T: AdventOfCode + ?Sized>(
+        advent_of_code: &T,
+        year: usize,
+        inputs: &Path,
+        timeout: Duration,
+    ) -> io::Result<Self> {
+        let catalog = advent_of_code.catalog();
+        let years = catalog.year(year).map(slice::from_ref).unwrap_or_default();
+        Self::solve_years(advent_of_code, years, inputs, timeout)
+    }
+
+    /// Solve every part of the challenges of the years with an input in the directory.
+    fn solve_years<T: AdventOfCode + ?Sized>(
+        advent_of_code: &T,
+        years: &[CatalogYear],
+        inputs: &Path,
+        timeout: Duration,
+    ) -> io::Result<Self> {
+        let days = read_inputs(years, inputs)?;
+        let puzzles = puzzles(years, &days);
+
+        // the workers take the next unsolved part until all are solved
+        let next = AtomicUsize::new(0);
+        let solved = Mutex::new(Vec::with_capacity(puzzles.len()));
+        let work = || {
+            while let Some(&(year, day, part, input)) =
+                puzzles.get(next.fetch_add(1, Ordering::Relaxed))
+            {
+                let (result, time) = measure(|| {
+                    panic::catch_unwind(AssertUnwindSafe(|| {
+                        advent_of_code.solve_with_deadline(year, day, part, input, timeout)
+                    }))
+                    .unwrap_or(Err(Error::Panicked))
+                });
+                solved
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push(ReportEntry {
+                        year,
+                        day,
+                        part,
+                        result,
+                        time,
+                    });
+            }
+        };
+        let workers = thread::available_parallelism()
+            .map_or(1, usize::from)
+            .min(puzzles.len());
+        let ((), total_time) = measure(|| {
+            if workers > 1 {
+                thread::scope(|scope| {
+                    for _ in 0..workers {
+                        scope.spawn(work);
+                    }
+                });
+            } else {
+                work();
+            }
+        });
+
+        let mut entries = solved.into_inner().unwrap_or_else(PoisonError::into_inner);
+        entries.sort_by_key(|entry| (entry.year, entry.day, entry.part));
+        Ok(Self {
+            entries,
+            total_time,
+        })
+    }
+
+    /// Get the entry of a part of a day's challenge.
+    /// # Arguments
+    /// * `year` - The identifier of the year of the challenge.
+    /// * `day` - The identifier of the day's challenge.
+    /// * `part` - The part of the challenge.
+    /// # Returns
+    /// * The entry of the part, if it was solved.
+    pub fn entry(&self, year: usize, day: usize, part: usize) -> Option<&ReportEntry> {
+        self.entries
+            .iter()
+            .find(|entry| (entry.year, entry.day, entry.part) == (year, day, part))
+    }
+
+    /// Get the entries of the parts which failed to be solved.
+    /// # Returns
+    /// * An iterator over the entries with an [Error].
+    pub fn failures(&self) -> impl Iterator<Item = &ReportEntry> {
+        self.entries.iter().filter(|entry| entry.result.is_err())
+    }
+
+    /// Get the time taken to solve all parts one after another.
+    /// # Returns
+    /// * The sum of the times of all entries.
+    pub fn solve_time(&self) -> Duration {
+        self.entries.iter().map(|entry| entry.time).sum()
+    }
+}
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            writeln!(f, "{}", entry)?;
+        }
+        write!(
+            f,
+            "{} parts, {} failed --- {} s (solve {} s)",
+            self.entries.len(),
+            self.failures().count(),
+            self.total_time.as_secs_f64(),
+            self.solve_time().as_secs_f64()
+        )
+    }
+}
+
+/// Read the inputs of the days' challenges of the years from `yearYYYY/dayDD.txt` files.
+/// # Returns
+/// * The identifiers of the years and days with an input file and the inputs, or the [io::Error].
+pub(crate) fn read_inputs(
+    years: &[CatalogYear],
+    inputs: &Path,
+) -> io::Result<Vec<(usize, usize, String)>> {
+    let mut days = Vec::new();
+    for year in years {
+        for day in &year.days {
+            let path = inputs
+                .join(format!("year{:04}", year.id))
+                .join(format!("day{:02}.txt", day.id));
+            match fs::read_to_string(path) {
+                Ok(input) => days.push((year.id, day.id, input.replace("\r\n", "\n"))),
+                Err(err) if err.kind() == ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
+    }
+    Ok(days)
+}
+
+/// Get every part of the years' challenges with an input read by [read_inputs].
+/// # Returns
+/// * The identifiers of the years, days and parts with the inputs, sorted in ascending order.
+pub(crate) fn puzzles<'a>(
+    years: &[CatalogYear],
+    days: &'a [(usize, usize, String)],
+) -> Vec<(usize, usize, usize, &'a str)> {
+    years
+        .iter()
+        .flat_map(|year| {
+            year.days
+                .iter()
+                .flat_map(move |day| day.parts.iter().map(move |&part| (year.id, day.id, part)))
+        })
+        .filter_map(|(year, day, part)| {
+            days.iter()
+                .find(|&&(input_year, input_day, _)| (input_year, input_day) == (year, day))
+                .map(|(_, _, input)| (year, day, part, input.as_str()))
+        })
+        .collect()
+}
+
+/// The result of solving a part of a day's challenge.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ReportEntry {
+    /// The identifier of the year of the challenge.
+    pub year: usize,
+    /// The identifier of the day's challenge.
+    pub day: usize,
+    /// The part of the challenge.
+    pub part: usize,
+    /// The solution to the part of the challenge or the [Error].
+    pub result: Result<Answer, Error>,
+    /// The time taken to parse the input and solve the part.
+    pub time: Duration,
+}
+impl Display for ReportEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {:>2} part {}: ", self.year, self.day, self.part)?;
+        match &self.result {
+            Ok(answer) => write!(f, "{}", answer)?,
+            Err(err) => write!(f, "{}", err)?,
+        }
+        write!(f, "    --- {} s", self.time.as_secs_f64())
+    }
+}
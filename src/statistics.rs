@@ -0,0 +1,245 @@
+//! Repeated benchmarking with summary statistics of the measured times.
+
+use crate::{Answer, Error, Solution, measure, now};
+use std::fmt::{self, Display, Formatter};
+use std::time::Duration;
+
+/// An enum representing when a benchmark stops measuring runs.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Runs {
+    /// Measure exactly this many runs (at least one).
+    Count(usize),
+    /// Measure runs until the budget has passed since the first measured run (at least one run).
+    Budget(Duration),
+}
+
+/// A structure representing the options of a repeated benchmark.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BenchmarkOptions {
+    /// The number of runs before measuring, which are not included in the statistics.
+    pub warmup: usize,
+    /// When to stop measuring runs.
+    pub runs: Runs,
+}
+impl Default for BenchmarkOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            runs: Runs::Budget(Duration::from_secs(1)),
+        }
+    }
+}
+impl BenchmarkOptions {
+    /// Create new [BenchmarkOptions] measuring a fixed number of runs after the default warm-up.
+    /// # Arguments
+    /// * `iterations` - The number of measured runs.
+    /// # Returns
+    /// * The new [BenchmarkOptions].
+    pub fn iterations(iterations: usize) -> Self {
+        Self {
+            runs: Runs::Count(iterations),
+            ..Self::default()
+        }
+    }
+
+    /// Create new [BenchmarkOptions] measuring runs for a time budget after the default warm-up.
+    /// # Arguments
+    /// * `budget` - The time after which no new run is started.
+    /// # Returns
+    /// * The new [BenchmarkOptions].
+    pub fn time_budget(budget: Duration) -> Self {
+        Self {
+            runs: Runs::Budget(budget),
+            ..Self::default()
+        }
+    }
+
+    /// Set the number of warm-up runs.
+    /// # Arguments
+    /// * `warmup` - The number of runs before measuring.
+    /// # Returns
+    /// * The [BenchmarkOptions] with the warm-up set.
+    pub fn with_warmup(mut self, warmup: usize) -> Self {
+        self.warmup = warmup;
+        self
+    }
+}
+
+/// A structure representing the summary statistics of measured times.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Statistics {
+    samples: Vec<Duration>,
+}
+impl Statistics {
+    /// Create new [Statistics] of measured times.
+    /// # Arguments
+    /// * `samples` - The measured times, which must not be empty.
+    /// # Returns
+    /// * The new [Statistics].
+    /// # Panics
+    /// * The samples are empty.
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "statistics need at least one sample");
+        samples.sort_unstable();
+        Self { samples }
+    }
+
+    /// Get the measured times.
+    /// # Returns
+    /// * The measured times, sorted in ascending order.
+    pub fn samples(&self) -> &[Duration] {
+        &self.samples
+    }
+
+    /// Get the shortest measured time.
+    /// # Returns
+    /// * The minimum.
+    pub fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    /// Get the longest measured time.
+    /// # Returns
+    /// * The maximum.
+    pub fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+
+    /// Get the arithmetic mean of the measured times.
+    /// # Returns
+    /// * The mean.
+    pub fn mean(&self) -> Duration {
+        let total = self.samples.iter().sum::<Duration>().as_nanos();
+        Duration::from_nanos((total / self.samples.len() as u128) as u64)
+    }
+
+    /// Get the median of the measured times, the mean of the middle two for an even count.
+    /// # Returns
+    /// * The median.
+    pub fn median(&self) -> Duration {
+        let middle = self.samples.len() / 2;
+        if self.samples.len().is_multiple_of(2) {
+            (self.samples[middle - 1] + self.samples[middle]) / 2
+        } else {
+            self.samples[middle]
+        }
+    }
+
+    /// Get the sample standard deviation of the measured times.
+    /// # Returns
+    /// * The standard deviation, zero for a single sample.
+    pub fn std_dev(&self) -> Duration {
+        if self.samples.len() < 2 {
+            return Duration::ZERO;
+        }
+        let mean = self.mean_secs();
+        let variance = self
+            .samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / (self.samples.len() - 1) as f64;
+        Duration::from_secs_f64(variance.sqrt())
+    }
+
+    /// Get a percentile of the measured times using the nearest-rank method.
+    /// # Arguments
+    /// * `percent` - The percentile, clamped between `0.0` and `100.0`.
+    /// # Returns
+    /// * The smallest measured time which is at least as long as the given percent of the times.
+    pub fn percentile(&self, percent: f64) -> Duration {
+        let rank = (percent.clamp(0.0, 100.0) / 100.0 * self.samples.len() as f64).ceil() as usize;
+        self.samples[rank.saturating_sub(1)]
+    }
+
+    /// Get the mean in seconds.
+    fn mean_secs(&self) -> f64 {
+        self.samples.iter().map(Duration::as_secs_f64).sum::<f64>() / self.samples.len() as f64
+    }
+}
+impl Display for Statistics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} s ± {} s (min {} s, median {} s, p95 {} s, max {} s, {} runs)",
+            self.mean().as_secs_f64(),
+            self.std_dev().as_secs_f64(),
+            self.min().as_secs_f64(),
+            self.median().as_secs_f64(),
+            self.percentile(95.0).as_secs_f64(),
+            self.max().as_secs_f64(),
+            self.samples.len()
+        )
+    }
+}
+
+/// A structure representing the solution to a part of a day's challenge
+/// with the statistics of the times of repeated runs.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BenchmarkStatistics {
+    /// The solution to the part of the challenge.
+    pub answer: Answer,
+    /// The times taken to parse the input.
+    pub parse: Statistics,
+    /// The times taken to solve the part from the parsed input.
+    pub solve: Statistics,
+    /// The times taken to parse the input and solve the part.
+    pub total: Statistics,
+}
+impl BenchmarkStatistics {
+    /// Measure repeated runs of parsing the input and solving a part of a day's challenge.
+    /// # Arguments
+    /// * `solution` - The [Solution] of the day's challenge.
+    /// * `part` - The part of the challenge to solve.
+    /// * `input` - The input to the challenge.
+    /// * `options` - The [BenchmarkOptions] of the runs.
+    /// # Returns
+    /// * The new [BenchmarkStatistics] or the [Error].
+    /// # Errors
+    /// * [Error::UnavailablePart] - The requested part is unavailable.
+    /// * [Error::NoSolution] - There is no solution for the challenge with the given input.
+    /// * [Error::InvalidInput] - The input to the challenge is malformed.
+    pub fn measure<T: Solution + ?Sized>(
+        solution: &T,
+        part: usize,
+        input: &str,
+        options: &BenchmarkOptions,
+    ) -> Result<Self, Error> {
+        for _ in 0..options.warmup {
+            solution.solve_parsed(part, &solution.parse(input)?)?;
+        }
+
+        let mut answer;
+        let mut parse_times = Vec::new();
+        let mut solve_times = Vec::new();
+        let start = now();
+        loop {
+            let (parsed, parse_time) = measure(|| solution.parse(input));
+            let parsed = parsed?;
+            let (solved, solve_time) = measure(|| solution.solve_parsed(part, &parsed));
+            answer = solved?;
+            parse_times.push(parse_time);
+            solve_times.push(solve_time);
+
+            let done = match options.runs {
+                Runs::Count(count) => parse_times.len() >= count,
+                Runs::Budget(budget) => now().saturating_sub(start) >= budget,
+            };
+            if done {
+                break;
+            }
+        }
+
+        let total_times = parse_times
+            .iter()
+            .zip(&solve_times)
+            .map(|(parse_time, solve_time)| *parse_time + *solve_time)
+            .collect();
+        Ok(Self {
+            answer,
+            parse: Statistics::new(parse_times),
+            solve: Statistics::new(solve_times),
+            total: Statistics::new(total_times),
+        })
+    }
+}
@@ -1,252 +0,0 @@
-mod runner;
-use runner::run_test;
-
-#[test]
-fn year2015_day01_part1() {
-    run_test(2015, 1, 1);
-}
-
-#[test]
-fn year2015_day01_part2() {
-    run_test(2015, 1, 2);
-}
-
-#[test]
-fn year2015_day02_part1() {
-    run_test(2015, 2, 1);
-}
-
-#[test]
-fn year2015_day02_part2() {
-    run_test(2015, 2, 2);
-}
-
-#[test]
-fn year2015_day03_part1() {
-    run_test(2015, 3, 1);
-}
-
-#[test]
-fn year2015_day03_part2() {
-    run_test(2015, 3, 2);
-}
-
-#[test]
-fn year2015_day04_part1() {
-    run_test(2015, 4, 1);
-}
-
-#[test]
-fn year2015_day04_part2() {
-    run_test(2015, 4, 2);
-}
-
-#[test]
-fn year2015_day05_part1() {
-    run_test(2015, 5, 1);
-}
-
-#[test]
-fn year2015_day05_part2() {
-    run_test(2015, 5, 2);
-}
-
-#[test]
-fn year2015_day06_part1() {
-    run_test(2015, 6, 1);
-}
-
-#[test]
-fn year2015_day06_part2() {
-    run_test(2015, 6, 2);
-}
-
-#[test]
-fn year2015_day07_part1() {
-    run_test(2015, 7, 1);
-}
-
-#[test]
-fn year2015_day07_part2() {
-    run_test(2015, 7, 2);
-}
-
-#[test]
-fn year2015_day08_part1() {
-    run_test(2015, 8, 1);
-}
-
-#[test]
-fn year2015_day08_part2() {
-    run_test(2015, 8, 2);
-}
-
-#[test]
-fn year2015_day09_part1() {
-    run_test(2015, 9, 1);
-}
-
-#[test]
-fn year2015_day09_part2() {
-    run_test(2015, 9, 2);
-}
-
-#[test]
-fn year2015_day10_part1() {
-    run_test(2015, 10, 1);
-}
-
-#[test]
-fn year2015_day10_part2() {
-    run_test(2015, 10, 2);
-}
-
-#[test]
-fn year2015_day11_part1() {
-    run_test(2015, 11, 1);
-}
-
-#[test]
-fn year2015_day11_part2() {
-    run_test(2015, 11, 2);
-}
-
-#[test]
-fn year2015_day12_part1() {
-    run_test(2015, 12, 1);
-}
-
-#[test]
-fn year2015_day12_part2() {
-    run_test(2015, 12, 2);
-}
-
-#[test]
-fn year2015_day13_part1() {
-    run_test(2015, 13, 1);
-}
-
-#[test]
-fn year2015_day13_part2() {
-    run_test(2015, 13, 2);
-}
-
-#[test]
-fn year2015_day14_part1() {
-    run_test(2015, 14, 1);
-}
-
-#[test]
-fn year2015_day14_part2() {
-    run_test(2015, 14, 2);
-}
-
-#[test]
-fn year2015_day15_part1() {
-    run_test(2015, 15, 1);
-}
-
-#[test]
-fn year2015_day15_part2() {
-    run_test(2015, 15, 2);
-}
-
-#[test]
-fn year2015_day16_part1() {
-    run_test(2015, 16, 1);
-}
-
-#[test]
-fn year2015_day16_part2() {
-    run_test(2015, 16, 2);
-}
-
-#[test]
-fn year2015_day17_part1() {
-    run_test(2015, 17, 1);
-}
-
-#[test]
-fn year2015_day17_part2() {
-    run_test(2015, 17, 2);
-}
-
-#[test]
-fn year2015_day18_part1() {
-    run_test(2015, 18, 1);
-}
-
-#[test]
-fn year2015_day18_part2() {
-    run_test(2015, 18, 2);
-}
-
-#[test]
-fn year2015_day19_part1() {
-    run_test(2015, 19, 1);
-}
-
-#[test]
-fn year2015_day19_part2() {
-    run_test(2015, 19, 2);
-}
-
-#[test]
-fn year2015_day20_part1() {
-    run_test(2015, 20, 1);
-}
-
-#[test]
-fn year2015_day20_part2() {
-    run_test(2015, 20, 2);
-}
-
-#[test]
-fn year2015_day21_part1() {
-    run_test(2015, 21, 1);
-}
-
-#[test]
-fn year2015_day21_part2() {
-    run_test(2015, 21, 2);
-}
-
-#[test]
-fn year2015_day22_part1() {
-    run_test(2015, 22, 1);
-}
-
-#[test]
-fn year2015_day22_part2() {
-    run_test(2015, 22, 2);
-}
-
-#[test]
-fn year2015_day23_part1() {
-    run_test(2015, 23, 1);
-}
-
-#[test]
-fn year2015_day23_part2() {
-    run_test(2015, 23, 2);
-}
-
-#[test]
-fn year2015_day24_part1() {
-    run_test(2015, 24, 1);
-}
-
-#[test]
-fn year2015_day24_part2() {
-    run_test(2015, 24, 2);
-}
-
-#[test]
-fn year2015_day25_part1() {
-    run_test(2015, 25, 1);
-}
-
-#[test]
-fn year2015_day25_part2() {
-    run_test(2015, 25, 2);
-}
@@ -1,252 +0,0 @@
-mod runner;
-use runner::run_test;
-
-#[test]
-fn year2016_day01_part1() {
-    run_test(2016, 1, 1);
-}
-
-#[test]
-fn year2016_day01_part2() {
-    run_test(2016, 1, 2);
-}
-
-#[test]
-fn year2016_day02_part1() {
-    run_test(2016, 2, 1);
-}
-
-#[test]
-fn year2016_day02_part2() {
-    run_test(2016, 2, 2);
-}
-
-#[test]
-fn year2016_day03_part1() {
-    run_test(2016, 3, 1);
-}
-
-#[test]
-fn year2016_day03_part2() {
-    run_test(2016, 3, 2);
-}
-
-#[test]
-fn year2016_day04_part1() {
-    run_test(2016, 4, 1);
-}
-
-#[test]
-fn year2016_day04_part2() {
-    run_test(2016, 4, 2);
-}
-
-#[test]
-fn year2016_day05_part1() {
-    run_test(2016, 5, 1);
-}
-
-#[test]
-fn year2016_day05_part2() {
-    run_test(2016, 5, 2);
-}
-
-#[test]
-fn year2016_day06_part1() {
-    run_test(2016, 6, 1);
-}
-
-#[test]
-fn year2016_day06_part2() {
-    run_test(2016, 6, 2);
-}
-
-#[test]
-fn year2016_day07_part1() {
-    run_test(2016, 7, 1);
-}
-
-#[test]
-fn year2016_day07_part2() {
-    run_test(2016, 7, 2);
-}
-
-#[test]
-fn year2016_day08_part1() {
-    run_test(2016, 8, 1);
-}
-
-#[test]
-fn year2016_day08_part2() {
-    run_test(2016, 8, 2);
-}
-
-#[test]
-fn year2016_day09_part1() {
-    run_test(2016, 9, 1);
-}
-
-#[test]
-fn year2016_day09_part2() {
-    run_test(2016, 9, 2);
-}
-
-#[test]
-fn year2016_day10_part1() {
-    run_test(2016, 10, 1);
-}
-
-#[test]
-fn year2016_day10_part2() {
-    run_test(2016, 10, 2);
-}
-
-#[test]
-fn year2016_day11_part1() {
-    run_test(2016, 11, 1);
-}
-
-#[test]
-fn year2016_day11_part2() {
-    run_test(2016, 11, 2);
-}
-
-#[test]
-fn year2016_day12_part1() {
-    run_test(2016, 12, 1);
-}
-
-#[test]
-fn year2016_day12_part2() {
-    run_test(2016, 12, 2);
-}
-
-#[test]
-fn year2016_day13_part1() {
-    run_test(2016, 13, 1);
-}
-
-#[test]
-fn year2016_day13_part2() {
-    run_test(2016, 13, 2);
-}
-
-#[test]
-fn year2016_day14_part1() {
-    run_test(2016, 14, 1);
-}
-
-#[test]
-fn year2016_day14_part2() {
-    run_test(2016, 14, 2);
-}
-
-#[test]
-fn year2016_day15_part1() {
-    run_test(2016, 15, 1);
-}
-
-#[test]
-fn year2016_day15_part2() {
-    run_test(2016, 15, 2);
-}
-
-#[test]
-fn year2016_day16_part1() {
-    run_test(2016, 16, 1);
-}
-
-#[test]
-fn year2016_day16_part2() {
-    run_test(2016, 16, 2);
-}
-
-#[test]
-fn year2016_day17_part1() {
-    run_test(2016, 17, 1);
-}
-
-#[test]
-fn year2016_day17_part2() {
-    run_test(2016, 17, 2);
-}
-
-#[test]
-fn year2016_day18_part1() {
-    run_test(2016, 18, 1);
-}
-
-#[test]
-fn year2016_day18_part2() {
-    run_test(2016, 18, 2);
-}
-
-#[test]
-fn year2016_day19_part1() {
-    run_test(2016, 19, 1);
-}
-
-#[test]
-fn year2016_day19_part2() {
-    run_test(2016, 19, 2);
-}
-
-#[test]
-fn year2016_day20_part1() {
-    run_test(2016, 20, 1);
-}
-
-#[test]
-fn year2016_day20_part2() {
-    run_test(2016, 20, 2);
-}
-
-#[test]
-fn year2016_day21_part1() {
-    run_test(2016, 21, 1);
-}
-
-#[test]
-fn year2016_day21_part2() {
-    run_test(2016, 21, 2);
-}
-
-#[test]
-fn year2016_day22_part1() {
-    run_test(2016, 22, 1);
-}
-
-#[test]
-fn year2016_day22_part2() {
-    run_test(2016, 22, 2);
-}
-
-#[test]
-fn year2016_day23_part1() {
-    run_test(2016, 23, 1);
-}
-
-#[test]
-fn year2016_day23_part2() {
-    run_test(2016, 23, 2);
-}
-
-#[test]
-fn year2016_day24_part1() {
-    run_test(2016, 24, 1);
-}
-
-#[test]
-fn year2016_day24_part2() {
-    run_test(2016, 24, 2);
-}
-
-#[test]
-fn year2016_day25_part1() {
-    run_test(2016, 25, 1);
-}
-
-#[test]
-fn year2016_day25_part2() {
-    run_test(2016, 25, 2);
-}
@@ -1,252 +0,0 @@
-mod runner;
-use runner::run_test;
-
-#[test]
-fn year2017_day01_part1() {
-    run_test(2017, 1, 1);
-}
-
-#[test]
-fn year2017_day01_part2() {
-    run_test(2017, 1, 2);
-}
-
-#[test]
-fn year2017_day02_part1() {
-    run_test(2017, 2, 1);
-}
-
-#[test]
-fn year2017_day02_part2() {
-    run_test(2017, 2, 2);
-}
-
-#[test]
-fn year2017_day03_part1() {
-    run_test(2017, 3, 1);
-}
-
-#[test]
-fn year2017_day03_part2() {
-    run_test(2017, 3, 2);
-}
-
-#[test]
-fn year2017_day04_part1() {
-    run_test(2017, 4, 1);
-}
-
-#[test]
-fn year2017_day04_part2() {
-    run_test(2017, 4, 2);
-}
-
-#[test]
-fn year2017_day05_part1() {
-    run_test(2017, 5, 1);
-}
-
-#[test]
-fn year2017_day05_part2() {
-    run_test(2017, 5, 2);
-}
-
-#[test]
-fn year2017_day06_part1() {
-    run_test(2017, 6, 1);
-}
-
-#[test]
-fn year2017_day06_part2() {
-    run_test(2017, 6, 2);
-}
-
-#[test]
-fn year2017_day07_part1() {
-    run_test(2017, 7, 1);
-}
-
-#[test]
-fn year2017_day07_part2() {
-    run_test(2017, 7, 2);
-}
-
-#[test]
-fn year2017_day08_part1() {
-    run_test(2017, 8, 1);
-}
-
-#[test]
-fn year2017_day08_part2() {
-    run_test(2017, 8, 2);
-}
-
-#[test]
-fn year2017_day09_part1() {
-    run_test(2017, 9, 1);
-}
-
-#[test]
-fn year2017_day09_part2() {
-    run_test(2017, 9, 2);
-}
-
-#[test]
-fn year2017_day10_part1() {
-    run_test(2017, 10, 1);
-}
-
-#[test]
-fn year2017_day10_part2() {
-    run_test(2017, 10, 2);
-}
-
-#[test]
-fn year2017_day11_part1() {
-    run_test(2017, 11, 1);
-}
-
-#[test]
-fn year2017_day11_part2() {
-    run_test(2017, 11, 2);
-}
-
-#[test]
-fn year2017_day12_part1() {
-    run_test(2017, 12, 1);
-}
-
-#[test]
-fn year2017_day12_part2() {
-    run_test(2017, 12, 2);
-}
-
-#[test]
-fn year2017_day13_part1() {
-    run_test(2017, 13, 1);
-}
-
-#[test]
-fn year2017_day13_part2() {
-    run_test(2017, 13, 2);
-}
-
-#[test]
-fn year2017_day14_part1() {
-    run_test(2017, 14, 1);
-}
-
-#[test]
-fn year2017_day14_part2() {
-    run_test(2017, 14, 2);
-}
-
-#[test]
-fn year2017_day15_part1() {
-    run_test(2017, 15, 1);
-}
-
-#[test]
-fn year2017_day15_part2() {
-    run_test(2017, 15, 2);
-}
-
-#[test]
-fn year2017_day16_part1() {
-    run_test(2017, 16, 1);
-}
-
-#[test]
-fn year2017_day16_part2() {
-    run_test(2017, 16, 2);
-}
-
-#[test]
-fn year2017_day17_part1() {
-    run_test(2017, 17, 1);
-}
-
-#[test]
-fn year2017_day17_part2() {
-    run_test(2017, 17, 2);
-}
-
-#[test]
-fn year2017_day18_part1() {
-    run_test(2017, 18, 1);
-}
-
-#[test]
-fn year2017_day18_part2() {
-    run_test(2017, 18, 2);
-}
-
-#[test]
-fn year2017_day19_part1() {
-    run_test(2017, 19, 1);
-}
-
-#[test]
-fn year2017_day19_part2() {
-    run_test(2017, 19, 2);
-}
-
-#[test]
-fn year2017_day20_part1() {
-    run_test(2017, 20, 1);
-}
-
-#[test]
-fn year2017_day20_part2() {
-    run_test(2017, 20, 2);
-}
-
-#[test]
-fn year2017_day21_part1() {
-    run_test(2017, 21, 1);
-}
-
-#[test]
-fn year2017_day21_part2() {
-    run_test(2017, 21, 2);
-}
-
-#[test]
-fn year2017_day22_part1() {
-    run_test(2017, 22, 1);
-}
-
-#[test]
-fn year2017_day22_part2() {
-    run_test(2017, 22, 2);
-}
-
-#[test]
-fn year2017_day23_part1() {
-    run_test(2017, 23, 1);
-}
-
-#[test]
-fn year2017_day23_part2() {
-    run_test(2017, 23, 2);
-}
-
-#[test]
-fn year2017_day24_part1() {
-    run_test(2017, 24, 1);
-}
-
-#[test]
-fn year2017_day24_part2() {
-    run_test(2017, 24, 2);
-}
-
-#[test]
-fn year2017_day25_part1() {
-    run_test(2017, 25, 1);
-}
-
-#[test]
-fn year2017_day25_part2() {
-    run_test(2017, 25, 2);
-}
@@ -1,72 +0,0 @@
-mod runner;
-use runner::run_test;
-
-#[test]
-fn year2018_day01_part1() {
-    run_test(2018, 1, 1);
-}
-
-#[test]
-fn year2018_day01_part2() {
-    run_test(2018, 1, 2);
-}
-
-#[test]
-fn year2018_day02_part1() {
-    run_test(2018, 2, 1);
-}
-
-#[test]
-fn year2018_day02_part2() {
-    run_test(2018, 2, 2);
-}
-
-#[test]
-fn year2018_day03_part1() {
-    run_test(2018, 3, 1);
-}
-
-#[test]
-fn year2018_day03_part2() {
-    run_test(2018, 3, 2);
-}
-
-#[test]
-fn year2018_day05_part1() {
-    run_test(2018, 5, 1);
-}
-
-#[test]
-fn year2018_day05_part2() {
-    run_test(2018, 5, 2);
-}
-
-#[test]
-fn year2018_day06_part1() {
-    run_test(2018, 6, 1);
-}
-
-#[test]
-fn year2018_day06_part2() {
-    run_test(2018, 6, 2);
-}
-
-#[test]
-fn year2018_day08_part1() {
-    run_test(2018, 8, 1);
-}
-
-#[test]
-fn year2018_day08_part2() {
-    run_test(2018, 8, 2);
-}
-
-#[test]
-fn year2018_day12_part1() {
-    run_test(2018, 12, 1);
-}
-
-#[test]
-fn year2018_day12_part2() {
-    run_test(2018, 12, 2);
-}
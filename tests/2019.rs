@@ -1,42 +0,0 @@
-mod runner;
-use runner::run_test;
-
-#[test]
-fn year2019_day01_part1() {
-    run_test(2019, 1, 1);
-}
-
-#[test]
-fn year2019_day01_part2() {
-    run_test(2019, 1, 2);
-}
-
-#[test]
-fn year2019_day02_part1() {
-    run_test(2019, 2, 1);
-}
-
-#[test]
-fn year2019_day02_part2() {
-    run_test(2019, 2, 2);
-}
-
-#[test]
-fn year2019_day03_part1() {
-    run_test(2019, 3, 1);
-}
-
-#[test]
-fn year2019_day03_part2() {
-    run_test(2019, 3, 2);
-}
-
-#[test]
-fn year2019_day04_part1() {
-    run_test(2019, 4, 1);
-}
-
-#[test]
-fn year2019_day04_part2() {
-    run_test(2019, 4, 2);
-}
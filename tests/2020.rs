@@ -1,92 +0,0 @@
-mod runner;
-use runner::run_test;
-
-#[test]
-fn year2020_day01_part1() {
-    run_test(2020, 1, 1);
-}
-
-#[test]
-fn year2020_day01_part2() {
-    run_test(2020, 1, 2);
-}
-
-#[test]
-fn year2020_day02_part1() {
-    run_test(2020, 2, 1);
-}
-
-#[test]
-fn year2020_day02_part2() {
-    run_test(2020, 2, 2);
-}
-
-#[test]
-fn year2020_day03_part1() {
-    run_test(2020, 3, 1);
-}
-
-#[test]
-fn year2020_day03_part2() {
-    run_test(2020, 3, 2);
-}
-
-#[test]
-fn year2020_day04_part1() {
-    run_test(2020, 4, 1);
-}
-
-#[test]
-fn year2020_day04_part2() {
-    run_test(2020, 4, 2);
-}
-
-#[test]
-fn year2020_day05_part1() {
-    run_test(2020, 5, 1);
-}
-
-#[test]
-fn year2020_day05_part2() {
-    run_test(2020, 5, 2);
-}
-
-#[test]
-fn year2020_day06_part1() {
-    run_test(2020, 6, 1);
-}
-
-#[test]
-fn year2020_day06_part2() {
-    run_test(2020, 6, 2);
-}
-
-#[test]
-fn year2020_day08_part1() {
-    run_test(2020, 8, 1);
-}
-
-#[test]
-fn year2020_day08_part2() {
-    run_test(2020, 8, 2);
-}
-
-#[test]
-fn year2020_day09_part1() {
-    run_test(2020, 9, 1);
-}
-
-#[test]
-fn year2020_day09_part2() {
-    run_test(2020, 9, 2);
-}
-
-#[test]
-fn year2020_day17_part1() {
-    run_test(2020, 17, 1);
-}
-
-#[test]
-fn year2020_day17_part2() {
-    run_test(2020, 17, 2);
-}
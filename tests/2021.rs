@@ -1,82 +0,0 @@
-mod runner;
-use runner::run_test;
-
-#[test]
-fn year2021_day01_part1() {
-    run_test(2021, 1, 1);
-}
-
-#[test]
-fn year2021_day01_part2() {
-    run_test(2021, 1, 2);
-}
-
-#[test]
-fn year2021_day02_part1() {
-    run_test(2021, 2, 1);
-}
-
-#[test]
-fn year2021_day02_part2() {
-    run_test(2021, 2, 2);
-}
-
-#[test]
-fn year2021_day03_part1() {
-    run_test(2021, 3, 1);
-}
-
-#[test]
-fn year2021_day03_part2() {
-    run_test(2021, 3, 2);
-}
-
-#[test]
-fn year2021_day04_part1() {
-    run_test(2021, 4, 1);
-}
-
-#[test]
-fn year2021_day04_part2() {
-    run_test(2021, 4, 2);
-}
-
-#[test]
-fn year2021_day05_part1() {
-    run_test(2021, 5, 1);
-}
-
-#[test]
-fn year2021_day05_part2() {
-    run_test(2021, 5, 2);
-}
-
-#[test]
-fn year2021_day06_part1() {
-    run_test(2021, 6, 1);
-}
-
-#[test]
-fn year2021_day06_part2() {
-    run_test(2021, 6, 2);
-}
-
-#[test]
-fn year2021_day09_part1() {
-    run_test(2021, 9, 1);
-}
-
-#[test]
-fn year2021_day09_part2() {
-    run_test(2021, 9, 2);
-}
-
-#[test]
-fn year2021_day10_part1() {
-    run_test(2021, 10, 1);
-}
-
-#[test]
-fn year2021_day10_part2() {
-    run_test(2021, 10, 2);
-}
@@ -1,152 +0,0 @@
-mod runner;
-use runner::run_test;
-
-#[test]
-fn year2022_day01_part1() {
-    run_test(2022, 1, 1);
-}
-
-#[test]
-fn year2022_day01_part2() {
-    run_test(2022, 1, 2);
-}
-
-#[test]
-fn year2022_day02_part1() {
-    run_test(2022, 2, 1);
-}
-
-#[test]
-fn year2022_day02_part2() {
-    run_test(2022, 2, 2);
-}
-
-#[test]
-fn year2022_day03_part1() {
-    run_test(2022, 3, 1);
-}
-
-#[test]
-fn year2022_day03_part2() {
-    run_test(2022, 3, 2);
-}
-
-#[test]
-fn year2022_day04_part1() {
-    run_test(2022, 4, 1);
-}
-
-#[test]
-fn year2022_day04_part2() {
-    run_test(2022, 4, 2);
-}
-
-#[test]
-fn year2022_day05_part1() {
-    run_test(2022, 5, 1);
-}
-
-#[test]
-fn year2022_day05_part2() {
-    run_test(2022, 5, 2);
-}
-
-#[test]
-fn year2022_day06_part1() {
-    run_test(2022, 6, 1);
-}
-
-#[test]
-fn year2022_day06_part2() {
-    run_test(2022, 6, 2);
-}
-
-#[test]
-fn year2022_day07_part1() {
-    run_test(2022, 7, 1);
-}
-
-#[test]
-fn year2022_day07_part2() {
-    run_test(2022, 7, 2);
-}
-
-#[test]
-fn year2022_day08_part1() {
-    run_test(2022, 8, 1);
-}
-
-#[test]
-fn year2022_day08_part2() {
-    run_test(2022, 8, 2);
-}
-
-#[test]
-fn year2022_day09_part1() {
-    run_test(2022, 9, 1);
-}
-
-#[test]
-fn year2022_day09_part2() {
-    run_test(2022, 9, 2);
-}
-
-#[test]
-fn year2022_day10_part1() {
-    run_test(2022, 10, 1);
-}
-
-#[test]
-fn year2022_day10_part2() {
-    run_test(2022, 10, 2);
-}
-
-#[test]
-fn year2022_day11_part1() {
-    run_test(2022, 11, 1);
-}
-
-#[test]
-fn year2022_day11_part2() {
-    run_test(2022, 11, 2);
-}
-
-#[test]
-fn year2022_day12_part1() {
-    run_test(2022, 12, 1);
-}
-
-#[test]
-fn year2022_day12_part2() {
-    run_test(2022, 12, 2);
-}
-
-#[test]
-fn year2022_day14_part1() {
-    run_test(2022, 14, 1);
-}
-
-#[test]
-fn year2022_day14_part2() {
-    run_test(2022, 14, 2);
-}
-
-#[test]
-fn year2022_day18_part1() {
-    run_test(2022, 18, 1);
-}
-
-#[test]
-fn year2022_day18_part2() {
-    run_test(2022, 18, 2);
-}
-
-#[test]
-fn year2022_day19_part1() {
-    run_test(2022, 25, 1);
-}
-
-#[test]
-fn year2022_day19_part2() {
-    run_test(2022, 25, 2);
-}
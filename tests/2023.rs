@@ -1,142 +0,0 @@
-mod runner;
-use runner::run_test;
-
-#[test]
-fn year2023_day01_part1() {
-    run_test(2023, 1, 1);
-}
-
-#[test]
-fn year2023_day01_part2() {
-    run_test(2023, 1, 2);
-}
-
-#[test]
-fn year2023_day02_part1() {
-    run_test(2023, 2, 1);
-}
-
-#[test]
-fn year2023_day02_part2() {
-    run_test(2023, 2, 2);
-}
-
-#[test]
-fn year2023_day03_part1() {
-    run_test(2023, 3, 1);
-}
-
-#[test]
-fn year2023_day03_part2() {
-    run_test(2023, 3, 2);
-}
-
-#[test]
-fn year2023_day04_part1() {
-    run_test(2023, 4, 1);
-}
-
-#[test]
-fn year2023_day04_part2() {
-    run_test(2023, 4, 2);
-}
-
-#[test]
-fn year2023_day05_part1() {
-    run_test(2023, 5, 1);
-}
-
-#[test]
-fn year2023_day05_part2() {
-    run_test(2023, 5, 2);
-}
-
-#[test]
-fn year2023_day06_part1() {
-    run_test(2023, 6, 1);
-}
-
-#[test]
-fn year2023_day06_part2() {
-    run_test(2023, 6, 2);
-}
-
-#[test]
-fn year2023_day07_part1() {
-    run_test(2023, 7, 1);
-}
-
-#[test]
-fn year2023_day07_part2() {
-    run_test(2023, 7, 2);
-}
-
-#[test]
-fn year2023_day08_part1() {
-    run_test(2023, 8, 1);
-}
-
-#[test]
-fn year2023_day08_part2() {
-    run_test(2023, 8, 2);
-}
-
-#[test]
-fn year2023_day09_part1() {
-    run_test(2023, 9, 1);
-}
-
-#[test]
-fn year2023_day09_part2() {
-    run_test(2023, 9, 2);
-}
-
-#[test]
-fn year2023_day10_part1() {
-    run_test(2023, 10, 1);
-}
-
-#[test]
-fn year2023_day10_part2() {
-    run_test(2023, 10, 2);
-}
-
-#[test]
-fn year2023_day11_part1() {
-    run_test(2023, 11, 1);
-}
-
-#[test]
-fn year2023_day11_part2() {
-    run_test(2023, 11, 2);
-}
-
-#[test]
-fn year2023_day14_part1() {
-    run_test(2023, 14, 1);
-}
-
-#[test]
-fn year2023_day14_part2() {
-    run_test(2023, 14, 2);
-}
-
-#[test]
-fn year2023_day15_part1() {
-    run_test(2023, 15, 1);
-}
-
-#[test]
-fn year2023_day15_part2() {
-    run_test(2023, 15, 2);
-}
-
-#[test]
-fn year2023_day18_part1() {
-    run_test(2023, 18, 1);
-}
-
-#[test]
-fn year2023_day18_part2() {
-    run_test(2023, 18, 2);
-}
@@ -1,102 +0,0 @@
-mod runner;
-use runner::run_test;
-
-#[test]
-fn year2024_day01_part1() {
-    run_test(2024, 1, 1);
-}
-
-#[test]
-fn year2024_day01_part2() {
-    run_test(2024, 1, 2);
-}
-
-#[test]
-fn year2024_day02_part1() {
-    run_test(2024, 2, 1);
-}
-
-#[test]
-fn year2024_day02_part2() {
-    run_test(2024, 2, 2);
-}
-
-#[test]
-fn year2024_day03_part1() {
-    run_test(2024, 3, 1);
-}
-
-#[test]
-fn year2024_day03_part2() {
-    run_test(2024, 3, 2);
-}
-
-#[test]
-fn year2024_day04_part1() {
-    run_test(2024, 4, 1);
-}
-
-#[test]
-fn year2024_day04_part2() {
-    run_test(2024, 4, 2);
-}
-
-#[test]
-fn year2024_day05_part1() {
-    run_test(2024, 5, 1);
-}
-
-#[test]
-fn year2024_day05_part2() {
-    run_test(2024, 5, 2);
-}
-
-#[test]
-fn year2024_day06_part1() {
-    run_test(2024, 6, 1);
-}
-
-#[test]
-fn year2024_day06_part2() {
-    run_test(2024, 6, 2);
-}
-
-#[test]
-fn year2024_day07_part1() {
-    run_test(2024, 7, 1);
-}
-
-#[test]
-fn year2024_day07_part2() {
-    run_test(2024, 7, 2);
-}
-
-#[test]
-fn year2024_day09_part1() {
-    run_test(2024, 9, 1);
-}
-
-#[test]
-fn year2024_day09_part2() {
-    run_test(2024, 9, 2);
-}
-
-#[test]
-fn year2024_day14_part1() {
-    run_test(2024, 14, 1);
-}
-
-#[test]
-fn year2024_day14_part2() {
-    run_test(2024, 14, 2);
-}
-
-#[test]
-fn year2024_day18_part1() {
-    run_test(2024, 18, 1);
-}
-
-#[test]
-fn year2024_day18_part2() {
-    run_test(2024, 18, 2);
-}
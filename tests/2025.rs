@@ -1,22 +0,0 @@
-mod runner;
-use runner::run_test;
-
-#[test]
-fn year2025_day01_part1() {
-    run_test(2025, 1, 1);
-}
-
-#[test]
-fn year2025_day01_part2() {
-    run_test(2025, 1, 2);
-}
-
-#[test]
-fn year2025_day04_part1() {
-    run_test(2025, 4, 1);
-}
-
-#[test]
-fn year2025_day04_part2() {
-    run_test(2025, 4, 2);
-}
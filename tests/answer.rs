@@ -0,0 +1,80 @@
+use aocode::{AdventOfCode, Answer, AoC};
+
+#[test]
+fn integer_conversions() {
+    assert_eq!(Answer::from(42_u8), Answer::Integer(42));
+    assert_eq!(Answer::from(-7_i32), Answer::Integer(-7));
+    assert_eq!(
+        Answer::from(u64::MAX),
+        Answer::BigInteger(i128::from(u64::MAX))
+    );
+    assert_eq!(Answer::from(5_i128), Answer::Integer(5));
+    assert_eq!(
+        Answer::from(u64::MAX).as_integer(),
+        Some(i128::from(u64::MAX))
+    );
+    assert_eq!(Answer::from("abc").as_integer(), None);
+}
+
+#[test]
+fn display() {
+    assert_eq!(Answer::from(-12_i64).to_string(), "-12");
+    assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+    assert_eq!(Answer::from("hxbxxyzz").to_string(), "hxbxxyzz");
+    assert_eq!(
+        Answer::Grid(vec![String::from("#."), String::from(".#")]).to_string(),
+        "#.\n.#"
+    );
+}
+
+#[test]
+fn submitted_equality() {
+    assert_eq!(Answer::from(1234_usize), "1234");
+    assert_eq!(Answer::from(1234_usize), " 1234\n");
+    assert_ne!(Answer::from(1234_usize), "1235");
+    assert_ne!(Answer::from(1234_usize), "abc");
+    assert_eq!(Answer::from("EHZRJLGP"), String::from("EHZRJLGP\n"));
+    assert_ne!(Answer::from("EHZRJLGP"), "ehzrjlgp");
+    assert_eq!(
+        Answer::Grid(vec![String::from("#."), String::from(".#")]),
+        "#.\n.#\n"
+    );
+}
+
+#[test]
+fn parse_submitted() {
+    assert_eq!(" 42\n".parse(), Ok(Answer::Integer(42)));
+    assert_eq!(
+        "170141183460469231731687303715884105727".parse(),
+        Ok(Answer::BigInteger(i128::MAX))
+    );
+    assert_eq!("abc".parse(), Ok(Answer::from("abc")));
+    assert_eq!(
+        "#.\n.#".parse(),
+        Ok(Answer::Grid(vec![String::from("#."), String::from(".#")]))
+    );
+}
+
+#[test]
+fn answer_kinds() {
+    let advent_of_code = AoC::new();
+
+    let answer = advent_of_code.solve(2015, 1, 1, "(()(()(").unwrap();
+    assert_eq!(answer, Answer::Integer(3));
+
+    let answer = advent_of_code.solve(2015, 11, 1, "abcdefgh").unwrap();
+    assert_eq!(answer.as_text(), Some("abcdffaa"));
+
+    // a screen without recognizable letters is returned as it is displayed
+    let answer = advent_of_code.solve(2016, 8, 2, "rect 1x1\n").unwrap();
+    let rows = answer.as_grid().unwrap();
+    assert_eq!(rows.len(), 6);
+    assert_eq!(rows[0], format!("#{}", ".".repeat(49)));
+
+    // a quantum entanglement beyond 64 bits
+    let input = [-2, -1, 0, 0, 1, 2]
+        .map(|offset: i64| format!("{}\n", (1 << 40) + offset))
+        .concat();
+    let answer = advent_of_code.solve(2015, 24, 1, &input).unwrap();
+    assert_eq!(answer, Answer::BigInteger(1208925819614629174706172));
+}
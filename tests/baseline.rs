@@ -0,0 +1,129 @@
+use aocode::{
+    AdventOfCode, AoC, Baseline, BaselineEntry, BenchmarkOptions, Change, Error, ReportEntry,
+};
+use std::time::Duration;
+use std::{env, fs, process};
+
+/// Create a baseline entry with the same minimum, median and maximum.
+fn entry(year: usize, day: usize, part: usize, median_us: u64) -> BaselineEntry {
+    let median = Duration::from_micros(median_us);
+    BaselineEntry {
+        year,
+        day,
+        part,
+        runs: 1,
+        min: median,
+        median,
+        max: median,
+    }
+}
+
+#[test]
+fn benchmark_all() {
+    let dir = env::temp_dir().join(format!("aocode-baseline-{}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("year2015")).unwrap();
+    fs::write(dir.join("year2015/day01.txt"), "(()))\n").unwrap();
+    fs::write(dir.join("year2015/day02.txt"), "2x3\n").unwrap();
+
+    let options = BenchmarkOptions::iterations(3).with_warmup(0);
+    let baseline = AoC::new().benchmark_all(&dir, &options).unwrap();
+    let baseline_2016 = AoC::new()
+        .benchmark_all_in_year(2016, &dir, &options)
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    let puzzles = baseline
+        .entries
+        .iter()
+        .map(|entry| (entry.year, entry.day, entry.part, entry.runs))
+        .collect::<Vec<_>>();
+    assert_eq!(puzzles, [(2015, 1, 1, 3), (2015, 1, 2, 3)]);
+    for entry in &baseline.entries {
+        assert!(entry.min <= entry.median && entry.median <= entry.max);
+    }
+    let failures = baseline
+        .failures
+        .iter()
+        .map(|ReportEntry { part, result, .. }| (*part, result.as_ref().map_err(Error::kind)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        failures,
+        [(1, Err("invalid-input")), (2, Err("invalid-input"))]
+    );
+    assert_eq!(baseline_2016, Baseline::default());
+}
+
+#[test]
+fn json() {
+    let baseline = Baseline {
+        entries: vec![entry(2015, 1, 1, 1500), entry(2016, 25, 2, 3)],
+        failures: Vec::new(),
+    };
+    let path = env::temp_dir().join(format!("aocode-baseline-{}.json", process::id()));
+    baseline.save(&path).unwrap();
+    let loaded = Baseline::load(&path);
+    fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.unwrap(), baseline);
+
+    assert!(baseline.to_json().contains("\"median\": 0.0015,"));
+    assert!(Baseline::from_json("{}").is_err());
+    assert!(Baseline::from_json(r#"[{"year": 2015, "day": 1, "part": 1}]"#).is_err());
+    assert_eq!(Baseline::from_json("[]").unwrap(), Baseline::default());
+}
+
+#[test]
+fn compare() {
+    let baseline = Baseline {
+        entries: vec![
+            entry(2015, 1, 1, 1000),
+            entry(2015, 1, 2, 1000),
+            entry(2015, 2, 1, 1000),
+            entry(2015, 2, 2, 1),
+            entry(2015, 3, 1, 1000),
+        ],
+        failures: Vec::new(),
+    };
+    let current = Baseline {
+        entries: vec![
+            entry(2015, 1, 1, 1500),
+            entry(2015, 1, 2, 500),
+            entry(2015, 2, 1, 1050),
+            entry(2015, 2, 2, 5),
+            entry(2015, 4, 1, 1000),
+        ],
+        failures: Vec::new(),
+    };
+
+    let comparison = baseline.compare(&current, 0.1, Duration::from_micros(10));
+    let changes = comparison
+        .entries
+        .iter()
+        .map(|entry| (entry.day, entry.part, entry.change))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        changes,
+        [
+            (1, 1, Change::Regressed),
+            (1, 2, Change::Improved),
+            (2, 1, Change::Unchanged),
+            (2, 2, Change::Unchanged),
+            (3, 1, Change::Removed),
+            (4, 1, Change::Added)
+        ]
+    );
+    let regressions = comparison.regressions().collect::<Vec<_>>();
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0].ratio(), Some(1.5));
+    assert_eq!(comparison.entries[4].ratio(), None);
+
+    // without a minimum difference, a tiny time becoming slower is flagged
+    let comparison = baseline.compare(&current, 0.1, Duration::ZERO);
+    assert_eq!(comparison.entries[3].change, Change::Regressed);
+
+    // a negative threshold is treated as zero
+    assert_eq!(
+        baseline.compare(&current, -1.0, Duration::from_micros(10)),
+        baseline.compare(&current, 0.0, Duration::from_micros(10))
+    );
+}
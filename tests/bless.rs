@@ -0,0 +1,50 @@
+use aocode::Answer;
+use aocode::golden::{bless, diff};
+use std::path::PathBuf;
+use std::{env, fs, process};
+
+/// Create an empty directory unique to the test.
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aocode-bless-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn line_diff() {
+    assert_eq!(diff("", ""), "");
+    assert_eq!(diff("4", "3"), "-4\n+3\n");
+    assert_eq!(diff("", "ab\ncd"), "+ab\n+cd\n");
+    assert_eq!(
+        diff("#..#\n#..#\n####", "#..#\n.##.\n####\n#..#"),
+        " #..#\n-#..#\n+.##.\n ####\n+#..#\n"
+    );
+}
+
+#[test]
+fn bless_expected_answers() {
+    let dir = temp_dir("expected");
+    let path = dir.join("year2015/day01/part2.txt");
+
+    // a missing expected answer is created with its directories
+    assert_eq!(
+        bless(&path, &Answer::Integer(3)).unwrap(),
+        Some("+3\n".to_string())
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), "3\n");
+
+    // an equal expected answer is left as it is
+    fs::write(&path, " 3\r\n").unwrap();
+    assert_eq!(bless(&path, &Answer::Integer(3)).unwrap(), None);
+    assert_eq!(fs::read_to_string(&path).unwrap(), " 3\r\n");
+
+    let grid = Answer::Grid(vec!["#.#".to_string(), ".#.".to_string()]);
+    assert_eq!(
+        bless(&path, &grid).unwrap(),
+        Some("- 3\n+#.#\n+.#.\n".to_string())
+    );
+    assert_eq!(fs::read_to_string(&path).unwrap(), "#.#\n.#.\n");
+
+    fs::remove_dir_all(&dir).unwrap();
+}
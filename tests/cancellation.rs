@@ -0,0 +1,72 @@
+use aocode::{AdventOfCode, Answer, AoC, CancellationToken, Error, Parameters};
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn deadline() {
+    let advent_of_code = AoC::new();
+
+    assert_eq!(
+        advent_of_code.solve_with_deadline(2016, 14, 2, "abc", Duration::from_millis(10)),
+        Err(Error::Timeout)
+    );
+    assert_eq!(
+        advent_of_code.solve_with_deadline(2015, 1, 1, "(()(()(", Duration::from_secs(60)),
+        Ok(Answer::Integer(3))
+    );
+}
+
+#[test]
+fn cancel_from_another_thread() {
+    let advent_of_code = AoC::new();
+    let token = CancellationToken::new();
+
+    let canceller = {
+        let token = token.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            token.cancel();
+        })
+    };
+    assert_eq!(
+        advent_of_code.solve_cancellable(2016, 14, 2, "abc", &token),
+        Err(Error::Cancelled)
+    );
+    canceller.join().unwrap();
+    assert!(token.is_cancelled());
+
+    // the token does not outlive the cancelled solve
+    assert_eq!(
+        advent_of_code.solve(2015, 1, 1, "(()(()("),
+        Ok(Answer::Integer(3))
+    );
+}
+
+#[test]
+fn cancelled_before_start() {
+    let token = CancellationToken::new();
+    token.cancel();
+
+    assert_eq!(token.check(), Err(Error::Cancelled));
+    assert_eq!(
+        AoC::new().solve_cancellable(2015, 1, 1, "(()(()(", &token),
+        Err(Error::Cancelled)
+    );
+}
+
+#[test]
+fn cancellable_with_parameters() {
+    let advent_of_code = AoC::new();
+    let parameters = Parameters::new().with("length1", 20);
+    let token = CancellationToken::with_timeout(Duration::from_secs(60));
+
+    assert_eq!(
+        advent_of_code.solve_cancellable_with(2016, 16, 1, "10000", &parameters, &token),
+        Ok(Answer::from("01100"))
+    );
+    token.cancel();
+    assert_eq!(
+        advent_of_code.solve_cancellable_with(2016, 16, 1, "10000", &parameters, &token),
+        Err(Error::Cancelled)
+    );
+}
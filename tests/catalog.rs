@@ -0,0 +1,107 @@
+use aocode::{AdventOfCode, AoC, Difficulty, Error, Tag};
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn catalog_matches_years() {
+    let advent_of_code = AoC::new();
+    let catalog = advent_of_code.catalog();
+
+    assert_eq!(catalog.years.len(), advent_of_code.years().count());
+    for (catalog_year, year) in catalog.years.iter().zip(advent_of_code.years()) {
+        assert_eq!(catalog_year.id, year.id());
+        assert_eq!(catalog_year.days.len(), year.days().count());
+        for (catalog_day, day) in catalog_year.days.iter().zip(year.days()) {
+            assert_eq!(catalog_day.id, day.id());
+            assert_eq!(catalog_day.title, day.title());
+            assert_eq!(catalog_day.parts, day.parts());
+            assert_eq!(catalog_day.url, day.url());
+            assert_eq!(catalog_day.difficulty, day.difficulty());
+            assert_eq!(catalog_day.tags, day.tags());
+        }
+    }
+}
+
+#[test]
+fn parts_match_solutions() {
+    let advent_of_code = AoC::new();
+
+    for year in advent_of_code.years() {
+        for day in year.days() {
+            let data = format!("year{:04}/day{:02}", year.id(), day.id());
+            let Ok(input) = fs::read_to_string(format!("tests/test-data/input/{data}.txt")) else {
+                continue;
+            };
+            for part in 0..=3 {
+                if day.parts().contains(&part) {
+                    // the puzzle test generated for the expected answer solves the declared part
+                    let output = format!("tests/test-data/output/{data}/part{part}.txt");
+                    assert!(Path::new(&output).exists(), "{output} is missing");
+                } else {
+                    assert_eq!(
+                        day.solve(part, &input).err(),
+                        Some(Error::UnavailablePart),
+                        "{data} part {part} is not declared"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn catalog_lookup() {
+    let catalog = AoC::new().catalog();
+
+    let day = catalog.year(2015).and_then(|year| year.day(1)).unwrap();
+    assert_eq!(day.title, "Not Quite Lisp");
+    assert_eq!(day.parts, [1, 2]);
+    assert!(catalog.year(2014).is_none());
+    assert_eq!(catalog.puzzles().next(), Some((2015, 1, 1)));
+}
+
+#[test]
+fn day_metadata() {
+    let advent_of_code = AoC::new();
+
+    let day = advent_of_code.year(2016).unwrap().day(11).unwrap();
+    assert_eq!(day.url(), "https://adventofcode.com/2016/day/11");
+    assert_eq!(day.difficulty(), Difficulty::Hard);
+    assert_eq!(day.tags(), [Tag::Search]);
+
+    for year in advent_of_code.years() {
+        for day in year.days() {
+            assert_eq!(
+                day.url(),
+                format!("https://adventofcode.com/{}/day/{}", year.id(), day.id())
+            );
+            assert!(day.tags().is_sorted());
+            assert!(day.parts().is_sorted());
+        }
+    }
+}
+
+#[test]
+fn days_tagged() {
+    let advent_of_code = AoC::new();
+
+    let days = advent_of_code
+        .days_tagged(Tag::Hashing)
+        .map(|(year, day)| (year, day.id()))
+        .collect::<Vec<_>>();
+    assert_eq!(days.first(), Some(&(2015, 4)));
+    assert!(days.contains(&(2016, 14)));
+    assert!(days.is_sorted());
+    assert!(
+        advent_of_code
+            .days_tagged(Tag::Vm)
+            .all(|(_, day)| day.tags().contains(&Tag::Vm))
+    );
+
+    for tag in Tag::ALL {
+        assert_eq!(Tag::from_name(tag.name()), Some(tag));
+        assert!(advent_of_code.days_tagged(tag).next().is_some());
+    }
+    assert_eq!(Tag::NumberTheory.to_string(), "number-theory");
+    assert_eq!(Tag::from_name("number theory"), None);
+}
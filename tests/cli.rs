@@ -0,0 +1,856 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::process::{self, Command, Output, Stdio};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use std::{env, fs};
+
+/// Run the binary with the arguments and the input on stdin.
+fn aocode(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aocode"))
+        .args(args)
+        .env_remove("AOCODE_INPUTS")
+        .env_remove("AOCODE_OUTPUTS")
+        .env_remove("AOCODE_BLESS")
+        .env_remove("AOCODE_SESSION")
+        .env_remove("AOCODE_BASE_URL")
+        .env_remove("AOCODE_CONTACT")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+/// Get the standard output of a successful run.
+fn stdout(output: Output) -> String {
+    assert!(output.status.success(), "{:?}", output);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// Serve the responses, one per connection, on a local port and collect the requests.
+fn mock_server(responses: Vec<String>) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let server = thread::spawn(move || {
+        let mut requests = Vec::new();
+        for response in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut request = String::new();
+            while reader.read_line(&mut request).unwrap() > 2 {}
+            let length = request
+                .lines()
+                .find_map(|line| {
+                    line.to_lowercase()
+                        .strip_prefix("content-length:")?
+                        .trim()
+                        .parse()
+                        .ok()
+                })
+                .unwrap_or(0);
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            requests.push(request.to_lowercase() + &String::from_utf8(body).unwrap());
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+        requests
+    });
+    (url, server)
+}
+
+/// Get an HTTP response with the status, the extra headers and the body.
+fn response(status: &str, headers: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {}\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        headers,
+        body.len(),
+        body
+    )
+}
+
+#[test]
+fn input_from_stdin() {
+    assert_eq!(stdout(aocode(&["2015", "1", "1"], "(()(()(")), "3\n");
+}
+
+#[test]
+fn input_file() {
+    let output = aocode(
+        &[
+            "2015",
+            "1",
+            "1",
+            "--input",
+            "tests/test-data/input/year2015/day01.txt",
+        ],
+        "",
+    );
+    assert_eq!(stdout(output), "232\n");
+
+    let output = aocode(&["2015", "1", "1", "-i", "tests/test-data/missing.txt"], "");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("missing.txt"));
+}
+
+#[test]
+fn inputs_directory() {
+    let output = aocode(&["2015", "1", "2", "--inputs", "tests/test-data/input"], "");
+    assert_eq!(stdout(output), "1783\n");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aocode"))
+        .args(["2015", "1", "1"])
+        .env("AOCODE_INPUTS", "tests/test-data/input")
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+    assert_eq!(stdout(output), "232\n");
+
+    // an explicit input file takes precedence over the inputs directory
+    let output = aocode(
+        &[
+            "2015",
+            "1",
+            "1",
+            "--inputs",
+            "tests/test-data/input",
+            "--input",
+            "tests/test-data/input/year2015/day02.txt",
+        ],
+        "",
+    );
+    assert_eq!(stdout(output), "0\n");
+}
+
+#[test]
+fn several_parts() {
+    let output = aocode(&["2015", "1", "--inputs", "tests/test-data/input"], "");
+    assert_eq!(stdout(output), "Part 1: 232\nPart 2: 1783\n");
+
+    let output = aocode(&["2015", "1", "2", "1"], "())");
+    assert_eq!(stdout(output), "Part 2: 3\nPart 1: -1\n");
+
+    let output = aocode(&["2014", "1"], "");
+    assert!(!output.status.success());
+}
+
+#[test]
+fn progress_without_terminal() {
+    let output = aocode(&["2016", "16", "1", "--progress"], "10000\n");
+    let stderr = String::from_utf8(output.stderr.clone()).unwrap();
+    assert_eq!(stdout(output), "11010011110011010\n");
+    // stderr is a pipe, so the progress is written as plain lines without escape codes
+    assert!(!stderr.contains('\x1b'));
+    assert!(!stderr.contains('\r'));
+    assert!(stderr.contains("generating data 100%\n"));
+    assert!(stderr.ends_with("checksum 100%\n"));
+}
+
+#[test]
+fn run_all() {
+    let dir = env::temp_dir().join(format!("aocode-cli-run-all-{}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("year2015")).unwrap();
+    fs::create_dir_all(dir.join("year2016")).unwrap();
+    fs::write(dir.join("year2015/day01.txt"), "())\n").unwrap();
+    fs::write(dir.join("year2016/day06.txt"), "ab\nac\nbb\n").unwrap();
+    let inputs = dir.to_str().unwrap();
+
+    let output = stdout(aocode(&["run-all", "--inputs", inputs], ""));
+    let lines = output.lines().collect::<Vec<_>>();
+    assert!(lines[0].starts_with("Year  Day  Title"));
+    assert!(lines[2].starts_with("2015  1    Not Quite Lisp"));
+    assert!(lines[2].contains("  1     -1  "));
+    assert!(lines[3].contains("  2     3  "));
+    assert!(lines[4].starts_with("2016  6    Signals and Noise"));
+    assert!(output.contains("\n2015  2      0       "));
+    assert!(output.contains("\nAll   4      0       "));
+
+    let output = stdout(aocode(&["run-all", "2016", "--inputs", inputs], ""));
+    assert!(!output.contains("Not Quite Lisp"));
+    assert!(output.contains("\nAll   2      0       "));
+
+    fs::write(dir.join("year2015/day02.txt"), "1x2\n").unwrap();
+    let output = aocode(&["run-all", "2015", "--inputs", inputs], "");
+    assert!(!output.status.success());
+    let output = String::from_utf8(output.stdout).unwrap();
+    assert!(output.contains("error: The input is invalid"));
+    assert!(output.contains("\nAll   4      2       "));
+
+    let output = aocode(
+        &["run-all", "2016", "--inputs", inputs, "--timeout", "0"],
+        "",
+    );
+    assert!(!output.status.success());
+    let output = String::from_utf8(output.stdout).unwrap();
+    assert!(output.contains("error: Solving the challenge took longer than allowed."));
+    assert!(output.contains("\nAll   2      2       "));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn verify() {
+    let dir = env::temp_dir().join(format!("aocode-cli-verify-{}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("inputs/year2015")).unwrap();
+    fs::create_dir_all(dir.join("outputs/year2015/day01")).unwrap();
+    fs::create_dir_all(dir.join("outputs/year2016/day06")).unwrap();
+    fs::write(dir.join("inputs/year2015/day01.txt"), "())\n").unwrap();
+    fs::write(dir.join("outputs/year2015/day01/part1.txt"), "-1\n").unwrap();
+    fs::write(dir.join("outputs/year2015/day01/part2.txt"), "3\r\n").unwrap();
+    let inputs = dir.join("inputs");
+    let outputs = dir.join("outputs");
+    let args = [
+        "verify",
+        "--inputs",
+        inputs.to_str().unwrap(),
+        "--outputs",
+        outputs.to_str().unwrap(),
+    ];
+
+    let output = stdout(aocode(&args, ""));
+    let lines = output.lines().collect::<Vec<_>>();
+    assert!(lines[0].starts_with("Year  Day  Title"));
+    assert!(lines[2].starts_with("2015  1    Not Quite Lisp  1     pass"));
+    assert!(lines[3].starts_with("2015  1    Not Quite Lisp  2     pass"));
+    assert!(output.ends_with("\n2 passed, 0 failed, 0 missing, 0 errors\n"));
+
+    // an expected answer without an input is missing, which is not a failure
+    fs::write(dir.join("outputs/year2016/day06/part1.txt"), "ab\n").unwrap();
+    let output = stdout(aocode(&args, ""));
+    assert!(output.contains("\n2016  6    Signals and Noise  1     missing"));
+    assert!(output.ends_with("\n2 passed, 0 failed, 1 missing, 0 errors\n"));
+
+    fs::write(dir.join("outputs/year2015/day01/part2.txt"), "4\n").unwrap();
+    fs::write(dir.join("inputs/year2015/day02.txt"), "1x2\n").unwrap();
+    let output = aocode(&[&args[..], &["2015", "--format", "csv"]].concat(), "");
+    assert!(!output.status.success());
+    let output = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(
+        output,
+        "year,day,title,part,status,answer,expected,error\n\
+         2015,1,Not Quite Lisp,1,pass,-1,-1,\n\
+         2015,1,Not Quite Lisp,2,fail,3,4,\n\
+         2015,2,I Was Told There Would Be No Math,1,error,,,invalid-input\n\
+         2015,2,I Was Told There Would Be No Math,2,error,,,invalid-input\n"
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn verify_bless() {
+    let dir = env::temp_dir().join(format!("aocode-cli-verify-bless-{}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("inputs/year2015")).unwrap();
+    fs::create_dir_all(dir.join("outputs/year2015/day01")).unwrap();
+    fs::write(dir.join("inputs/year2015/day01.txt"), "())\n").unwrap();
+    fs::write(dir.join("inputs/year2015/day02.txt"), "1x2\n").unwrap();
+    fs::write(dir.join("outputs/year2015/day01/part1.txt"), "-1\r\n").unwrap();
+    fs::write(dir.join("outputs/year2015/day01/part2.txt"), "4\n").unwrap();
+    let inputs = dir.join("inputs");
+    let outputs = dir.join("outputs");
+    let args = [
+        "verify",
+        "2015",
+        "--inputs",
+        inputs.to_str().unwrap(),
+        "--outputs",
+        outputs.to_str().unwrap(),
+        "--bless",
+    ];
+
+    // failed parts are still reported, but wrong or missing expected answers are replaced
+    let output = aocode(&args, "");
+    assert!(!output.status.success());
+    let printed = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        printed
+            .contains("\n2015  1    Not Quite Lisp                     2     blessed  3       4")
+    );
+    assert!(printed.ends_with("\n1 passed, 0 failed, 0 missing, 2 errors, 1 blessed\n"));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let path = outputs.join("year2015/day01/part2.txt");
+    assert_eq!(stderr, format!("Blessed {}:\n-4\n+3\n\n", path.display()));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "3\n");
+    assert_eq!(
+        fs::read_to_string(outputs.join("year2015/day01/part1.txt")).unwrap(),
+        "-1\r\n"
+    );
+    assert!(!outputs.join("year2015/day02").exists());
+
+    fs::write(dir.join("inputs/year2015/day02.txt"), "2x3x4\n").unwrap();
+    let output = stdout(aocode(&args[..6], ""));
+    assert!(output.ends_with("\n2 passed, 0 failed, 2 missing, 0 errors\n"));
+    let output = stdout(
+        Command::new(env!("CARGO_BIN_EXE_aocode"))
+            .args(&args[..6])
+            .env("AOCODE_BLESS", "1")
+            .output()
+            .unwrap(),
+    );
+    assert!(output.ends_with("\n2 passed, 0 failed, 0 missing, 0 errors, 2 blessed\n"));
+    assert_eq!(
+        fs::read_to_string(outputs.join("year2015/day02/part1.txt")).unwrap(),
+        "58\n"
+    );
+    let output = stdout(aocode(&args[..6], ""));
+    assert!(output.ends_with("\n4 passed, 0 failed, 0 missing, 0 errors\n"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn baseline() {
+    let dir = env::temp_dir().join(format!("aocode-cli-baseline-{}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("inputs/year2015")).unwrap();
+    fs::write(dir.join("inputs/year2015/day01.txt"), "())\n").unwrap();
+    let inputs = dir.join("inputs");
+    let file = dir.join("baseline.json");
+    let options = [
+        "--inputs",
+        inputs.to_str().unwrap(),
+        "--iterations",
+        "2",
+        "--warmup",
+        "0",
+    ];
+
+    let output = stdout(aocode(
+        &[&["baseline", "save", file.to_str().unwrap()], &options[..]].concat(),
+        "",
+    ));
+    assert_eq!(output, format!("Saved 2 parts to {}\n", file.display()));
+    let output = stdout(aocode(
+        &[
+            &["baseline", "compare", file.to_str().unwrap()],
+            &options[..],
+        ]
+        .concat(),
+        "",
+    ));
+    assert!(output.starts_with("Year  Day  Title"));
+    assert!(
+        output.ends_with("\n0 regressed, 0 improved, 2 unchanged, 0 added, 0 removed, 0 failed\n")
+    );
+
+    // a part which took no time regressed, a very slow part improved and a part of another day was removed
+    fs::write(
+        &file,
+        r#"[
+            {"year": 2015, "day": 1, "part": 1, "runs": 1, "min": 0.0, "median": 0.0, "max": 0.0},
+            {"year": 2015, "day": 1, "part": 2, "runs": 1, "min": 10.0, "median": 10.0, "max": 10.0},
+            {"year": 2015, "day": 2, "part": 1, "runs": 1, "min": 0.001, "median": 0.001, "max": 0.001}
+        ]"#,
+    )
+    .unwrap();
+    let args = [
+        &["baseline", "compare", file.to_str().unwrap(), "2015"],
+        &options[..],
+        &["--min-difference", "0", "--format", "csv"],
+    ]
+    .concat();
+    let output = aocode(&args, "");
+    assert!(!output.status.success());
+    let output = String::from_utf8(output.stdout).unwrap();
+    let statuses = output
+        .lines()
+        .map(|line| line.split(',').next_back().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(statuses, ["status", "regressed", "improved", "removed"]);
+    assert!(output.contains("\n2015,2,I Was Told There Would Be No Math,1,0.001,,,removed\n"));
+
+    fs::write(&file, "{}").unwrap();
+    assert!(!aocode(&args, "").status.success());
+
+    let output = aocode(
+        &[
+            "baseline",
+            "compare",
+            file.to_str().unwrap(),
+            "--threshold=-5",
+        ],
+        "",
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("expected a non-negative number"));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn new_day() {
+    let dir = env::temp_dir().join(format!("aocode-cli-new-{}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    for path in [
+        "src/lib.rs",
+        "src/problems/mod.rs",
+        "src/problems/year2024/mod.rs",
+    ] {
+        fs::create_dir_all(dir.join(path).parent().unwrap()).unwrap();
+        fs::copy(path, dir.join(path)).unwrap();
+    }
+    // hand-written parts of a year's module are kept
+    let year_path = dir.join("src/problems/year2024/mod.rs");
+    let year = fs::read_to_string(&year_path).unwrap();
+    let year = year.replacen(
+        "\n\n",
+        "\n//! With helpers.\n\nmod helpers;\nuse std::fmt;\n\n",
+        1,
+    );
+    fs::write(&year_path, year).unwrap();
+    let root = dir.to_str().unwrap();
+
+    let output = stdout(aocode(
+        &["new", "2024", "8", "Resonant Collinearity", "--root", root],
+        "",
+    ));
+    assert_eq!(output.lines().count(), 2);
+    let day = fs::read_to_string(dir.join("src/problems/year2024/day08.rs")).unwrap();
+    assert!(day.contains("    \"Resonant Collinearity\",\n    parts = [],\n"));
+    assert!(day.contains("impl Solution for Day08 {"));
+    assert!(!day.contains("todo!()"));
+    assert_eq!(day.matches("Err(Error::UnavailablePart)").count(), 2);
+    let year = fs::read_to_string(dir.join("src/problems/year2024/mod.rs")).unwrap();
+    assert!(year.contains("mod day07;\nmod day08;\nmod day09;\n"));
+    assert!(year.contains("pub use day08::Day08;\n#[doc(inline)]\npub use day09::Day09;\n"));
+    assert!(year.contains(" Day07, Day08, Day09, "));
+    assert!(year.contains("\n//! With helpers.\n\nmod helpers;\nuse std::fmt;\n\n"));
+    // the test data is filled by fetching the input and blessing the answers
+    assert!(!dir.join("tests/test-data").exists());
+
+    let output = aocode(&["new", "2024", "8", "Again", "--root", root], "");
+    assert!(!output.status.success());
+
+    stdout(aocode(&["new", "2030", "25", "Last", "--root", root], ""));
+    let day = fs::read_to_string(dir.join("src/problems/year2030/day25.rs")).unwrap();
+    assert!(day.contains("    \"Last\",\n    parts = [2],\n"));
+    assert!(day.contains("Ok(\"Advent of Code 2030 solved!\".into())"));
+    let year = fs::read_to_string(dir.join("src/problems/year2030/mod.rs")).unwrap();
+    assert!(year.ends_with("year!(Year2030, 2030, Day25);\n"));
+    stdout(aocode(&["new", "2030", "1", "First", "--root", root], ""));
+    let year = fs::read_to_string(dir.join("src/problems/year2030/mod.rs")).unwrap();
+    assert!(year.contains("mod day01;\nmod day25;\n"));
+    assert!(year.ends_with("year!(Year2030, 2030, Day01, Day25);\n"));
+    assert!(dir.join("src/problems/year2030/examples.txt").exists());
+    let problems = fs::read_to_string(dir.join("src/problems/mod.rs")).unwrap();
+    assert!(problems.contains("pub mod year2030;\n"));
+    assert!(problems.ends_with("pub use year2030::Year2030;\n"));
+    let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+    assert!(lib.contains("problems::Year2030::new()"));
+    assert!(!dir.join("tests/2030.rs").exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn fetch() {
+    let dir = env::temp_dir().join(format!("aocode-cli-fetch-{}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("year2015")).unwrap();
+    fs::write(dir.join("year2015/day01.txt"), "(()\n").unwrap();
+    let inputs = dir.to_str().unwrap();
+    let fetch = |args: &[&str], url: &str| {
+        let options = ["--inputs", inputs, "--session", "abc", "--base-url", url];
+        aocode(
+            &[&["fetch"], args, &options, &["--delay", "0"]].concat(),
+            "",
+        )
+    };
+
+    // cached inputs are not downloaded again
+    let output = stdout(fetch(&["2015", "1"], "http://127.0.0.1:1"));
+    assert_eq!(output, "2015 day  1: cached\n");
+
+    let (url, server) = mock_server(vec![
+        response("429 Too Many Requests", "Retry-After: 0\r\n", ""),
+        response("200 OK", "", "1x1x1\n"),
+    ]);
+    let output = stdout(fetch(&["2015", "2"], &url));
+    assert_eq!(output, "2015 day  2: downloaded\n");
+    assert_eq!(
+        fs::read_to_string(dir.join("year2015/day02.txt")).unwrap(),
+        "1x1x1\n"
+    );
+    let requests = server.join().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[1].starts_with("get /2015/day/2/input http/1.1\r\n"));
+    assert!(requests[1].contains("\r\ncookie: session=abc\r\n"));
+    let user_agent = format!("\r\nuser-agent: aocode/{}\r\n", env!("CARGO_PKG_VERSION"));
+    assert!(requests[1].contains(&user_agent));
+
+    let (url, server) = mock_server(vec![
+        response("200 OK", "", "R8, R4\n"),
+        response("404 Not Found", "", ""),
+    ]);
+    let output = fetch(&["2025", "--all", "--contact", "me@example.com"], &url);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "2025 day  1: downloaded\n"
+    );
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("not available yet")
+    );
+    assert!(!dir.join("year2025/day04.txt").exists());
+    let requests = server.join().unwrap();
+    assert!(requests[1].starts_with("get /2025/day/4/input "));
+    let user_agent = format!(
+        "\r\nuser-agent: aocode/{} (+me@example.com)\r\n",
+        env!("CARGO_PKG_VERSION")
+    );
+    assert!(requests[0].contains(&user_agent));
+
+    let (url, server) = mock_server(vec![response(
+        "400 Bad Request",
+        "",
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+    )]);
+    let output = fetch(&["2016", "1"], &url);
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("session cookie")
+    );
+    assert!(!dir.join("year2016").exists());
+    server.join().unwrap();
+
+    let output = aocode(&["fetch", "2015", "3", "--inputs", inputs], "");
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("AOCODE_SESSION")
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn submit() {
+    let dir = env::temp_dir().join(format!("aocode-cli-submit-{}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let log = dir.join("submissions.log");
+    let submit = |part: &str, input: &str, url: &str| {
+        let log = log.to_str().unwrap();
+        let options = ["--log", log, "--session", "abc", "--base-url", url];
+        let args = [
+            &["submit", "2015", "1", part][..],
+            &options,
+            &["--delay", "0"],
+        ]
+        .concat();
+        aocode(&args, input)
+    };
+    let page = |text: &str| {
+        response(
+            "200 OK",
+            "",
+            &format!("<main><article><p>{}</p></article></main>", text),
+        )
+    };
+    let offline = "http://127.0.0.1:1";
+    // the times to wait asked for by the website pass when they are cleared in the log
+    let wait = || {
+        let lines = fs::read_to_string(&log).unwrap();
+        let lines = lines
+            .lines()
+            .map(|line| line.split('\t').take(6).collect::<Vec<_>>().join("\t") + "\n");
+        fs::write(&log, lines.collect::<String>()).unwrap();
+    };
+
+    let (url, server) = mock_server(vec![page(
+        "That's not the right answer; your answer is too high.  Please wait one minute.",
+    )]);
+    let output = submit("1", "(()))", &url);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "2015 day 1 part 1: -1 is wrong, too high\n"
+    );
+    let requests = server.join().unwrap();
+    assert!(requests[0].starts_with("post /2015/day/1/answer http/1.1\r\n"));
+    assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=-1"));
+
+    // known wrong answers and answers above a too high answer are not submitted
+    let output = submit("1", "(()))", offline);
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("already known to be wrong")
+    );
+    let output = submit("1", "()", offline);
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("-1 is already too high")
+    );
+    let output = submit("1", "())))", offline);
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("not submitting -3, wait 60 s before submitting again")
+    );
+    // the website limits the rate of the answers of every part
+    let output = submit("2", "())", offline);
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("not submitting 3, wait 60 s before submitting again")
+    );
+    wait();
+
+    let (url, server) = mock_server(vec![
+        page(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.",
+        ),
+        page("That's not the right answer; your answer is too low."),
+    ]);
+    let output = submit("1", "())))", &url);
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "2015 day 1 part 1: -3 is not checked, wait 65 s before submitting again\n"
+    );
+    let output = submit("1", "())))", offline);
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("wait 65 s before submitting again")
+    );
+    wait();
+    let output = submit("1", "())))", &url);
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "2015 day 1 part 1: -3 is wrong, too low\n"
+    );
+    server.join().unwrap();
+    let output = submit("1", ")))))", offline);
+    assert!(
+        String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("-3 is already too low")
+    );
+
+    let (url, server) = mock_server(vec![page(
+        "That's the right answer!  You are one gold star closer.",
+    )]);
+    assert_eq!(
+        stdout(submit("2", "())", &url)),
+        "2015 day 1 part 2: 3 is correct\n"
+    );
+    server.join().unwrap();
+    assert_eq!(
+        stdout(submit("2", "())", offline)),
+        "2015 day 1 part 2: 3 is correct (already submitted)\n"
+    );
+
+    // a rate limit without a known time to wait is still logged
+    let (url, server) = mock_server(vec![page(
+        "You gave an answer too recently; please come back later.",
+    )]);
+    let output = submit("1", "()))", &url);
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "2015 day 1 part 1: -2 is not checked, an answer was submitted too recently\n"
+    );
+    server.join().unwrap();
+
+    let log = fs::read_to_string(&log).unwrap();
+    let verdicts = log
+        .lines()
+        .map(|line| {
+            line.split('\t')
+                .skip(1)
+                .take(5)
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        verdicts,
+        [
+            "2015 1 1 too-high -1",
+            "2015 1 1 rate-limited -3",
+            "2015 1 1 too-low -3",
+            "2015 1 2 correct 3",
+            "2015 1 1 rate-limited -2"
+        ]
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn watch() {
+    let dir = env::temp_dir().join(format!("aocode-cli-watch-{}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let input = dir.join("example.txt");
+    fs::write(&input, "(()\n").unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aocode"))
+        .args(["2015", "1", "--watch", "--input", input.to_str().unwrap()])
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let output = Arc::new(Mutex::new(String::new()));
+    let mut stdout = child.stdout.take().unwrap();
+    let reader = {
+        let output = Arc::clone(&output);
+        thread::spawn(move || {
+            let mut buffer = [0; 1024];
+            while let Ok(length @ 1..) = stdout.read(&mut buffer) {
+                let text = String::from_utf8_lossy(&buffer[..length]);
+                output.lock().unwrap().push_str(&text);
+            }
+        })
+    };
+    let wait_for = |text: &str| {
+        let start = Instant::now();
+        while !output.lock().unwrap().contains(text) {
+            assert!(start.elapsed() < Duration::from_secs(10), "{:?}", output);
+            thread::sleep(Duration::from_millis(50));
+        }
+    };
+
+    wait_for("(run 1)\nPart 1: 1    --- ");
+    assert!(output.lock().unwrap().contains("\nPart 2: error: "));
+    fs::write(&input, "())\n").unwrap();
+    wait_for("(run 2)\nPart 1: -1 (was 1)    --- ");
+    assert!(output.lock().unwrap().contains("\nPart 2: 3 (was error: "));
+    fs::remove_file(&input).unwrap();
+    wait_for("Error reading the input: ");
+    fs::write(&input, "())\n").unwrap();
+    wait_for("(run 3)\nPart 1: -1    --- ");
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+    reader.join().unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn stats() {
+    let output = stdout(aocode(&["stats", "2025"], ""));
+    let lines = output.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "Advent of Code 2025: 4/50 stars");
+    assert_eq!(lines[1], " 1 **    2 ..    3 ..    4 **    5 ..");
+    assert_eq!(lines[5], "21 ..   22 ..   23 ..   24 ..   25 ..");
+    assert_eq!(lines.len(), 6);
+
+    let output = stdout(aocode(&["stats"], ""));
+    assert!(output.contains("\n\nAdvent of Code 2016: 50/50 stars\n"));
+    assert!(output.contains("\n\nTotal: "));
+
+    // the best times are summed over the parts from all benchmark runs
+    let path = env::temp_dir().join(format!("aocode-cli-stats-{}.json", process::id()));
+    let timings = r#"[
+        {"year": 2025, "day": 1, "part": 1, "duration": 0.002},
+        {"year": 2025, "day": 1, "part": 2, "duration": 0.003, "answer": "6"}
+    ]
+    [
+        {"year": 2025, "day": 1, "part": 1, "duration": 0.004, "min": 0.001},
+        {"year": 2025, "day": 4, "part": 1, "duration": null, "error": "invalid-input"}
+    ]"#;
+    fs::write(&path, timings).unwrap();
+    let path = path.to_str().unwrap();
+    let output = stdout(aocode(&["stats", "2025", "--timings", path], ""));
+    let lines = output.lines().collect::<Vec<_>>();
+    assert_eq!(
+        lines[1],
+        " 1 **     4.000 ms    2 ..                 3 ..                 4 **                 5 .."
+    );
+    let output = stdout(aocode(
+        &["stats", "2025", "--timings", path, "-f", "csv"],
+        "",
+    ));
+    let lines = output.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "year,day,title,parts,stars,best_duration");
+    assert_eq!(lines[1], "2025,1,Secret Entrance,1;2,2,0.004");
+    assert_eq!(lines[2], "2025,2,,,0,");
+    assert_eq!(lines.len(), 26);
+    fs::remove_file(path).unwrap();
+
+    let output = aocode(&["stats", "2025", "--timings", "missing.json"], "");
+    assert!(!output.status.success());
+}
+
+#[test]
+fn json_format() {
+    let output = stdout(aocode(&["2015", "1", "-f", "json"], "())"));
+    let records: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(records.as_array().unwrap().len(), 2);
+    assert_eq!(records[0]["year"], 2015);
+    assert_eq!(records[0]["day"], 1);
+    assert_eq!(records[0]["part"], 1);
+    assert_eq!(records[0]["title"], "Not Quite Lisp");
+    assert_eq!(records[0]["answer"], "-1");
+    assert_eq!(records[0]["error"], serde_json::Value::Null);
+    assert!(records[0]["duration"].is_f64());
+
+    let output = aocode(&["2015", "1", "-f", "json"], "(");
+    assert!(!output.status.success());
+    let records: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(records[1]["answer"], serde_json::Value::Null);
+    assert_eq!(records[1]["error"], "no-solution");
+
+    let output = stdout(aocode(
+        &["2015", "1", "1", "-b", "--iterations", "2", "-f", "json"],
+        "(",
+    ));
+    let records: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(records[0]["answer"], "1");
+    assert_eq!(records[0]["runs"], 2);
+}
+
+#[test]
+fn csv_and_markdown_formats() {
+    let output = stdout(aocode(
+        &["2016", "6", "1", "--format", "csv"],
+        "ab\nac\nbb\n",
+    ));
+    let lines = output.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "year,day,title,part,answer,error,duration");
+    assert!(lines[1].starts_with("2016,6,Signals and Noise,1,ab,,"));
+
+    let output = stdout(aocode(&["2015", "1", "1", "-b", "-f", "csv"], "("));
+    assert!(
+        output.starts_with(
+            "year,day,title,part,answer,error,duration,parse_duration,solve_duration\n"
+        )
+    );
+
+    let output = stdout(aocode(&["2015", "1", "2", "-f", "markdown"], "())"));
+    let lines = output.lines().collect::<Vec<_>>();
+    assert_eq!(
+        lines[0],
+        "| Year | Day | Title | Part | Answer | Error | Time |"
+    );
+    assert_eq!(lines[1], "| --- | --- | --- | --- | --- | --- | --- |");
+    assert!(lines[2].starts_with("| 2015 | 1 | Not Quite Lisp | 2 | 3 |  | "));
+
+    let output = stdout(aocode(&["--list", "--tag", "ranges", "-f", "csv"], ""));
+    let lines = output.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "year,day,title,parts,url,difficulty,tags");
+    assert_eq!(
+        lines[1],
+        "2016,20,Firewall Rules,1;2,https://adventofcode.com/2016/day/20,medium,ranges"
+    );
+
+    let output = aocode(&["2015", "1", "-f", "yaml"], "");
+    assert!(!output.status.success());
+}
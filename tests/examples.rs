@@ -0,0 +1,72 @@
+use aocode::{AdventOfCode, AoC};
+
+#[test]
+fn puzzle_examples() {
+    let advent_of_code = AoC::new();
+    let mut failures = Vec::new();
+    for year in advent_of_code.years() {
+        for day in year.days() {
+            let examples = year.examples(day.id()).unwrap();
+            for (i, example) in examples.iter().enumerate() {
+                for (part, expected) in &example.answers {
+                    let answer = advent_of_code.solve_with(
+                        year.id(),
+                        day.id(),
+                        *part,
+                        &example.input,
+                        &example.parameters,
+                    );
+                    let answer = answer.map(|answer| answer.to_string());
+                    if answer.as_deref() != Ok(expected.as_str()) {
+                        failures.push(format!(
+                            "{} day {} example {} part {}: expected {}, got {:?}",
+                            year.id(),
+                            day.id(),
+                            i + 1,
+                            part,
+                            expected,
+                            answer
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// The days whose puzzle texts have no example which fits the solutions.
+const WITHOUT_EXAMPLES: [(usize, usize); 3] = [
+    // the puzzle text only lists the things detected in the gift, without any other aunt
+    (2015, 16),
+    // the example fight has no shop, so it has no answer to either part
+    (2015, 21),
+    // the puzzle text has no example of a program producing a clock signal
+    (2016, 25),
+];
+
+#[test]
+fn every_day_has_examples() {
+    let advent_of_code = AoC::new();
+    let missing = advent_of_code
+        .years()
+        .flat_map(|year| year.days().map(move |day| (year, day.id())))
+        .filter(|(year, day)| year.examples(*day).unwrap().is_empty())
+        .map(|(year, day)| (year.id(), day))
+        .collect::<Vec<_>>();
+    assert_eq!(missing, WITHOUT_EXAMPLES);
+}
+
+#[test]
+fn unavailable_day() {
+    let advent_of_code = AoC::new();
+    assert!(advent_of_code.examples(2015, 26).is_err());
+    assert!(advent_of_code.examples(2014, 1).is_err());
+
+    let examples = advent_of_code.examples(2024, 18).unwrap();
+    assert_eq!(examples[0].day, 18);
+    assert_eq!(examples[0].answer(1), Some("22"));
+    assert!(examples[0].input.starts_with("5,4\n4,2\n"));
+    assert!(examples[0].input.ends_with("\n2,0\n"));
+    assert_eq!(examples[0].parameters.iter().count(), 2);
+}
@@ -0,0 +1,429 @@
+//! A harness feeding mutated and truncated inputs and examples to the solutions of every day,
+//! which checks that solving returns an answer or an error instead of panicking, crashing or hanging.
+//!
+//! The build script generates a target for every day with an input in `tests/test-data/input`.
+//! The harness only runs with the `fuzz` feature, e.g. `cargo test --features fuzz --test fuzz year2016_day23`.
+//! The cases are solved with a deadline, which stops the solutions polling it with an error.
+//! The target solves the cases in a child process, so that a stack overflow or a hang
+//! is reported like a panic, after which the remaining cases are solved in a new child process.
+//! The inputs of the failed cases are written to the `fuzz` directory of the temporary directory
+//! of the tests, e.g. `target/tmp/fuzz/year2016_day23_case12.txt`.
+//!
+//! Besides random edits, the mutations replace numbers with huge ones or with zero,
+//! which turns relative jumps into jumps to themselves.
+//!
+//! The environment variable `AOCODE_FUZZ_CASES` sets the number of mutated versions
+//! of every input and example, and `AOCODE_FUZZ_SEED` sets the seed of the mutations.
+//! The seed is fixed by default, so that every run solves the same cases,
+//! and `AOCODE_FUZZ_SEED=random` picks a new one, which is reported with the failures to reproduce them.
+
+use aocode::{AdventOfCode, AoC, CancellationToken, Parameters};
+use std::any::Any;
+use std::env;
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+include!(concat!(env!("OUT_DIR"), "/fuzz.rs"));
+
+/// The environment variable set for the child process to the index of the first case to solve.
+const CHILD: &str = "AOCODE_FUZZ_CHILD";
+/// The environment variable with the number of mutated versions of every input and example.
+const CASES: &str = "AOCODE_FUZZ_CASES";
+/// The environment variable with the seed of the mutations, which is also set for the child process.
+const SEED: &str = "AOCODE_FUZZ_SEED";
+/// The value of the seed variable which picks a random seed.
+const RANDOM_SEED: &str = "random";
+/// The seed of the mutations if none is given.
+const DEFAULT_SEED: u64 = 2015;
+/// The default number of mutated versions of every input and example.
+const DEFAULT_CASES: usize = 16;
+/// The number of truncated versions of every input and example.
+const TRUNCATIONS: usize = 2;
+/// The deadline of solving a part of a case, after which the solutions polling it stop with an error.
+const DEADLINE: Duration = Duration::from_secs(2);
+/// The time after which solving a part of a case is considered to hang.
+const TIMEOUT: Duration = Duration::from_secs(10);
+/// The characters inserted into the inputs besides their own characters.
+const SPECIAL: [char; 8] = [' ', '\n', '0', '9', '-', '#', '.', ','];
+/// The numbers replacing the numbers of the inputs, which overflow the common integer types.
+const HUGE: [&str; 6] = [
+    "99999999999",
+    "2147483648",
+    "4294967296",
+    "9223372036854775808",
+    "18446744073709551615",
+    "340282366920938463463374607431768211456",
+];
+/// The inputs which once made a solution hang or abort, solved besides the mutated ones.
+const REGRESSIONS: [(usize, usize, &str); 3] = [
+    (2015, 20, "99999999999\n"),
+    (2016, 12, "jnz 1 0\n"),
+    (2017, 18, "jgz 1 0\n"),
+];
+
+/// An input solved by the harness.
+struct Case {
+    /// The description of the origin of the input.
+    description: String,
+    input: String,
+    parameters: Parameters,
+}
+
+/// Fuzz the solutions to a day's challenge.
+/// In the child process, the cases are solved and reported on the standard output.
+/// Otherwise, the child processes are supervised and the failures are collected.
+fn fuzz(year: usize, day: usize) {
+    let seed = match env::var(SEED) {
+        Ok(seed) if seed == RANDOM_SEED => {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+            now.as_nanos() as u64
+        }
+        Ok(seed) => seed.parse().unwrap(),
+        Err(_) => DEFAULT_SEED,
+    };
+    let cases = cases(year, day, seed);
+    match env::var(CHILD) {
+        Ok(start) => solve_cases(year, day, &cases, start.parse().unwrap()),
+        Err(_) => {
+            let failures = supervise(year, day, seed, &cases);
+            assert!(
+                failures.is_empty(),
+                "{}\nreproduce with {}={}",
+                failures.join("\n"),
+                SEED,
+                seed
+            );
+        }
+    }
+}
+
+/// Solve the cases from the given index on, reporting every part before solving it
+/// and the message of every panic.
+fn solve_cases(year: usize, day: usize, cases: &[Case], start: usize) {
+    panic::set_hook(Box::new(|_| {}));
+    let advent_of_code = AoC::new();
+    let parts = advent_of_code.year(year).unwrap().day(day).unwrap().parts();
+    let mut stdout = std::io::stdout();
+    // the test harness has printed the name of the test without ending the line
+    writeln!(stdout).unwrap();
+
+    for (i, case) in cases.iter().enumerate().skip(start) {
+        for &part in parts {
+            writeln!(stdout, "fuzz-case {} {}", i, part).unwrap();
+            stdout.flush().unwrap();
+            let token = CancellationToken::with_timeout(DEADLINE);
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                advent_of_code.solve_cancellable_with(
+                    year,
+                    day,
+                    part,
+                    &case.input,
+                    &case.parameters,
+                    &token,
+                )
+            }));
+            if let Err(payload) = result {
+                let message = panic_message(payload.as_ref()).replace('\n', " ");
+                writeln!(stdout, "fuzz-panic {} {} {}", i, part, message).unwrap();
+            }
+        }
+    }
+    writeln!(stdout, "fuzz-done").unwrap();
+}
+
+/// Solve the cases in child processes and collect the descriptions of the failed cases.
+/// A child process which crashes or hangs is replaced by a new one starting after the failed case.
+fn supervise(year: usize, day: usize, seed: u64, cases: &[Case]) -> Vec<String> {
+    let name = format!("year{:04}_day{:02}", year, day);
+    let mut failures = Vec::new();
+    let mut start = 0;
+
+    while start < cases.len() {
+        let mut child = Command::new(env::current_exe().unwrap())
+            .args([name.as_str(), "--exact", "--nocapture", "--test-threads=1"])
+            .env(CHILD, start.to_string())
+            .env(SEED, seed.to_string())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+        let mut stderr = child.stderr.take().unwrap();
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for line in stdout.lines().map_while(Result::ok) {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        // the case and part being solved
+        let mut current = None;
+        start = loop {
+            match receiver.recv_timeout(TIMEOUT) {
+                Ok(line) => {
+                    let mut words = line.splitn(4, ' ');
+                    match (words.next(), words.next(), words.next(), words.next()) {
+                        (Some("fuzz-case"), Some(i), Some(part), None) => {
+                            current = Some((i.parse::<usize>().unwrap(), part.to_string()));
+                        }
+                        (Some("fuzz-panic"), Some(i), Some(part), message) => {
+                            let i = i.parse().unwrap();
+                            let message = format!("panicked: {}", message.unwrap_or_default());
+                            failures.push(failure(&name, i, &cases[i], part, &message));
+                        }
+                        (Some("fuzz-done"), None, None, None) => {
+                            child.wait().unwrap();
+                            break cases.len();
+                        }
+                        _ => {}
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    child.kill().unwrap();
+                    child.wait().unwrap();
+                    let (i, part) = current.expect("the child process did not start solving");
+                    let message = format!("did not finish within {:?}", TIMEOUT);
+                    failures.push(failure(&name, i, &cases[i], &part, &message));
+                    break i + 1;
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    let status = child.wait().unwrap();
+                    let mut output = String::new();
+                    stderr.read_to_string(&mut output).unwrap();
+                    let (i, part) = current.expect("the child process did not start solving");
+                    let message = format!(
+                        "crashed with {}: {}",
+                        status,
+                        output.lines().last().unwrap_or_default()
+                    );
+                    failures.push(failure(&name, i, &cases[i], &part, &message));
+                    break i + 1;
+                }
+            }
+        };
+    }
+
+    failures
+}
+
+/// Describe a failed case and save its input.
+fn failure(name: &str, i: usize, case: &Case, part: &str, message: &str) -> String {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("fuzz");
+    let path = dir.join(format!("{}_case{}.txt", name, i));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(&path, &case.input).unwrap();
+    format!(
+        "{} part {}, {} ({}): {}",
+        name,
+        part,
+        case.description,
+        path.display(),
+        message
+    )
+}
+
+/// Get the message of a panic.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+/// Get the cases of a day: the empty input, the regressions and truncated and mutated versions
+/// of the inputs in the test data and of the examples.
+/// The cases only depend on the day, the seed and the environment,
+/// so the child processes get the same ones.
+fn cases(year: usize, day: usize, seed: u64) -> Vec<Case> {
+    let count = env::var(CASES).map_or(DEFAULT_CASES, |count| count.parse().unwrap());
+
+    let mut seeds = Vec::new();
+    let data = Path::new("tests/test-data/input").join(format!("year{:04}", year));
+    let main = data.join(format!("day{:02}.txt", day));
+    let mut paths = vec![main];
+    if let Ok(entries) = fs::read_dir(data.join(format!("day{:02}", day))) {
+        let mut named = entries
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>();
+        named.sort();
+        paths.extend(named);
+    }
+    for path in paths {
+        let input = fs::read_to_string(&path).unwrap().replace("\r\n", "\n");
+        seeds.push((path.display().to_string(), input, Parameters::new()));
+    }
+    for (i, example) in AoC::new()
+        .examples(year, day)
+        .unwrap()
+        .into_iter()
+        .enumerate()
+    {
+        seeds.push((
+            format!("example {}", i + 1),
+            example.input,
+            example.parameters,
+        ));
+    }
+
+    let mut cases = vec![
+        Case {
+            description: String::from("empty input"),
+            input: String::new(),
+            parameters: Parameters::new(),
+        },
+        Case {
+            description: String::from("empty line"),
+            input: String::from("\n"),
+            parameters: Parameters::new(),
+        },
+    ];
+    for (_, _, input) in REGRESSIONS
+        .iter()
+        .filter(|regression| (regression.0, regression.1) == (year, day))
+    {
+        cases.push(Case {
+            description: format!("regression {:?}", input),
+            input: input.to_string(),
+            parameters: Parameters::new(),
+        });
+    }
+    let mut rng = Rng::new(seed ^ (year as u64 * 100 + day as u64));
+    for (origin, input, parameters) in seeds {
+        let chars = input.chars().collect::<Vec<_>>();
+        for _ in 0..TRUNCATIONS {
+            let length = rng.below(chars.len() + 1);
+            cases.push(Case {
+                description: format!("{} truncated to {} characters", origin, length),
+                input: chars[..length].iter().collect(),
+                parameters: parameters.clone(),
+            });
+        }
+        for _ in 0..count {
+            let mut mutated = chars.clone();
+            let mutations = 1 + rng.below(3);
+            for _ in 0..mutations {
+                mutate(&mut mutated, &mut rng);
+            }
+            cases.push(Case {
+                description: format!("{} with {} mutations", origin, mutations),
+                input: mutated.into_iter().collect(),
+                parameters: parameters.clone(),
+            });
+        }
+    }
+    cases
+}
+
+/// Apply a random mutation to the characters of an input.
+fn mutate(chars: &mut Vec<char>, rng: &mut Rng) {
+    if chars.is_empty() {
+        chars.push(SPECIAL[rng.below(SPECIAL.len())]);
+        return;
+    }
+    let position = rng.below(chars.len());
+    let random_char = |rng: &mut Rng| match rng.below(2) {
+        0 => SPECIAL[rng.below(SPECIAL.len())],
+        _ => chars[rng.below(chars.len())],
+    };
+    // the lines as ranges of characters, including their newlines
+    let lines = chars
+        .split_inclusive(|&c| c == '\n')
+        .scan(0, |start, line| {
+            *start += line.len();
+            Some(*start - line.len()..*start)
+        })
+        .collect::<Vec<_>>();
+    let line = lines[rng.below(lines.len())].clone();
+    // the numbers as ranges of digits
+    let numbers = chars
+        .iter()
+        .enumerate()
+        .filter(|&(i, c)| c.is_ascii_digit() && (i == 0 || !chars[i - 1].is_ascii_digit()))
+        .map(|(start, _)| {
+            let length = chars[start..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit())
+                .count();
+            start..start + length
+        })
+        .collect::<Vec<_>>();
+
+    match rng.below(9) {
+        0 => {
+            chars.remove(position);
+        }
+        1 => chars[position] = random_char(rng),
+        2 => {
+            let c = random_char(rng);
+            chars.insert(position, c);
+        }
+        3 => {
+            chars.drain(line);
+        }
+        4 => {
+            let copy = chars[line.clone()].to_vec();
+            chars.splice(line.end..line.end, copy);
+        }
+        5 => {
+            let other = lines[rng.below(lines.len())].clone();
+            let (first, second) = if other.start < line.start {
+                (other, line)
+            } else {
+                (line, other)
+            };
+            // the later line is replaced first, so that the range of the earlier one stays valid
+            let (first_chars, second_chars) = (
+                chars[first.clone()].to_vec(),
+                chars[second.clone()].to_vec(),
+            );
+            chars.splice(second, first_chars);
+            chars.splice(first, second_chars);
+        }
+        7 if !numbers.is_empty() => {
+            let number = numbers[rng.below(numbers.len())].clone();
+            chars.splice(number, HUGE[rng.below(HUGE.len())].chars());
+        }
+        8 if !numbers.is_empty() => {
+            let number = numbers[rng.below(numbers.len())].clone();
+            chars.splice(number, ['0']);
+        }
+        _ => {
+            // a number gets another digit or loses one
+            if chars[position].is_ascii_digit() {
+                if rng.below(2) == 0 {
+                    chars.remove(position);
+                } else {
+                    chars.insert(position, char::from(b'0' + rng.below(10) as u8));
+                }
+            }
+        }
+    }
+}
+
+/// A small deterministic pseudorandom number generator (xorshift64*).
+struct Rng(u64);
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // the state must not be zero
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    /// Get a random number below the bound, which must be positive.
+    fn below(&mut self, bound: usize) -> usize {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        (self.0.wrapping_mul(0x2545_F491_4F6C_DD1D) >> 32) as usize % bound
+    }
+}
@@ -0,0 +1,108 @@
+use aocode::{AdventOfCode, AoC, Error};
+
+fn invalid_input(line: usize, column: usize, message: &'static str) -> Error {
+    Error::InvalidInput {
+        line,
+        column,
+        message,
+    }
+}
+
+#[test]
+fn truncated_almanac() {
+    let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50";
+    assert_eq!(
+        AoC::new().solve(2023, 5, 1, input),
+        Err(invalid_input(5, 1, "expected a mapping"))
+    );
+
+    let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n";
+    assert_eq!(
+        AoC::new().solve(2023, 5, 1, input),
+        Err(invalid_input(5, 1, "expected a map"))
+    );
+}
+
+#[test]
+fn malformed_number() {
+    let input = "swap position 4 with position 0\nrotate left x steps\n";
+    assert_eq!(
+        AoC::new().solve(2016, 21, 1, input),
+        Err(invalid_input(2, 13, "expected a number"))
+    );
+}
+
+#[test]
+fn malformed_particle() {
+    let input = "p=<3,0,0>, v=<2,0,0>, a=<-1,0,0>\nq=<4,0,0>, v=<0,0,0>, a=<-2,0,0>\n";
+    assert_eq!(
+        AoC::new().solve(2017, 20, 1, input),
+        Err(invalid_input(2, 1, "expected a vector"))
+    );
+}
+
+#[test]
+fn overflowing_weights() {
+    let input = "18446744073709551615\n1\n2\n";
+    assert_eq!(
+        AoC::new().solve(2015, 24, 1, input),
+        Err(invalid_input(2, 1, "the total weight is too large"))
+    );
+}
+
+#[test]
+fn wrong_day_input() {
+    // the input of 2022 day 10 fed to 2022 day 11
+    let input = "noop\naddx 3\naddx -5\n";
+    assert_eq!(
+        AoC::new().solve(2022, 11, 1, input),
+        Err(invalid_input(1, 1, "expected a monkey description"))
+    );
+}
+
+#[test]
+fn column_counts_characters() {
+    let input = "Ø|\n";
+    assert_eq!(
+        AoC::new().solve(2023, 10, 1, input),
+        Err(invalid_input(1, 1, "expected a tile"))
+    );
+
+    let input = "|Ø\n";
+    assert_eq!(
+        AoC::new().solve(2023, 10, 1, input),
+        Err(invalid_input(1, 2, "expected a tile"))
+    );
+}
+
+#[test]
+fn fragment_outside_input() {
+    assert_eq!(
+        Error::invalid_input("input", "other", "expected something"),
+        invalid_input(0, 0, "expected something")
+    );
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        invalid_input(3, 7, "expected a number").to_string(),
+        "The input is invalid at line 3, column 7: expected a number."
+    );
+    assert_eq!(
+        invalid_input(0, 0, "expected a number").to_string(),
+        "The input is invalid: expected a number."
+    );
+}
+
+#[test]
+fn error_kinds() {
+    assert_eq!(
+        invalid_input(1, 2, "expected a number").kind(),
+        "invalid-input"
+    );
+    assert_eq!(Error::UnavailableYear.kind(), "unavailable-year");
+    assert_eq!(Error::NoSolution.kind(), "no-solution");
+    assert_eq!(Error::Timeout.kind(), "timeout");
+    assert_eq!(Error::Panicked.kind(), "panicked");
+}
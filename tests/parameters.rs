@@ -0,0 +1,163 @@
+use aocode::{AdventOfCode, Answer, AoC, Error, Parameter, ParameterValue, Parameters};
+
+#[test]
+fn puzzle_examples() {
+    let advent_of_code = AoC::new();
+
+    let input = "5,4\n4,2\n4,5\n3,0\n2,1\n6,3\n2,4\n1,5\n0,6\n3,3\n2,6\n5,1\n1,2\n5,5\n2,5\n\
+                 6,5\n1,4\n0,4\n6,4\n1,1\n6,1\n1,0\n0,5\n1,6\n2,0\n";
+    let parameters = Parameters::new().with("size", 7).with("bytes", 12);
+    assert_eq!(
+        advent_of_code.solve_with(2024, 18, 1, input, &parameters),
+        Ok(Answer::Integer(22))
+    );
+    assert_eq!(
+        advent_of_code.solve_with(2024, 18, 2, input, &parameters),
+        Ok(Answer::from("6,1"))
+    );
+
+    let input = "p=0,4 v=3,-3\np=6,3 v=-1,-3\np=10,3 v=-1,2\np=2,0 v=2,-1\np=0,0 v=1,3\n\
+                 p=3,0 v=-2,-2\np=7,6 v=-1,-3\np=3,0 v=-1,-2\np=9,3 v=2,3\np=7,3 v=-1,2\n\
+                 p=2,4 v=2,-3\np=9,5 v=-3,-3\n";
+    let parameters = Parameters::new().with("width", 11).with("height", 7);
+    assert_eq!(
+        advent_of_code.solve_with(2024, 14, 1, input, &parameters),
+        Ok(Answer::Integer(12))
+    );
+
+    let parameters = Parameters::new().with("length1", 20);
+    assert_eq!(
+        advent_of_code.solve_with(2016, 16, 1, "10000", &parameters),
+        Ok(Answer::from("01100"))
+    );
+
+    let parameters = Parameters::new().with("programs", 5).with("rounds", 2);
+    assert_eq!(
+        advent_of_code.solve_with(2017, 16, 1, "s1,x3/4,pe/b", &parameters),
+        Ok(Answer::from("baedc"))
+    );
+    assert_eq!(
+        advent_of_code.solve_with(2017, 16, 2, "s1,x3/4,pe/b", &parameters),
+        Ok(Answer::from("ceadb"))
+    );
+
+    let input = "initial state: #..#.#..##......###...###\n\n...## => #\n..#.. => #\n\
+                 .#... => #\n.#.#. => #\n.#.## => #\n.##.. => #\n.#### => #\n#.#.# => #\n\
+                 #.### => #\n##.#. => #\n##.## => #\n###.. => #\n###.# => #\n####. => #\n";
+    assert_eq!(
+        advent_of_code.solve(2018, 12, 1, input),
+        Ok(Answer::Integer(325))
+    );
+    let parameters = Parameters::new().with("generations2", 20);
+    assert_eq!(
+        advent_of_code.solve_with(2018, 12, 2, input, &parameters),
+        Ok(Answer::Integer(325))
+    );
+}
+
+#[test]
+fn invalid_parameters() {
+    let advent_of_code = AoC::new();
+
+    let unknown = Parameters::new().with("depth", 3);
+    assert_eq!(
+        advent_of_code.solve_with(2024, 18, 1, "0,0\n", &unknown),
+        Err(Error::InvalidParameter)
+    );
+    assert_eq!(
+        advent_of_code.solve_with(2015, 1, 1, "(()", &unknown),
+        Err(Error::InvalidParameter)
+    );
+
+    let wrong_type = Parameters::new().with("size", "large");
+    assert_eq!(
+        advent_of_code.solve_with(2024, 18, 1, "0,0\n", &wrong_type),
+        Err(Error::InvalidParameter)
+    );
+
+    let out_of_range = Parameters::new().with("size", 0);
+    assert_eq!(
+        advent_of_code.solve_with(2024, 18, 1, "0,0\n", &out_of_range),
+        Err(Error::InvalidParameter)
+    );
+    let out_of_range = Parameters::new().with("size", i64::MAX);
+    assert_eq!(
+        advent_of_code.solve_with(2024, 18, 2, "0,0\n", &out_of_range),
+        Err(Error::InvalidParameter)
+    );
+    let out_of_range = Parameters::new()
+        .with("width", 2_000_000_000)
+        .with("height", 2_000_000_000);
+    assert_eq!(
+        advent_of_code.solve_with(2024, 14, 2, "p=0,4 v=3,-3\n", &out_of_range),
+        Err(Error::InvalidParameter)
+    );
+    let out_of_range = Parameters::new().with("length2", i64::MAX);
+    assert_eq!(
+        advent_of_code.solve_with(2016, 16, 2, "10000\n", &out_of_range),
+        Err(Error::InvalidParameter)
+    );
+    let out_of_range = Parameters::new().with("programs", 27);
+    assert_eq!(
+        advent_of_code.solve_with(2017, 16, 1, "s1", &out_of_range),
+        Err(Error::InvalidParameter)
+    );
+    let out_of_range = Parameters::new().with("health", 0);
+    assert_eq!(
+        advent_of_code.solve_with(2015, 22, 2, "Hit Points: 13\nDamage: 8\n", &out_of_range),
+        Err(Error::InvalidParameter)
+    );
+    let unknown_register = Parameters::new().with("register", "c");
+    assert_eq!(
+        advent_of_code.solve_with(2015, 23, 1, "inc a\n", &unknown_register),
+        Err(Error::InvalidParameter)
+    );
+}
+
+#[test]
+fn declared_parameters() {
+    let advent_of_code = AoC::new();
+
+    let day = advent_of_code.year(2024).unwrap().day(18).unwrap();
+    let names = day
+        .parameters()
+        .into_iter()
+        .map(|parameter| parameter.name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["size", "bytes"]);
+
+    let day = advent_of_code.year(2015).unwrap().day(1).unwrap();
+    assert!(day.parameters().is_empty());
+}
+
+#[test]
+fn resolve() {
+    let declared = [
+        Parameter::integer("size", "The size", 71),
+        Parameter::text("password", "The password", "abcdefgh"),
+    ];
+
+    let resolved = Parameters::new()
+        .with("size", "7")
+        .with("password", 1234)
+        .resolve(&declared)
+        .unwrap();
+    assert_eq!(resolved.integer::<usize>("size"), Ok(7));
+    assert_eq!(resolved.text("password"), Ok("1234"));
+
+    let resolved = Parameters::new().resolve(&declared).unwrap();
+    assert_eq!(resolved.get("size"), Some(&ParameterValue::Integer(71)));
+    assert_eq!(resolved.text("password"), Ok("abcdefgh"));
+    assert_eq!(
+        resolved.integer::<u8>("password"),
+        Err(Error::InvalidParameter)
+    );
+    assert_eq!(resolved.integer::<u8>("size"), Ok(71));
+    assert_eq!(
+        resolved.integer::<i8>("missing"),
+        Err(Error::InvalidParameter)
+    );
+
+    assert_eq!("12".parse(), Ok(ParameterValue::Integer(12)));
+    assert_eq!("abc".parse(), Ok(ParameterValue::from("abc")));
+}
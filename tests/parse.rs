@@ -0,0 +1,68 @@
+use aocode::{AdventOfCode, Answer, AoC, Error};
+
+const ASSEMBUNNY: &str = "cpy 41 a\ninc a\ninc a\ndec a\njnz a 2\ndec a\n";
+
+#[test]
+fn parse_once_solve_both_parts() {
+    let advent_of_code = AoC::new();
+    let day = advent_of_code.year(2016).unwrap().day(12).unwrap();
+
+    let parsed = day.parse(ASSEMBUNNY).unwrap();
+    assert_eq!(day.solve_parsed(1, &parsed), Ok(Answer::Integer(42)));
+    assert_eq!(day.solve_parsed(2, &parsed), Ok(Answer::Integer(42)));
+    assert_eq!(day.solve_parsed(3, &parsed), Err(Error::UnavailablePart));
+    assert_eq!(day.solve(1, ASSEMBUNNY), Ok(Answer::Integer(42)));
+}
+
+#[test]
+fn parse_raw_input() {
+    let advent_of_code = AoC::new();
+    let day = advent_of_code.year(2015).unwrap().day(1).unwrap();
+
+    let parsed = day.parse("(()(()(").unwrap();
+    assert_eq!(day.solve_parsed(1, &parsed), Ok(Answer::Integer(3)));
+    assert_eq!(day.solve_parsed(2, &parsed), Err(Error::NoSolution));
+}
+
+#[test]
+fn parse_error() {
+    let advent_of_code = AoC::new();
+    let day = advent_of_code.year(2016).unwrap().day(12).unwrap();
+
+    assert!(matches!(
+        day.parse("cpy 41 e\n"),
+        Err(Error::InvalidInput {
+            line: 1,
+            column: 8,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn parsed_by_another_day() {
+    let advent_of_code = AoC::new();
+    let day11 = advent_of_code.year(2016).unwrap().day(11).unwrap();
+    let day12 = advent_of_code.year(2016).unwrap().day(12).unwrap();
+
+    let parsed = day12.parse(ASSEMBUNNY).unwrap();
+    assert!(matches!(
+        day11.solve_parsed(1, &parsed),
+        Err(Error::InvalidInput { line: 0, .. })
+    ));
+}
+
+#[test]
+fn benchmark_parse_and_solve_time() {
+    let benchmark = AoC::new().benchmark(2016, 12, 1, ASSEMBUNNY).unwrap();
+    assert_eq!(benchmark.answer, Answer::Integer(42));
+    assert_eq!(
+        benchmark.total_time(),
+        benchmark.parse_time + benchmark.solve_time
+    );
+
+    assert!(matches!(
+        AoC::new().benchmark(2016, 12, 1, "cpy 41 e\n"),
+        Err(Error::InvalidInput { .. })
+    ));
+}
@@ -0,0 +1,107 @@
+use aocode::{AdventOfCode, Answer, AoC, Parameters, Progress};
+use std::sync::{Arc, Mutex};
+
+/// A reporter recording every reported phase and fraction.
+#[derive(Default)]
+struct Recorder(Mutex<Vec<(String, f64)>>);
+impl Progress for Recorder {
+    fn report(&self, phase: &str, fraction: f64) {
+        self.0.lock().unwrap().push((phase.to_string(), fraction));
+    }
+}
+impl Recorder {
+    /// Get the reported fractions of a phase, checking that they increase within bounds.
+    fn phase(&self, phase: &str) -> Vec<f64> {
+        let fractions = self
+            .0
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(name, _)| name == phase)
+            .map(|&(_, fraction)| fraction)
+            .collect::<Vec<_>>();
+        assert!(
+            fractions
+                .iter()
+                .all(|fraction| (0.0..=1.0).contains(fraction))
+        );
+        assert!(fractions.is_sorted());
+        fractions
+    }
+}
+
+#[test]
+fn dragon_checksum() {
+    let recorder = Arc::new(Recorder::default());
+    let parameters = Parameters::new().with("length1", 20);
+    assert_eq!(
+        AoC::new().solve_with_progress(2016, 16, 1, "10000", &parameters, recorder.clone()),
+        Ok(Answer::from("01100"))
+    );
+
+    let phases = recorder
+        .0
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(phase, _)| phase.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(phases.first().map(String::as_str), Some("generating data"));
+    assert_eq!(phases.last().map(String::as_str), Some("checksum"));
+    assert_eq!(recorder.phase("generating data").last(), Some(&1.0));
+    assert_eq!(recorder.phase("checksum"), [0.5, 1.0]);
+}
+
+#[test]
+fn state_search_and_byte_drops() {
+    let advent_of_code = AoC::new();
+
+    let recorder = Arc::new(Recorder::default());
+    let input = "The first floor contains a hydrogen-compatible microchip and a lithium-compatible microchip.\n\
+                 The second floor contains a hydrogen generator.\n\
+                 The third floor contains a lithium generator.\n\
+                 The fourth floor contains nothing relevant.\n";
+    assert_eq!(
+        advent_of_code.solve_with_progress(
+            2016,
+            11,
+            1,
+            input,
+            &Parameters::new(),
+            recorder.clone()
+        ),
+        Ok(Answer::Integer(11))
+    );
+    assert!(!recorder.phase("searching").is_empty());
+
+    let recorder = Arc::new(Recorder::default());
+    let input = "5,4\n4,2\n4,5\n3,0\n2,1\n6,3\n2,4\n1,5\n0,6\n3,3\n2,6\n5,1\n1,2\n5,5\n2,5\n\
+                 6,5\n1,4\n0,4\n6,4\n1,1\n6,1\n1,0\n0,5\n1,6\n2,0\n";
+    let parameters = Parameters::new().with("size", 7).with("bytes", 12);
+    assert_eq!(
+        advent_of_code.solve_with_progress(2024, 18, 2, input, &parameters, recorder.clone()),
+        Ok(Answer::from("6,1"))
+    );
+    assert_eq!(recorder.phase("dropping bytes"), [0.0]);
+}
+
+#[test]
+fn closure_reporter() {
+    let advent_of_code = AoC::new();
+    let reports = Arc::new(Mutex::new(0));
+
+    let counter = reports.clone();
+    let progress = Arc::new(move |_: &str, _: f64| *counter.lock().unwrap() += 1);
+    let parameters = Parameters::new().with("length1", 20);
+    assert!(
+        advent_of_code
+            .solve_with_progress(2016, 16, 1, "10000", &parameters, progress)
+            .is_ok()
+    );
+    let reported = *reports.lock().unwrap();
+    assert!(reported > 0);
+
+    // the reporter does not outlive the solve
+    assert!(advent_of_code.solve(2016, 16, 1, "10000").is_ok());
+    assert_eq!(*reports.lock().unwrap(), reported);
+}
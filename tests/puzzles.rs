@@ -0,0 +1,7 @@
+//! The tests of the solutions against the test data.
+//! A test is generated by the build script for every expected output in `tests/test-data/output`.
+
+mod runner;
+use runner::run_test;
+
+include!(concat!(env!("OUT_DIR"), "/puzzles.rs"));
@@ -0,0 +1,155 @@
+use aocode::{AdventOfCode, Answer, AoC, Error, ReportEntry, Year};
+use std::path::PathBuf;
+use std::time::Duration;
+use std::{env, fs, process};
+
+/// Create an empty input directory unique to the test.
+fn input_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aocode-report-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn solve_all() {
+    let dir = input_dir("solve-all");
+    fs::create_dir_all(dir.join("year2015")).unwrap();
+    fs::create_dir_all(dir.join("year2017")).unwrap();
+    fs::write(dir.join("year2015/day01.txt"), "(()))\r\n").unwrap();
+    fs::write(dir.join("year2015/day02.txt"), "2x3x4\n1x1x10\n").unwrap();
+    fs::write(dir.join("year2017/day01.txt"), "1122x\n").unwrap();
+
+    let report = AoC::new().solve_all(&dir, Duration::MAX).unwrap();
+    let report_2017 = AoC::new()
+        .solve_all_in_year(2017, &dir, Duration::MAX)
+        .unwrap();
+    let report_2014 = AoC::new()
+        .solve_all_in_year(2014, &dir, Duration::MAX)
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    let puzzles = report
+        .entries
+        .iter()
+        .map(|entry| (entry.year, entry.day, entry.part))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        puzzles,
+        [
+            (2015, 1, 1),
+            (2015, 1, 2),
+            (2015, 2, 1),
+            (2015, 2, 2),
+            (2017, 1, 1),
+            (2017, 1, 2)
+        ]
+    );
+    assert_eq!(
+        report.entry(2015, 1, 2).unwrap().result,
+        Ok(Answer::Integer(5))
+    );
+    assert_eq!(
+        report.entry(2015, 2, 1).unwrap().result,
+        Ok(Answer::Integer(101))
+    );
+    assert!(report.entry(2016, 1, 1).is_none());
+    let results = |entries: &[ReportEntry]| {
+        entries
+            .iter()
+            .map(|entry| (entry.year, entry.day, entry.part, entry.result.clone()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(results(&report_2017.entries), results(&report.entries[4..]));
+    assert!(report_2014.entries.is_empty());
+
+    let failures = report.failures().collect::<Vec<_>>();
+    assert_eq!(failures.len(), 2);
+    assert!(
+        failures
+            .iter()
+            .all(|entry| matches!(entry.result, Err(Error::InvalidInput { .. })))
+    );
+    assert_eq!(
+        report.solve_time(),
+        report.entries.iter().map(|entry| entry.time).sum()
+    );
+}
+
+#[test]
+fn timeout() {
+    let dir = input_dir("timeout");
+    fs::create_dir_all(dir.join("year2015")).unwrap();
+    fs::write(dir.join("year2015/day01.txt"), "(()))\n").unwrap();
+
+    let report = AoC::new().solve_all(&dir, Duration::ZERO).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(report.entries.len(), 2);
+    assert!(
+        report
+            .entries
+            .iter()
+            .all(|entry| entry.result == Err(Error::Timeout))
+    );
+}
+
+/// An [AdventOfCode] whose solutions of the second parts panic.
+struct Panicking(AoC);
+impl AdventOfCode for Panicking {
+    fn years<'a>(&'a self) -> Box<dyn Iterator<Item = &'a dyn Year> + 'a> {
+        self.0.years()
+    }
+
+    fn solve_with_deadline(
+        &self,
+        year: usize,
+        day: usize,
+        part: usize,
+        input: &str,
+        timeout: Duration,
+    ) -> Result<Answer, Error> {
+        assert_eq!(part, 1, "the second part panics");
+        self.0.solve_with_deadline(year, day, part, input, timeout)
+    }
+}
+
+#[test]
+fn panic() {
+    let dir = input_dir("panic");
+    fs::create_dir_all(dir.join("year2015")).unwrap();
+    fs::write(dir.join("year2015/day01.txt"), "(()))\n").unwrap();
+    fs::write(dir.join("year2015/day02.txt"), "2x3x4\n").unwrap();
+
+    let report = Panicking(AoC::new())
+        .solve_all(&dir, Duration::MAX)
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    let results = report
+        .entries
+        .iter()
+        .map(|entry| (entry.day, entry.part, entry.result.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        results,
+        [
+            (1, 1, Ok(Answer::Integer(-1))),
+            (1, 2, Err(Error::Panicked)),
+            (2, 1, Ok(Answer::Integer(58))),
+            (2, 2, Err(Error::Panicked))
+        ]
+    );
+}
+
+#[test]
+fn empty_and_missing_directories() {
+    let dir = input_dir("empty");
+    let report = AoC::new().solve_all(&dir, Duration::MAX).unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(report.entries.is_empty());
+
+    // a missing directory has no inputs either
+    let report = AoC::new().solve_all(&dir, Duration::MAX).unwrap();
+    assert!(report.entries.is_empty());
+}
@@ -1,24 +1,48 @@
 use aocode::{AdventOfCode, AoC};
-use std::fs;
+use std::path::Path;
+use std::{env, fs};
+
+/// The environment variable which makes [run_test] write the answers as the new expected outputs.
+const BLESS: &str = "AOCODE_BLESS";
+
+/// A function that runs a test for a given year, day, input and part against the expected output.
+/// The main input of a day is `dayDD.txt`, while its other inputs are named `dayDD/NAME.txt`
+/// and have their expected outputs in `dayDD/NAME/partN.txt`.
+/// If `AOCODE_BLESS` is set to anything but `0` or nothing, the answer is written as the new expected output
+/// and the changes are printed, e.g. `AOCODE_BLESS=1 cargo test --test puzzles -- --nocapture`.
+pub fn run_test(year: usize, day: usize, name: Option<&str>, part: usize) {
+    let (input_path, output_dir) = match name {
+        Some(name) => (
+            format!("year{year:04}/day{day:02}/{name}.txt"),
+            format!("year{year:04}/day{day:02}/{name}"),
+        ),
+        None => (
+            format!("year{year:04}/day{day:02}.txt"),
+            format!("year{year:04}/day{day:02}"),
+        ),
+    };
 
-/// A function that runs a test for a given year, day, part and expected output.
-pub fn run_test(year: usize, day: usize, part: usize) {
     // load input
-    let input = fs::read_to_string(format!(
-        "./tests/test-data/input/year{year:04}/day{day:02}.txt"
-    ))
-    .expect("Failed to read the input file!")
-    .replace("\r\n", "\n");
+    let input = fs::read_to_string(format!("./tests/test-data/input/{input_path}"))
+        .expect("Failed to read the input file!")
+        .replace("\r\n", "\n");
 
-    // load output
-    let output = fs::read_to_string(format!(
-        "./tests/test-data/output/year{year:04}/day{day:02}/part{part:01}.txt"
-    ))
-    .expect("Failed to read the output file!")
-    .replace("\r\n", "\n");
+    let output_path = format!("./tests/test-data/output/{output_dir}/part{part:01}.txt");
 
     // test library
     let output_lib = AoC::new().solve(year, day, part, &input).unwrap();
 
-    assert_eq!(output_lib.trim(), output.trim());
+    if env::var(BLESS).is_ok_and(|bless| !bless.is_empty() && bless != "0") {
+        if let Some(diff) = aocode::golden::bless(Path::new(&output_path), &output_lib).unwrap() {
+            println!("\nBlessed {output_path}:\n{diff}");
+        }
+        return;
+    }
+
+    // load output
+    let output = fs::read_to_string(output_path)
+        .expect("Failed to read the output file!")
+        .replace("\r\n", "\n");
+
+    assert_eq!(output_lib, output);
 }
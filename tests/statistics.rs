@@ -0,0 +1,74 @@
+use aocode::{AdventOfCode, Answer, AoC, BenchmarkOptions, Error, Runs, Statistics};
+use std::time::Duration;
+
+#[test]
+fn summary_statistics() {
+    let statistics = Statistics::new(
+        [5, 1, 4, 2, 3, 10]
+            .into_iter()
+            .map(Duration::from_millis)
+            .collect(),
+    );
+
+    assert_eq!(statistics.samples().len(), 6);
+    assert_eq!(statistics.min(), Duration::from_millis(1));
+    assert_eq!(statistics.max(), Duration::from_millis(10));
+    assert_eq!(statistics.mean(), Duration::from_millis(25) / 6);
+    assert_eq!(statistics.median(), Duration::from_micros(3500));
+    assert_eq!(statistics.percentile(0.0), Duration::from_millis(1));
+    assert_eq!(statistics.percentile(50.0), Duration::from_millis(3));
+    assert_eq!(statistics.percentile(90.0), Duration::from_millis(10));
+    assert_eq!(statistics.percentile(100.0), Duration::from_millis(10));
+
+    // the sample standard deviation of 1, 2, 3, 4, 5 and 10 ms
+    let std_dev = statistics.std_dev().as_secs_f64() * 1000.0;
+    assert!((std_dev - 3.188_52).abs() < 1e-4);
+
+    let single = Statistics::new(vec![Duration::from_millis(7)]);
+    assert_eq!(single.median(), Duration::from_millis(7));
+    assert_eq!(single.std_dev(), Duration::ZERO);
+}
+
+#[test]
+fn repeated_runs() {
+    let advent_of_code = AoC::new();
+
+    let options = BenchmarkOptions::iterations(10).with_warmup(2);
+    let statistics = advent_of_code
+        .benchmark_statistics(2015, 1, 1, "(()(()(", &options)
+        .unwrap();
+    assert_eq!(statistics.answer, Answer::Integer(3));
+    assert_eq!(statistics.parse.samples().len(), 10);
+    assert_eq!(statistics.solve.samples().len(), 10);
+    assert_eq!(statistics.total.samples().len(), 10);
+    assert!(statistics.total.min() >= statistics.solve.min());
+
+    let options = BenchmarkOptions::time_budget(Duration::from_millis(20));
+    assert_eq!(options.runs, Runs::Budget(Duration::from_millis(20)));
+    let statistics = advent_of_code
+        .benchmark_statistics(2015, 1, 1, "(()(()(", &options)
+        .unwrap();
+    assert!(!statistics.total.samples().is_empty());
+
+    // a single run is measured even without a count
+    let options = BenchmarkOptions::iterations(0).with_warmup(0);
+    let statistics = advent_of_code
+        .benchmark_statistics(2015, 1, 1, "(()(()(", &options)
+        .unwrap();
+    assert_eq!(statistics.total.samples().len(), 1);
+}
+
+#[test]
+fn failed_runs() {
+    let advent_of_code = AoC::new();
+    let options = BenchmarkOptions::default();
+
+    assert_eq!(
+        advent_of_code.benchmark_statistics(2015, 1, 3, "(()", &options),
+        Err(Error::UnavailablePart)
+    );
+    assert!(matches!(
+        advent_of_code.benchmark_statistics(2015, 2, 1, "2x3\n", &options),
+        Err(Error::InvalidInput { .. })
+    ));
+}